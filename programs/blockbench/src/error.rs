@@ -69,4 +69,13 @@ pub enum BlockbenchError {
 
     #[msg("Exclusion range check failed: low leaf does not bound the query")]
     ExclusionRangeInvalid,
+
+    #[msg("Scan cursor is past the end of the provided accounts")]
+    InvalidScanCursor,
+
+    #[msg("Zipfian theta must be between 1 and 99 (hundredths)")]
+    InvalidZipfianTheta,
+
+    #[msg("Operation consumed more compute units than allowed")]
+    ComputeBudgetExceeded,
 }
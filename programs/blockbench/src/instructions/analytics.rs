@@ -59,7 +59,7 @@ pub fn analytics_aggregate<'info>(
         aggregation_type: aggregation_type as u8,
         result_value,
         records_scanned: count,
-        compute_units_used: 0, // Will be populated by caller
+        compute_units_used: 0, // Filled in by compute_fn_measured! in lib.rs
     };
     
     msg!(
@@ -76,20 +76,19 @@ pub fn analytics_aggregate<'info>(
 pub fn analytics_scan<'info>(
     ctx: Context<'info, AnalyticsScan<'info>>,
     filter_threshold: u64,
-) -> Result<u32> {
+) -> Result<ScanResult> {
     let remaining = ctx.remaining_accounts;
     
-    let mut matches: u32 = 0;
-    let mut scanned: u32 = 0;
+    let mut result = ScanResult::default();
     
     // Scan through all provided accounts and filter
     for account in remaining.iter() {
-        scanned += 1;
+        result.records_scanned += 1;
         
         if let Ok(io_account) = Account::<IoHeavyAccount>::try_from(account) {
-            // Filter: count accounts where write_count > threshold
+            // Filter: keep accounts where write_count > threshold
             if io_account.write_count > filter_threshold {
-                matches += 1;
+                result.record_match(account.key().to_bytes());
             }
         }
     }
@@ -97,11 +96,11 @@ pub fn analytics_scan<'info>(
     msg!(
         "Analytics Scan: threshold={}, matches={}, scanned={}",
         filter_threshold,
-        matches,
-        scanned
+        result.matched,
+        result.records_scanned
     );
     
-    Ok(matches)
+    Ok(result)
 }
 
 #[derive(Accounts)]
@@ -5,6 +5,10 @@
 
 use anchor_lang::prelude::*;
 use crate::state::*;
+use crate::error::BlockbenchError;
+
+/// Maximum no-op sub-transactions per `do_nothing_batch` call
+pub const MAX_DO_NOTHING_BATCH: u16 = 4096;
 
 /// DoNothing benchmark - empty instruction
 pub fn do_nothing(_ctx: Context<DoNothing>) -> Result<()> {
@@ -20,6 +24,33 @@ pub fn do_nothing_nonce(_ctx: Context<DoNothingNonce>, _nonce: u32) -> Result<()
     Ok(())
 }
 
+/// DoNothing batch - `count` no-op sub-transactions in one instruction
+///
+/// Each iteration mixes a distinct nonce (`base_nonce + i`) into an accumulator that is
+/// returned, so the loop can't be optimized away. Dividing the instruction's CU by
+/// `count` gives the per-op overhead with the transaction floor amortized out.
+pub fn do_nothing_batch(_ctx: Context<DoNothing>, count: u16, base_nonce: u64) -> Result<u64> {
+    require!(count > 0, BlockbenchError::InvalidConfig);
+    require!(
+        count <= MAX_DO_NOTHING_BATCH,
+        BlockbenchError::OperationCountExceeded
+    );
+
+    Ok(batch_accumulator(count, base_nonce))
+}
+
+/// splitmix64 finalizer folded over `base_nonce..base_nonce + count`
+fn batch_accumulator(count: u16, base_nonce: u64) -> u64 {
+    let mut acc: u64 = 0;
+    for i in 0..count as u64 {
+        let mut z = base_nonce.wrapping_add(i).wrapping_add(0x9E37_79B9_7F4A_7C15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        acc = acc.rotate_left(5) ^ z ^ (z >> 31);
+    }
+    acc
+}
+
 #[derive(Accounts)]
 pub struct DoNothing<'info> {
     /// The payer for the transaction (required for fee accounting)
@@ -40,3 +71,16 @@ pub struct DoNothingNonce<'info> {
     )]
     pub benchmark_state: Option<Account<'info, BlockbenchState>>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn batch_accumulator_depends_on_every_nonce() {
+        assert_eq!(batch_accumulator(16, 7), batch_accumulator(16, 7));
+        assert_ne!(batch_accumulator(16, 7), batch_accumulator(16, 8));
+        assert_ne!(batch_accumulator(16, 7), batch_accumulator(17, 7));
+        assert_ne!(batch_accumulator(1, 0), 0);
+    }
+}
@@ -98,6 +98,55 @@ pub fn io_heavy_read<'info>(
     Ok(checksum)
 }
 
+/// IOHeavy: Resumable range scan benchmark
+///
+/// Reads up to `max_accounts` of `remaining_accounts` starting at `start_cursor` and returns
+/// where the next call should resume, so a range larger than one transaction can be scanned
+/// as a chain of calls over the same account list.
+pub fn io_heavy_scan<'info>(
+    ctx: Context<'info, IoHeavyScan<'info>>,
+    start_cursor: u64,
+    max_accounts: u8,
+) -> Result<ScanCursor> {
+    require!(max_accounts > 0, BlockbenchError::InvalidConfig);
+    require!(max_accounts <= MAX_IO_OPS, BlockbenchError::TooManyIoOperations);
+
+    let remaining = ctx.remaining_accounts;
+    let window = ScanCursor::window(start_cursor, max_accounts, remaining.len())
+        .ok_or(BlockbenchError::InvalidScanCursor)?;
+
+    let mut checksum: u64 = 0;
+    let mut total_bytes_read: u64 = 0;
+
+    for account in &remaining[window.clone()] {
+        // Non-IoHeavy accounts still advance the cursor
+        if let Ok(io_account) = Account::<IoHeavyAccount>::try_from(account) {
+            total_bytes_read += io_account.data.len() as u64;
+
+            for byte in &io_account.data {
+                checksum = checksum.wrapping_add(*byte as u64);
+            }
+        }
+    }
+
+    let cursor = ScanCursor {
+        next: window.end as u64,
+        done: window.end == remaining.len(),
+        checksum,
+    };
+
+    msg!(
+        "IOHeavy Scan: range={}..{}, bytes_read={}, checksum={}, done={}",
+        window.start,
+        window.end,
+        total_bytes_read,
+        checksum,
+        cursor.done
+    );
+
+    Ok(cursor)
+}
+
 /// IOHeavy: Mixed read-write benchmark
 pub fn io_heavy_mixed<'info>(
     ctx: Context<'info, IoHeavyMixed<'info>>,
@@ -174,6 +223,12 @@ pub struct IoHeavyRead<'info> {
     // Remaining accounts are the accounts to read from
 }
 
+#[derive(Accounts)]
+pub struct IoHeavyScan<'info> {
+    pub payer: Signer<'info>,
+    // Remaining accounts are the full range being scanned
+}
+
 #[derive(Accounts)]
 #[instruction(read_ratio: u8, total_ops: u8)]
 pub struct IoHeavyMixed<'info> {
@@ -5,6 +5,7 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::error::BlockbenchError;
+use gridtokenx_shared::{BenchmarkResult, BenchmarkSuite};
 
 /// Record a benchmark metric
 pub fn record_metric(
@@ -49,6 +50,8 @@ pub fn record_metric(
     if compute_units > metrics.max_compute_units {
         metrics.max_compute_units = compute_units;
     }
+
+    metrics.record_histograms(latency_us, compute_units);
     
     // Update per-type counters
     match benchmark_type {
@@ -123,14 +126,14 @@ pub fn finalize_benchmark(ctx: Context<FinalizeBenchmark>) -> Result<BenchmarkSu
     let summary = BenchmarkSummary {
         tps,
         avg_latency_us,
-        // Percentiles require histogram data - use estimates for now
-        p50_latency_us: avg_latency_us,
-        p90_latency_us: avg_latency_us.saturating_mul(2),
-        p95_latency_us: avg_latency_us.saturating_mul(3),
-        p99_latency_us: metrics.max_latency_us,
+        p50_latency_us: metrics.latency_percentile_us(50),
+        p90_latency_us: metrics.latency_percentile_us(90),
+        p95_latency_us: metrics.latency_percentile_us(95),
+        p99_latency_us: metrics.latency_percentile_us(99),
         success_rate_bps,
         avg_compute_units,
         duration_seconds,
+        median_compute_units: metrics.compute_unit_percentile(50),
     };
     
     msg!(
@@ -139,6 +142,17 @@ pub fn finalize_benchmark(ctx: Context<FinalizeBenchmark>) -> Result<BenchmarkSu
         summary.avg_latency_us,
         summary.success_rate_bps as f64 / 100.0
     );
+
+    let (latency, throughput) = metrics.to_common_summary(duration_seconds);
+    emit!(BenchmarkFinalized {
+        result: BenchmarkResult {
+            suite: BenchmarkSuite::Blockbench,
+            run_id: state.run_id,
+            latency,
+            throughput,
+            timestamp: clock.unix_timestamp,
+        },
+    });
     
     Ok(summary)
 }
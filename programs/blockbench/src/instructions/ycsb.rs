@@ -75,6 +75,46 @@ pub fn ycsb_read(ctx: Context<YcsbRead>, key: [u8; 32]) -> Result<Vec<u8>> {
     Ok(record.value.clone())
 }
 
+/// YCSB: Read the record picked by the zipfian generator
+///
+/// The key is derived on-chain from `seed` and `theta` over the store's current
+/// `record_count`, so a run is reproducible from its seed list alone. The caller
+/// still passes the matching record account; a wrong one fails the key check.
+pub fn ycsb_read_zipfian(ctx: Context<YcsbReadZipfian>, seed: u64, theta: u16) -> Result<Vec<u8>> {
+    require!(
+        theta > 0 && theta < ZIPFIAN_THETA_SCALE,
+        BlockbenchError::InvalidZipfianTheta
+    );
+    let item_count = ctx.accounts.ycsb_store.record_count;
+    require!(item_count > 0, BlockbenchError::YcsbRecordNotFound);
+
+    let key = ycsb_zipfian_key(item_count, seed, theta);
+    let record = &ctx.accounts.record;
+    let expected = Pubkey::create_program_address(
+        &[
+            b"ycsb_record",
+            ctx.accounts.ycsb_store.key().as_ref(),
+            &key,
+            &[record.bump],
+        ],
+        &crate::ID,
+    )
+    .map_err(|_| error!(BlockbenchError::YcsbRecordNotFound))?;
+    require!(
+        record.key() == expected && record.key == key,
+        BlockbenchError::YcsbRecordNotFound
+    );
+
+    msg!(
+        "YCSB Read (zipfian): key={:?}, value_size={}, version={}",
+        &key[..4],
+        record.value.len(),
+        record.version
+    );
+
+    Ok(record.value.clone())
+}
+
 /// YCSB: Update an existing record
 pub fn ycsb_update(
     ctx: Context<YcsbUpdate>,
@@ -148,6 +188,69 @@ pub fn ycsb_batch_insert(
     Ok(())
 }
 
+// ═══════════════════════════════════════════════════════════════════════════════
+// KEY GENERATION
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// `theta` is expressed in hundredths, like `BlockbenchConfig::zipfian_constant` (99 = 0.99)
+pub const ZIPFIAN_THETA_SCALE: u16 = 100;
+
+/// Canonical 32-byte key of the `index`-th record: little-endian index, zero padded.
+/// Loaders must insert records under these keys for the zipfian reads to find them.
+pub fn ycsb_key(index: u64) -> [u8; 32] {
+    let mut key = [0u8; 32];
+    key[..8].copy_from_slice(&index.to_le_bytes());
+    key
+}
+
+/// Deterministic zipfian key over `item_count` records (Gray et al., as used by YCSB).
+///
+/// Rank 0 is the hottest item. `theta` must lie in (0, 100). The generalized harmonic
+/// number is approximated with Euler-Maclaurin so the cost is constant in `item_count`.
+pub fn ycsb_zipfian_key(item_count: u32, seed: u64, theta: u16) -> [u8; 32] {
+    ycsb_key(zipfian_rank(item_count as u64, seed, theta))
+}
+
+fn zipfian_rank(n: u64, seed: u64, theta: u16) -> u64 {
+    if n <= 1 {
+        return 0;
+    }
+    let theta = theta as f64 / ZIPFIAN_THETA_SCALE as f64;
+    let nf = n as f64;
+    let zeta2 = 1.0 + 0.5f64.powf(theta);
+    let zetan = zeta_approx(nf, theta);
+    let alpha = 1.0 / (1.0 - theta);
+    let eta = (1.0 - (2.0 / nf).powf(1.0 - theta)) / (1.0 - zeta2 / zetan);
+
+    let u = unit_interval(seed);
+    let uz = u * zetan;
+    if uz < 1.0 {
+        return 0;
+    }
+    if uz < zeta2 {
+        return 1;
+    }
+    let rank = (nf * (eta * u - eta + 1.0).powf(alpha)) as u64;
+    rank.min(n - 1)
+}
+
+/// sum_{i=1..n} i^-theta via Euler-Maclaurin (exact enough for bucketing ranks)
+fn zeta_approx(n: f64, theta: f64) -> f64 {
+    let one_minus = 1.0 - theta;
+    (n.powf(one_minus) - 1.0) / one_minus
+        + 0.5 * (1.0 + n.powf(-theta))
+        + theta / 12.0 * (1.0 - n.powf(-theta - 1.0))
+}
+
+/// splitmix64 of `seed`, mapped to [0, 1) with 53 bits of precision
+fn unit_interval(seed: u64) -> f64 {
+    let mut z = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^= z >> 31;
+    (z >> 11) as f64 / (1u64 << 53) as f64
+}
+
 // ═══════════════════════════════════════════════════════════════════════════════
 // ACCOUNT CONTEXTS
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -212,6 +315,20 @@ pub struct YcsbRead<'info> {
     pub record: Account<'info, YcsbRecord>,
 }
 
+#[derive(Accounts)]
+pub struct YcsbReadZipfian<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [b"ycsb_store", authority.key().as_ref()],
+        bump = ycsb_store.bump,
+    )]
+    pub ycsb_store: Account<'info, YcsbStore>,
+    
+    /// PDA for the generated key; checked in the handler
+    pub record: Account<'info, YcsbRecord>,
+}
+
 #[derive(Accounts)]
 #[instruction(key: [u8; 32], value: Vec<u8>)]
 pub struct YcsbUpdate<'info> {
@@ -273,3 +390,52 @@ pub struct YcsbBatchInsert<'info> {
     
     pub system_program: Program<'info, System>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rank_of(key: [u8; 32]) -> u64 {
+        u64::from_le_bytes(key[..8].try_into().unwrap())
+    }
+
+    #[test]
+    fn zipfian_key_is_deterministic_and_in_range() {
+        for seed in 0..1_000u64 {
+            let key = ycsb_zipfian_key(50, seed, 99);
+            assert_eq!(key, ycsb_zipfian_key(50, seed, 99));
+            assert!(rank_of(key) < 50);
+            assert_eq!(key[8..], [0u8; 24]);
+        }
+        assert_eq!(ycsb_zipfian_key(1, 42, 99), ycsb_key(0));
+    }
+
+    #[test]
+    fn zipfian_distribution_is_skewed_toward_low_ranks() {
+        const N: u32 = 1_000;
+        const SAMPLES: u64 = 20_000;
+        let mut counts = vec![0u64; N as usize];
+        for seed in 0..SAMPLES {
+            counts[rank_of(ycsb_zipfian_key(N, seed, 99)) as usize] += 1;
+        }
+        // theta = 0.99 over 1000 items: rank 0 draws ~13% of samples, the top 1% ~40%
+        let hottest = counts[0];
+        let top_ten: u64 = counts[..10].iter().sum();
+        let bottom_half: u64 = counts[500..].iter().sum();
+        assert!(hottest > SAMPLES / 10, "rank 0 drew {hottest}");
+        assert!(top_ten > SAMPLES * 3 / 10, "top 10 drew {top_ten}");
+        assert!(bottom_half < SAMPLES / 5, "bottom half drew {bottom_half}");
+        assert!(counts[0] > counts[1] && counts[1] > counts[9]);
+    }
+
+    #[test]
+    fn lower_theta_flattens_the_distribution() {
+        const SAMPLES: u64 = 10_000;
+        let hot = |theta| {
+            (0..SAMPLES)
+                .filter(|&seed| rank_of(ycsb_zipfian_key(1_000, seed, theta)) < 10)
+                .count()
+        };
+        assert!(hot(99) > 2 * hot(50));
+    }
+}
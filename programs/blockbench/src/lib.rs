@@ -38,7 +38,7 @@ pub use state::*;
 declare_id!("9AM4JkvUkK8ZfRneTAQVahFgPe9rEisNkB9byRfZ4TwT");
 
 #[cfg(feature = "localnet")]
-use compute_debug::{compute_checkpoint, compute_fn};
+use compute_debug::{compute_checkpoint, compute_fn, compute_fn_measured};
 
 #[cfg(not(feature = "localnet"))]
 macro_rules! compute_fn {
@@ -47,6 +47,12 @@ macro_rules! compute_fn {
     };
 }
 #[cfg(not(feature = "localnet"))]
+macro_rules! compute_fn_measured {
+    ($name:expr => $block:block) => {
+        ($block, 0u64)
+    };
+}
+#[cfg(not(feature = "localnet"))]
 #[allow(unused_macros)]
 macro_rules! compute_checkpoint {
     ($name:expr) => {};
@@ -111,6 +117,13 @@ pub mod blockbench {
         })
     }
 
+    pub fn do_nothing_batch(ctx: Context<DoNothing>, count: u16, base_nonce: u64) -> Result<u64> {
+        let res = compute_fn!("do_nothing_batch" => {
+            instructions::do_nothing_batch(ctx, count, base_nonce)
+        })?;
+        Ok(res)
+    }
+
     pub fn cpu_heavy_sort(ctx: Context<CpuHeavy>, array_size: u16, seed: u64) -> Result<u64> {
         let res = compute_fn!("cpu_heavy_sort" => {
             instructions::cpu_heavy_sort(ctx, array_size, seed)
@@ -118,6 +131,23 @@ pub mod blockbench {
         Ok(res)
     }
 
+    pub fn cpu_heavy_sort_bounded(
+        ctx: Context<CpuHeavy>,
+        array_size: u16,
+        seed: u64,
+        max_cu: u64,
+    ) -> Result<u64> {
+        let (res, compute_units_used) = compute_fn_measured!("cpu_heavy_sort_bounded" => {
+            instructions::cpu_heavy_sort(ctx, array_size, seed)
+        });
+        let checksum = res?;
+        require!(
+            compute_units_used <= max_cu,
+            BlockbenchError::ComputeBudgetExceeded
+        );
+        Ok(checksum)
+    }
+
     pub fn cpu_heavy_loop(ctx: Context<CpuHeavy>, iterations: u32) -> Result<u64> {
         let res = compute_fn!("cpu_heavy_loop" => {
             instructions::cpu_heavy_loop(ctx, iterations)
@@ -164,6 +194,17 @@ pub mod blockbench {
         Ok(res)
     }
 
+    pub fn io_heavy_scan<'info>(
+        ctx: Context<'info, IoHeavyScan<'info>>,
+        start_cursor: u64,
+        max_accounts: u8,
+    ) -> Result<ScanCursor> {
+        let res = compute_fn!("io_heavy_scan" => {
+            instructions::io_heavy_scan(ctx, start_cursor, max_accounts)
+        })?;
+        Ok(res)
+    }
+
     pub fn io_heavy_mixed<'info>(
         ctx: Context<'info, IoHeavyMixed<'info>>,
         read_ratio: u8,
@@ -178,16 +219,19 @@ pub mod blockbench {
         ctx: Context<'info, AnalyticsAggregate<'info>>,
         aggregation_type: AggregationType,
     ) -> Result<AnalyticsResult> {
-        let res = compute_fn!("analytics_aggregate" => {
+        let (res, compute_units_used) = compute_fn_measured!("analytics_aggregate" => {
             instructions::analytics_aggregate(ctx, aggregation_type)
-        })?;
-        Ok(res)
+        });
+        Ok(AnalyticsResult {
+            compute_units_used,
+            ..res?
+        })
     }
 
     pub fn analytics_scan<'info>(
         ctx: Context<'info, AnalyticsScan<'info>>,
         filter_threshold: u64,
-    ) -> Result<u32> {
+    ) -> Result<ScanResult> {
         let res = compute_fn!("analytics_scan" => {
             instructions::analytics_scan(ctx, filter_threshold)
         })?;
@@ -213,6 +257,17 @@ pub mod blockbench {
         Ok(res)
     }
 
+    pub fn ycsb_read_zipfian(
+        ctx: Context<YcsbReadZipfian>,
+        seed: u64,
+        theta: u16,
+    ) -> Result<Vec<u8>> {
+        let res = compute_fn!("ycsb_read_zipfian" => {
+            instructions::ycsb_read_zipfian(ctx, seed, theta)
+        })?;
+        Ok(res)
+    }
+
     pub fn ycsb_update(ctx: Context<YcsbUpdate>, key: [u8; 32], value: Vec<u8>) -> Result<()> {
         compute_fn!("ycsb_update" => {
             instructions::ycsb_update(ctx, key, value)
@@ -3,6 +3,7 @@
 //! Defines account structures for benchmarking state management.
 
 use anchor_lang::prelude::*;
+use gridtokenx_shared::{BenchmarkResult, LatencySummary, ThroughputSummary};
 
 // ═══════════════════════════════════════════════════════════════════════════════
 // BENCHMARK CONFIGURATION AND METRICS
@@ -75,6 +76,58 @@ impl BlockbenchConfig {
     pub const LEN: usize = 1 + 8 + 2 + 8 + 4 + 1 + 2 + 1 + 2;
 }
 
+/// Number of buckets in the latency and compute-unit histograms
+pub const HISTOGRAM_BUCKETS: usize = 10;
+
+/// Exclusive upper edges (microseconds) of the first nine latency buckets:
+/// <100us, <500us, <1ms, <5ms, <10ms, <50ms, <100ms, <500ms, <1s; the last bucket is >=1s
+pub const LATENCY_BUCKET_EDGES_US: [u64; HISTOGRAM_BUCKETS - 1] = [
+    100, 500, 1_000, 5_000, 10_000, 50_000, 100_000, 500_000, 1_000_000,
+];
+
+/// Exclusive upper edges of the first nine compute-unit buckets; the last bucket is >=1.4M CU
+pub const COMPUTE_UNIT_BUCKET_EDGES: [u64; HISTOGRAM_BUCKETS - 1] = [
+    1_000, 5_000, 10_000, 25_000, 50_000, 100_000, 200_000, 400_000, 1_400_000,
+];
+
+/// Histogram bucket index for `value` given the bucket `edges`
+pub fn histogram_bucket(value: u64, edges: &[u64; HISTOGRAM_BUCKETS - 1]) -> usize {
+    edges
+        .iter()
+        .position(|&edge| value < edge)
+        .unwrap_or(HISTOGRAM_BUCKETS - 1)
+}
+
+/// Approximate percentile from a bucket histogram.
+///
+/// Returns the upper edge of the bucket holding the `pct`-th ranked sample (nearest rank),
+/// capped at `max_value` when known. Samples in the open-ended last bucket report `max_value`.
+pub fn histogram_percentile(
+    histogram: &[u64; HISTOGRAM_BUCKETS],
+    edges: &[u64; HISTOGRAM_BUCKETS - 1],
+    pct: u64,
+    max_value: u64,
+) -> u64 {
+    let total = histogram.iter().fold(0u64, |acc, &c| acc.saturating_add(c));
+    if total == 0 {
+        return 0;
+    }
+    let rank = ((total as u128 * pct as u128).div_ceil(100) as u64).max(1);
+
+    let mut cumulative = 0u64;
+    for (i, &count) in histogram.iter().enumerate() {
+        cumulative = cumulative.saturating_add(count);
+        if cumulative >= rank {
+            return match edges.get(i) {
+                Some(&edge) if max_value > 0 => edge.min(max_value),
+                Some(&edge) => edge,
+                None => max_value.max(edges[HISTOGRAM_BUCKETS - 2]),
+            };
+        }
+    }
+    max_value
+}
+
 /// Aggregated benchmark metrics
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
 pub struct BlockbenchMetrics {
@@ -105,10 +158,60 @@ pub struct BlockbenchMetrics {
     pub timeout_errors: u64,
     pub conflict_errors: u64,
     pub other_errors: u64,
+
+    // Distributions over LATENCY_BUCKET_EDGES_US / COMPUTE_UNIT_BUCKET_EDGES
+    pub latency_histogram: [u64; HISTOGRAM_BUCKETS],
+    pub compute_unit_histogram: [u64; HISTOGRAM_BUCKETS],
 }
 
 impl BlockbenchMetrics {
-    pub const LEN: usize = 8 * 18; // 18 u64 fields
+    pub const LEN: usize = 8 * 18 // 18 u64 fields
+        + 2 * 8 * HISTOGRAM_BUCKETS; // latency + compute-unit histograms
+
+    /// Count one sample into both histograms
+    pub fn record_histograms(&mut self, latency_us: u64, compute_units: u64) {
+        let l = histogram_bucket(latency_us, &LATENCY_BUCKET_EDGES_US);
+        let c = histogram_bucket(compute_units, &COMPUTE_UNIT_BUCKET_EDGES);
+        self.latency_histogram[l] = self.latency_histogram[l].saturating_add(1);
+        self.compute_unit_histogram[c] = self.compute_unit_histogram[c].saturating_add(1);
+    }
+
+    /// Latency percentile (microseconds) from the histogram
+    pub fn latency_percentile_us(&self, pct: u64) -> u64 {
+        histogram_percentile(
+            &self.latency_histogram,
+            &LATENCY_BUCKET_EDGES_US,
+            pct,
+            self.max_latency_us,
+        )
+    }
+
+    /// Compute-unit percentile from the histogram
+    pub fn compute_unit_percentile(&self, pct: u64) -> u64 {
+        histogram_percentile(
+            &self.compute_unit_histogram,
+            &COMPUTE_UNIT_BUCKET_EDGES,
+            pct,
+            self.max_compute_units,
+        )
+    }
+
+    /// Project the running metrics into the cross-suite summary format
+    pub fn to_common_summary(&self, duration_seconds: u64) -> (LatencySummary, ThroughputSummary) {
+        (
+            LatencySummary::new(
+                self.successful_operations,
+                self.total_latency_us,
+                self.min_latency_us,
+                self.max_latency_us,
+            ),
+            ThroughputSummary::new(
+                self.successful_operations,
+                self.failed_operations,
+                duration_seconds,
+            ),
+        )
+    }
 }
 
 /// Benchmark summary returned after finalization
@@ -120,7 +223,7 @@ pub struct BenchmarkSummary {
     /// Average latency in microseconds
     pub avg_latency_us: u64,
     
-    /// Latency percentiles (p50, p90, p95, p99)
+    /// Latency percentiles (p50, p90, p95, p99), bucket upper edges from the histogram
     pub p50_latency_us: u64,
     pub p90_latency_us: u64,
     pub p95_latency_us: u64,
@@ -134,6 +237,9 @@ pub struct BenchmarkSummary {
     
     /// Total duration in seconds
     pub duration_seconds: u64,
+
+    /// Median compute units per transaction (bucket upper edge from the histogram)
+    pub median_compute_units: u64,
 }
 
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -204,6 +310,27 @@ pub struct AnalyticsResult {
     pub compute_units_used: u64,
 }
 
+/// Scan query result with a bounded sample of matching record keys
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, Debug, PartialEq, Eq)]
+pub struct ScanResult {
+    pub matched: u32,
+    pub records_scanned: u32,
+    /// First `MAX_SAMPLE_KEYS` matching account keys, in scan order
+    pub sample_keys: Vec<[u8; 32]>,
+}
+
+impl ScanResult {
+    /// Keeps return data well under the 1 KiB limit
+    pub const MAX_SAMPLE_KEYS: usize = 16;
+
+    pub fn record_match(&mut self, key: [u8; 32]) {
+        self.matched += 1;
+        if self.sample_keys.len() < Self::MAX_SAMPLE_KEYS {
+            self.sample_keys.push(key);
+        }
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════════
 // YCSB KEY-VALUE STORE ACCOUNTS
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -276,6 +403,26 @@ impl IoHeavyAccount {
     pub const MAX_LEN: usize = Self::BASE_LEN + Self::MAX_DATA_SIZE;
 }
 
+/// Resumable position returned by `io_heavy_scan` so a client can chain calls
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub struct ScanCursor {
+    /// `start_cursor` for the next call
+    pub next: u64,
+    /// True once the last remaining account has been read
+    pub done: bool,
+    /// Wrapping byte sum of the accounts read by this call; clients fold calls with wrapping_add
+    pub checksum: u64,
+}
+
+impl ScanCursor {
+    /// Range of `remaining_accounts` read by one call, or `None` if the cursor is past the end
+    pub fn window(start_cursor: u64, max_accounts: u8, total: usize) -> Option<core::ops::Range<usize>> {
+        let start = usize::try_from(start_cursor).ok().filter(|start| *start <= total)?;
+        let end = start.saturating_add(max_accounts as usize).min(total);
+        Some(start..end)
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════════
 // METRIC RECORDING ACCOUNT
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -379,3 +526,105 @@ impl SmallbankChecking {
         8 + // balance
         1; // bump
 }
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// EVENTS
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Emitted by `finalize_benchmark`; `result` is the type the TPC-C suite emits too
+#[event]
+pub struct BenchmarkFinalized {
+    pub result: BenchmarkResult,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn metrics_project_into_common_format() {
+        let metrics = BlockbenchMetrics {
+            total_operations: 100,
+            successful_operations: 90,
+            failed_operations: 10,
+            total_latency_us: 9_000,
+            min_latency_us: 20,
+            max_latency_us: 400,
+            ..Default::default()
+        };
+        let (latency, throughput) = metrics.to_common_summary(10);
+        assert_eq!(latency, LatencySummary::new(90, 9_000, 20, 400));
+        assert_eq!(latency.avg_us, 100);
+        assert_eq!(throughput, ThroughputSummary::new(90, 10, 10));
+        assert_eq!(throughput.tps, 9);
+        assert_eq!(throughput.success_rate_bps, 9_000);
+    }
+
+    #[test]
+    fn scan_result_bounds_sample_keys() {
+        let mut result = ScanResult::default();
+        for i in 0..20u8 {
+            result.record_match([i; 32]);
+        }
+        assert_eq!(result.matched, 20);
+        assert_eq!(result.sample_keys.len(), ScanResult::MAX_SAMPLE_KEYS);
+        assert_eq!(result.sample_keys[0], [0u8; 32]);
+        assert_eq!(result.sample_keys[15], [15u8; 32]);
+    }
+
+    #[test]
+    fn fresh_metrics_normalize_min_latency() {
+        // reset_metrics seeds min_latency_us with u64::MAX
+        let metrics = BlockbenchMetrics {
+            min_latency_us: u64::MAX,
+            ..Default::default()
+        };
+        let (latency, _) = metrics.to_common_summary(1);
+        assert_eq!(latency.min_us, 0);
+    }
+
+    #[test]
+    fn histograms_drive_percentiles() {
+        let mut metrics = BlockbenchMetrics {
+            max_latency_us: 20_000,
+            max_compute_units: 30_000,
+            ..Default::default()
+        };
+        // 90 fast samples, 9 medium, 1 slow
+        for _ in 0..90 {
+            metrics.record_histograms(80, 4_000);
+        }
+        for _ in 0..9 {
+            metrics.record_histograms(3_000, 8_000);
+        }
+        metrics.record_histograms(20_000, 30_000);
+
+        assert_eq!(metrics.latency_histogram[0], 90);
+        assert_eq!(metrics.latency_histogram[3], 9);
+        // 20ms lands in the <50ms bucket
+        assert_eq!(metrics.latency_histogram[5], 1);
+        assert_eq!(metrics.compute_unit_histogram[1], 90);
+        assert_eq!(metrics.latency_percentile_us(50), 100);
+        assert_eq!(metrics.latency_percentile_us(99), 5_000);
+        // top sample's bucket edge (50ms) is capped at the observed max
+        assert_eq!(metrics.latency_percentile_us(100), 20_000);
+        assert_eq!(metrics.compute_unit_percentile(50), 5_000);
+    }
+
+    #[test]
+    fn empty_histogram_percentile_is_zero() {
+        let metrics = BlockbenchMetrics::default();
+        assert_eq!(metrics.latency_percentile_us(50), 0);
+        assert_eq!(metrics.compute_unit_percentile(50), 0);
+        assert_eq!(histogram_bucket(u64::MAX, &LATENCY_BUCKET_EDGES_US), HISTOGRAM_BUCKETS - 1);
+    }
+
+    #[test]
+    fn scan_cursor_window_pages_through_accounts() {
+        assert_eq!(ScanCursor::window(0, 4, 10), Some(0..4));
+        assert_eq!(ScanCursor::window(8, 4, 10), Some(8..10));
+        assert_eq!(ScanCursor::window(10, 4, 10), Some(10..10));
+        assert_eq!(ScanCursor::window(11, 4, 10), None);
+        assert_eq!(ScanCursor::window(u64::MAX, 4, 10), None);
+    }
+}
@@ -26,4 +26,38 @@ pub enum EnergyTokenError {
     RemoveValidatorNotFound,
     #[msg("Window start must be a positive 15-minute (900_000 ms) boundary")]
     MisalignedWindow,
+    #[msg("Mint would push supply past the configured max_supply")]
+    SupplyCapExceeded,
+    #[msg("Minting is paused")]
+    MintingPaused,
+    #[msg("Authority config needs 1-5 distinct signers and a threshold between 1 and the signer count")]
+    InvalidAuthorityConfig,
+    #[msg("Signer is not listed in the authority config")]
+    NotConfiguredSigner,
+    #[msg("Not enough distinct configured signers approved")]
+    InsufficientApprovals,
+    #[msg("Signer already approved this proposal")]
+    AlreadyApproved,
+    #[msg("Mint proposal already executed")]
+    ProposalAlreadyExecuted,
+    #[msg("Authority config changed since this proposal was created")]
+    StaleProposal,
+    #[msg("Multi-signer authority is enabled; use propose_mint / approve_mint")]
+    MultisigRequired,
+    #[msg("Account is not a registry MeterAccount")]
+    InvalidMeterAccount,
+    #[msg("No unreconciled consumption on this meter")]
+    NothingToReconcile,
+    #[msg("Transfer memo exceeds 64 bytes")]
+    MemoTooLong,
+    #[msg("Minter key must not be the default pubkey")]
+    InvalidMinter,
+    #[msg("Minter is already listed")]
+    MinterAlreadyExists,
+    #[msg("Maximum number of minters reached")]
+    MaxMintersReached,
+    #[msg("Minter to remove is not listed")]
+    MinterNotFound,
+    #[msg("Account already has the current layout")]
+    AccountAlreadyMigrated,
 }
@@ -16,9 +16,79 @@ pub struct TokensMinted {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct TokensBurned {
+    pub owner: Pubkey,
+    pub token_account: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct TotalSupplySynced {
     pub authority: Pubkey,
     pub supply: u64,
     pub timestamp: i64,
 }
+
+#[event]
+pub struct MintPauseUpdated {
+    pub authority: Pubkey,
+    pub paused: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityConfigUpdated {
+    pub updated_by: Pubkey,
+    pub signer_count: u8,
+    pub threshold: u8,
+    pub version: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MintProposed {
+    pub nonce: u64,
+    pub proposer: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MintApproved {
+    pub nonce: u64,
+    pub approver: Pubkey,
+    pub approval_count: u8,
+    pub threshold: u8,
+    pub executed: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConsumptionReconciled {
+    pub meter: Pubkey,
+    pub owner: Pubkey,
+    pub token_account: Pubkey,
+    pub amount: u64,
+    pub settled_consumption: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TokensTransferred {
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub amount: u64,
+    pub memo: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MinterUpdated {
+    pub minter: Pubkey,
+    pub added: bool,
+    pub minter_count: u8,
+    pub timestamp: i64,
+}
@@ -47,6 +47,9 @@ macro_rules! compute_checkpoint {
 
 declare_id!("6FZKcVKCLFSNLMxypFJGU4K14xUBnxNW9VAuKGhmqjGX");
 
+/// Longest memo (bytes) `transfer_tokens_memo` will carry in its event.
+pub const MAX_TRANSFER_MEMO_LEN: usize = 64;
+
 /// True if `key` is one of the registered REC validators. Single source of truth for
 /// the REC co-signature gate, shared by every mint path (`mint_to_wallet`,
 /// `mint_generation`, `mint_tokens_direct`) so the membership check can never drift
@@ -55,6 +58,93 @@ fn rec_validator_registered(token_info: &TokenInfo, key: &Pubkey) -> bool {
     token_info.rec_validators[..token_info.rec_validators_count as usize].contains(key)
 }
 
+/// True if minting `amount` on top of `current_supply` stays within `max_supply`.
+/// `max_supply == 0` means uncapped (tokens initialized before the cap existed).
+/// The sum saturates, so an overflowing mint lands on `u64::MAX` and is rejected
+/// explicitly rather than wrapping under the cap.
+fn within_supply_cap(token_info: &TokenInfo, current_supply: u64, amount: u64) -> bool {
+    token_info.max_supply == 0 || current_supply.saturating_add(amount) <= token_info.max_supply
+}
+
+/// Checks a proposed M-of-N signer set: 1..=MAX_MINT_SIGNERS distinct, non-default
+/// keys and a threshold in `1..=signers.len()`.
+fn validate_authority_config(signers: &[Pubkey], threshold: u8) -> Result<()> {
+    require!(
+        !signers.is_empty() && signers.len() <= MAX_MINT_SIGNERS,
+        EnergyTokenError::InvalidAuthorityConfig
+    );
+    for (i, key) in signers.iter().enumerate() {
+        require!(
+            *key != Pubkey::default() && !signers[..i].contains(key),
+            EnergyTokenError::InvalidAuthorityConfig
+        );
+    }
+    require!(
+        threshold >= 1 && threshold as usize <= signers.len(),
+        EnergyTokenError::InvalidAuthorityConfig
+    );
+    Ok(())
+}
+
+/// Bitmask (by signer index) of the configured signers present in `keys`. Unlisted
+/// keys are ignored and repeats collapse, so `count_ones()` is the distinct approvals.
+fn approval_mask(config: &AuthorityConfig, keys: &[Pubkey]) -> u8 {
+    keys.iter()
+        .filter_map(|k| config.signer_index(k))
+        .fold(0u8, |mask, i| mask | (1 << i))
+}
+
+/// Anchor discriminator of the registry's `MeterAccount` (`sha256("account:MeterAccount")[..8]`).
+/// The registry depends on this crate, so its types cannot be imported here.
+const METER_ACCOUNT_DISCRIMINATOR: [u8; 8] = [87, 111, 139, 87, 181, 20, 104, 255];
+/// Byte size of the registry's `#[repr(C)]` `MeterAccount` payload (after the discriminator).
+const METER_ACCOUNT_LEN: usize = 120;
+/// Payload offsets of `MeterAccount.owner` and `MeterAccount.total_consumption`.
+const METER_OWNER_OFFSET: usize = 32;
+const METER_CONSUMPTION_OFFSET: usize = 96;
+
+/// Reads `(owner, total_consumption)` out of raw registry `MeterAccount` data, or
+/// `None` if the bytes are too short or carry another account's discriminator.
+fn parse_meter_consumption(data: &[u8]) -> Option<(Pubkey, u64)> {
+    if data.len() < 8 + METER_ACCOUNT_LEN || data[..8] != METER_ACCOUNT_DISCRIMINATOR {
+        return None;
+    }
+    let body = &data[8..];
+    let owner = Pubkey::try_from(&body[METER_OWNER_OFFSET..METER_OWNER_OFFSET + 32]).ok()?;
+    let consumption = u64::from_le_bytes(
+        body[METER_CONSUMPTION_OFFSET..METER_CONSUMPTION_OFFSET + 8].try_into().ok()?,
+    );
+    Some((owner, consumption))
+}
+
+#[cfg(test)]
+mod meter_parse_tests {
+    use super::*;
+
+    fn meter_bytes(owner: Pubkey, consumption: u64) -> Vec<u8> {
+        let mut data = vec![0u8; 8 + METER_ACCOUNT_LEN];
+        data[..8].copy_from_slice(&METER_ACCOUNT_DISCRIMINATOR);
+        data[8 + METER_OWNER_OFFSET..8 + METER_OWNER_OFFSET + 32].copy_from_slice(owner.as_ref());
+        data[8 + METER_CONSUMPTION_OFFSET..8 + METER_CONSUMPTION_OFFSET + 8]
+            .copy_from_slice(&consumption.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn reads_owner_and_consumption() {
+        let owner = Pubkey::new_from_array([7u8; 32]);
+        assert_eq!(parse_meter_consumption(&meter_bytes(owner, 4_200)), Some((owner, 4_200)));
+    }
+
+    #[test]
+    fn rejects_wrong_discriminator_and_short_data() {
+        let mut data = meter_bytes(Pubkey::default(), 1);
+        assert!(parse_meter_consumption(&data[..data.len() - 1]).is_none());
+        data[0] ^= 1;
+        assert!(parse_meter_consumption(&data).is_none());
+    }
+}
+
 #[cfg(test)]
 mod rec_validator_tests {
     use super::*;
@@ -72,7 +162,10 @@ mod rec_validator_tests {
             created_at: 0,
             rec_validators: validators,
             rec_validators_count: count,
-            _padding: [0u8; 7],
+            mint_paused: 0,
+            multisig_enabled: 0,
+            _padding: [0u8; 5],
+            max_supply: 0,
         }
     }
 
@@ -115,6 +208,95 @@ mod rec_validator_tests {
     }
 }
 
+#[cfg(test)]
+mod supply_cap_tests {
+    use super::*;
+
+    fn capped(max_supply: u64) -> TokenInfo {
+        let mut ti: TokenInfo = bytemuck::Zeroable::zeroed();
+        ti.max_supply = max_supply;
+        ti
+    }
+
+    #[test]
+    fn zero_cap_is_uncapped() {
+        assert!(within_supply_cap(&capped(0), u64::MAX - 1, 1));
+    }
+
+    #[test]
+    fn mint_up_to_the_cap_is_allowed() {
+        assert!(within_supply_cap(&capped(1_000), 400, 600));
+    }
+
+    #[test]
+    fn mint_past_the_cap_is_rejected() {
+        assert!(!within_supply_cap(&capped(1_000), 400, 601));
+    }
+
+    #[test]
+    fn overflowing_sum_is_rejected_not_wrapped() {
+        // u64::MAX + 2 would wrap to 1 (under the cap); saturation keeps it at MAX.
+        assert!(!within_supply_cap(&capped(u64::MAX - 1), u64::MAX, 2));
+    }
+}
+
+#[cfg(test)]
+mod authority_config_tests {
+    use super::*;
+
+    fn key(b: u8) -> Pubkey {
+        Pubkey::new_from_array([b; 32])
+    }
+
+    fn config(signers: &[Pubkey], threshold: u8) -> AuthorityConfig {
+        let mut list = [Pubkey::default(); MAX_MINT_SIGNERS];
+        list[..signers.len()].copy_from_slice(signers);
+        AuthorityConfig {
+            signers: list,
+            signer_count: signers.len() as u8,
+            threshold,
+            version: 1,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn accepts_two_of_three() {
+        assert!(validate_authority_config(&[key(1), key(2), key(3)], 2).is_ok());
+    }
+
+    #[test]
+    fn rejects_empty_oversized_and_duplicate_sets() {
+        assert!(validate_authority_config(&[], 1).is_err());
+        assert!(validate_authority_config(&[key(1), key(2), key(3), key(4), key(5), key(6)], 1).is_err());
+        assert!(validate_authority_config(&[key(1), key(1)], 1).is_err());
+        assert!(validate_authority_config(&[Pubkey::default()], 1).is_err());
+    }
+
+    #[test]
+    fn rejects_threshold_outside_signer_count() {
+        assert!(validate_authority_config(&[key(1), key(2)], 0).is_err());
+        assert!(validate_authority_config(&[key(1), key(2)], 3).is_err());
+    }
+
+    #[test]
+    fn approval_mask_counts_distinct_listed_signers_only() {
+        let cfg = config(&[key(1), key(2), key(3)], 2);
+        // key(9) is unlisted; key(2) repeated counts once.
+        let mask = approval_mask(&cfg, &[key(2), key(9), key(2), key(3)]);
+        assert_eq!(mask, 0b110);
+        assert_eq!(mask.count_ones(), 2);
+    }
+
+    #[test]
+    fn signer_index_ignores_unpopulated_tail() {
+        let mut cfg = config(&[key(1)], 1);
+        cfg.signers[3] = key(4);
+        assert_eq!(cfg.signer_index(&key(1)), Some(0));
+        assert_eq!(cfg.signer_index(&key(4)), None);
+    }
+}
+
 #[program]
 pub mod energy_token {
     use super::*;
@@ -181,6 +363,9 @@ pub mod energy_token {
                     token_info.authority == ctx.accounts.authority.key(),
                     EnergyTokenError::UnauthorizedAuthority
                 );
+                // Once an AuthorityConfig exists the single key can no longer mint at will.
+                require!(token_info.multisig_enabled == 0, EnergyTokenError::MultisigRequired);
+                require!(token_info.mint_paused == 0, EnergyTokenError::MintingPaused);
 
                 // REC provenance (mandatory — no opt-out): every mint must be co-signed by a
                 // registered REC validator, proving the minted GRID is backed by a Renewable
@@ -197,6 +382,10 @@ pub mod energy_token {
                     rec_validator_registered(&token_info, &rec_key),
                     EnergyTokenError::RecValidatorNotFound
                 );
+                require!(
+                    within_supply_cap(&token_info, ctx.accounts.mint.supply, amount),
+                    EnergyTokenError::SupplyCapExceeded
+                );
             }
             // Cache clock before CPI — avoids an inline syscall inside the emit! macro
             // and ensures the timestamp is captured before the CPI context is consumed.
@@ -271,6 +460,9 @@ pub mod energy_token {
                     token_info.authority == ctx.accounts.authority.key(),
                     EnergyTokenError::UnauthorizedAuthority
                 );
+                // A fresh (meter, window) would otherwise let the single key mint any amount.
+                require!(token_info.multisig_enabled == 0, EnergyTokenError::MultisigRequired);
+                require!(token_info.mint_paused == 0, EnergyTokenError::MintingPaused);
 
                 // REC provenance (mandatory — no opt-out): every generation mint must be
                 // co-signed by a registered REC validator. A fresh token cannot mint until a
@@ -285,6 +477,10 @@ pub mod energy_token {
                     rec_validator_registered(&token_info, &rec_key),
                     EnergyTokenError::RecValidatorNotFound
                 );
+                require!(
+                    within_supply_cap(&token_info, ctx.accounts.mint.supply, amount),
+                    EnergyTokenError::SupplyCapExceeded
+                );
             }
 
             let now = Clock::get()?.unix_timestamp;
@@ -324,11 +520,13 @@ pub mod energy_token {
         Ok(())
     }
 
-    /// Initialize the energy token program
+    /// Initialize the energy token program. `max_supply` caps the GRID mint supply
+    /// across every mint path; pass 0 for an uncapped token.
     pub fn initialize_token(
         ctx: Context<InitializeToken>,
         registry_program_id: Pubkey,
         registry_authority: Pubkey,
+        max_supply: u64,
     ) -> Result<()> {
         compute_fn!("initialize_token" => {
             let clock = Clock::get()?;
@@ -341,6 +539,9 @@ pub mod energy_token {
             token_info.created_at = clock.unix_timestamp;
             token_info.rec_validators_count = 0;
             token_info.rec_validators = [Pubkey::default(); 5];
+            token_info.max_supply = max_supply;
+            token_info.mint_paused = 0;
+            token_info.multisig_enabled = 0;
         });
         Ok(())
     }
@@ -404,6 +605,66 @@ pub mod energy_token {
         Ok(())
     }
 
+    /// Grant `minter` the right to call `mint_tokens_direct` (admin only).
+    ///
+    /// Lets a program (e.g. the registry PDA) mint without holding `authority`.
+    /// Refused once multi-signer minting is on, so a single key cannot grant itself
+    /// a way around the threshold.
+    pub fn add_minter(ctx: Context<AddMinter>, minter: Pubkey) -> Result<()> {
+        compute_fn!("add_minter" => {
+            {
+                let token_info = ctx.accounts.token_info.load()?;
+                require!(token_info.multisig_enabled == 0, EnergyTokenError::MultisigRequired);
+            }
+            require!(minter != Pubkey::default(), EnergyTokenError::InvalidMinter);
+
+            let config = &mut ctx.accounts.minter_config;
+            require!(!config.is_minter(&minter), EnergyTokenError::MinterAlreadyExists);
+            require!(
+                (config.minter_count as usize) < MAX_MINTERS,
+                EnergyTokenError::MaxMintersReached
+            );
+
+            let index = config.minter_count as usize;
+            config.minters[index] = minter;
+            config.minter_count += 1;
+            config.bump = ctx.bumps.minter_config;
+
+            let now = Clock::get()?.unix_timestamp;
+            emit!(MinterUpdated {
+                minter,
+                added: true,
+                minter_count: config.minter_count,
+                timestamp: now,
+            });
+        });
+        Ok(())
+    }
+
+    /// Revoke a delegated minter (admin only). Swap-removes to keep the list dense.
+    pub fn remove_minter(ctx: Context<RemoveMinter>, minter: Pubkey) -> Result<()> {
+        compute_fn!("remove_minter" => {
+            let config = &mut ctx.accounts.minter_config;
+            let idx = config
+                .minter_index(&minter)
+                .ok_or(EnergyTokenError::MinterNotFound)?;
+
+            let last = config.minter_count as usize - 1;
+            config.minters[idx] = config.minters[last];
+            config.minters[last] = Pubkey::default();
+            config.minter_count -= 1;
+
+            let now = Clock::get()?.unix_timestamp;
+            emit!(MinterUpdated {
+                minter,
+                added: false,
+                minter_count: config.minter_count,
+                timestamp: now,
+            });
+        });
+        Ok(())
+    }
+
     /// Transfer energy tokens between accounts
     pub fn transfer_tokens(ctx: Context<TransferTokens>, amount: u64) -> Result<()> {
         compute_fn!("transfer_tokens" => {
@@ -424,9 +685,44 @@ pub mod energy_token {
         Ok(())
     }
 
+    /// Transfer energy tokens with an accounting memo.
+    ///
+    /// Same `transfer_checked` CPI as `transfer_tokens`. The SPL transfer itself has
+    /// no memo field, so the memo is only carried by the `TokensTransferred` event.
+    pub fn transfer_tokens_memo(ctx: Context<TransferTokens>, amount: u64, memo: String) -> Result<()> {
+        compute_fn!("transfer_tokens_memo" => {
+            require!(memo.len() <= MAX_TRANSFER_MEMO_LEN, EnergyTokenError::MemoTooLong);
+
+            let now = Clock::get()?.unix_timestamp;
+            let cpi_accounts = TransferCheckedInterface {
+                from: ctx.accounts.from_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.to_token_account.to_account_info(),
+                authority: ctx.accounts.from_authority.to_account_info(),
+            };
+
+            let cpi_ctx = CpiContext::new(ctx.accounts.token_program.key(), cpi_accounts);
+
+            compute_checkpoint!("before_transfer_cpi");
+            token_interface::transfer_checked(cpi_ctx, amount, 9)?;
+            compute_checkpoint!("after_transfer_cpi");
+
+            emit!(TokensTransferred {
+                from: ctx.accounts.from_token_account.key(),
+                to: ctx.accounts.to_token_account.key(),
+                amount,
+                memo,
+                timestamp: now,
+            });
+        });
+        Ok(())
+    }
+
     /// Burn energy tokens (for energy consumption)
     pub fn burn_tokens(ctx: Context<BurnTokens>, amount: u64) -> Result<()> {
         compute_fn!("burn_tokens" => {
+            // Cache clock before CPI — same pattern as the mint paths.
+            let now = Clock::get()?.unix_timestamp;
             let cpi_accounts = BurnInterface {
                 mint: ctx.accounts.mint.to_account_info(),
                 from: ctx.accounts.token_account.to_account_info(),
@@ -440,11 +736,71 @@ pub mod energy_token {
             compute_checkpoint!("after_burn_cpi");
 
             // total_supply is NOT updated here — use sync_total_supply for batch updates
+
+            emit!(TokensBurned {
+                // Wallet that owned the burned tokens (may differ from a delegate signer).
+                owner: ctx.accounts.token_account.owner,
+                token_account: ctx.accounts.token_account.key(),
+                amount,
+                timestamp: now,
+            });
+        });
+        Ok(())
+    }
+
+    /// Burn the consumption a registry meter has recorded since its last reconciliation.
+    ///
+    /// `meter_account` must be a `MeterAccount` owned by `token_info.registry_program`;
+    /// its owner signs and the burn comes out of their `token_account`. The burned
+    /// amount is `total_consumption - settled_consumption`, after which the
+    /// `ConsumptionMarker` catches up to `total_consumption`. The first call for a
+    /// meter creates the marker at zero and so burns all consumption recorded so far.
+    pub fn reconcile_consumption(ctx: Context<ReconcileConsumption>) -> Result<()> {
+        compute_fn!("reconcile_consumption" => {
+            let (meter_owner, total_consumption) = {
+                let data = ctx.accounts.meter_account.try_borrow_data()?;
+                parse_meter_consumption(&data).ok_or(EnergyTokenError::InvalidMeterAccount)?
+            };
+            require_keys_eq!(
+                meter_owner,
+                ctx.accounts.owner.key(),
+                EnergyTokenError::UnauthorizedAuthority
+            );
+
+            let marker = &mut ctx.accounts.consumption_marker;
+            let amount = total_consumption.saturating_sub(marker.settled_consumption);
+            require!(amount > 0, EnergyTokenError::NothingToReconcile);
+
+            let now = Clock::get()?.unix_timestamp;
+            let cpi_accounts = BurnInterface {
+                mint: ctx.accounts.mint.to_account_info(),
+                from: ctx.accounts.token_account.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new(ctx.accounts.token_program.key(), cpi_accounts);
+
+            compute_checkpoint!("before_burn_cpi");
+            token_interface::burn(cpi_ctx, amount)?;
+            compute_checkpoint!("after_burn_cpi");
+
+            marker.meter = ctx.accounts.meter_account.key();
+            marker.settled_consumption = total_consumption;
+            marker.last_reconciled_at = now;
+            marker.bump = ctx.bumps.consumption_marker;
+
+            emit!(ConsumptionReconciled {
+                meter: marker.meter,
+                owner: meter_owner,
+                token_account: ctx.accounts.token_account.key(),
+                amount,
+                settled_consumption: total_consumption,
+                timestamp: now,
+            });
         });
         Ok(())
     }
 
-    /// Mint tokens directly to a user (authority or registry program only)
+    /// Mint tokens directly to a user (authority, registry program, or a delegated minter)
     ///
     /// Sealevel-optimized: token_info is read-only (no total_supply write).
     /// If REC validators are registered, one must co-sign to prove energy provenance.
@@ -453,12 +809,24 @@ pub mod energy_token {
         compute_fn!("mint_tokens_direct" => {
             let token_info = ctx.accounts.token_info.load()?;
 
-            // Check if caller has permission (Admin or Registry Program)
+            // Check if caller has permission (Admin, Registry Program or delegated minter)
             let is_admin = ctx.accounts.authority.key() == token_info.authority;
             let is_registry = ctx.accounts.authority.key() == ctx.accounts.registry_authority.key();
-            
-
-            require!(is_admin || is_registry, EnergyTokenError::UnauthorizedAuthority);
+            let is_minter = ctx
+                .accounts
+                .minter_config
+                .as_ref()
+                .is_some_and(|c| c.is_minter(&ctx.accounts.authority.key()));
+
+            require!(is_admin || is_registry || is_minter, EnergyTokenError::UnauthorizedAuthority);
+            // Only the registry CPI path is exempt from multi-signer minting: it cannot sign
+            // a proposal, and its key is frozen (set_registry_authority is locked too).
+            // Admin and delegated-minter mints move to propose/approve.
+            require!(
+                is_registry || token_info.multisig_enabled == 0,
+                EnergyTokenError::MultisigRequired
+            );
+            require!(token_info.mint_paused == 0, EnergyTokenError::MintingPaused);
 
             // REC Validator co-signature: when validators are registered, one must sign.
             // This proves the minted energy has a corresponding Renewable Energy Certificate.
@@ -478,6 +846,13 @@ pub mod energy_token {
                 require!(is_registry, EnergyTokenError::RecValidatorNotFound);
             }
 
+            // Checked against the canonical mint supply: token_info.total_supply is only
+            // refreshed by sync_total_supply, so it can lag behind the real supply.
+            require!(
+                within_supply_cap(&token_info, ctx.accounts.mint.supply, amount),
+                EnergyTokenError::SupplyCapExceeded
+            );
+
             drop(token_info);
 
             // Cache clock before CPI — avoids an inline syscall inside the emit! macro
@@ -541,13 +916,40 @@ pub mod energy_token {
         Ok(())
     }
 
-    /// Update the registry authority (admin only)
+    /// Pause or resume all minting (admin only).
+    ///
+    /// Incident-response switch: while paused, mint_to_wallet, mint_generation and
+    /// mint_tokens_direct reject with MintingPaused. Transfers and burns do not read
+    /// token_info and are unaffected.
+    pub fn set_mint_paused(ctx: Context<SetMintPaused>, paused: bool) -> Result<()> {
+        compute_fn!("set_mint_paused" => {
+            let mut token_info = ctx.accounts.token_info.load_mut()?;
+            require!(
+                ctx.accounts.authority.key() == token_info.authority,
+                EnergyTokenError::UnauthorizedAuthority
+            );
+            token_info.mint_paused = paused as u8;
+
+            let now = Clock::get()?.unix_timestamp;
+            emit!(MintPauseUpdated {
+                authority: ctx.accounts.authority.key(),
+                paused,
+                timestamp: now,
+            });
+        });
+        Ok(())
+    }
+
+    /// Update the registry authority (admin only). Refused once multi-signer minting is
+    /// on: the registry branch of `mint_tokens_direct` bypasses the threshold, so the
+    /// single key must not be able to point it at itself.
     pub fn set_registry_authority(ctx: Context<SetRegistryAuthority>, new_registry_authority: Pubkey) -> Result<()> {
         let mut token_info = ctx.accounts.token_info.load_mut()?;
         require!(
             ctx.accounts.authority.key() == token_info.authority,
             EnergyTokenError::UnauthorizedAuthority
         );
+        require!(token_info.multisig_enabled == 0, EnergyTokenError::MultisigRequired);
 
         token_info.registry_authority = new_registry_authority;
         Ok(())
@@ -560,7 +962,8 @@ pub mod energy_token {
     /// previously fixed at `initialize_token` with no rotation path, so a deployment
     /// whose authority must become a different signer (e.g. an off-chain bridge's
     /// signing key) had to be re-initialized. This transfers authority in place. The
-    /// CURRENT authority must sign, so it cannot be hijacked.
+    /// CURRENT authority must sign, so it cannot be hijacked. Refused once multi-signer
+    /// minting is on; the signer set is then changed through `set_authority_config`.
     pub fn set_authority(ctx: Context<SetAuthority>, new_authority: Pubkey) -> Result<()> {
         compute_fn!("set_authority" => {
             let mut token_info = ctx.accounts.token_info.load_mut()?;
@@ -568,10 +971,240 @@ pub mod energy_token {
                 ctx.accounts.authority.key() == token_info.authority,
                 EnergyTokenError::UnauthorizedAuthority
             );
+            require!(token_info.multisig_enabled == 0, EnergyTokenError::MultisigRequired);
             token_info.authority = new_authority;
         });
         Ok(())
     }
+
+    /// Create or replace the M-of-N mint authority.
+    ///
+    /// The first call is made by `token_info.authority` and switches the token to
+    /// multi-signer minting: `mint_to_wallet`, `mint_generation`, the admin and minter
+    /// branches of `mint_tokens_direct`, `set_authority` and `set_registry_authority` then
+    /// reject with `MultisigRequired`, and the delegated minters in `minter_config` are
+    /// cleared (`add_minter` stays refused, so none come back). Later changes need
+    /// the CURRENT threshold of configured signers — `authority` plus co-signers passed
+    /// as remaining accounts — so no single key can reconfigure itself back in. Each
+    /// change bumps `version`, which invalidates approvals on open proposals.
+    pub fn set_authority_config<'info>(
+        ctx: Context<'info, SetAuthorityConfig<'info>>,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        compute_fn!("set_authority_config" => {
+            validate_authority_config(&signers, threshold)?;
+
+            let mut token_info = ctx.accounts.token_info.load_mut()?;
+            let config = &mut ctx.accounts.authority_config;
+
+            if token_info.multisig_enabled == 0 {
+                require!(
+                    ctx.accounts.authority.key() == token_info.authority,
+                    EnergyTokenError::UnauthorizedAuthority
+                );
+            } else {
+                let mut keys = Vec::with_capacity(1 + ctx.remaining_accounts.len());
+                keys.push(ctx.accounts.authority.key());
+                for acc in ctx.remaining_accounts.iter() {
+                    require!(acc.is_signer, EnergyTokenError::InsufficientApprovals);
+                    keys.push(acc.key());
+                }
+                require!(
+                    approval_mask(config, &keys).count_ones() >= config.threshold as u32,
+                    EnergyTokenError::InsufficientApprovals
+                );
+            }
+
+            config.signers = [Pubkey::default(); MAX_MINT_SIGNERS];
+            config.signers[..signers.len()].copy_from_slice(&signers);
+            config.signer_count = signers.len() as u8;
+            config.threshold = threshold;
+            config.version = config.version.wrapping_add(1);
+            config.bump = ctx.bumps.authority_config;
+            token_info.multisig_enabled = 1;
+
+            let now = Clock::get()?.unix_timestamp;
+            if let Some(minters) = ctx.accounts.minter_config.as_mut() {
+                while minters.minter_count > 0 {
+                    minters.minter_count -= 1;
+                    emit!(MinterUpdated {
+                        minter: minters.minters[minters.minter_count as usize],
+                        added: false,
+                        minter_count: minters.minter_count,
+                        timestamp: now,
+                    });
+                }
+                minters.minters = [Pubkey::default(); MAX_MINTERS];
+            }
+            emit!(AuthorityConfigUpdated {
+                updated_by: ctx.accounts.authority.key(),
+                signer_count: config.signer_count,
+                threshold,
+                version: config.version,
+                timestamp: now,
+            });
+        });
+        Ok(())
+    }
+
+    /// Open a multi-signer mint of `amount` to the `destination` token account. The
+    /// proposer must be a configured signer and counts as the first approval.
+    pub fn propose_mint(
+        ctx: Context<ProposeMint>,
+        destination: Pubkey,
+        amount: u64,
+        nonce: u64,
+    ) -> Result<()> {
+        compute_fn!("propose_mint" => {
+            let config = &ctx.accounts.authority_config;
+            let proposer = ctx.accounts.proposer.key();
+            let index = config
+                .signer_index(&proposer)
+                .ok_or(EnergyTokenError::NotConfiguredSigner)?;
+
+            let now = Clock::get()?.unix_timestamp;
+            let proposal = &mut ctx.accounts.mint_proposal;
+            proposal.nonce = nonce;
+            proposal.destination = destination;
+            proposal.amount = amount;
+            proposal.proposer = proposer;
+            proposal.config_version = config.version;
+            proposal.approvals = 1 << index;
+            proposal.approval_count = 1;
+            proposal.executed = false;
+            proposal.created_at = now;
+            proposal.bump = ctx.bumps.mint_proposal;
+
+            emit!(MintProposed {
+                nonce,
+                proposer,
+                destination,
+                amount,
+                timestamp: now,
+            });
+        });
+        Ok(())
+    }
+
+    /// Approve a pending mint proposal; executes the mint once `threshold` distinct
+    /// configured signers have approved.
+    ///
+    /// The executing call runs the same pause, REC co-signature and supply-cap checks
+    /// as `mint_to_wallet`, so `rec_validator` must be supplied on the approval that
+    /// reaches the threshold. A signer who already approved may call again to execute
+    /// a proposal that is at threshold (e.g. a 1-of-N proposer); otherwise a repeat
+    /// approval is rejected.
+    pub fn approve_mint(ctx: Context<ApproveMint>, nonce: u64) -> Result<()> {
+        compute_fn!("approve_mint" => {
+            let now = Clock::get()?.unix_timestamp;
+            let approver = ctx.accounts.approver.key();
+            let threshold = ctx.accounts.authority_config.threshold;
+
+            let proposal = &mut ctx.accounts.mint_proposal;
+            require!(!proposal.executed, EnergyTokenError::ProposalAlreadyExecuted);
+            require!(
+                proposal.config_version == ctx.accounts.authority_config.version,
+                EnergyTokenError::StaleProposal
+            );
+            let index = ctx
+                .accounts
+                .authority_config
+                .signer_index(&approver)
+                .ok_or(EnergyTokenError::NotConfiguredSigner)?;
+
+            let bit = 1u8 << index;
+            let newly_approved = proposal.approvals & bit == 0;
+            if newly_approved {
+                proposal.approvals |= bit;
+                proposal.approval_count += 1;
+            }
+            let ready = proposal.approval_count >= threshold;
+            require!(newly_approved || ready, EnergyTokenError::AlreadyApproved);
+
+            if ready {
+                let amount = proposal.amount;
+                {
+                    let token_info = ctx.accounts.token_info.load()?;
+                    require!(token_info.mint_paused == 0, EnergyTokenError::MintingPaused);
+                    let rec_key = ctx
+                        .accounts
+                        .rec_validator
+                        .as_ref()
+                        .map(|v| v.key())
+                        .ok_or(EnergyTokenError::RecValidatorNotFound)?;
+                    require!(
+                        rec_validator_registered(&token_info, &rec_key),
+                        EnergyTokenError::RecValidatorNotFound
+                    );
+                    require!(
+                        within_supply_cap(&token_info, ctx.accounts.mint.supply, amount),
+                        EnergyTokenError::SupplyCapExceeded
+                    );
+                }
+
+                let cpi_accounts = token_interface::MintTo {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.destination.to_account_info(),
+                    authority: ctx.accounts.token_info.to_account_info(),
+                };
+                let seeds = &[b"token_info_2022".as_ref(), &[ctx.bumps.token_info]];
+                let signer = &[&seeds[..]];
+                let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.key(), cpi_accounts, signer);
+
+                compute_checkpoint!("before_mint_cpi");
+                token_interface::mint_to(cpi_ctx, amount)?;
+                compute_checkpoint!("after_mint_cpi");
+
+                proposal.executed = true;
+
+                emit!(TokensMinted {
+                    recipient: ctx.accounts.destination.key(),
+                    amount,
+                    timestamp: now,
+                });
+            }
+
+            emit!(MintApproved {
+                nonce,
+                approver,
+                approval_count: proposal.approval_count,
+                threshold,
+                executed: ready,
+                timestamp: now,
+            });
+        });
+        Ok(())
+    }
+
+    /// Grow a `TokenInfo` created before `max_supply` existed (8 bytes short) to the current
+    /// layout. Permissionless: the appended bytes are zero, so the account reads
+    /// `max_supply = 0` (uncapped) and nothing else changes; `payer` funds the extra rent.
+    pub fn migrate_token_info(ctx: Context<MigrateTokenInfo>) -> Result<()> {
+        compute_fn!("migrate_token_info" => {
+            let token_info = ctx.accounts.token_info.to_account_info();
+            let new_len = 8 + std::mem::size_of::<TokenInfo>();
+            require!(token_info.data_len() < new_len, EnergyTokenError::AccountAlreadyMigrated);
+
+            let top_up = Rent::get()?
+                .minimum_balance(new_len)
+                .saturating_sub(token_info.lamports());
+            if top_up > 0 {
+                anchor_lang::system_program::transfer(
+                    CpiContext::new(
+                        ctx.accounts.system_program.key(),
+                        anchor_lang::system_program::Transfer {
+                            from: ctx.accounts.payer.to_account_info(),
+                            to: token_info.clone(),
+                        },
+                    ),
+                    top_up,
+                )?;
+            }
+            token_info.resize(new_len)?;
+        });
+        Ok(())
+    }
 }
 
 // Account structs
@@ -748,6 +1381,51 @@ pub struct AddRecValidator<'info> {
     pub authority: Signer<'info>,
 }
 
+#[derive(Accounts)]
+pub struct AddMinter<'info> {
+    #[account(
+        seeds = [b"token_info_2022"],
+        bump,
+        has_one = authority @ EnergyTokenError::UnauthorizedAuthority,
+    )]
+    pub token_info: AccountLoader<'info, TokenInfo>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + MinterConfig::LEN,
+        seeds = [b"minter_config"],
+        bump,
+    )]
+    pub minter_config: Account<'info, MinterConfig>,
+
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveMinter<'info> {
+    #[account(
+        seeds = [b"token_info_2022"],
+        bump,
+        has_one = authority @ EnergyTokenError::UnauthorizedAuthority,
+    )]
+    pub token_info: AccountLoader<'info, TokenInfo>,
+
+    #[account(
+        mut,
+        seeds = [b"minter_config"],
+        bump = minter_config.bump,
+    )]
+    pub minter_config: Account<'info, MinterConfig>,
+
+    pub authority: Signer<'info>,
+}
+
 #[derive(Accounts)]
 pub struct TransferTokens<'info> {
     #[account(mut)]
@@ -776,6 +1454,53 @@ pub struct BurnTokens<'info> {
     pub token_program: Interface<'info, TokenInterface>,
 }
 
+#[derive(Accounts)]
+pub struct ReconcileConsumption<'info> {
+    #[account(
+        seeds = [b"token_info_2022"],
+        bump
+    )]
+    pub token_info: AccountLoader<'info, TokenInfo>,
+
+    #[account(
+        mut,
+        constraint = mint.key() == token_info.load()?.mint @ EnergyTokenError::UnauthorizedAuthority,
+    )]
+    pub mint: InterfaceAccount<'info, MintInterface>,
+
+    /// CHECK: owner pinned to the recorded registry program; layout checked in the handler
+    #[account(
+        constraint = *meter_account.owner == token_info.load()?.registry_program @ EnergyTokenError::InvalidMeterAccount
+    )]
+    pub meter_account: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + ConsumptionMarker::LEN,
+        seeds = [b"consumption", meter_account.key().as_ref()],
+        bump,
+    )]
+    pub consumption_marker: Account<'info, ConsumptionMarker>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = owner,
+        token::token_program = token_program,
+    )]
+    pub token_account: Box<InterfaceAccount<'info, TokenAccountInterface>>,
+
+    /// Meter owner; authorizes the burn from their token account
+    pub owner: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
 #[derive(Accounts)]
 pub struct MintTokensDirect<'info> {
     /// Global config — read-only, no write lock for Sealevel parallelism
@@ -814,6 +1539,11 @@ pub struct MintTokensDirect<'info> {
     pub rec_validator: Signer<'info>,
 
     pub token_program: Interface<'info, TokenInterface>,
+
+    /// Delegated minter list; only needed when `authority` mints as a minter. Only
+    /// `add_minter` creates a MinterConfig, always at `[b"minter_config"]`, so the
+    /// owner + discriminator check is enough to pin it.
+    pub minter_config: Option<Account<'info, MinterConfig>>,
 }
 
 #[derive(Accounts)]
@@ -856,3 +1586,141 @@ pub struct SetAuthority<'info> {
 
     pub authority: Signer<'info>,
 }
+
+#[derive(Accounts)]
+pub struct SetMintPaused<'info> {
+    #[account(
+        mut,
+        seeds = [b"token_info_2022"],
+        bump,
+    )]
+    pub token_info: AccountLoader<'info, TokenInfo>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetAuthorityConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"token_info_2022"],
+        bump,
+    )]
+    pub token_info: AccountLoader<'info, TokenInfo>,
+
+    /// Created by the first call (authority-gated); later calls replace it under the
+    /// current threshold (checked in the handler).
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + AuthorityConfig::LEN,
+        seeds = [b"authority_config"],
+        bump,
+    )]
+    pub authority_config: Account<'info, AuthorityConfig>,
+
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Delegated minter list, cleared here; pass it whenever a MinterConfig exists.
+    /// Leaving it out cannot keep the minters usable: `mint_tokens_direct` refuses the
+    /// minter branch under multi-signer minting either way.
+    #[account(
+        mut,
+        seeds = [b"minter_config"],
+        bump = minter_config.bump,
+    )]
+    pub minter_config: Option<Account<'info, MinterConfig>>,
+}
+
+#[derive(Accounts)]
+#[instruction(destination: Pubkey, amount: u64, nonce: u64)]
+pub struct ProposeMint<'info> {
+    #[account(
+        seeds = [b"authority_config"],
+        bump = authority_config.bump,
+    )]
+    pub authority_config: Account<'info, AuthorityConfig>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + MintProposal::LEN,
+        seeds = [b"mint_proposal".as_ref(), &nonce.to_le_bytes()],
+        bump,
+    )]
+    pub mint_proposal: Account<'info, MintProposal>,
+
+    pub proposer: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct ApproveMint<'info> {
+    #[account(
+        mut,
+        constraint = mint.key() == token_info.load()?.mint @ EnergyTokenError::UnauthorizedAuthority,
+    )]
+    pub mint: InterfaceAccount<'info, MintInterface>,
+
+    #[account(
+        seeds = [b"token_info_2022"],
+        bump,
+    )]
+    pub token_info: AccountLoader<'info, TokenInfo>,
+
+    #[account(
+        seeds = [b"authority_config"],
+        bump = authority_config.bump,
+    )]
+    pub authority_config: Account<'info, AuthorityConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"mint_proposal".as_ref(), &nonce.to_le_bytes()],
+        bump = mint_proposal.bump,
+    )]
+    pub mint_proposal: Account<'info, MintProposal>,
+
+    // Bound to the proposal so approvers cannot redirect the mint at execution time.
+    #[account(
+        mut,
+        address = mint_proposal.destination,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub destination: Box<InterfaceAccount<'info, TokenAccountInterface>>,
+
+    pub approver: Signer<'info>,
+
+    /// REC validator co-signer — required on the approval that executes the mint.
+    pub rec_validator: Option<Signer<'info>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateTokenInfo<'info> {
+    /// Legacy-sized `TokenInfo`; `AccountLoader` only checks owner and discriminator here,
+    /// so the short account passes until it is loaded.
+    #[account(
+        mut,
+        seeds = [b"token_info_2022"],
+        bump,
+    )]
+    pub token_info: AccountLoader<'info, TokenInfo>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -14,7 +14,11 @@ pub struct TokenInfo {
     pub created_at: i64,             // 8
     pub rec_validators: [Pubkey; 5], // 32 * 5 = 160
     pub rec_validators_count: u8,    // 1
-    pub _padding: [u8; 7],           // 7
+    pub mint_paused: u8,             // 1 — 0 = minting open, 1 = paused (u8: bool is not Pod)
+    pub multisig_enabled: u8,        // 1 — 1 once an AuthorityConfig exists; single-key mints off
+    pub _padding: [u8; 5],           // 5
+    pub max_supply: u64,             // 8 — hard ceiling on mint.supply; 0 = uncapped
+                                     //     (appended: older accounts need migrate_token_info)
 }
 
 /// On-chain idempotency guard for generation mints. One PDA per
@@ -36,3 +40,102 @@ impl GenerationMintRecord {
     /// Payload size (excludes the 8-byte Anchor discriminator).
     pub const LEN: usize = 16 + 8 + 8 + 1 + 1;
 }
+
+/// Maximum number of signers an [`AuthorityConfig`] can list.
+pub const MAX_MINT_SIGNERS: usize = 5;
+
+/// M-of-N mint authority. Once created, discretionary mints go through
+/// `propose_mint` / `approve_mint` and need `threshold` distinct listed signers;
+/// the single-key `mint_to_wallet` path is disabled. PDA seeds: `[b"authority_config"]`.
+#[account]
+pub struct AuthorityConfig {
+    pub signers: [Pubkey; MAX_MINT_SIGNERS], // 160 — populated prefix is `signer_count`
+    pub signer_count: u8,                    // 1
+    pub threshold: u8,                       // 1  — approvals required (1..=signer_count)
+    pub version: u32,                        // 4  — bumped on every change; stales open proposals
+    pub bump: u8,                            // 1
+}
+
+impl AuthorityConfig {
+    /// Payload size (excludes the 8-byte Anchor discriminator).
+    pub const LEN: usize = 32 * MAX_MINT_SIGNERS + 1 + 1 + 4 + 1;
+
+    pub fn active_signers(&self) -> &[Pubkey] {
+        &self.signers[..self.signer_count as usize]
+    }
+
+    /// Position of `key` in the populated signer prefix, if listed.
+    pub fn signer_index(&self, key: &Pubkey) -> Option<usize> {
+        self.active_signers().iter().position(|s| s == key)
+    }
+}
+
+/// A pending multi-signer mint of `amount` to `destination`. One PDA per `nonce`:
+/// `[b"mint_proposal", nonce.to_le_bytes()]`. `approvals` is a bitmask over
+/// `AuthorityConfig.signers` indices, valid only for `config_version`.
+#[account]
+pub struct MintProposal {
+    pub nonce: u64,           // 8
+    pub destination: Pubkey,  // 32 — recipient token account
+    pub amount: u64,          // 8
+    pub proposer: Pubkey,     // 32
+    pub config_version: u32,  // 4
+    pub approvals: u8,        // 1
+    pub approval_count: u8,   // 1
+    pub executed: bool,       // 1
+    pub created_at: i64,      // 8
+    pub bump: u8,             // 1
+}
+
+impl MintProposal {
+    /// Payload size (excludes the 8-byte Anchor discriminator).
+    pub const LEN: usize = 8 + 32 + 8 + 32 + 4 + 1 + 1 + 1 + 8 + 1;
+}
+
+/// How much of a registry meter's `total_consumption` has already been burned by
+/// `reconcile_consumption`. The registry owns the `MeterAccount` (and it has no
+/// spare bytes), so the marker lives in this program. PDA seeds:
+/// `[b"consumption", meter_account]`.
+#[account]
+pub struct ConsumptionMarker {
+    pub meter: Pubkey,             // 32 — registry MeterAccount this marker tracks
+    pub settled_consumption: u64,  // 8  — consumption (atomic GRID) burned so far
+    pub last_reconciled_at: i64,   // 8
+    pub bump: u8,                  // 1
+}
+
+impl ConsumptionMarker {
+    /// Payload size (excludes the 8-byte Anchor discriminator).
+    pub const LEN: usize = 32 + 8 + 8 + 1;
+}
+
+/// Maximum number of delegated minters a [`MinterConfig`] can list.
+pub const MAX_MINTERS: usize = 4;
+
+/// Keys other than `authority` allowed to call `mint_tokens_direct` (e.g. the
+/// registry PDA). Managed by `add_minter` / `remove_minter`. PDA seeds:
+/// `[b"minter_config"]`. Kept out of `TokenInfo`, which has no spare bytes for it.
+#[account]
+pub struct MinterConfig {
+    pub minters: [Pubkey; MAX_MINTERS], // 128 — populated prefix is `minter_count`
+    pub minter_count: u8,               // 1
+    pub bump: u8,                       // 1
+}
+
+impl MinterConfig {
+    /// Payload size (excludes the 8-byte Anchor discriminator).
+    pub const LEN: usize = 32 * MAX_MINTERS + 1 + 1;
+
+    pub fn active_minters(&self) -> &[Pubkey] {
+        &self.minters[..self.minter_count as usize]
+    }
+
+    /// Position of `key` in the populated minter prefix, if listed.
+    pub fn minter_index(&self, key: &Pubkey) -> Option<usize> {
+        self.active_minters().iter().position(|m| m == key)
+    }
+
+    pub fn is_minter(&self, key: &Pubkey) -> bool {
+        self.minter_index(key).is_some()
+    }
+}
@@ -100,6 +100,43 @@ pub struct IssueErc<'info> {
     pub system_program: Program<'info, System>,
 }
 
+/// Batch issuance: the per-certificate accounts (certificate PDA, meter, meter owner,
+/// owner's REC token account) come in groups of four through `remaining_accounts`.
+#[derive(Accounts)]
+pub struct IssueErcBatch<'info> {
+    #[account(
+        mut,
+        seeds = [b"poa_config"],
+        bump,
+        has_one = authority @ GovernanceError::UnauthorizedAuthority
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+    /// Registry singleton PDA ["registry"] - authority must match governance authority
+    /// CHECK: Registry authority is validated against governance authority below
+    #[account(
+        constraint = {
+            let data = registry.try_borrow_data()?;
+            require!(data.len() >= 40, GovernanceError::InvalidMeterAccount);
+            let reg_authority = Pubkey::try_from(&data[8..40]).map_err(|_| GovernanceError::InvalidMeterAccount)?;
+            require!(
+                reg_authority == authority.key(),
+                GovernanceError::UnauthorizedAuthority
+            );
+            true
+        }
+    )]
+    pub registry: UncheckedAccount<'info>,
+    /// CHECK: pinned to the real registry program ID
+    #[account(constraint = registry_program.key() == registry::ID @ GovernanceError::InvalidMeterAccount)]
+    pub registry_program: UncheckedAccount<'info>,
+    #[account(mut, seeds = [b"rec_mint"], bump)]
+    pub rec_mint: Box<InterfaceAccount<'info, MintInterface>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
 // ========== FUNGIBLE REC TOKEN (1 token = 1 MWh) ==========
 
 #[derive(Accounts)]
@@ -163,6 +200,34 @@ pub struct ValidateErc<'info> {
     pub authority: Signer<'info>,
 }
 
+/// Permissionless: anyone may flip a certificate past its expiry
+#[derive(Accounts)]
+pub struct ExpireErc<'info> {
+    #[account(
+        mut,
+        seeds = [b"erc_certificate", erc_certificate.certificate_id[..erc_certificate.id_len as usize].as_ref()],
+        bump
+    )]
+    pub erc_certificate: Account<'info, ErcCertificate>,
+}
+
+#[derive(Accounts)]
+pub struct ReserveErc<'info> {
+    #[account(
+        mut,
+        seeds = [b"erc_certificate", erc_certificate.certificate_id[..erc_certificate.id_len as usize].as_ref()],
+        bump
+    )]
+    pub erc_certificate: Account<'info, ErcCertificate>,
+    /// Trading's `market_authority` PDA; only the trading program can sign for it
+    #[account(
+        seeds = [b"market_authority"],
+        bump,
+        seeds::program = crate::TRADING_PROGRAM_ID
+    )]
+    pub trading_authority: Signer<'info>,
+}
+
 #[derive(Accounts)]
 pub struct RevokeErc<'info> {
     #[account(
@@ -178,7 +243,59 @@ pub struct RevokeErc<'info> {
         bump
     )]
     pub erc_certificate: Account<'info, ErcCertificate>,
+    /// Meter the certificate was issued against - its ERC claim is released
+    /// CHECK: pinned to the certificate and owned by the registry program
+    #[account(
+        mut,
+        address = erc_certificate.meter_account @ GovernanceError::InvalidMeterAccount,
+        owner = registry::ID @ GovernanceError::InvalidMeterAccount
+    )]
+    pub meter_account: UncheckedAccount<'info>,
+    /// Registry singleton PDA ["registry"] - validated by the registry program during the CPI
+    /// CHECK: seeds and authority are checked by `release_erc_claim`
+    pub registry: UncheckedAccount<'info>,
+    /// The registry program - used to invoke release_erc_claim
+    /// CHECK: pinned to the real registry program ID
+    #[account(constraint = registry_program.key() == registry::ID @ GovernanceError::InvalidMeterAccount)]
+    pub registry_program: UncheckedAccount<'info>,
     pub authority: Signer<'info>,
+    /// REC burn accounts - all four or none. The claim is released only when
+    /// `energy_amount * 1000` REC base units are burned from `rec_token_account`.
+    #[account(mut, seeds = [b"rec_mint"], bump)]
+    pub rec_mint: Option<Box<InterfaceAccount<'info, MintInterface>>>,
+    /// Holder of the RECs being burned; signs the burn
+    pub rec_holder: Option<Signer<'info>>,
+    #[account(mut)]
+    pub rec_token_account: Option<Box<InterfaceAccount<'info, TokenAccountInterface>>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+/// Grows a certificate issued before `meter_account` / `reserved_amount` existed
+#[derive(Accounts)]
+#[instruction(certificate_id: String)]
+pub struct MigrateErcCertificate<'info> {
+    #[account(
+        seeds = [b"poa_config"],
+        bump,
+        has_one = authority @ GovernanceError::UnauthorizedAuthority
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+    /// Legacy certificate - too short to load as `Account<ErcCertificate>`
+    /// CHECK: seeds and owner pinned here; discriminator checked in the handler
+    #[account(
+        mut,
+        seeds = [b"erc_certificate", certificate_id.as_bytes()],
+        bump,
+        owner = crate::ID
+    )]
+    pub erc_certificate: UncheckedAccount<'info>,
+    /// Meter the certificate was issued against, recorded for `revoke_erc`
+    /// CHECK: owned by the registry program; the PoA authority attests the pairing
+    #[account(owner = registry::ID @ GovernanceError::InvalidMeterAccount)]
+    pub meter_account: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
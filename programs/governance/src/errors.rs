@@ -100,4 +100,22 @@ pub enum GovernanceError {
     MathOverflow,
     #[msg("Amount must be greater than zero")]
     InvalidAmount,
+    #[msg("Energy amount exceeds the meter's claimable generation")]
+    ExceedsClaimableGeneration,
+    #[msg("ERC certificate has not reached its expiry")]
+    ErcNotExpired,
+    #[msg("Fee bounds must satisfy min <= max <= 10000 bps")]
+    InvalidFeeBounds,
+    #[msg("ERC batch must hold 1-5 entries with four accounts each")]
+    InvalidErcBatch,
+    #[msg("ERC certificate already exists")]
+    CertificateAlreadyExists,
+    #[msg("REC token account must be the meter owner's account for the REC mint")]
+    InvalidRecTokenAccount,
+    #[msg("Sell orders would reserve more energy than the ERC certifies")]
+    ErcOverCommitted,
+    #[msg("REC burn needs rec_mint, rec_holder, rec_token_account and token_program together")]
+    IncompleteRecBurnAccounts,
+    #[msg("Account already has the current layout")]
+    AccountAlreadyMigrated,
 }
@@ -68,6 +68,16 @@ pub struct ErcLimitsUpdated {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct FeeBoundsUpdated {
+    pub authority: Pubkey,
+    pub old_min: u16,
+    pub new_min: u16,
+    pub old_max: u16,
+    pub new_max: u16,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct AuthorityInfoUpdated {
     pub authority: Pubkey,
@@ -85,6 +95,27 @@ pub struct ErcRevoked {
     pub reason: String,
     pub energy_amount: u64,
     pub timestamp: i64,
+    /// RECs were burned and the meter's claim released, so the energy can be certified again
+    pub claim_released: bool,
+}
+
+#[event]
+pub struct ErcExpired {
+    pub certificate_id: String,
+    pub owner: Pubkey,
+    pub energy_amount: u64,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ErcReservationUpdated {
+    pub certificate_id: String,
+    /// kWh reserved (`reserved == true`) or released by this call
+    pub amount: u64,
+    pub reserved: bool,
+    pub reserved_amount: u64,
+    pub timestamp: i64,
 }
 
 // === NEW EVENTS: Transfer ===
@@ -53,6 +53,11 @@ pub fn update_erc_limits(
     let poa_config = &mut ctx.accounts.governance_config;
     let clock = Clock::get()?;
 
+    // Certification limits are frozen while the system is in maintenance
+    require!(
+        poa_config.is_operational(),
+        GovernanceError::MaintenanceMode
+    );
     require!(min_energy_amount > 0, GovernanceError::InvalidMinimumEnergy);
     require!(
         max_erc_amount > min_energy_amount,
@@ -86,6 +91,38 @@ pub fn update_erc_limits(
     Ok(())
 }
 
+pub fn set_fee_bounds(
+    ctx: Context<UpdateGovernanceConfig>,
+    min_fee_bps: u16,
+    max_fee_bps: u16,
+) -> Result<()> {
+    let poa_config = &mut ctx.accounts.governance_config;
+    let clock = Clock::get()?;
+
+    require!(
+        min_fee_bps <= max_fee_bps && max_fee_bps <= 10_000,
+        GovernanceError::InvalidFeeBounds
+    );
+
+    let old_min = poa_config.min_fee_bps;
+    let old_max = poa_config.max_fee_bps;
+
+    poa_config.min_fee_bps = min_fee_bps;
+    poa_config.max_fee_bps = max_fee_bps;
+    poa_config.last_updated = clock.unix_timestamp;
+
+    emit!(FeeBoundsUpdated {
+        authority: ctx.accounts.authority.key(),
+        old_min,
+        new_min: min_fee_bps,
+        old_max,
+        new_max: max_fee_bps,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
 pub fn update_authority_info(
     ctx: Context<UpdateGovernanceConfig>,
     contact_info: String,
@@ -1,9 +1,9 @@
 use crate::errors::*;
 use crate::events::*;
 use crate::state::*;
-use crate::{InitRecMint, IssueErc, RetireRec, ValidateErc};
+use crate::{InitRecMint, IssueErc, IssueErcBatch, RetireRec, ValidateErc};
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface;
+use anchor_spl::token_interface::{self, TokenAccount};
 
 pub fn issue(
     ctx: Context<IssueErc>,
@@ -14,70 +14,23 @@ pub fn issue(
 ) -> Result<()> {
     let clock = Clock::get()?;
 
-    let (meter_owner, unclaimed_generation) = {
-        let meter_data = ctx.accounts.meter_account.try_borrow_data()?;
-        require!(
-            meter_data.len() >= 8 + std::mem::size_of::<MeterAccount>(),
-            GovernanceError::InvalidMeterAccount
-        );
-        // Slice EXACTLY 8..8+size — `from_bytes` panics on a length mismatch, so passing the
-        // whole `[8..]` remainder would DoS issuance if the account carries trailing bytes.
-        // (Matches the safe pattern in dao.rs.)
-        let meter = bytemuck::from_bytes::<MeterAccount>(
-            &meter_data[8..8 + std::mem::size_of::<MeterAccount>()],
-        );
-        let meter_owner = Pubkey::new_from_array(meter.owner);
-        let unclaimed = meter
-            .total_generation
-            .saturating_sub(meter.claimed_erc_generation)
-            .saturating_sub(meter.settled_net_generation);
-        (meter_owner, unclaimed)
-    };
+    let (meter_owner, claimable_generation) = read_meter(&ctx.accounts.meter_account)?;
 
     let poa_config = &mut ctx.accounts.governance_config;
     let erc_certificate = &mut ctx.accounts.erc_certificate;
 
-    // Operational and config validation
-    require!(
-        poa_config.can_issue_erc(),
-        GovernanceError::ErcValidationDisabled
-    );
-    require!(
-        energy_amount >= poa_config.min_energy_amount,
-        GovernanceError::BelowMinimumEnergy
-    );
-    require!(
-        energy_amount <= poa_config.max_erc_amount,
-        GovernanceError::ExceedsMaximumEnergy
-    );
-    require!(
-        certificate_id.len() <= 64,
-        GovernanceError::CertificateIdTooLong
-    );
-    require!(
-        renewable_source.len() <= 64,
-        GovernanceError::SourceNameTooLong
-    );
-    require!(
-        validation_data.len() <= 256,
-        GovernanceError::ValidationDataTooLong
-    );
-
-    // === PREVENT DOUBLE-CLAIMING ===
-    require!(
-        energy_amount <= unclaimed_generation,
-        GovernanceError::InsufficientUnclaimedGeneration
-    );
-
-    // Check oracle requirement
-    if poa_config.require_oracle_validation {
-        require!(
-            poa_config.oracle_authority!= Pubkey::default(),
-            GovernanceError::OracleValidationRequired
-        );
-    }
+    check_issue_params(
+        poa_config,
+        &certificate_id,
+        energy_amount,
+        &renewable_source,
+        &validation_data,
+        claimable_generation,
+    )?;
 
-    // === CPI: mark energy as claimed in registry (prevents double-claiming) ===
+    // === CPI: bump claimed_erc_generation in registry (prevents double-claiming) ===
+    // The meter is registry-owned, so the watermark can only be written through this
+    // CPI. It runs before the certificate is filled in; any failure aborts the issue.
     {
         let cpi_accounts = registry::cpi::accounts::MarkErcClaimed {
             meter_account: ctx.accounts.meter_account.to_account_info(),
@@ -125,6 +78,8 @@ pub fn issue(
     erc_certificate.revoked_at = None;
     erc_certificate.transfer_count = 0;
     erc_certificate.last_transferred_at = None;
+    erc_certificate.reserved_amount = 0;
+    erc_certificate.meter_account = ctx.accounts.meter_account.key();
 
     // Update comprehensive statistics
     poa_config.total_ercs_issued = poa_config.total_ercs_issued.saturating_add(1);
@@ -172,6 +127,285 @@ pub fn issue(
     Ok(())
 }
 
+/// Maximum certificates `issue_erc_batch` creates in one instruction. Each entry costs a
+/// registry CPI, an account creation and a REC mint, plus four accounts of transaction space.
+pub const MAX_ERC_BATCH_SIZE: usize = 5;
+
+/// Accounts each `issue_erc_batch` entry takes from `remaining_accounts`:
+/// `[erc_certificate, meter_account, owner, rec_token_account]`.
+const ERC_BATCH_ACCOUNTS_PER_ENTRY: usize = 4;
+
+/// Issue several certificates in one instruction, e.g. at monthly settlement. Entry `i` uses
+/// `remaining_accounts[4*i..4*i+4]`; every entry gets the same checks as `issue_erc`, and
+/// any failure (including a `certificate_id` that already exists) aborts the whole batch.
+pub fn issue_batch<'info>(
+    ctx: Context<'info, IssueErcBatch<'info>>,
+    entries: Vec<ErcBatchEntry>,
+) -> Result<()> {
+    require!(
+        !entries.is_empty() && entries.len() <= MAX_ERC_BATCH_SIZE,
+        GovernanceError::InvalidErcBatch
+    );
+    require!(
+        ctx.remaining_accounts.len() == entries.len() * ERC_BATCH_ACCOUNTS_PER_ENTRY,
+        GovernanceError::InvalidErcBatch
+    );
+
+    let clock = Clock::get()?;
+    let rec_mint_key = ctx.accounts.rec_mint.key();
+    let gov_bump = ctx.bumps.governance_config;
+
+    for (entry, accounts) in entries
+        .into_iter()
+        .zip(ctx.remaining_accounts.chunks(ERC_BATCH_ACCOUNTS_PER_ENTRY))
+    {
+        let (certificate_info, meter_info, owner_info, rec_token_info) =
+            (&accounts[0], &accounts[1], &accounts[2], &accounts[3]);
+
+        // Same meter and owner guarantees the IssueErc context enforces
+        require_keys_eq!(
+            *meter_info.owner,
+            registry::ID,
+            GovernanceError::InvalidMeterAccount
+        );
+        let (meter_owner, claimable_generation) = read_meter(meter_info)?;
+        require!(
+            owner_info.is_signer && owner_info.key() == meter_owner,
+            GovernanceError::UnauthorizedAuthority
+        );
+        {
+            require_keys_eq!(
+                *rec_token_info.owner,
+                ctx.accounts.token_program.key(),
+                GovernanceError::InvalidRecTokenAccount
+            );
+            let data = rec_token_info.try_borrow_data()?;
+            let token_account = TokenAccount::try_deserialize(&mut &data[..])
+                .map_err(|_| error!(GovernanceError::InvalidRecTokenAccount))?;
+            require!(
+                token_account.mint == rec_mint_key && token_account.owner == meter_owner,
+                GovernanceError::InvalidRecTokenAccount
+            );
+        }
+
+        check_issue_params(
+            &ctx.accounts.governance_config,
+            &entry.certificate_id,
+            entry.energy_amount,
+            &entry.renewable_source,
+            "",
+            claimable_generation,
+        )?;
+
+        {
+            let cpi_accounts = registry::cpi::accounts::MarkErcClaimed {
+                meter_account: meter_info.clone(),
+                registry: ctx.accounts.registry.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new(ctx.accounts.registry_program.key(), cpi_accounts);
+            registry::cpi::mark_erc_claimed(cpi_ctx, entry.energy_amount)?;
+        }
+
+        create_certificate_account(
+            certificate_info,
+            &entry.certificate_id,
+            ctx.accounts.authority.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        )?;
+
+        let poa_config = &mut ctx.accounts.governance_config;
+        let mut id_bytes = [0u8; 64];
+        id_bytes[..entry.certificate_id.len()].copy_from_slice(entry.certificate_id.as_bytes());
+        let mut source_bytes = [0u8; 64];
+        source_bytes[..entry.renewable_source.len()]
+            .copy_from_slice(entry.renewable_source.as_bytes());
+        let certificate = ErcCertificate {
+            certificate_id: id_bytes,
+            id_len: entry.certificate_id.len() as u8,
+            authority: ctx.accounts.authority.key(),
+            owner: meter_owner,
+            energy_amount: entry.energy_amount,
+            renewable_source: source_bytes,
+            source_len: entry.renewable_source.len() as u8,
+            validation_data: [0u8; 256],
+            data_len: 0,
+            issued_at: clock.unix_timestamp,
+            expires_at: Some(clock.unix_timestamp + poa_config.erc_validity_period),
+            status: ErcStatus::Valid,
+            validated_for_trading: false,
+            trading_validated_at: None,
+            revocation_reason: [0u8; 128],
+            reason_len: 0,
+            revoked_at: None,
+            transfer_count: 0,
+            last_transferred_at: None,
+            meter_account: meter_info.key(),
+            reserved_amount: 0,
+        };
+        certificate.try_serialize(&mut &mut certificate_info.try_borrow_mut_data()?[..])?;
+
+        poa_config.total_ercs_issued = poa_config.total_ercs_issued.saturating_add(1);
+        poa_config.total_energy_certified = poa_config
+            .total_energy_certified
+            .saturating_add(entry.energy_amount);
+
+        emit!(ErcIssued {
+            certificate_id: entry.certificate_id,
+            authority: ctx.accounts.authority.key(),
+            energy_amount: entry.energy_amount,
+            renewable_source: entry.renewable_source,
+            timestamp: clock.unix_timestamp,
+        });
+
+        // REC tokens at the same 1 kWh = 1_000 base units rate as `issue`
+        let rec_amount = entry
+            .energy_amount
+            .checked_mul(1_000)
+            .ok_or(GovernanceError::MathOverflow)?;
+        let seeds: &[&[u8]] = &[b"poa_config", std::slice::from_ref(&gov_bump)];
+        let signer = &[seeds];
+        let cpi_accounts = token_interface::MintTo {
+            mint: ctx.accounts.rec_mint.to_account_info(),
+            to: rec_token_info.clone(),
+            authority: ctx.accounts.governance_config.to_account_info(),
+        };
+        token_interface::mint_to(
+            CpiContext::new_with_signer(ctx.accounts.token_program.key(), cpi_accounts, signer),
+            rec_amount,
+        )?;
+
+        emit!(RecMinted {
+            owner: meter_owner,
+            energy_amount: entry.energy_amount,
+            rec_amount,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    let poa_config = &mut ctx.accounts.governance_config;
+    poa_config.last_updated = clock.unix_timestamp;
+    poa_config.last_erc_issued_at = clock.unix_timestamp;
+
+    Ok(())
+}
+
+/// Owner and still-claimable generation of a registry meter account.
+fn read_meter(meter_account: &AccountInfo) -> Result<(Pubkey, u64)> {
+    let meter_data = meter_account.try_borrow_data()?;
+    require!(
+        meter_data.len() >= 8 + std::mem::size_of::<MeterAccount>(),
+        GovernanceError::InvalidMeterAccount
+    );
+    // Slice EXACTLY 8..8+size — `from_bytes` panics on a length mismatch, so passing the
+    // whole `[8..]` remainder would DoS issuance if the account carries trailing bytes.
+    // (Matches the safe pattern in dao.rs.)
+    let meter = bytemuck::from_bytes::<MeterAccount>(
+        &meter_data[8..8 + std::mem::size_of::<MeterAccount>()],
+    );
+    Ok((Pubkey::new_from_array(meter.owner), claimable_generation(meter)))
+}
+
+/// Config, field-length and double-claim checks shared by `issue` and `issue_batch`.
+fn check_issue_params(
+    poa_config: &GovernanceConfig,
+    certificate_id: &str,
+    energy_amount: u64,
+    renewable_source: &str,
+    validation_data: &str,
+    claimable_generation: u64,
+) -> Result<()> {
+    // Operational and config validation. Maintenance is checked on its own so a paused
+    // system reports MaintenanceMode rather than ErcValidationDisabled.
+    require!(
+        poa_config.is_operational(),
+        GovernanceError::MaintenanceMode
+    );
+    require!(
+        poa_config.can_issue_erc(),
+        GovernanceError::ErcValidationDisabled
+    );
+    require!(
+        energy_amount >= poa_config.min_energy_amount,
+        GovernanceError::BelowMinimumEnergy
+    );
+    require!(
+        energy_amount <= poa_config.max_erc_amount,
+        GovernanceError::ExceedsMaximumEnergy
+    );
+    require!(
+        certificate_id.len() <= 64,
+        GovernanceError::CertificateIdTooLong
+    );
+    require!(
+        renewable_source.len() <= 64,
+        GovernanceError::SourceNameTooLong
+    );
+    require!(
+        validation_data.len() <= 256,
+        GovernanceError::ValidationDataTooLong
+    );
+
+    // === PREVENT DOUBLE-CLAIMING ===
+    // Checked here so an over-claim fails with a governance error instead of surfacing
+    // as the registry's generic `NoUnsettledBalance` from inside the CPI below.
+    require!(
+        energy_amount <= claimable_generation,
+        GovernanceError::ExceedsClaimableGeneration
+    );
+
+    // Check oracle requirement
+    if poa_config.require_oracle_validation {
+        require!(
+            poa_config.oracle_authority!= Pubkey::default(),
+            GovernanceError::OracleValidationRequired
+        );
+    }
+    Ok(())
+}
+
+/// Create the `["erc_certificate", certificate_id]` PDA for a batch entry. An account that
+/// is already program-owned means the id was issued before (or twice in this batch).
+fn create_certificate_account<'info>(
+    acct: &AccountInfo<'info>,
+    certificate_id: &str,
+    payer: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+) -> Result<()> {
+    let (expected, bump) = Pubkey::find_program_address(
+        &[b"erc_certificate", certificate_id.as_bytes()],
+        &crate::ID,
+    );
+    require_keys_eq!(*acct.key, expected, GovernanceError::InvalidErcBatch);
+    require!(acct.owner != &crate::ID, GovernanceError::CertificateAlreadyExists);
+    let space = 8 + ErcCertificate::LEN;
+    let lamports = Rent::get()?.minimum_balance(space);
+    let bump_arr = [bump];
+    let seeds: [&[u8]; 3] = [b"erc_certificate", certificate_id.as_bytes(), &bump_arr];
+    let signer_seeds: &[&[&[u8]]] = &[&seeds];
+    anchor_lang::system_program::create_account(
+        CpiContext::new_with_signer(
+            system_program.key(),
+            anchor_lang::system_program::CreateAccount { from: payer, to: acct.clone() },
+            signer_seeds,
+        ),
+        lamports,
+        space as u64,
+        &crate::ID,
+    )?;
+    Ok(())
+}
+
+/// Generation still available for ERC issuance. Mirrors the bound `registry::mark_erc_claimed`
+/// enforces: net generation minus what was already certified or settled for GRID.
+fn claimable_generation(meter: &MeterAccount) -> u64 {
+    meter
+        .total_generation
+        .saturating_sub(meter.total_consumption)
+        .saturating_sub(meter.claimed_erc_generation)
+        .saturating_sub(meter.settled_net_generation)
+}
+
 /// Initialize the fungible REC mint (PDA `[b"rec_mint"]`, 6 decimals, mint authority =
 /// governance_config PDA). Run once before issuing certificates. The mint is created
 /// entirely via account constraints.
@@ -279,6 +513,53 @@ pub fn revoke(ctx: Context<crate::RevokeErc>, reason: String) -> Result<()> {
     // Store certificate data before revocation
     let energy_amount = erc_certificate.energy_amount;
 
+    // === CPI: burn the RECs minted at issuance ===
+    // Releasing the claim without this would let the energy be certified (and REC-minted)
+    // a second time while the first batch stays in circulation.
+    let claim_released = match (
+        ctx.accounts.rec_mint.as_ref(),
+        ctx.accounts.rec_holder.as_ref(),
+        ctx.accounts.rec_token_account.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+    ) {
+        (Some(rec_mint), Some(rec_holder), Some(rec_token_account), Some(token_program)) => {
+            require!(
+                rec_token_account.mint == rec_mint.key()
+                    && rec_token_account.owner == rec_holder.key(),
+                GovernanceError::InvalidRecTokenAccount
+            );
+            let rec_amount = energy_amount
+                .checked_mul(1_000)
+                .ok_or(GovernanceError::MathOverflow)?;
+            let cpi_accounts = token_interface::Burn {
+                mint: rec_mint.to_account_info(),
+                from: rec_token_account.to_account_info(),
+                authority: rec_holder.to_account_info(),
+            };
+            token_interface::burn(
+                CpiContext::new(token_program.key(), cpi_accounts),
+                rec_amount,
+            )?;
+            true
+        }
+        (None, None, None, None) => false,
+        _ => return err!(GovernanceError::IncompleteRecBurnAccounts),
+    };
+
+    // === CPI: hand the certified generation back to the meter ===
+    // Undoes the `mark_erc_claimed` bump from issuance so the energy can be certified
+    // again; the meter is pinned to the certificate by the context. Without the burn the
+    // claim stays, so the revoked energy is never certified twice.
+    if claim_released {
+        let cpi_accounts = registry::cpi::accounts::ReleaseErcClaim {
+            meter_account: ctx.accounts.meter_account.to_account_info(),
+            registry: ctx.accounts.registry.to_account_info(),
+            authority: ctx.accounts.authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.registry_program.key(), cpi_accounts);
+        registry::cpi::release_erc_claim(cpi_ctx, energy_amount)?;
+    }
+
     // Revoke the certificate
     erc_certificate.status = ErcStatus::Revoked;
     erc_certificate.revoked_at = Some(clock.unix_timestamp);
@@ -306,11 +587,147 @@ pub fn revoke(ctx: Context<crate::RevokeErc>, reason: String) -> Result<()> {
         reason, // moved — no clone needed
         energy_amount,
         timestamp: clock.unix_timestamp,
+        claim_released,
+    });
+
+    Ok(())
+}
+
+/// Grow a certificate issued before `meter_account` and `reserved_amount` existed to the
+/// current size and record its meter - PoA authority only. The appended bytes are zero, so
+/// `reserved_amount` starts at 0; a certificate that already names a meter must match it.
+pub fn migrate_certificate(
+    ctx: Context<crate::MigrateErcCertificate>,
+    _certificate_id: String,
+) -> Result<()> {
+    let cert_info = ctx.accounts.erc_certificate.to_account_info();
+    let new_len = 8 + ErcCertificate::LEN;
+    require!(
+        cert_info.data_len() < new_len,
+        GovernanceError::AccountAlreadyMigrated
+    );
+    require!(
+        cert_info.try_borrow_data()?.starts_with(ErcCertificate::DISCRIMINATOR),
+        ErrorCode::AccountDiscriminatorMismatch
+    );
+
+    let top_up = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(cert_info.lamports());
+    if top_up > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.key(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: cert_info.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+    cert_info.resize(new_len)?;
+
+    // Option fields only ever go None -> Some, so the bytes past a legacy certificate's
+    // Borsh payload were never written and the new fields decode as zero.
+    let mut certificate = ErcCertificate::try_deserialize(&mut &cert_info.try_borrow_data()?[..])?;
+    let meter = ctx.accounts.meter_account.key();
+    if certificate.meter_account == Pubkey::default() {
+        certificate.meter_account = meter;
+    } else {
+        require_keys_eq!(
+            certificate.meter_account,
+            meter,
+            GovernanceError::InvalidMeterAccount
+        );
+    }
+    certificate.try_serialize(&mut &mut cert_info.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
+
+/// Mark a Valid certificate Expired once `expires_at` has passed - permissionless keeper call
+pub fn expire(ctx: Context<crate::ExpireErc>) -> Result<()> {
+    let erc_certificate = &mut ctx.accounts.erc_certificate;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(
+        erc_certificate.status == ErcStatus::Valid,
+        GovernanceError::InvalidErcStatus
+    );
+    require!(
+        erc_certificate.is_expired_at(now),
+        GovernanceError::ErcNotExpired
+    );
+
+    erc_certificate.status = ErcStatus::Expired;
+    erc_certificate.validated_for_trading = false;
+
+    emit!(ErcExpired {
+        certificate_id: String::from_utf8_lossy(
+            &erc_certificate.certificate_id[..erc_certificate.id_len as usize],
+        )
+        .into_owned(),
+        owner: erc_certificate.owner,
+        energy_amount: erc_certificate.energy_amount,
+        expires_at: erc_certificate.expires_at.unwrap_or_default(),
+        timestamp: now,
     });
 
     Ok(())
 }
 
+/// Commit `amount` kWh of a certificate to a trading sell order. Only callable by the
+/// trading program (its `market_authority` PDA signs the CPI); the cumulative total across
+/// all orders may not exceed the certified amount.
+pub fn reserve(ctx: Context<crate::ReserveErc>, amount: u64) -> Result<()> {
+    let erc_certificate = &mut ctx.accounts.erc_certificate;
+    require!(amount > 0, GovernanceError::InvalidAmount);
+
+    let reserved_amount = erc_certificate
+        .reserved_amount
+        .checked_add(amount)
+        .ok_or(GovernanceError::MathOverflow)?;
+    require!(
+        reserved_amount <= erc_certificate.energy_amount,
+        GovernanceError::ErcOverCommitted
+    );
+    erc_certificate.reserved_amount = reserved_amount;
+
+    let now = Clock::get()?.unix_timestamp;
+    emit!(ErcReservationUpdated {
+        certificate_id: String::from_utf8_lossy(
+            &erc_certificate.certificate_id[..erc_certificate.id_len as usize],
+        )
+        .into_owned(),
+        amount,
+        reserved: true,
+        reserved_amount,
+        timestamp: now,
+    });
+    Ok(())
+}
+
+/// Return the unfilled part of a cancelled or expired order's reservation (trading CPI only).
+/// Saturates so a release can never strand the order, e.g. after a revocation.
+pub fn release(ctx: Context<crate::ReserveErc>, amount: u64) -> Result<()> {
+    let erc_certificate = &mut ctx.accounts.erc_certificate;
+    erc_certificate.reserved_amount = erc_certificate.reserved_amount.saturating_sub(amount);
+
+    let now = Clock::get()?.unix_timestamp;
+    emit!(ErcReservationUpdated {
+        certificate_id: String::from_utf8_lossy(
+            &erc_certificate.certificate_id[..erc_certificate.id_len as usize],
+        )
+        .into_owned(),
+        amount,
+        reserved: false,
+        reserved_amount: erc_certificate.reserved_amount,
+        timestamp: now,
+    });
+    Ok(())
+}
+
 /// Transfer ERC ownership
 pub fn transfer(ctx: Context<crate::TransferErc>) -> Result<()> {
     let poa_config = &mut ctx.accounts.governance_config;
@@ -372,3 +789,78 @@ pub fn transfer(ctx: Context<crate::TransferErc>) -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn meter(generation: u64, consumption: u64, claimed: u64, settled: u64) -> MeterAccount {
+        let mut m: MeterAccount = bytemuck::Zeroable::zeroed();
+        m.total_generation = generation;
+        m.total_consumption = consumption;
+        m.claimed_erc_generation = claimed;
+        m.settled_net_generation = settled;
+        m
+    }
+
+    #[test]
+    fn fresh_meter_can_claim_all_net_generation() {
+        assert_eq!(claimable_generation(&meter(1_000, 200, 0, 0)), 800);
+    }
+
+    #[test]
+    fn prior_claims_and_settlement_reduce_claimable() {
+        // net 800 - 300 certified - 100 settled.
+        assert_eq!(claimable_generation(&meter(1_000, 200, 300, 100)), 400);
+    }
+
+    #[test]
+    fn second_overlapping_claim_is_not_claimable() {
+        // The full net was already certified, so nothing is left for another certificate.
+        assert_eq!(claimable_generation(&meter(1_000, 200, 800, 0)), 0);
+    }
+
+    #[test]
+    fn consumption_above_generation_saturates_to_zero() {
+        assert_eq!(claimable_generation(&meter(100, 500, 0, 0)), 0);
+    }
+
+    fn certificate(expires_at: Option<i64>) -> ErcCertificate {
+        ErcCertificate {
+            certificate_id: [0; 64],
+            id_len: 0,
+            authority: Pubkey::default(),
+            owner: Pubkey::default(),
+            energy_amount: 0,
+            renewable_source: [0; 64],
+            source_len: 0,
+            validation_data: [0; 256],
+            data_len: 0,
+            issued_at: 0,
+            expires_at,
+            status: ErcStatus::Valid,
+            validated_for_trading: false,
+            trading_validated_at: None,
+            revocation_reason: [0; 128],
+            reason_len: 0,
+            revoked_at: None,
+            transfer_count: 0,
+            last_transferred_at: None,
+            meter_account: Pubkey::default(),
+            reserved_amount: 0,
+        }
+    }
+
+    #[test]
+    fn certificate_expires_at_its_deadline() {
+        let erc = certificate(Some(1_000));
+        assert!(!erc.is_expired_at(999));
+        assert!(erc.is_expired_at(1_000));
+        assert!(erc.is_expired_at(5_000));
+    }
+
+    #[test]
+    fn certificate_without_expiry_never_expires() {
+        assert!(!certificate(None).is_expired_at(i64::MAX));
+    }
+}
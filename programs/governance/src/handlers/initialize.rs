@@ -59,8 +59,12 @@ pub fn handler(ctx: Context<InitializeGovernance>) -> Result<()> {
     poa_config.pending_authority_proposed_at = 0;
     poa_config.pending_authority_expires_at = 0;
 
+    // Trading fee bounds (unset)
+    poa_config.min_fee_bps = 0;
+    poa_config.max_fee_bps = 0;
+
     // Reserved padding
-    poa_config._reserved = [0u8; 5];
+    poa_config._reserved = [0u8; 1];
 
     // Validate configuration
     poa_config.validate_config()?;
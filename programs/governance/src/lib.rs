@@ -19,6 +19,10 @@ pub use state::*;
 
 declare_id!("FokVuBSPXP11aeL7VZWd8n8aVAhWqVpyPZETToSxdvTS");
 
+/// Trading program, whose `market_authority` PDA reserves ERC capacity for sell orders.
+/// Hardcoded because trading depends on this crate (a crate dependency would be a cycle).
+pub const TRADING_PROGRAM_ID: Pubkey = pubkey!("CnWDEUhTvSixeLSyViWgAnnu9YouBAYVGcrrFm1s9WcX");
+
 #[cfg(feature = "localnet")]
 use compute_debug::{compute_checkpoint, compute_fn};
 
@@ -62,6 +66,15 @@ pub mod governance {
         })
     }
 
+    pub fn issue_erc_batch<'info>(
+        ctx: Context<'info, IssueErcBatch<'info>>,
+        entries: Vec<ErcBatchEntry>,
+    ) -> Result<()> {
+        compute_fn!("issue_erc_batch" => {
+            handlers::erc::issue_batch(ctx, entries)
+        })
+    }
+
     pub fn validate_erc_for_trading(ctx: Context<ValidateErc>) -> Result<()> {
         compute_fn!("validate_erc_for_trading" => {
             handlers::erc::validate_for_trading(ctx)
@@ -117,6 +130,18 @@ pub mod governance {
         })
     }
 
+    /// Bound every trading market's `market_fee_bps` to `[min_fee_bps, max_fee_bps]`.
+    /// `max_fee_bps == 0` lifts the bounds.
+    pub fn set_fee_bounds(
+        ctx: Context<UpdateGovernanceConfig>,
+        min_fee_bps: u16,
+        max_fee_bps: u16,
+    ) -> Result<()> {
+        compute_fn!("set_fee_bounds" => {
+            handlers::config::set_fee_bounds(ctx, min_fee_bps, max_fee_bps)
+        })
+    }
+
     pub fn update_authority_info(
         ctx: Context<UpdateGovernanceConfig>,
         contact_info: String,
@@ -138,6 +163,34 @@ pub mod governance {
         })
     }
 
+    /// Grow a certificate issued before `meter_account` existed and record its meter.
+    pub fn migrate_erc_certificate(
+        ctx: Context<MigrateErcCertificate>,
+        certificate_id: String,
+    ) -> Result<()> {
+        compute_fn!("migrate_erc_certificate" => {
+            handlers::erc::migrate_certificate(ctx, certificate_id)
+        })
+    }
+
+    pub fn expire_erc(ctx: Context<ExpireErc>) -> Result<()> {
+        compute_fn!("expire_erc" => {
+            handlers::erc::expire(ctx)
+        })
+    }
+
+    pub fn reserve_erc(ctx: Context<ReserveErc>, amount: u64) -> Result<()> {
+        compute_fn!("reserve_erc" => {
+            handlers::erc::reserve(ctx, amount)
+        })
+    }
+
+    pub fn release_erc(ctx: Context<ReserveErc>, amount: u64) -> Result<()> {
+        compute_fn!("release_erc" => {
+            handlers::erc::release(ctx, amount)
+        })
+    }
+
     pub fn transfer_erc(ctx: Context<TransferErc>) -> Result<()> {
         compute_fn!("transfer_erc" => {
             handlers::erc::transfer(ctx)
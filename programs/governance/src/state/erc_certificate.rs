@@ -40,6 +40,12 @@ pub struct ErcCertificate {
     pub transfer_count: u8,
     /// Last transfer timestamp
     pub last_transferred_at: Option<i64>,
+
+    /// Registry meter the generation was claimed from (credited back on revocation)
+    pub meter_account: Pubkey,
+
+    /// kWh committed to open or filled trading sell orders (see `reserve_erc`)
+    pub reserved_amount: u64,
 }
 
 impl ErcCertificate {
@@ -48,8 +54,9 @@ impl ErcCertificate {
     // renewable_source (64 + 1) + validation_data (256 + 2) + i64 (8) +
     // expires_at (Option<i64>: 9) + ErcStatus (1) + bool (1) +
     // trading_validated_at (Option<i64>: 9) + revocation_reason (128 + 1) +
-    // revoked_at (Option<i64>: 9) + u8 (1) + last_transferred_at (Option<i64>: 9)
-    pub const LEN: usize = 65 + 32 + 32 + 8 + 65 + 258 + 8 + 9 + 1 + 1 + 9 + 129 + 9 + 1 + 9;
+    // revoked_at (Option<i64>: 9) + u8 (1) + last_transferred_at (Option<i64>: 9) +
+    // meter_account (32) + reserved_amount (8)
+    pub const LEN: usize = 65 + 32 + 32 + 8 + 65 + 258 + 8 + 9 + 1 + 1 + 9 + 129 + 9 + 1 + 9 + 32 + 8;
 
     /// Check if certificate can be transferred
     pub fn can_transfer(&self) -> bool {
@@ -60,6 +67,24 @@ impl ErcCertificate {
     pub fn can_revoke(&self) -> bool {
         self.status == ErcStatus::Valid || self.status == ErcStatus::Pending
     }
+
+    /// Certified energy not yet committed to sell orders
+    pub fn unreserved_amount(&self) -> u64 {
+        self.energy_amount.saturating_sub(self.reserved_amount)
+    }
+
+    /// Check if the validity window has closed at `now` (certificates without expiry never expire)
+    pub fn is_expired_at(&self, now: i64) -> bool {
+        matches!(self.expires_at, Some(expires_at) if now >= expires_at)
+    }
+}
+
+/// One certificate of an `issue_erc_batch` call; its meter is passed in `remaining_accounts`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ErcBatchEntry {
+    pub certificate_id: String,
+    pub energy_amount: u64,
+    pub renewable_source: String,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
@@ -68,8 +68,14 @@ pub struct GovernanceConfig {
     pub pending_authority_proposed_at: i64,
     /// When the pending authority change expires (48 hours)
     pub pending_authority_expires_at: i64,
+
+    // === Trading Fee Bounds ===
+    /// Lowest `market_fee_bps` a trading market may charge
+    pub min_fee_bps: u16,
+    /// Highest `market_fee_bps` a trading market may charge (0 = bounds disabled)
+    pub max_fee_bps: u16,
     /// Reserved space for future upgrades (and to match on-chain size)
-    pub _reserved: [u8; 5],
+    pub _reserved: [u8; 1],
 }
 
 impl GovernanceConfig {
@@ -113,7 +119,11 @@ impl GovernanceConfig {
         32 +    // pending_authority (Pubkey)
         8 +     // pending_authority_proposed_at (i64)
         8 +      // pending_authority_expires_at (i64)
-        5;      // _reserved
+
+        // Trading Fee Bounds
+        2 +     // min_fee_bps
+        2 +     // max_fee_bps
+        1;      // _reserved
 
     /// Validate that config parameters are within acceptable ranges
     pub fn validate_config(&self) -> Result<()> {
@@ -145,6 +155,11 @@ impl GovernanceConfig {
     pub fn can_issue_erc(&self) -> bool {
         self.is_operational() && self.erc_validation_enabled
     }
+
+    /// Check a trading fee against the governance bounds (always true while unset)
+    pub fn fee_within_bounds(&self, fee_bps: u16) -> bool {
+        self.max_fee_bps == 0 || (self.min_fee_bps..=self.max_fee_bps).contains(&fee_bps)
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -28,4 +28,12 @@ pub enum OracleError {
     MeterIdTooLong,
     #[msg("Aggregator is not on the governance allow-list, inactive, or entry mismatched")]
     AggregatorNotAdmitted,
+    #[msg("Consensus is required - submit via submit_meter_reading_consensus")]
+    ConsensusRequired,
+    #[msg("Not enough distinct authorized oracles signed the reading")]
+    InsufficientConsensus,
+    #[msg("Reading deviates from the previous accepted reading by more than the allowed percent")]
+    ReadingDeviationExceeded,
+    #[msg("Account already has the current layout")]
+    AccountAlreadyMigrated,
 }
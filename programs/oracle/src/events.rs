@@ -66,3 +66,63 @@ pub struct ReadingsAggregated {
     pub rejected_count: u64,
     pub timestamp: i64,
 }
+
+#[event]
+pub struct ConsensusReached {
+    pub meter_id: String,
+    pub signer_count: u8,
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConsensusConfigUpdated {
+    pub authority: Pubkey,
+    pub backup_oracle_count: u8,
+    pub consensus_threshold: u8,
+    pub require_consensus: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReadingRejected {
+    pub meter_id: String,
+    pub energy_produced: u64,
+    pub energy_consumed: u64,
+    pub previous_energy_produced: u64,
+    pub previous_energy_consumed: u64,
+    pub max_deviation_percent: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DeviationConfigUpdated {
+    pub authority: Pubkey,
+    pub max_reading_deviation_percent: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MeterRejectionsRecorded {
+    pub meter_id: String,
+    pub rejected_count: u64,
+    pub total_rejected: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct QualityConfigUpdated {
+    pub authority: Pubkey,
+    pub quality_ema_alpha: u8,
+    pub quality_alert_threshold: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct QualityScoreUpdated {
+    pub previous_score: u8,
+    pub new_score: u8,
+    pub alert_threshold: u8,
+    pub below_threshold: bool,
+    pub timestamp: i64,
+}
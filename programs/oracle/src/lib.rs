@@ -60,6 +60,16 @@ pub mod oracle {
             oracle_data.total_global_energy_consumed = 0;
             oracle_data.min_reading_interval = 60;
             oracle_data.last_cleared_epoch = 0;
+
+            // Consensus off by default: the chain bridge alone satisfies a threshold of 1.
+            oracle_data.backup_oracle_count = 0;
+            oracle_data.consensus_threshold = 1;
+            oracle_data.require_consensus = 0;
+            oracle_data.last_consensus_timestamp = 0;
+
+            oracle_data.max_reading_deviation_percent = 0; // jump check off until configured
+            oracle_data.quality_ema_alpha = 0; // lifetime success rate until configured
+            oracle_data.quality_alert_threshold = 0;
         });
 
         Ok(())
@@ -102,77 +112,99 @@ pub mod oracle {
                 OracleError::UnauthorizedGateway
             );
 
+            // Single-signer path is closed once the admin opts into multi-oracle consensus.
+            require!(oracle_data.require_consensus == 0, OracleError::ConsensusRequired);
+
             let current_time = Clock::get()?.unix_timestamp;
 
-            // Validate timestamp sanity relative to current time only
+            apply_meter_reading(
+                &oracle_data,
+                &mut ctx.accounts.meter_state,
+                ctx.bumps.meter_state,
+                ctx.accounts.authority.key(),
+                &meter_id,
+                energy_produced,
+                energy_consumed,
+                reading_timestamp,
+                zone_id,
+                current_time,
+            )?;
+
+            let meter_stats = &mut ctx.accounts.meter_stats;
+            meter_stats.bind(&meter_id, ctx.bumps.meter_stats);
+            meter_stats.record_accepted(reading_timestamp);
+        });
+
+        Ok(())
+    }
+
+    /// Submit a meter reading co-signed by multiple oracles
+    ///
+    /// The fee payer plus every `remaining_accounts` entry are the co-signers. Each must sign
+    /// and be either the chain bridge or a configured backup oracle; at least
+    /// `consensus_threshold` distinct keys are required. Unlike `submit_meter_reading` this
+    /// write-locks oracle_data (for `last_consensus_timestamp`), so consensus readings serialize.
+    pub fn submit_meter_reading_consensus<'info>(
+        ctx: Context<'info, SubmitMeterReadingConsensus<'info>>,
+        meter_id: String,
+        energy_produced: u64,
+        energy_consumed: u64,
+        reading_timestamp: i64,
+        zone_id: i32,
+    ) -> Result<()> {
+        compute_fn!("submit_meter_reading_consensus" => {
             require!(
-                reading_timestamp <= current_time + 60,
-                OracleError::FutureReading
+                meter_id.len() <= MAX_METER_ID_LEN,
+                OracleError::MeterIdTooLong
             );
 
-            // Rate limit and outdated reading validation
-            if ctx.accounts.meter_state.total_readings > 0 {
-                require!(
-                    reading_timestamp > ctx.accounts.meter_state.last_reading_timestamp,
-                    OracleError::OutdatedReading
-                );
-                require!(
-                    reading_timestamp >= ctx.accounts.meter_state.last_reading_timestamp.saturating_add(oracle_data.min_reading_interval as i64),
-                    OracleError::RateLimitExceeded
-                );
-            }
+            let mut oracle_data = ctx.accounts.oracle_data.load_mut()?;
 
-            // Validation logic (stateless, uses read-only config)
-            validate_meter_reading(
-                energy_produced,
-                energy_consumed,
-                &oracle_data,
-            ).map_err(|e| {
-                emit!(MeterReadingRejected {
-                    meter_id: meter_id.clone(),
-                    energy_produced,
-                    energy_consumed,
-                    timestamp: reading_timestamp,
-                    zone_id,
-                    reason: format!("{:?}", e),
-                });
-                e
-            })?;
-
-            // Drop the read-only borrow before writing to meter_state
-            drop(oracle_data);
-
-            // Write to per-meter PDA — each meter locks its own account
-            let meter_state = &mut ctx.accounts.meter_state;
-
-            // Initialize meter_id on first use
-            if meter_state.total_readings == 0 {
-                let mut id_bytes = [0u8; MAX_METER_ID_LEN];
-                let id_src = meter_id.as_bytes();
-                id_bytes[..id_src.len()].copy_from_slice(id_src);
-                meter_state.meter_id = id_bytes;
-                meter_state.meter_id_len = id_src.len() as u8;
-                meter_state.bump = ctx.bumps.meter_state;
-                meter_state.created_at = current_time;
-            }
+            require!(oracle_data.active == 1, OracleError::OracleInactive);
 
-            // Update zone_id on every submission (allows relocation of meters between zones)
-            meter_state.zone_id = zone_id;
+            let mut signers = Vec::with_capacity(ctx.remaining_accounts.len() + 1);
+            signers.push(ctx.accounts.authority.key());
+            for info in ctx.remaining_accounts.iter() {
+                require!(info.is_signer, OracleError::UnauthorizedGateway);
+                signers.push(info.key());
+            }
+            let signer_count = count_consensus_signers(
+                &signers,
+                oracle_data.chain_bridge,
+                oracle_data.active_backup_oracles(),
+            )?;
+            let threshold = oracle_data.consensus_threshold.max(1);
+            require!(
+                signer_count >= threshold as usize,
+                OracleError::InsufficientConsensus
+            );
 
-            meter_state.energy_produced = energy_produced;
-            meter_state.energy_consumed = energy_consumed;
-            meter_state.total_energy_produced = meter_state.total_energy_produced.saturating_add(energy_produced);
-            meter_state.total_energy_consumed = meter_state.total_energy_consumed.saturating_add(energy_consumed);
-            meter_state.last_reading_timestamp = reading_timestamp;
-            meter_state.total_readings = meter_state.total_readings.saturating_add(1);
+            let current_time = Clock::get()?.unix_timestamp;
 
-            emit!(MeterReadingSubmitted {
-                meter_id: meter_id.clone(),
+            apply_meter_reading(
+                &oracle_data,
+                &mut ctx.accounts.meter_state,
+                ctx.bumps.meter_state,
+                ctx.accounts.authority.key(),
+                &meter_id,
                 energy_produced,
                 energy_consumed,
-                timestamp: reading_timestamp,
+                reading_timestamp,
                 zone_id,
-                submitter: ctx.accounts.authority.key(),
+                current_time,
+            )?;
+
+            let meter_stats = &mut ctx.accounts.meter_stats;
+            meter_stats.bind(&meter_id, ctx.bumps.meter_stats);
+            meter_stats.record_accepted(reading_timestamp);
+
+            oracle_data.last_consensus_timestamp = current_time;
+
+            emit!(ConsensusReached {
+                meter_id,
+                signer_count: signer_count as u8,
+                threshold,
+                timestamp: current_time,
             });
         });
 
@@ -300,6 +332,61 @@ pub mod oracle {
         Ok(())
     }
 
+    /// Update the max allowed change between consecutive readings of a meter (admin only)
+    /// Expressed in whole percent of the prior accepted value; 0 disables the check.
+    pub fn update_deviation_config(
+        ctx: Context<UpdateValidationConfig>,
+        max_reading_deviation_percent: u16,
+    ) -> Result<()> {
+        compute_fn!("update_deviation_config" => {
+            let mut oracle_data = ctx.accounts.oracle_data.load_mut()?;
+            require_oracle_admin(&oracle_data, ctx.accounts.authority.key())?;
+
+            oracle_data.max_reading_deviation_percent = max_reading_deviation_percent;
+
+            // Hoist Clock::get() before emit! (invariant #5).
+            let now = Clock::get()?.unix_timestamp;
+            emit!(DeviationConfigUpdated {
+                authority: ctx.accounts.authority.key(),
+                max_reading_deviation_percent,
+                timestamp: now,
+            });
+        });
+        Ok(())
+    }
+
+    /// Configure how the quality score reacts to recent batches (admin only)
+    /// `quality_ema_alpha` is the percent weight of the latest `aggregate_readings` batch
+    /// (0 keeps the lifetime success rate); `quality_alert_threshold` is the score whose
+    /// crossing emits `QualityScoreUpdated` (0 disables the alert).
+    pub fn update_quality_config(
+        ctx: Context<UpdateValidationConfig>,
+        quality_ema_alpha: u8,
+        quality_alert_threshold: u8,
+    ) -> Result<()> {
+        compute_fn!("update_quality_config" => {
+            let mut oracle_data = ctx.accounts.oracle_data.load_mut()?;
+            require_oracle_admin(&oracle_data, ctx.accounts.authority.key())?;
+
+            require!(
+                quality_ema_alpha <= 100 && quality_alert_threshold <= 100,
+                OracleError::InvalidConfiguration
+            );
+
+            oracle_data.quality_ema_alpha = quality_ema_alpha;
+            oracle_data.quality_alert_threshold = quality_alert_threshold;
+
+            let now = Clock::get()?.unix_timestamp;
+            emit!(QualityConfigUpdated {
+                authority: ctx.accounts.authority.key(),
+                quality_ema_alpha,
+                quality_alert_threshold,
+                timestamp: now,
+            });
+        });
+        Ok(())
+    }
+
     /// Update validation configuration (admin only)
     pub fn update_validation_config(
         ctx: Context<UpdateValidationConfig>,
@@ -332,6 +419,55 @@ pub mod oracle {
         Ok(())
     }
 
+    /// Configure the backup oracle set and consensus policy (admin only)
+    pub fn update_consensus_config(
+        ctx: Context<UpdateValidationConfig>,
+        backup_oracles: Vec<Pubkey>,
+        consensus_threshold: u8,
+        require_consensus: bool,
+    ) -> Result<()> {
+        compute_fn!("update_consensus_config" => {
+            let mut oracle_data = ctx.accounts.oracle_data.load_mut()?;
+            require_oracle_admin(&oracle_data, ctx.accounts.authority.key())?;
+
+            require!(
+                backup_oracles.len() <= MAX_BACKUP_ORACLES,
+                OracleError::InvalidConfiguration
+            );
+            // A duplicate slot would let one key be listed twice without adding a signer.
+            for (i, key) in backup_oracles.iter().enumerate() {
+                require!(
+                    *key != oracle_data.chain_bridge && !backup_oracles[..i].contains(key),
+                    OracleError::InvalidConfiguration
+                );
+            }
+            // Threshold must be reachable by the chain bridge plus every backup.
+            require!(
+                consensus_threshold >= 1 && consensus_threshold as usize <= backup_oracles.len() + 1,
+                OracleError::InvalidConfiguration
+            );
+
+            let mut slots = [Pubkey::default(); MAX_BACKUP_ORACLES];
+            slots[..backup_oracles.len()].copy_from_slice(&backup_oracles);
+            oracle_data.backup_oracles = slots;
+            oracle_data.backup_oracle_count = backup_oracles.len() as u8;
+            oracle_data.consensus_threshold = consensus_threshold;
+            oracle_data.require_consensus = if require_consensus { 1 } else { 0 };
+
+            // Hoist Clock::get() before emit! (invariant #5).
+            let now = Clock::get()?.unix_timestamp;
+            emit!(ConsensusConfigUpdated {
+                authority: ctx.accounts.authority.key(),
+                backup_oracle_count: backup_oracles.len() as u8,
+                consensus_threshold,
+                require_consensus,
+                timestamp: now,
+            });
+        });
+
+        Ok(())
+    }
+
     /// Aggregate meter readings into global counters (only via API Gateway)
     ///
     /// Called periodically by the Gateway to batch-update global totals,
@@ -365,15 +501,19 @@ pub mod oracle {
             oracle_data.total_readings = oracle_data.total_readings.saturating_add(valid_count).saturating_add(rejected_count);
             oracle_data.last_reading_timestamp = current_time;
 
-            // Update quality score inline
-            let total = oracle_data.total_valid_readings.saturating_add(oracle_data.total_rejected_readings);
-            if total > 0 {
-                let success_rate = oracle_data.total_valid_readings
-                    .saturating_mul(100)
-                    .checked_div(total)
-                    .unwrap_or(0);
-                oracle_data.last_quality_score = success_rate.min(100) as u8;
-                oracle_data.quality_score_updated_at = current_time;
+            // Rejections (range, ratio, deviation) revert their own submission, so the
+            // gateway reports them here and they feed the quality score.
+            let previous_score = oracle_data.last_quality_score;
+            update_quality_score(&mut oracle_data, valid_count, rejected_count, current_time);
+            let threshold = oracle_data.quality_alert_threshold;
+            if crosses_quality_threshold(previous_score, oracle_data.last_quality_score, threshold) {
+                emit!(QualityScoreUpdated {
+                    previous_score,
+                    new_score: oracle_data.last_quality_score,
+                    alert_threshold: threshold,
+                    below_threshold: oracle_data.last_quality_score < threshold,
+                    timestamp: current_time,
+                });
             }
 
             emit!(ReadingsAggregated {
@@ -388,12 +528,88 @@ pub mod oracle {
 
         Ok(())
     }
+
+    /// Record gateway-observed rejections against one meter (only via API Gateway)
+    ///
+    /// A rejected reading reverts its own submission, so `meter_stats.rejected_count` cannot
+    /// be bumped in-line. The gateway reports per-meter rejections here, alongside the global
+    /// count it passes to `aggregate_readings`.
+    pub fn record_meter_rejections(
+        ctx: Context<RecordMeterRejections>,
+        meter_id: String,
+        rejected_count: u64,
+    ) -> Result<()> {
+        compute_fn!("record_meter_rejections" => {
+            require!(
+                meter_id.len() <= MAX_METER_ID_LEN,
+                OracleError::MeterIdTooLong
+            );
+
+            let oracle_data = ctx.accounts.oracle_data.load()?;
+
+            require!(oracle_data.active == 1, OracleError::OracleInactive);
+
+            authorize_node_caller(
+                ctx.accounts.authority.key(),
+                oracle_data.chain_bridge,
+                ctx.accounts.aggregator_entry.as_ref().map(|a| a.as_ref()),
+            )?;
+
+            let meter_stats = &mut ctx.accounts.meter_stats;
+            meter_stats.bind(&meter_id, ctx.bumps.meter_stats);
+            meter_stats.record_rejected(rejected_count);
+
+            let now = Clock::get()?.unix_timestamp;
+            emit!(MeterRejectionsRecorded {
+                meter_id,
+                rejected_count,
+                total_rejected: meter_stats.rejected_count,
+                timestamp: now,
+            });
+        });
+
+        Ok(())
+    }
+
+    /// Grow an `OracleData` created before the consensus block (168-byte payload) to the
+    /// current layout. Permissionless: the appended bytes are zero apart from
+    /// `consensus_threshold = 1`, which matches `initialize` — no backups, consensus not
+    /// required, deviation check and quality EMA off. `payer` funds the extra rent.
+    pub fn migrate_oracle_data(ctx: Context<MigrateOracleData>) -> Result<()> {
+        compute_fn!("migrate_oracle_data" => {
+            let oracle_info = ctx.accounts.oracle_data.to_account_info();
+            let new_len = 8 + std::mem::size_of::<OracleData>();
+            require!(oracle_info.data_len() < new_len, OracleError::AccountAlreadyMigrated);
+
+            let top_up = Rent::get()?
+                .minimum_balance(new_len)
+                .saturating_sub(oracle_info.lamports());
+            if top_up > 0 {
+                anchor_lang::system_program::transfer(
+                    CpiContext::new(
+                        ctx.accounts.system_program.key(),
+                        anchor_lang::system_program::Transfer {
+                            from: ctx.accounts.payer.to_account_info(),
+                            to: oracle_info.clone(),
+                        },
+                    ),
+                    top_up,
+                )?;
+            }
+            oracle_info.resize(new_len)?;
+
+            let mut oracle_data = ctx.accounts.oracle_data.load_mut()?;
+            oracle_data.consensus_threshold = 1;
+        });
+
+        Ok(())
+    }
 }
 
 /// Admin gate for the oracle config handlers — the signer must be the stored
 /// `OracleData.authority`. Single source of truth shared by every admin instruction
 /// (update_oracle_status / update_api_gateway / update_production_ratio_config /
-/// update_validation_config) so the check can never drift between them.
+/// update_validation_config / update_deviation_config / update_consensus_config) so the check can never drift between them.
 fn require_oracle_admin(oracle_data: &OracleData, signer: Pubkey) -> Result<()> {
     require!(signer == oracle_data.authority, OracleError::UnauthorizedAuthority);
     Ok(())
@@ -424,10 +640,177 @@ fn authorize_node_caller(
     Ok(())
 }
 
+/// Count the distinct co-signers of a consensus reading. Every key must be the chain
+/// bridge or a configured backup oracle; a repeated key is counted once so a single
+/// oracle can't satisfy the threshold by appearing several times.
+fn count_consensus_signers(
+    signers: &[Pubkey],
+    chain_bridge: Pubkey,
+    backup_oracles: &[Pubkey],
+) -> Result<usize> {
+    let mut distinct: Vec<Pubkey> = Vec::with_capacity(signers.len());
+    for key in signers {
+        require!(
+            *key == chain_bridge || backup_oracles.contains(key),
+            OracleError::UnauthorizedGateway
+        );
+        if !distinct.contains(key) {
+            distinct.push(*key);
+        }
+    }
+    Ok(distinct.len())
+}
+
+/// Shared body of the reading instructions once the caller is authorized: timestamp and
+/// rate-limit checks, stateless validation, and the per-meter write.
+#[allow(clippy::too_many_arguments)]
+fn apply_meter_reading(
+    oracle_data: &OracleData,
+    meter_state: &mut MeterState,
+    meter_bump: u8,
+    submitter: Pubkey,
+    meter_id: &str,
+    energy_produced: u64,
+    energy_consumed: u64,
+    reading_timestamp: i64,
+    zone_id: i32,
+    current_time: i64,
+) -> Result<()> {
+    // Validate timestamp sanity relative to current time only
+    require!(
+        reading_timestamp <= current_time + 60,
+        OracleError::FutureReading
+    );
+
+    // Rate limit and outdated reading validation
+    if meter_state.total_readings > 0 {
+        require!(
+            reading_timestamp > meter_state.last_reading_timestamp,
+            OracleError::OutdatedReading
+        );
+        require!(
+            reading_timestamp >= meter_state.last_reading_timestamp.saturating_add(oracle_data.min_reading_interval as i64),
+            OracleError::RateLimitExceeded
+        );
+    }
+
+    // Validation logic (read-only config + this meter's previous accepted reading)
+    let previous = (meter_state.total_readings > 0)
+        .then_some((meter_state.energy_produced, meter_state.energy_consumed));
+    validate_meter_reading(
+        energy_produced,
+        energy_consumed,
+        previous,
+        oracle_data,
+    ).map_err(|e| {
+        if let Some((previous_energy_produced, previous_energy_consumed)) = previous {
+            if e == OracleError::ReadingDeviationExceeded.into() {
+                emit!(ReadingRejected {
+                    meter_id: meter_id.to_string(),
+                    energy_produced,
+                    energy_consumed,
+                    previous_energy_produced,
+                    previous_energy_consumed,
+                    max_deviation_percent: oracle_data.max_reading_deviation_percent,
+                    timestamp: reading_timestamp,
+                });
+            }
+        }
+        emit!(MeterReadingRejected {
+            meter_id: meter_id.to_string(),
+            energy_produced,
+            energy_consumed,
+            timestamp: reading_timestamp,
+            zone_id,
+            reason: format!("{:?}", e),
+        });
+        e
+    })?;
+
+    // Initialize meter_id on first use
+    if meter_state.total_readings == 0 {
+        let mut id_bytes = [0u8; MAX_METER_ID_LEN];
+        let id_src = meter_id.as_bytes();
+        id_bytes[..id_src.len()].copy_from_slice(id_src);
+        meter_state.meter_id = id_bytes;
+        meter_state.meter_id_len = id_src.len() as u8;
+        meter_state.bump = meter_bump;
+        meter_state.created_at = current_time;
+    }
+
+    // Update zone_id on every submission (allows relocation of meters between zones)
+    meter_state.zone_id = zone_id;
+
+    meter_state.energy_produced = energy_produced;
+    meter_state.energy_consumed = energy_consumed;
+    meter_state.total_energy_produced = meter_state.total_energy_produced.saturating_add(energy_produced);
+    meter_state.total_energy_consumed = meter_state.total_energy_consumed.saturating_add(energy_consumed);
+    meter_state.last_reading_timestamp = reading_timestamp;
+    meter_state.total_readings = meter_state.total_readings.saturating_add(1);
+
+    emit!(MeterReadingSubmitted {
+        meter_id: meter_id.to_string(),
+        energy_produced,
+        energy_consumed,
+        timestamp: reading_timestamp,
+        zone_id,
+        submitter,
+    });
+
+    Ok(())
+}
+
+/// Recompute `last_quality_score`. With `quality_ema_alpha == 0` it is the valid share
+/// (0-100) of all counted readings. Otherwise the latest batch's valid share is blended in
+/// as `(alpha * batch + (100 - alpha) * previous) / 100`, so recent failures move the score
+/// regardless of how long the lifetime history is. An empty batch leaves the EMA unchanged.
+fn update_quality_score(oracle_data: &mut OracleData, batch_valid: u64, batch_rejected: u64, now: i64) {
+    let alpha = oracle_data.quality_ema_alpha.min(100) as u64;
+    let (valid, total) = if alpha == 0 {
+        (
+            oracle_data.total_valid_readings,
+            oracle_data.total_valid_readings.saturating_add(oracle_data.total_rejected_readings),
+        )
+    } else {
+        (batch_valid, batch_valid.saturating_add(batch_rejected))
+    };
+    if total > 0 {
+        let success_rate = valid
+            .saturating_mul(100)
+            .checked_div(total)
+            .unwrap_or(0)
+            .min(100);
+        let score = if alpha == 0 {
+            success_rate
+        } else {
+            (alpha * success_rate + (100 - alpha) * oracle_data.last_quality_score as u64) / 100
+        };
+        oracle_data.last_quality_score = score.min(100) as u8;
+        oracle_data.quality_score_updated_at = now;
+    }
+}
+
+/// True when the score moved from one side of `threshold` to the other (0 = alert off).
+fn crosses_quality_threshold(previous: u8, current: u8, threshold: u8) -> bool {
+    threshold > 0 && (previous < threshold) != (current < threshold)
+}
+
+/// True when `current` differs from `previous` by more than `max_percent` % of `previous`.
+/// A zero previous value is never a deviation: unilateral meters legitimately move 0 → x
+/// (e.g. solar at sunrise), and a percentage of zero is undefined.
+fn exceeds_reading_deviation(previous: u64, current: u64, max_percent: u16) -> bool {
+    if max_percent == 0 || previous == 0 {
+        return false;
+    }
+    let delta = previous.abs_diff(current) as u128;
+    delta * 100 > previous as u128 * max_percent as u128
+}
+
 // Validation functions
 fn validate_meter_reading(
     energy_produced: u64,
     energy_consumed: u64,
+    previous: Option<(u64, u64)>,
     oracle_data: &OracleData,
 ) -> Result<()> {
     // Range validation (only check min bound if value is non-zero to allow unilateral meters)
@@ -471,6 +854,16 @@ fn validate_meter_reading(
         }
     }
 
+    // Jump check against the meter's previous accepted reading
+    if let Some((prev_produced, prev_consumed)) = previous {
+        let max_percent = oracle_data.max_reading_deviation_percent;
+        require!(
+            !exceeds_reading_deviation(prev_produced, energy_produced, max_percent)
+                && !exceeds_reading_deviation(prev_consumed, energy_consumed, max_percent),
+            OracleError::ReadingDeviationExceeded
+        );
+    }
+
     Ok(())
 }
 
@@ -509,6 +902,50 @@ pub struct SubmitMeterReading<'info> {
     )]
     pub meter_state: Account<'info, MeterState>,
 
+    /// Per-meter counters (accepted readings, rejections)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = MeterOracleStats::SPACE,
+        seeds = [b"meter_stats", meter_id.as_bytes()],
+        bump
+    )]
+    pub meter_stats: Account<'info, MeterOracleStats>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(meter_id: String)]
+pub struct SubmitMeterReadingConsensus<'info> {
+    /// Global config — writable here to record `last_consensus_timestamp`
+    #[account(mut, seeds = [b"oracle_data"], bump)]
+    pub oracle_data: AccountLoader<'info, OracleData>,
+
+    /// Per-meter PDA — each meter locks its own account
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = MeterState::SPACE,
+        seeds = [b"meter", meter_id.as_bytes()],
+        bump
+    )]
+    pub meter_state: Account<'info, MeterState>,
+
+    /// Per-meter counters (accepted readings, rejections)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = MeterOracleStats::SPACE,
+        seeds = [b"meter_stats", meter_id.as_bytes()],
+        bump
+    )]
+    pub meter_stats: Account<'info, MeterOracleStats>,
+
+    /// First co-signer and rent payer; further co-signers arrive via remaining_accounts
     #[account(mut)]
     pub authority: Signer<'info>,
 
@@ -527,6 +964,44 @@ pub struct AggregateReadings<'info> {
     pub aggregator_entry: Option<UncheckedAccount<'info>>,
 }
 
+#[derive(Accounts)]
+#[instruction(meter_id: String)]
+pub struct RecordMeterRejections<'info> {
+    #[account(seeds = [b"oracle_data"], bump)]
+    pub oracle_data: AccountLoader<'info, OracleData>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = MeterOracleStats::SPACE,
+        seeds = [b"meter_stats", meter_id.as_bytes()],
+        bump
+    )]
+    pub meter_stats: Account<'info, MeterOracleStats>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: optional governance `AggregatorEntry` PDA, validated in-handler when the
+    /// caller is an admitted aggregator rather than the chain bridge.
+    pub aggregator_entry: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateOracleData<'info> {
+    /// Legacy-sized `OracleData`; `AccountLoader` only checks owner and discriminator here,
+    /// so the short account passes until it is loaded.
+    #[account(mut, seeds = [b"oracle_data"], bump)]
+    pub oracle_data: AccountLoader<'info, OracleData>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
 #[derive(Accounts)]
 pub struct TriggerMarketClearing<'info> {
     #[account(mut, seeds = [b"oracle_data"], bump)]
@@ -600,69 +1075,77 @@ mod validate_meter_reading_tests {
             active: 1,
             anomaly_detection_enabled: anomaly,
             last_quality_score: 0,
-            _padding: [0; 1],
+            require_consensus: 0,
+            backup_oracles: [Pubkey::default(); MAX_BACKUP_ORACLES],
+            last_consensus_timestamp: 0,
+            backup_oracle_count: 0,
+            consensus_threshold: 1,
+            max_reading_deviation_percent: 0,
+            quality_ema_alpha: 0,
+            quality_alert_threshold: 0,
+            _padding: [0; 2],
         }
     }
 
     #[test]
     fn accepts_a_reading_within_bounds_and_ratio() {
         // min 10, max 1000, ratio 10x, anomaly on. 100*100=10000 <= 1000*50=50000.
-        assert!(validate_meter_reading(100, 50, &od(10, 1000, 1000, 1)).is_ok());
+        assert!(validate_meter_reading(100, 50, None, &od(10, 1000, 1000, 1)).is_ok());
     }
 
     #[test]
     fn rejects_nonzero_produced_below_min() {
-        let e = validate_meter_reading(5, 0, &od(10, 1000, 1000, 1)).unwrap_err();
+        let e = validate_meter_reading(5, 0, None, &od(10, 1000, 1000, 1)).unwrap_err();
         assert_eq!(err_code(e), code_of(OracleError::EnergyValueOutOfRange));
     }
 
     #[test]
     fn zero_produced_bypasses_min_bound() {
         // produced == 0 is allowed (unilateral consumer); consumed 50 is within bounds.
-        assert!(validate_meter_reading(0, 50, &od(10, 1000, 1000, 1)).is_ok());
+        assert!(validate_meter_reading(0, 50, None, &od(10, 1000, 1000, 1)).is_ok());
     }
 
     #[test]
     fn rejects_produced_above_max() {
-        let e = validate_meter_reading(2000, 0, &od(10, 1000, 1000, 1)).unwrap_err();
+        let e = validate_meter_reading(2000, 0, None, &od(10, 1000, 1000, 1)).unwrap_err();
         assert_eq!(err_code(e), code_of(OracleError::EnergyValueOutOfRange));
     }
 
     #[test]
     fn rejects_nonzero_consumed_below_min() {
-        let e = validate_meter_reading(0, 5, &od(10, 1000, 1000, 1)).unwrap_err();
+        let e = validate_meter_reading(0, 5, None, &od(10, 1000, 1000, 1)).unwrap_err();
         assert_eq!(err_code(e), code_of(OracleError::EnergyValueOutOfRange));
     }
 
     #[test]
     fn rejects_anomalous_production_consumption_ratio() {
         // ratio 1x (100). 300*100=30000 > 100*100=10000 → anomalous.
-        let e = validate_meter_reading(300, 100, &od(1, 100_000, 100, 1)).unwrap_err();
+        let e = validate_meter_reading(300, 100, None, &od(1, 100_000, 100, 1)).unwrap_err();
         assert_eq!(err_code(e), code_of(OracleError::AnomalousReading));
     }
 
     #[test]
     fn accepts_ratio_exactly_at_limit() {
         // 100*100 == 100*100 → boundary is inclusive (<=).
-        assert!(validate_meter_reading(100, 100, &od(1, 100_000, 100, 1)).is_ok());
+        assert!(validate_meter_reading(100, 100, None, &od(1, 100_000, 100, 1)).is_ok());
     }
 
     #[test]
     fn zero_consumed_skips_ratio_check() {
         // consumed == 0 with anomaly on → ratio check skipped (unilateral producer).
-        assert!(validate_meter_reading(99_999, 0, &od(1, 100_000, 100, 1)).is_ok());
+        assert!(validate_meter_reading(99_999, 0, None, &od(1, 100_000, 100, 1)).is_ok());
     }
 
     #[test]
     fn anomaly_disabled_allows_any_ratio() {
-        assert!(validate_meter_reading(99_999, 1, &od(1, 100_000, 100, 0)).is_ok());
+        assert!(validate_meter_reading(99_999, 1, None, &od(1, 100_000, 100, 0)).is_ok());
     }
 
     #[test]
     fn rejects_overflow_in_produced_times_100() {
         // produced * 100 overflows u64 → InvalidConfiguration (not a silent wrap).
         let p = u64::MAX / 10; // *100 overflows
-        let e = validate_meter_reading(p, 1, &od(0, u64::MAX, u16::MAX, 1)).unwrap_err();
+        let e = validate_meter_reading(p, 1, None, &od(0, u64::MAX, u16::MAX, 1)).unwrap_err();
         assert_eq!(err_code(e), code_of(OracleError::InvalidConfiguration));
     }
 
@@ -670,7 +1153,129 @@ mod validate_meter_reading_tests {
     fn rejects_overflow_in_ratio_times_consumed() {
         // produced*100 fits, but max_ratio * consumed overflows → InvalidConfiguration.
         let c = u64::MAX / 2;
-        let e = validate_meter_reading(1, c, &od(0, u64::MAX, u16::MAX, 1)).unwrap_err();
+        let e = validate_meter_reading(1, c, None, &od(0, u64::MAX, u16::MAX, 1)).unwrap_err();
         assert_eq!(err_code(e), code_of(OracleError::InvalidConfiguration));
     }
+
+    #[test]
+    fn consensus_counts_distinct_authorized_signers() {
+        let bridge = Pubkey::new_unique();
+        let backups = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let n = count_consensus_signers(&[bridge, backups[1], backups[0]], bridge, &backups).unwrap();
+        assert_eq!(n, 3);
+    }
+
+    #[test]
+    fn consensus_counts_repeated_signer_once() {
+        let bridge = Pubkey::new_unique();
+        let backups = [Pubkey::new_unique()];
+        let n = count_consensus_signers(&[backups[0], backups[0], backups[0]], bridge, &backups).unwrap();
+        assert_eq!(n, 1);
+    }
+
+    #[test]
+    fn consensus_rejects_unlisted_signer() {
+        let bridge = Pubkey::new_unique();
+        let backups = [Pubkey::new_unique()];
+        let e = count_consensus_signers(&[bridge, Pubkey::new_unique()], bridge, &backups).unwrap_err();
+        assert_eq!(err_code(e), code_of(OracleError::UnauthorizedGateway));
+    }
+
+    #[test]
+    fn backup_oracles_slice_respects_count() {
+        let mut d = od(0, 1000, 1000, 1);
+        d.backup_oracles[0] = Pubkey::new_unique();
+        d.backup_oracles[1] = Pubkey::new_unique();
+        assert!(d.active_backup_oracles().is_empty());
+        d.backup_oracle_count = 2;
+        assert_eq!(d.active_backup_oracles(), &d.backup_oracles[..2]);
+    }
+
+    fn od_dev(max_percent: u16) -> OracleData {
+        let mut d = od(0, 1_000_000, 1000, 0);
+        d.max_reading_deviation_percent = max_percent;
+        d
+    }
+
+    #[test]
+    fn rejects_reading_jump_beyond_deviation_percent() {
+        // prev 100, max 50% → 151 is a 51% jump.
+        let e = validate_meter_reading(151, 100, Some((100, 100)), &od_dev(50)).unwrap_err();
+        assert_eq!(err_code(e), code_of(OracleError::ReadingDeviationExceeded));
+        let e = validate_meter_reading(100, 40, Some((100, 100)), &od_dev(50)).unwrap_err();
+        assert_eq!(err_code(e), code_of(OracleError::ReadingDeviationExceeded));
+    }
+
+    #[test]
+    fn accepts_reading_jump_exactly_at_deviation_percent() {
+        assert!(validate_meter_reading(150, 50, Some((100, 100)), &od_dev(50)).is_ok());
+    }
+
+    #[test]
+    fn deviation_skipped_without_previous_or_when_disabled() {
+        assert!(validate_meter_reading(900, 900, None, &od_dev(10)).is_ok());
+        assert!(validate_meter_reading(900, 900, Some((1, 1)), &od_dev(0)).is_ok());
+    }
+
+    #[test]
+    fn deviation_skipped_when_previous_is_zero() {
+        assert!(validate_meter_reading(500, 0, Some((0, 0)), &od_dev(10)).is_ok());
+    }
+
+    #[test]
+    fn quality_score_tracks_valid_share() {
+        let mut d = od(0, 1000, 1000, 1);
+        d.total_valid_readings = 3;
+        d.total_rejected_readings = 1;
+        update_quality_score(&mut d, 0, 0, 42);
+        assert_eq!(d.last_quality_score, 75);
+        assert_eq!(d.quality_score_updated_at, 42);
+    }
+
+    #[test]
+    fn ema_quality_score_weights_the_latest_batch() {
+        let mut d = od(0, 1000, 1000, 1);
+        d.total_valid_readings = 1_000_000;
+        d.last_quality_score = 100;
+        d.quality_ema_alpha = 50;
+        // Lifetime share would stay ~100; half weight on an all-failed batch halves it.
+        update_quality_score(&mut d, 0, 10, 7);
+        assert_eq!(d.last_quality_score, 50);
+        update_quality_score(&mut d, 10, 0, 8);
+        assert_eq!(d.last_quality_score, 75);
+        // An empty batch leaves the score (and its timestamp) alone.
+        update_quality_score(&mut d, 0, 0, 9);
+        assert_eq!(d.last_quality_score, 75);
+        assert_eq!(d.quality_score_updated_at, 8);
+    }
+
+    #[test]
+    fn quality_threshold_crossing_in_both_directions() {
+        assert!(crosses_quality_threshold(90, 70, 80));
+        assert!(crosses_quality_threshold(70, 80, 80));
+        assert!(!crosses_quality_threshold(90, 85, 80));
+        assert!(!crosses_quality_threshold(60, 70, 80));
+        assert!(!crosses_quality_threshold(90, 10, 0));
+    }
+
+    #[test]
+    fn average_reading_interval_is_the_running_mean_of_gaps() {
+        let mut stats = MeterOracleStats {
+            meter_id: [0u8; MAX_METER_ID_LEN],
+            meter_id_len: 0,
+            bump: 0,
+            total_readings: 0,
+            last_timestamp: 0,
+            rejected_count: 0,
+            average_reading_interval: 0,
+        };
+        stats.record_accepted(1_000);
+        assert_eq!(stats.average_reading_interval, 0);
+        stats.record_accepted(1_300);
+        assert_eq!(stats.average_reading_interval, 300);
+        stats.record_accepted(1_400);
+        assert_eq!(stats.average_reading_interval, 200);
+        stats.record_accepted(1_700);
+        assert_eq!(stats.average_reading_interval, 233);
+    }
 }
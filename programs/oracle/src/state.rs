@@ -5,6 +5,9 @@ use anchor_lang::prelude::*;
 /// Maximum length for meter ID strings (used for PDA seeds and storage)
 pub const MAX_METER_ID_LEN: usize = 32;
 
+/// Maximum number of backup oracles that may co-sign a consensus reading
+pub const MAX_BACKUP_ORACLES: usize = 4;
+
 /// MeterState PDA - per-meter account for Sealevel parallel execution
 /// Seeds: [b"meter", meter_id.as_bytes()]
 /// Each meter writes to its own account, avoiding write-lock contention on the global OracleData.
@@ -28,6 +31,56 @@ impl MeterState {
     pub const SPACE: usize = 8 + MAX_METER_ID_LEN + 1 + 1 + 4 + 8 + 8 + 8 + 8 + 8 + 8 + 8;
 }
 
+/// MeterOracleStats PDA - per-meter submission counters for spotting anomalous meters
+/// Seeds: [b"meter_stats", meter_id.as_bytes()]
+/// Kept apart from `MeterState` so the existing fixed-size meter accounts need no realloc.
+/// Accepted readings are counted by the submit instructions; rejections revert their own
+/// submission, so the gateway reports them through `record_meter_rejections`.
+#[account]
+pub struct MeterOracleStats {
+    pub meter_id: [u8; MAX_METER_ID_LEN], // 32 bytes - fixed-size meter identifier
+    pub meter_id_len: u8,                 // 1 byte  - actual length of meter_id
+    pub bump: u8,                         // 1 byte  - PDA bump seed
+    pub total_readings: u64,              // 8 bytes - accepted readings
+    pub last_timestamp: i64,              // 8 bytes - timestamp of the last accepted reading
+    pub rejected_count: u64,              // 8 bytes - rejections reported by the gateway
+    pub average_reading_interval: i64,    // 8 bytes - running mean of gaps between accepted readings (s)
+}
+
+impl MeterOracleStats {
+    /// Space: 8 (discriminator) + 32 + 1 + 1 + 8 + 8 + 8 + 8 = 74
+    pub const SPACE: usize = 8 + MAX_METER_ID_LEN + 1 + 1 + 8 + 8 + 8 + 8;
+
+    /// Stamp the meter id and bump the first time the PDA is used
+    pub fn bind(&mut self, meter_id: &str, bump: u8) {
+        if self.meter_id_len == 0 {
+            let id_src = meter_id.as_bytes();
+            let len = id_src.len().min(MAX_METER_ID_LEN);
+            self.meter_id[..len].copy_from_slice(&id_src[..len]);
+            self.meter_id_len = len as u8;
+            self.bump = bump;
+        }
+    }
+
+    /// Count an accepted reading and fold its gap to the previous one into
+    /// `average_reading_interval` (incremental mean over `total_readings - 1` gaps).
+    pub fn record_accepted(&mut self, reading_timestamp: i64) {
+        if self.total_readings > 0 {
+            let gap = reading_timestamp.saturating_sub(self.last_timestamp);
+            let gaps = self.total_readings.min(i64::MAX as u64) as i64;
+            self.average_reading_interval = self
+                .average_reading_interval
+                .saturating_add(gap.saturating_sub(self.average_reading_interval) / gaps);
+        }
+        self.total_readings = self.total_readings.saturating_add(1);
+        self.last_timestamp = reading_timestamp;
+    }
+
+    pub fn record_rejected(&mut self, count: u64) {
+        self.rejected_count = self.rejected_count.saturating_add(count);
+    }
+}
+
 /// OracleData account with zero_copy for efficient data access
 /// Direct memory access avoids deserialization overhead
 /// All fields explicitly defined including padding to satisfy bytemuck's Pod trait
@@ -60,9 +113,29 @@ pub struct OracleData {
     pub active: u8,                    // 1 byte (1 = active, 0 = inactive)
     pub anomaly_detection_enabled: u8, // 1 byte (1 = enabled, 0 = disabled)
     pub last_quality_score: u8,        // 1 byte (0-100 quality score)
-
-    // Explicit padding to reach 8-byte alignment.
+    pub require_consensus: u8,         // 1 byte (1 = plain submit_meter_reading disabled)
     // After the Pubkeys (64) + 12×u64 (96) = 160 bytes (8-aligned),
-    // u16*2(4) + u8*3(3) = 7 bytes → add 1 byte to reach 168 (divisible by 8).
-    pub _padding: [u8; 1], // 1 byte explicit padding
+    // u16*2(4) + u8*4(4) = 8 bytes → 168 (divisible by 8).
+
+    // === Consensus (appended; 168 → 312 — older singletons need migrate_oracle_data) ===
+    pub backup_oracles: [Pubkey; MAX_BACKUP_ORACLES], // 128 bytes - co-signers besides chain_bridge
+    pub last_consensus_timestamp: i64,                // 8 bytes - last accepted consensus reading
+    pub backup_oracle_count: u8,                      // 1 byte - populated slots in backup_oracles
+    pub consensus_threshold: u8,                      // 1 byte - distinct valid signers required
+    pub max_reading_deviation_percent: u16, // 2 bytes - max change vs. prior accepted reading (0 = disabled)
+
+    // === Quality score EMA (carved from the consensus block's padding; 0 = lifetime mode) ===
+    pub quality_ema_alpha: u8,       // 1 byte - weight (0-100 %) of the latest batch; 0 = lifetime rate
+    pub quality_alert_threshold: u8, // 1 byte - score whose crossing emits QualityScoreUpdated; 0 = off
+
+    // Explicit padding: 168 + 128 + 8 + 2 + 2 + 2 = 310 → add 2 bytes to reach 312 (divisible by 8).
+    pub _padding: [u8; 2], // 2 bytes explicit padding
+}
+
+impl OracleData {
+    /// Configured backup oracles (the populated prefix of `backup_oracles`)
+    pub fn active_backup_oracles(&self) -> &[Pubkey] {
+        let count = (self.backup_oracle_count as usize).min(MAX_BACKUP_ORACLES);
+        &self.backup_oracles[..count]
+    }
 }
@@ -64,8 +64,44 @@ pub enum RegistryError {
     InvalidAggregatorEntry,
     #[msg("Active validator cannot unstake below the minimum bond; deregister or be slashed first")]
     ValidatorStakeLocked,
-    #[msg("set_meter_status cannot set or leave Inactive; Inactive is terminal (use deactivate_meter)")]
+    #[msg("Invalid meter status transition: Inactive is terminal (use deactivate_meter); leave Maintenance with reactivate_meter")]
     InvalidMeterStatusTransition,
     #[msg("Zone id must be non-negative")]
     InvalidZone,
+    #[msg("Correction reason exceeds 100 bytes")]
+    ReasonTooLong,
+    #[msg("New owner must differ from the current meter owner")]
+    InvalidNewOwner,
+    #[msg("User still has registered meters; deactivate them before deregistering")]
+    ActiveMetersExist,
+    #[msg("User still has staked GRX or a validator role; unstake before deregistering")]
+    StakeOutstanding,
+    #[msg("Release amount exceeds the meter's claimed ERC generation")]
+    ReleaseExceedsClaimed,
+    #[msg("Maximum reading age must be non-negative")]
+    InvalidMaxAge,
+    #[msg("Meter batch must hold 1..=MAX_METER_BATCH entries, one remaining account per meter")]
+    InvalidMeterBatch,
+    #[msg("Meter account is not the PDA for this owner and meter id")]
+    InvalidMeterAccount,
+    #[msg("Meter is already registered")]
+    MeterAlreadyRegistered,
+    #[msg("Unsettled balance is below the minimum settlement amount")]
+    BelowMinimumSettlement,
+    #[msg("Balance query must hold 1..=MAX_UNSETTLED_BATCH meter accounts")]
+    InvalidBalanceBatch,
+    #[msg("Reactivation baseline is below the meter's recorded totals")]
+    InvalidMeterBaseline,
+    #[msg("Cumulative meter reading is below the last recorded value")]
+    NonMonotonicReading,
+    #[msg("Unknown meter reading mode")]
+    InvalidReadingMode,
+    #[msg("Absolute-mode meters must pass their meter_absolute account")]
+    MissingAbsoluteReading,
+    #[msg("Oracle authority is already set; rotate it with propose_oracle_authority")]
+    OracleAlreadySet,
+    #[msg("Proposed oracle authority is already the current one")]
+    OracleUnchanged,
+    #[msg("Signer is not the proposed oracle authority")]
+    InvalidPendingOracle,
 }
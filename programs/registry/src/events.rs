@@ -54,12 +54,43 @@ pub struct MeterBalanceSettled {
     pub total_settled: u64,
 }
 
+/// One summary per `settle_meters_batch` call; each settled meter still emits its own
+/// `MeterBalanceSettled`.
+#[event]
+pub struct BatchSettled {
+    pub owner: Pubkey,
+    pub meter_count: u32,
+    pub total_tokens: u64,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct OracleAuthoritySet {
     pub old_oracle: Option<Pubkey>,
     pub new_oracle: Pubkey,
 }
 
+#[event]
+pub struct OracleAuthorityProposed {
+    pub current_oracle: Option<Pubkey>,
+    pub proposed_oracle: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OracleAuthorityAccepted {
+    pub old_oracle: Option<Pubkey>,
+    pub new_oracle: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OracleAuthorityProposalCancelled {
+    pub authority: Pubkey,
+    pub cancelled_oracle: Pubkey,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct SlashDestinationSet {
     pub old_destination: Option<Pubkey>,
@@ -74,6 +105,13 @@ pub struct MeterStatusUpdated {
     pub new_status: MeterStatus,
 }
 
+#[event]
+pub struct MeterReadingModeUpdated {
+    pub meter_id: String,
+    pub owner: Pubkey,
+    pub reading_mode: u8,
+}
+
 #[event]
 pub struct MeterDeactivated {
     pub meter_id: String,
@@ -96,6 +134,14 @@ pub struct ErcClaimed {
     pub total_claimed: u64,
 }
 
+#[event]
+pub struct ErcClaimReleased {
+    pub meter_id: String,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub total_claimed: u64,
+}
+
 #[event]
 pub struct Unstaked {
     pub user: Pubkey,
@@ -123,3 +169,63 @@ pub struct SlashFundDisbursed {
     pub disbursement_count: u64,
     pub timestamp: i64,
 }
+
+#[event]
+pub struct MeterReadingCorrected {
+    pub meter_id: String,
+    pub owner: Pubkey,
+    pub old_generation: u64,
+    pub old_consumption: u64,
+    pub new_generation: u64,
+    pub new_consumption: u64,
+    pub settled_net_generation: u64,
+    pub claimed_erc_generation: u64,
+    pub reason: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MeterTransferred {
+    pub meter_id: String,
+    pub old_owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UserDeregistered {
+    pub user: Pubkey,
+    pub shard_id: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MaxPowerSet {
+    pub old_max_power: u32,
+    pub new_max_power: u32,
+}
+
+#[event]
+pub struct AnomalousMeterReading {
+    pub meter_id: String,
+    pub owner: Pubkey,
+    pub energy_generated: u64,
+    pub elapsed_seconds: i64,
+    pub implied_power: u64,
+    pub max_power: u32,
+    pub reading_timestamp: i64,
+}
+
+#[event]
+pub struct MeterReactivated {
+    pub meter_id: String,
+    pub owner: Pubkey,
+    pub authority: Pubkey,
+    pub old_generation: u64,
+    pub old_consumption: u64,
+    pub baseline_generation: u64,
+    pub baseline_consumption: u64,
+    pub settled_net_generation: u64,
+    pub timestamp: i64,
+}
@@ -36,7 +36,7 @@ pub const UNSTAKE_COOLDOWN_SECS: i64 = 24 * 60 * 60; // 24h
 pub const RESIGN_COOLDOWN_SECS: i64 = 24 * 60 * 60; // 24h
 
 #[cfg(feature = "localnet")]
-use compute_debug::{compute_checkpoint, compute_fn};
+use compute_debug::{compute_fn, compute_span};
 
 #[cfg(not(feature = "localnet"))]
 macro_rules! compute_fn {
@@ -45,11 +45,23 @@ macro_rules! compute_fn {
     };
 }
 #[cfg(not(feature = "localnet"))]
-#[allow(unused_macros)]
-macro_rules! compute_checkpoint {
-    ($name:expr) => {};
+macro_rules! compute_span {
+    ($name:expr => $block:block) => {
+        $block
+    };
 }
 
+/// Maximum length of the free-text reason recorded by `correct_meter_reading`
+pub const MAX_CORRECTION_REASON_LEN: usize = 100;
+
+/// Maximum number of meters created by one `register_meters_batch` call, or settled by one
+/// `settle_meters_batch` call
+pub const MAX_METER_BATCH: usize = 10;
+
+/// Maximum number of meters read by one `get_unsettled_balances` call. The `Vec<u64>`
+/// return value (4 + 8 * n bytes) must fit the 1 KiB return-data limit.
+pub const MAX_UNSETTLED_BATCH: usize = 64;
+
 /// Helper to convert fixed [u8; 32] to String (trimming nulls)
 fn bytes32_to_string(bytes: &[u8; 32]) -> String {
     let mut len = 0;
@@ -74,6 +86,76 @@ fn string_to_bytes32(s: &str) -> [u8; 32] {
     bytes
 }
 
+/// Create one `["meter", owner, meter_id]` PDA for `register_meters_batch` and stamp its
+/// discriminator. The remaining fields are zero; the caller fills them in. Rejects an account
+/// that is already program-owned, which covers both a meter registered earlier and an id
+/// repeated within the same batch. Like Anchor's `init`, a PDA someone already sent lamports
+/// to is topped up to rent exemption, then allocated and assigned, since `create_account`
+/// refuses an address that holds lamports.
+fn create_meter_account<'info>(
+    acct: &AccountInfo<'info>,
+    owner: &Pubkey,
+    meter_id: &str,
+    payer: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    program_id: &Pubkey,
+) -> Result<()> {
+    let (expected, bump) = Pubkey::find_program_address(
+        &[b"meter", owner.as_ref(), meter_id.as_bytes()],
+        program_id,
+    );
+    require_keys_eq!(*acct.key, expected, RegistryError::InvalidMeterAccount);
+    require!(acct.owner != program_id, RegistryError::MeterAlreadyRegistered);
+    let space = 8 + std::mem::size_of::<MeterAccount>();
+    let lamports = Rent::get()?.minimum_balance(space);
+    let bump_arr = [bump];
+    let seeds: [&[u8]; 4] = [b"meter", owner.as_ref(), meter_id.as_bytes(), &bump_arr];
+    let signer_seeds: &[&[&[u8]]] = &[&seeds];
+    let current_lamports = acct.lamports();
+    if current_lamports == 0 {
+        anchor_lang::system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.key(),
+                anchor_lang::system_program::CreateAccount { from: payer, to: acct.clone() },
+                signer_seeds,
+            ),
+            lamports,
+            space as u64,
+            program_id,
+        )?;
+    } else {
+        let top_up = lamports.saturating_sub(current_lamports);
+        if top_up > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    system_program.key(),
+                    anchor_lang::system_program::Transfer { from: payer, to: acct.clone() },
+                ),
+                top_up,
+            )?;
+        }
+        anchor_lang::system_program::allocate(
+            CpiContext::new_with_signer(
+                system_program.key(),
+                anchor_lang::system_program::Allocate { account_to_allocate: acct.clone() },
+                signer_seeds,
+            ),
+            space as u64,
+        )?;
+        anchor_lang::system_program::assign(
+            CpiContext::new_with_signer(
+                system_program.key(),
+                anchor_lang::system_program::Assign { account_to_assign: acct.clone() },
+                signer_seeds,
+            ),
+            program_id,
+        )?;
+    }
+    let disc = MeterAccount::DISCRIMINATOR;
+    acct.try_borrow_mut_data()?[..disc.len()].copy_from_slice(disc);
+    Ok(())
+}
+
 #[program]
 pub mod registry {
     use super::*;
@@ -85,6 +167,7 @@ pub mod registry {
             registry.authority = ctx.accounts.authority.key();
             registry.has_oracle_authority = 0;
             registry.has_slash_destination = 0;
+            registry.max_power = 0;
             registry.user_count = 0;
             registry.meter_count = 0;
             registry.active_meter_count = 0;
@@ -110,7 +193,9 @@ pub mod registry {
         Ok(())
     }
 
-    /// Set the oracle authority (admin only)
+    /// Set the first oracle authority (admin only). Once an oracle is configured this fails
+    /// with `OracleAlreadySet`: rotations go through `propose_oracle_authority` and
+    /// `accept_oracle_authority`, so a mistyped key can never replace a working oracle.
     pub fn set_oracle_authority(ctx: Context<SetOracleAuthority>, oracle: Pubkey) -> Result<()> {
         compute_fn!("set_oracle_authority" => {
             let mut registry = ctx.accounts.registry.load_mut()?;
@@ -119,19 +204,125 @@ pub mod registry {
                 ctx.accounts.authority.key(),
                 RegistryError::UnauthorizedAuthority
             );
+            require!(registry.has_oracle_authority == 0, RegistryError::OracleAlreadySet);
 
-            let old_oracle = if registry.has_oracle_authority == 1 {
+            registry.oracle_authority = oracle;
+            registry.has_oracle_authority = 1;
+
+            emit!(OracleAuthoritySet {
+                old_oracle: None,
+                new_oracle: oracle,
+            });
+        });
+        Ok(())
+    }
+
+    /// Propose a new oracle authority (step 1 of 2, admin only). Stores the key in the
+    /// `oracle_proposal` PDA; the current oracle keeps working until the proposed key
+    /// accepts. Only one proposal may be pending: cancel it to propose another.
+    pub fn propose_oracle_authority(
+        ctx: Context<ProposeOracleAuthority>,
+        new_oracle: Pubkey,
+    ) -> Result<()> {
+        compute_fn!("propose_oracle_authority" => {
+            let registry = ctx.accounts.registry.load()?;
+            require_keys_eq!(
+                registry.authority,
+                ctx.accounts.authority.key(),
+                RegistryError::UnauthorizedAuthority
+            );
+            let current_oracle = if registry.has_oracle_authority == 1 {
                 Some(registry.oracle_authority)
             } else {
                 None
             };
+            require!(current_oracle != Some(new_oracle), RegistryError::OracleUnchanged);
 
-            registry.oracle_authority = oracle;
+            let now = Clock::get()?.unix_timestamp;
+            let mut proposal = ctx.accounts.oracle_proposal.load_init()?;
+            proposal.proposer = ctx.accounts.authority.key();
+            proposal.proposed_oracle = new_oracle;
+            proposal.proposed_at = now;
+            proposal.bump = ctx.bumps.oracle_proposal;
+
+            emit!(OracleAuthorityProposed {
+                current_oracle,
+                proposed_oracle: new_oracle,
+                timestamp: now,
+            });
+        });
+        Ok(())
+    }
+
+    /// Accept a pending oracle rotation (step 2 of 2). Must be signed by the proposed key,
+    /// which proves it is live before readings depend on it. Closes the proposal.
+    pub fn accept_oracle_authority(ctx: Context<AcceptOracleAuthority>) -> Result<()> {
+        compute_fn!("accept_oracle_authority" => {
+            let new_oracle = ctx.accounts.oracle_proposal.load()?.proposed_oracle;
+            require_keys_eq!(
+                ctx.accounts.new_oracle.key(),
+                new_oracle,
+                RegistryError::InvalidPendingOracle
+            );
+
+            let mut registry = ctx.accounts.registry.load_mut()?;
+            let old_oracle = if registry.has_oracle_authority == 1 {
+                Some(registry.oracle_authority)
+            } else {
+                None
+            };
+            registry.oracle_authority = new_oracle;
             registry.has_oracle_authority = 1;
 
-            emit!(OracleAuthoritySet {
+            let now = Clock::get()?.unix_timestamp;
+            emit!(OracleAuthorityAccepted {
                 old_oracle,
-                new_oracle: oracle,
+                new_oracle,
+                timestamp: now,
+            });
+        });
+        Ok(())
+    }
+
+    /// Withdraw a pending oracle rotation (admin only). Closes the proposal.
+    pub fn cancel_oracle_authority_proposal(
+        ctx: Context<CancelOracleAuthorityProposal>,
+    ) -> Result<()> {
+        compute_fn!("cancel_oracle_authority_proposal" => {
+            let registry = ctx.accounts.registry.load()?;
+            require_keys_eq!(
+                registry.authority,
+                ctx.accounts.authority.key(),
+                RegistryError::UnauthorizedAuthority
+            );
+            let cancelled_oracle = ctx.accounts.oracle_proposal.load()?.proposed_oracle;
+
+            let now = Clock::get()?.unix_timestamp;
+            emit!(OracleAuthorityProposalCancelled {
+                authority: ctx.accounts.authority.key(),
+                cancelled_oracle,
+                timestamp: now,
+            });
+        });
+        Ok(())
+    }
+
+    /// Set the implied-power ceiling used to flag meter readings (0 disables the check)
+    pub fn set_max_power(ctx: Context<SetMaxPower>, max_power: u32) -> Result<()> {
+        compute_fn!("set_max_power" => {
+            let mut registry = ctx.accounts.registry.load_mut()?;
+            require_keys_eq!(
+                registry.authority,
+                ctx.accounts.authority.key(),
+                RegistryError::UnauthorizedAuthority
+            );
+
+            let old_max_power = registry.max_power;
+            registry.max_power = max_power;
+
+            emit!(MaxPowerSet {
+                old_max_power,
+                new_max_power: max_power,
             });
         });
         Ok(())
@@ -341,6 +532,7 @@ pub mod registry {
                 registry_authority: ctx.accounts.registry.to_account_info(), // Must match stored registry_authority
                 rec_validator: ctx.accounts.registry.to_account_info(), // Placeholder when REC count is 0
                 token_program: ctx.accounts.token_program.to_account_info(),
+                minter_config: None,
             };
             let registry_seeds = &[b"registry".as_ref(), &[ctx.bumps.registry]];
             let signer = &[&registry_seeds[..]];
@@ -350,9 +542,9 @@ pub mod registry {
                 signer,
             );
 
-            compute_checkpoint!("before_claim_cpi");
-            energy_token::cpi::mint_tokens_direct(cpi_ctx, AIRDROP_AMOUNT)?;
-            compute_checkpoint!("after_claim_cpi");
+            compute_span!("claim_cpi" => {
+                energy_token::cpi::mint_tokens_direct(cpi_ctx, AIRDROP_AMOUNT)?;
+            });
 
             let now = Clock::get()?.unix_timestamp;
             emit!(AirdropClaimed {
@@ -424,6 +616,84 @@ pub mod registry {
         Ok(())
     }
 
+    /// Register up to `MAX_METER_BATCH` meters for one owner in a single transaction.
+    ///
+    /// The `MeterAccount` PDAs (`["meter", owner, meter_id]`) are passed as `remaining_accounts`
+    /// (all `mut`), in the same order as `meters`, and are created here rather than through an
+    /// `init` constraint. Every meter lands on `shard_id` and in `zone_id`. If any PDA already
+    /// exists — a prior registration or a duplicate id inside the batch — the whole batch
+    /// reverts with `MeterAlreadyRegistered`.
+    pub fn register_meters_batch<'info>(
+        ctx: Context<'info, RegisterMetersBatch<'info>>,
+        meters: Vec<MeterRegistration>,
+        shard_id: u8,
+        zone_id: i32,
+    ) -> Result<()> {
+        require!(shard_id < 16, RegistryError::InvalidShardId);
+        require!(zone_id >= 0, RegistryError::InvalidZone);
+        require!(
+            !meters.is_empty() && meters.len() <= MAX_METER_BATCH,
+            RegistryError::InvalidMeterBatch
+        );
+        require!(
+            ctx.remaining_accounts.len() == meters.len(),
+            RegistryError::InvalidMeterBatch
+        );
+        let owner = ctx.accounts.owner.key();
+        require!(shard_id == shard_for(&owner), RegistryError::InvalidShardId);
+        compute_fn!("register_meters_batch" => {
+            let mut user_account = ctx.accounts.user_account.load_mut()?;
+            let mut shard = ctx.accounts.registry_shard.load_mut()?;
+
+            require!(
+                user_account.status == UserStatus::Active,
+                RegistryError::UnauthorizedUser
+            );
+            require_keys_eq!(
+                owner,
+                user_account.authority,
+                RegistryError::UnauthorizedUser
+            );
+
+            let now = Clock::get()?.unix_timestamp;
+            for (meter, acct) in meters.iter().zip(ctx.remaining_accounts.iter()) {
+                require!(meter.meter_id.len() <= 32, RegistryError::InvalidMeterId);
+                create_meter_account(
+                    acct,
+                    &owner,
+                    &meter.meter_id,
+                    ctx.accounts.payer.to_account_info(),
+                    ctx.accounts.system_program.to_account_info(),
+                    ctx.program_id,
+                )?;
+
+                let mut data = acct.try_borrow_mut_data()?;
+                let meter_account: &mut MeterAccount =
+                    bytemuck::from_bytes_mut(&mut data[8..8 + std::mem::size_of::<MeterAccount>()]);
+                meter_account.meter_id = string_to_bytes32(&meter.meter_id);
+                meter_account.owner = owner;
+                meter_account.meter_type = meter.meter_type;
+                meter_account.status = MeterStatus::Active;
+                meter_account.zone_id = zone_id;
+                meter_account.registered_at = now;
+
+                emit!(MeterRegistered {
+                    meter_id: meter.meter_id.clone(),
+                    owner,
+                    meter_type: meter.meter_type,
+                });
+            }
+
+            let count = meters.len() as u64;
+            user_account.meter_count = user_account.meter_count.checked_add(meters.len() as u32).ok_or(RegistryError::MathOverflow)?;
+            // Shard-local counters only, as in register_meter; aggregate_shards reconciles the
+            // global Registry totals.
+            shard.meter_count = shard.meter_count.checked_add(count).ok_or(RegistryError::MathOverflow)?;
+            shard.active_meter_count = shard.active_meter_count.checked_add(count).ok_or(RegistryError::MathOverflow)?;
+        });
+        Ok(())
+    }
+
     /// Update user status (admin only)
     pub fn update_user_status(
         ctx: Context<UpdateUserStatus>,
@@ -489,6 +759,19 @@ pub mod registry {
                 );
             }
 
+            // Absolute-mode meters report their cumulative register; everything below works
+            // on the derived deltas, so the two modes share the same guards.
+            let (energy_generated, energy_consumed) = if meter_account.reading_mode == READING_MODE_ABSOLUTE {
+                let register = ctx.accounts.absolute_reading.as_ref()
+                    .ok_or(RegistryError::MissingAbsoluteReading)?;
+                let mut register = register.load_mut()?;
+                let deltas = register.deltas(energy_generated, energy_consumed)?;
+                register.record(energy_generated, energy_consumed);
+                deltas
+            } else {
+                (energy_generated, energy_consumed)
+            };
+
             const MAX_READING_DELTA: u64 = 1_000_000_000_000;
             require!(
                 energy_generated <= MAX_READING_DELTA,
@@ -499,10 +782,38 @@ pub mod registry {
                 RegistryError::ReadingTooHigh
             );
 
+            // Implied power check (skipped on first reading and while max_power == 0). The
+            // reading is still recorded: a revert would also discard the event, and operators
+            // want the warning, not a silently dropped reading.
+            if registry.max_power > 0 && meter_account.last_reading_at > 0 {
+                let elapsed = (reading_timestamp - meter_account.last_reading_at) as u64;
+                let implied_power = energy_generated / elapsed;
+                if implied_power > registry.max_power as u64 {
+                    emit!(AnomalousMeterReading {
+                        meter_id: bytes32_to_string(&meter_account.meter_id),
+                        owner: meter_account.owner,
+                        energy_generated,
+                        elapsed_seconds: elapsed as i64,
+                        implied_power,
+                        max_power: registry.max_power,
+                        reading_timestamp,
+                    });
+                }
+            }
+
             meter_account.last_reading_at = reading_timestamp;
             meter_account.total_generation = meter_account.total_generation.checked_add(energy_generated).ok_or(RegistryError::MathOverflow)?;
             meter_account.total_consumption = meter_account.total_consumption.checked_add(energy_consumed).ok_or(RegistryError::MathOverflow)?;
 
+            // Opt-in audit trail; meters without a log keep the totals-only path.
+            if let Some(reading_log) = ctx.accounts.reading_log.as_ref() {
+                reading_log.load_mut()?.push(ReadingLogEntry {
+                    generated: energy_generated,
+                    consumed: energy_consumed,
+                    timestamp: reading_timestamp,
+                })?;
+            }
+
             emit!(MeterReadingUpdated {
                 meter_id: bytes32_to_string(&meter_account.meter_id),
                 owner: meter_account.owner,
@@ -513,6 +824,58 @@ pub mod registry {
         Ok(())
     }
 
+    /// Create the opt-in reading log for a meter (any payer; only update_meter_reading writes it)
+    pub fn init_reading_log(ctx: Context<InitReadingLog>) -> Result<()> {
+        compute_fn!("init_reading_log" => {
+            let mut reading_log = ctx.accounts.reading_log.load_init()?;
+            reading_log.meter = ctx.accounts.meter_account.key();
+            reading_log.bump = ctx.bumps.reading_log;
+            reading_log.head = 0;
+            reading_log.count = 0;
+        });
+        Ok(())
+    }
+
+    /// Switch a meter between delta and absolute readings (registry authority only; the
+    /// owner could otherwise re-baseline its own register). Always clears the stored
+    /// register, so the next absolute reading becomes the new baseline.
+    pub fn set_meter_reading_mode(ctx: Context<SetMeterReadingMode>, reading_mode: u8) -> Result<()> {
+        compute_fn!("set_meter_reading_mode" => {
+            let mut meter = ctx.accounts.meter_account.load_mut()?;
+            let registry_acc = ctx.accounts.registry.load()?;
+
+            require_keys_eq!(
+                registry_acc.authority,
+                ctx.accounts.authority.key(),
+                RegistryError::UnauthorizedAuthority
+            );
+            require!(
+                reading_mode == READING_MODE_DELTA || reading_mode == READING_MODE_ABSOLUTE,
+                RegistryError::InvalidReadingMode
+            );
+
+            // init_if_needed: a fresh register goes through load_init, an existing one is reset.
+            let mut register = match ctx.accounts.absolute_reading.load_mut() {
+                Ok(register) => register,
+                Err(_) => ctx.accounts.absolute_reading.load_init()?,
+            };
+            register.meter = ctx.accounts.meter_account.key();
+            register.bump = ctx.bumps.absolute_reading;
+            register.last_generation = 0;
+            register.last_consumption = 0;
+            register.has_baseline = 0;
+
+            meter.reading_mode = reading_mode;
+
+            emit!(MeterReadingModeUpdated {
+                meter_id: bytes32_to_string(&meter.meter_id),
+                owner: meter.owner,
+                reading_mode,
+            });
+        });
+        Ok(())
+    }
+
     /// Set meter status (owner or authority)
     pub fn set_meter_status(ctx: Context<SetMeterStatus>, new_status: MeterStatus) -> Result<()> {
         compute_fn!("set_meter_status" => {
@@ -534,8 +897,8 @@ pub mod registry {
             let old_status = meter.status;
 
             // Inactive is terminal and owned solely by `deactivate_meter` (which also drops
-            // meter_count + user.meter_count). `set_meter_status` only toggles the reversible
-            // Active<->Maintenance states. It must NOT revive a deactivated meter (old ==
+            // meter_count + user.meter_count). `set_meter_status` only moves an Active meter
+            // into Maintenance. It must NOT revive a deactivated meter (old ==
             // Inactive) — that would re-add active_meter_count without restoring meter_count,
             // leaving active_meter_count > meter_count — nor set Inactive itself (which would
             // drop active_meter_count but leave meter_count/user.meter_count overcounted).
@@ -543,11 +906,15 @@ pub mod registry {
                 old_status != MeterStatus::Inactive && new_status != MeterStatus::Inactive,
                 RegistryError::InvalidMeterStatusTransition
             );
+            // Leaving Maintenance goes through `reactivate_meter`, which rebases the totals so
+            // generation from the gap is never minted. A plain flip back would skip that.
+            require!(
+                !(old_status == MeterStatus::Maintenance && new_status == MeterStatus::Active),
+                RegistryError::InvalidMeterStatusTransition
+            );
 
             if old_status == MeterStatus::Active && new_status != MeterStatus::Active {
                 shard.active_meter_count = shard.active_meter_count.saturating_sub(1);
-            } else if old_status != MeterStatus::Active && new_status == MeterStatus::Active {
-                shard.active_meter_count = shard.active_meter_count.saturating_add(1);
             }
 
             meter.status = new_status;
@@ -562,6 +929,137 @@ pub mod registry {
         Ok(())
     }
 
+    /// Bring a meter back from `Maintenance` (owner or authority).
+    ///
+    /// `baseline_generation` / `baseline_consumption` are the meter's cumulative register at
+    /// reactivation and may not be below the recorded totals. Net generation that accrued
+    /// during the maintenance gap is rebased into `settled_net_generation`, so it can never be
+    /// minted, and `last_reading_at` moves to now, so the next reading must be fresh. An
+    /// absolute-mode meter's register is cleared too, so its first reading re-baselines instead
+    /// of crediting the whole gap.
+    pub fn reactivate_meter(
+        ctx: Context<ReactivateMeter>,
+        baseline_generation: u64,
+        baseline_consumption: u64,
+    ) -> Result<()> {
+        compute_fn!("reactivate_meter" => {
+            let mut meter = ctx.accounts.meter_account.load_mut()?;
+            let registry_acc = ctx.accounts.registry.load()?;
+            let mut shard = ctx.accounts.registry_shard.load_mut()?;
+
+            let is_owner = ctx.accounts.authority.key() == meter.owner;
+            let is_admin = ctx.accounts.authority.key() == registry_acc.authority;
+            require!(is_owner || is_admin, RegistryError::UnauthorizedUser);
+            require!(
+                shard.shard_id == shard_for(&meter.owner),
+                RegistryError::InvalidShardId
+            );
+            require!(
+                meter.status == MeterStatus::Maintenance,
+                RegistryError::InvalidMeterStatusTransition
+            );
+            require!(
+                baseline_generation >= meter.total_generation
+                    && baseline_consumption >= meter.total_consumption,
+                RegistryError::InvalidMeterBaseline
+            );
+
+            // The register holds the meter's own cumulative counters, not registry totals, so
+            // it cannot take the baseline; dropping it makes the next reading the new baseline.
+            if meter.reading_mode == READING_MODE_ABSOLUTE {
+                let register = ctx.accounts.absolute_reading.as_ref()
+                    .ok_or(RegistryError::MissingAbsoluteReading)?;
+                let mut register = register.load_mut()?;
+                register.last_generation = 0;
+                register.last_consumption = 0;
+                register.has_baseline = 0;
+            }
+
+            let old_generation = meter.total_generation;
+            let old_consumption = meter.total_consumption;
+            apply_meter_rebase(&mut meter, baseline_generation, baseline_consumption);
+
+            let now = Clock::get()?.unix_timestamp;
+            meter.last_reading_at = now;
+            meter.status = MeterStatus::Active;
+            shard.active_meter_count = shard.active_meter_count.saturating_add(1);
+
+            emit!(MeterReactivated {
+                meter_id: bytes32_to_string(&meter.meter_id),
+                owner: meter.owner,
+                authority: ctx.accounts.authority.key(),
+                old_generation,
+                old_consumption,
+                baseline_generation,
+                baseline_consumption,
+                settled_net_generation: meter.settled_net_generation,
+                timestamp: now,
+            });
+        });
+        Ok(())
+    }
+
+    /// Transfer a meter to another registered user (current owner or registry authority).
+    /// Cumulative totals and the settlement watermarks travel with the meter unchanged, so
+    /// already-settled or ERC-claimed generation cannot be minted again by the new owner.
+    pub fn transfer_meter(ctx: Context<TransferMeter>, new_owner: Pubkey) -> Result<()> {
+        compute_fn!("transfer_meter" => {
+            let registry = ctx.accounts.registry.load()?;
+            let mut meter = ctx.accounts.meter_account.load_mut()?;
+            let old_owner = meter.owner;
+
+            let is_owner = ctx.accounts.authority.key() == old_owner;
+            let is_admin = ctx.accounts.authority.key() == registry.authority;
+            require!(is_owner || is_admin, RegistryError::UnauthorizedUser);
+            require_keys_neq!(new_owner, old_owner, RegistryError::InvalidNewOwner);
+
+            // Inactive meters already dropped out of meter_count in deactivate_meter.
+            require!(
+                meter.status != MeterStatus::Inactive,
+                RegistryError::InvalidMeterStatus
+            );
+
+            let mut old_user = ctx.accounts.old_user_account.load_mut()?;
+            let mut new_user = ctx.accounts.new_user_account.load_mut()?;
+            require!(
+                new_user.status == UserStatus::Active,
+                RegistryError::UnauthorizedUser
+            );
+
+            old_user.meter_count = old_user.meter_count.saturating_sub(1);
+            new_user.meter_count = new_user.meter_count.checked_add(1).ok_or(RegistryError::MathOverflow)?;
+
+            // Meters count on their owner's shard; move the counts when the owners' shards differ.
+            if shard_for(&old_owner) != shard_for(&new_owner) {
+                let new_shard_loader = ctx
+                    .accounts
+                    .new_registry_shard
+                    .as_ref()
+                    .ok_or(RegistryError::InvalidShardId)?;
+                let mut old_shard = ctx.accounts.old_registry_shard.load_mut()?;
+                let mut new_shard = new_shard_loader.load_mut()?;
+                old_shard.meter_count = old_shard.meter_count.saturating_sub(1);
+                new_shard.meter_count = new_shard.meter_count.checked_add(1).ok_or(RegistryError::MathOverflow)?;
+                if meter.status == MeterStatus::Active {
+                    old_shard.active_meter_count = old_shard.active_meter_count.saturating_sub(1);
+                    new_shard.active_meter_count = new_shard.active_meter_count.checked_add(1).ok_or(RegistryError::MathOverflow)?;
+                }
+            }
+
+            meter.owner = new_owner;
+
+            let now = Clock::get()?.unix_timestamp;
+            emit!(MeterTransferred {
+                meter_id: bytes32_to_string(&meter.meter_id),
+                old_owner,
+                new_owner,
+                authority: ctx.accounts.authority.key(),
+                timestamp: now,
+            });
+        });
+        Ok(())
+    }
+
     /// Deactivate a meter permanently (owner only)
     pub fn deactivate_meter(ctx: Context<DeactivateMeter>) -> Result<()> {
         compute_fn!("deactivate_meter" => {
@@ -601,6 +1099,37 @@ pub mod registry {
         Ok(())
     }
 
+    /// Close a user's account and return its rent (user only). Every meter must be
+    /// deactivated first so no MeterAccount is left pointing at a closed user.
+    pub fn deregister_user(ctx: Context<DeregisterUser>) -> Result<()> {
+        compute_fn!("deregister_user" => {
+            let mut user = ctx.accounts.user_account.load_mut()?;
+            let mut registry = ctx.accounts.registry.load_mut()?;
+            let mut shard = ctx.accounts.registry_shard.load_mut()?;
+
+            // deactivate_meter decrements meter_count, so zero means every meter is Inactive.
+            require!(user.meter_count == 0, RegistryError::ActiveMetersExist);
+            // Closing would drop the staked_grx record while the GRX stays in the vault.
+            require!(
+                user.staked_grx == 0 && user.validator_status == ValidatorStatus::None,
+                RegistryError::StakeOutstanding
+            );
+
+            user.status = UserStatus::Inactive;
+            shard.user_count = shard.user_count.saturating_sub(1);
+            // Reconciled from the shards by aggregate_shards; kept in step here meanwhile.
+            registry.user_count = registry.user_count.saturating_sub(1);
+
+            let now = Clock::get()?.unix_timestamp;
+            emit!(UserDeregistered {
+                user: user.authority,
+                shard_id: user.shard_id,
+                timestamp: now,
+            });
+        });
+        Ok(())
+    }
+
     /// Verify if a user is valid and active
     pub fn is_valid_user(ctx: Context<IsValidUser>) -> Result<bool> {
         let res = compute_fn!("is_valid_user" => {
@@ -619,19 +1148,55 @@ pub mod registry {
         Ok(res)
     }
 
+    /// Verify a meter is active and its last reading is at most `max_age_seconds` old
+    pub fn is_meter_fresh(ctx: Context<IsValidMeter>, max_age_seconds: i64) -> Result<bool> {
+        compute_fn!("is_meter_fresh" => {
+            require!(max_age_seconds >= 0, RegistryError::InvalidMaxAge);
+            let now = Clock::get()?.unix_timestamp;
+            let meter_account = ctx.accounts.meter_account.load()?;
+            Ok(meter_account.is_fresh_at(now, max_age_seconds))
+        })
+    }
+
     /// Calculate unsettled net generation ready for tokenization
     /// This is a view function that returns how much energy can be minted as GRID tokens
     pub fn get_unsettled_balance(ctx: Context<GetUnsettledBalance>) -> Result<u64> {
         let res = compute_fn!("get_unsettled_balance" => {
             let meter = ctx.accounts.meter_account.load()?;
+            meter.unsettled_balance()
+        });
+        Ok(res)
+    }
 
-            // Calculate current net generation (total produced - total consumed)
-            let current_net_gen = meter
-                .total_generation
-                .saturating_sub(meter.total_consumption);
+    /// Batch form of `get_unsettled_balance` for settlement schedulers.
+    ///
+    /// The `MeterAccount`s are passed as read-only `remaining_accounts`
+    /// (`1..=MAX_UNSETTLED_BATCH`); the result holds one unsettled balance per account, in
+    /// the same order. Each account must be a registry `MeterAccount` (owner and discriminator
+    /// are checked). Nothing is written and no events are emitted.
+    pub fn get_unsettled_balances<'info>(
+        ctx: Context<'info, GetUnsettledBalances<'info>>,
+    ) -> Result<Vec<u64>> {
+        require!(
+            !ctx.remaining_accounts.is_empty()
+                && ctx.remaining_accounts.len() <= MAX_UNSETTLED_BATCH,
+            RegistryError::InvalidBalanceBatch
+        );
+        compute_fn!("get_unsettled_balances" => {
+            let mut balances = Vec::with_capacity(ctx.remaining_accounts.len());
+            for acct in ctx.remaining_accounts.iter() {
+                let loader = AccountLoader::<MeterAccount>::try_from(acct)?;
+                balances.push(loader.load()?.unsettled_balance());
+            }
+            Ok(balances)
+        })
+    }
 
-            // Calculate how much hasn't been tokenized yet
-            current_net_gen.saturating_sub(meter.settled_net_generation)
+    /// Read a meter's derived balances in one call: net generation, the GRID-unsettled
+    /// and ERC-unclaimed remainders, status and last reading time. Nothing is written.
+    pub fn get_meter_summary(ctx: Context<GetMeterSummary>) -> Result<MeterSummary> {
+        let res = compute_fn!("get_meter_summary" => {
+            ctx.accounts.meter_account.load()?.summary()
         });
         Ok(res)
     }
@@ -639,21 +1204,64 @@ pub mod registry {
     /// Settle meter balance and prepare for GRID token minting
     /// This updates the settled_net_generation tracker to prevent double-minting
     /// The actual token minting should be called by the energy_token program
-    pub fn settle_meter_balance(ctx: Context<SettleMeterBalance>) -> Result<u64> {
+    pub fn settle_meter_balance(
+        ctx: Context<SettleMeterBalance>,
+        min_settlement_amount: u64,
+    ) -> Result<u64> {
         let res = compute_fn!("settle_meter_balance" => {
             let mut meter = ctx.accounts.meter_account.load_mut()?;
-            do_settle_meter(&mut meter, ctx.accounts.meter_owner.key())?
+            do_settle_meter(&mut meter, ctx.accounts.meter_owner.key(), min_settlement_amount)?
         });
 
         Ok(res)
     }
 
+    /// Settle up to `MAX_METER_BATCH` meters of one owner in a single call and emit one
+    /// `BatchSettled` summary. The `MeterAccount`s are passed writable as
+    /// `remaining_accounts`; each is settled exactly as `settle_meter_balance(0)` would,
+    /// except that a meter with nothing to settle is skipped instead of failing the batch.
+    /// Returns the total tokens to mint; minting stays with the caller.
+    pub fn settle_meters_batch<'info>(ctx: Context<'info, SettleMetersBatch<'info>>) -> Result<u64> {
+        require!(
+            !ctx.remaining_accounts.is_empty()
+                && ctx.remaining_accounts.len() <= MAX_METER_BATCH,
+            RegistryError::InvalidMeterBatch
+        );
+        compute_fn!("settle_meters_batch" => {
+            let owner = ctx.accounts.meter_owner.key();
+            let mut meter_count = 0u32;
+            let mut total_tokens = 0u64;
+            for acct in ctx.remaining_accounts.iter() {
+                require!(acct.is_writable, RegistryError::InvalidMeterBatch);
+                let loader = AccountLoader::<MeterAccount>::try_from(acct)?;
+                let mut meter = loader.load_mut()?;
+                if meter.settleable_balance() == 0 {
+                    continue;
+                }
+                total_tokens = total_tokens.saturating_add(do_settle_meter(&mut meter, owner, 0)?);
+                meter_count += 1;
+            }
+
+            let now = Clock::get()?.unix_timestamp;
+            emit!(BatchSettled {
+                owner,
+                meter_count,
+                total_tokens,
+                timestamp: now,
+            });
+            Ok(total_tokens)
+        })
+    }
+
     /// Settle meter balance and automatically mint GRID tokens via CPI
     /// This is a convenience function that combines settlement + minting in one transaction
-    pub fn settle_and_mint_tokens(ctx: Context<SettleAndMintTokens>) -> Result<()> {
+    pub fn settle_and_mint_tokens(
+        ctx: Context<SettleAndMintTokens>,
+        min_settlement_amount: u64,
+    ) -> Result<()> {
         compute_fn!("settle_and_mint_tokens" => {
             let mut meter = ctx.accounts.meter_account.load_mut()?;
-            let new_tokens_to_mint = do_settle_meter(&mut meter, ctx.accounts.meter_owner.key())?;
+            let new_tokens_to_mint = do_settle_meter(&mut meter, ctx.accounts.meter_owner.key(), min_settlement_amount)?;
 
             // We need to sign as the Registry because the Registry is the authority of the Energy Token (TokenInfo)
             let bump = ctx.bumps.registry;
@@ -672,6 +1280,7 @@ pub mod registry {
                 registry_authority: ctx.accounts.registry.to_account_info(),
                 rec_validator: ctx.accounts.rec_validator.to_account_info(),
                 token_program: ctx.accounts.token_program.to_account_info(),
+                minter_config: None,
             };
 
             let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.energy_token_program.key(), cpi_accounts, signer);
@@ -696,11 +1305,8 @@ pub mod registry {
 
             // Bound ERC claims against NET generation (same base as do_settle_meter),
             // so combined GRID + ERC claims can never exceed net generation.
-            let net_gen = meter
-                .total_generation
-                .saturating_sub(meter.total_consumption);
-            let unclaimed = net_gen
-                .saturating_sub(meter.claimed_erc_generation)
+            let unclaimed = meter
+                .unclaimed_erc()
                 .saturating_sub(meter.settled_net_generation);
             require!(amount <= unclaimed, RegistryError::NoUnsettledBalance);
 
@@ -716,6 +1322,76 @@ pub mod registry {
         Ok(())
     }
 
+    /// Return previously claimed ERC generation to the meter after a certificate is
+    /// revoked, so the energy can be certified again (authorized by governance/oracle)
+    pub fn release_erc_claim(ctx: Context<ReleaseErcClaim>, amount: u64) -> Result<()> {
+        compute_fn!("release_erc_claim" => {
+            let mut meter = ctx.accounts.meter_account.load_mut()?;
+
+            let registry = ctx.accounts.registry.load()?;
+            require!(
+                ctx.accounts.authority.key() == registry.authority
+                    || ctx.accounts.authority.key() == registry.oracle_authority,
+                RegistryError::UnauthorizedAuthority
+            );
+            require!(
+                amount <= meter.claimed_erc_generation,
+                RegistryError::ReleaseExceedsClaimed
+            );
+
+            meter.claimed_erc_generation -= amount;
+
+            emit!(ErcClaimReleased {
+                meter_id: bytes32_to_string(&meter.meter_id),
+                owner: meter.owner,
+                amount,
+                total_claimed: meter.claimed_erc_generation,
+            });
+        });
+        Ok(())
+    }
+
+    /// Overwrite a meter's cumulative totals after an erroneous reading (admin only).
+    /// The settlement watermarks are clamped to the corrected net generation so the
+    /// unsettled balance can never go negative.
+    pub fn correct_meter_reading(
+        ctx: Context<CorrectMeterReading>,
+        corrected_generation: u64,
+        corrected_consumption: u64,
+        reason: String,
+    ) -> Result<()> {
+        require!(reason.len() <= MAX_CORRECTION_REASON_LEN, RegistryError::ReasonTooLong);
+        compute_fn!("correct_meter_reading" => {
+            let registry = ctx.accounts.registry.load()?;
+            require_keys_eq!(
+                ctx.accounts.authority.key(),
+                registry.authority,
+                RegistryError::UnauthorizedAuthority
+            );
+
+            let mut meter = ctx.accounts.meter_account.load_mut()?;
+            let old_generation = meter.total_generation;
+            let old_consumption = meter.total_consumption;
+
+            apply_meter_correction(&mut meter, corrected_generation, corrected_consumption);
+
+            let now = Clock::get()?.unix_timestamp;
+            emit!(MeterReadingCorrected {
+                meter_id: bytes32_to_string(&meter.meter_id),
+                owner: meter.owner,
+                old_generation,
+                old_consumption,
+                new_generation: corrected_generation,
+                new_consumption: corrected_consumption,
+                settled_net_generation: meter.settled_net_generation,
+                claimed_erc_generation: meter.claimed_erc_generation,
+                reason,
+                timestamp: now,
+            });
+        });
+        Ok(())
+    }
+
     /// Initialize the staking vault for GRX tokens (admin only)
     pub fn initialize_vault(_ctx: Context<InitializeVault>) -> Result<()> {
         Ok(())
@@ -733,9 +1409,9 @@ pub mod registry {
             };
             let cpi_ctx = CpiContext::new(ctx.accounts.token_program.key(), cpi_accounts);
 
-            compute_checkpoint!("before_stake_transfer_cpi");
-            token_interface::transfer_checked(cpi_ctx, amount, ctx.accounts.grx_mint.decimals)?;
-            compute_checkpoint!("after_stake_transfer_cpi");
+            compute_span!("stake_transfer_cpi" => {
+                token_interface::transfer_checked(cpi_ctx, amount, ctx.accounts.grx_mint.decimals)?;
+            });
 
             let mut user_account = ctx.accounts.user_account.load_mut()?;
             user_account.staked_grx = user_account
@@ -867,9 +1543,9 @@ pub mod registry {
                 signer,
             );
 
-            compute_checkpoint!("before_unstake_transfer_cpi");
-            token_interface::transfer_checked(cpi_ctx, amount, ctx.accounts.grx_mint.decimals)?;
-            compute_checkpoint!("after_unstake_transfer_cpi");
+            compute_span!("unstake_transfer_cpi" => {
+                token_interface::transfer_checked(cpi_ctx, amount, ctx.accounts.grx_mint.decimals)?;
+            });
 
             let mut user_account = ctx.accounts.user_account.load_mut()?;
             let remaining = user_account
@@ -963,17 +1639,17 @@ pub mod registry {
                     authority: ctx.accounts.registry.to_account_info(),
                     mint: ctx.accounts.grx_mint.to_account_info(),
                 };
-                compute_checkpoint!("before_victim_comp_cpi");
-                token_interface::transfer_checked(
-                    CpiContext::new_with_signer(
-                        ctx.accounts.token_program.key(),
-                        cpi_accounts,
-                        signer,
-                    ),
-                    compensation,
-                    decimals,
-                )?;
-                compute_checkpoint!("after_victim_comp_cpi");
+                compute_span!("victim_comp_cpi" => {
+                    token_interface::transfer_checked(
+                        CpiContext::new_with_signer(
+                            ctx.accounts.token_program.key(),
+                            cpi_accounts,
+                            signer,
+                        ),
+                        compensation,
+                        decimals,
+                    )?;
+                });
             }
 
             // Fund remainder to the configured destination (skip zero-amount transfer).
@@ -984,17 +1660,17 @@ pub mod registry {
                     authority: ctx.accounts.registry.to_account_info(),
                     mint: ctx.accounts.grx_mint.to_account_info(),
                 };
-                compute_checkpoint!("before_fund_cpi");
-                token_interface::transfer_checked(
-                    CpiContext::new_with_signer(
-                        ctx.accounts.token_program.key(),
-                        cpi_accounts,
-                        signer,
-                    ),
-                    fund_amount,
-                    decimals,
-                )?;
-                compute_checkpoint!("after_fund_cpi");
+                compute_span!("fund_cpi" => {
+                    token_interface::transfer_checked(
+                        CpiContext::new_with_signer(
+                            ctx.accounts.token_program.key(),
+                            cpi_accounts,
+                            signer,
+                        ),
+                        fund_amount,
+                        decimals,
+                    )?;
+                });
             }
 
             let mut user_account = ctx.accounts.target_user_account.load_mut()?;
@@ -1222,7 +1898,11 @@ pub mod registry {
 }
 
 // Internal helpers
-fn do_settle_meter(meter: &mut MeterAccount, owner_key: Pubkey) -> Result<u64> {
+
+/// Settle the meter's unclaimed net generation. `min_settlement_amount` rejects dust:
+/// a balance below it stays unsettled (`BelowMinimumSettlement`) and accrues until a
+/// later call clears the threshold. Pass 0 to settle any nonzero balance.
+fn do_settle_meter(meter: &mut MeterAccount, owner_key: Pubkey, min_settlement_amount: u64) -> Result<u64> {
     require!(
         meter.status == MeterStatus::Active,
         RegistryError::InvalidMeterStatus
@@ -1234,17 +1914,15 @@ fn do_settle_meter(meter: &mut MeterAccount, owner_key: Pubkey) -> Result<u64> {
         RegistryError::UnauthorizedUser
     );
 
-    let current_net_gen = meter
-        .total_generation
-        .saturating_sub(meter.total_consumption);
-
     // FIX: Subtract claimed_erc_generation to prevent double-claiming
     // Total claims (GRX + ERC) cannot exceed total generation.
-    let new_tokens_to_mint = current_net_gen
-        .saturating_sub(meter.settled_net_generation)
-        .saturating_sub(meter.claimed_erc_generation);
+    let new_tokens_to_mint = meter.settleable_balance();
 
     require!(new_tokens_to_mint > 0, RegistryError::NoUnsettledBalance);
+    require!(
+        new_tokens_to_mint >= min_settlement_amount,
+        RegistryError::BelowMinimumSettlement
+    );
 
     meter.settled_net_generation = meter.settled_net_generation.saturating_add(new_tokens_to_mint);
 
@@ -1258,6 +1936,32 @@ fn do_settle_meter(meter: &mut MeterAccount, owner_key: Pubkey) -> Result<u64> {
     Ok(new_tokens_to_mint)
 }
 
+/// Overwrite a meter's cumulative totals and re-derive the settlement watermarks so that
+/// `settled_net_generation + claimed_erc_generation <= net generation` still holds. GRID
+/// settlement is clamped first, then the ERC claim takes whatever net generation remains.
+fn apply_meter_correction(meter: &mut MeterAccount, generation: u64, consumption: u64) {
+    meter.total_generation = generation;
+    meter.total_consumption = consumption;
+
+    let net_gen = generation.saturating_sub(consumption);
+    meter.settled_net_generation = meter.settled_net_generation.min(net_gen);
+    meter.claimed_erc_generation = meter
+        .claimed_erc_generation
+        .min(net_gen - meter.settled_net_generation);
+}
+
+/// Move a meter's totals up to a reactivation baseline without creating mintable balance:
+/// the net generation gained between the old totals and the baseline is added to
+/// `settled_net_generation`, then the watermarks are clamped as in a correction.
+fn apply_meter_rebase(meter: &mut MeterAccount, generation: u64, consumption: u64) {
+    let old_net = meter.total_generation.saturating_sub(meter.total_consumption);
+    let new_net = generation.saturating_sub(consumption);
+    meter.settled_net_generation = meter
+        .settled_net_generation
+        .saturating_add(new_net.saturating_sub(old_net));
+    apply_meter_correction(meter, generation, consumption);
+}
+
 /// PoA slash gate — shared by `slash_validator` and `slash_validator_multi`. Verifies
 /// the caller is the registry authority and that the passed destination is the single
 /// configured `slash_destination`, so the slash remainder can never be misrouted.
@@ -1497,6 +2201,34 @@ pub struct RegisterMeter<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+#[instruction(meters: Vec<MeterRegistration>, shard_id: u8)]
+pub struct RegisterMetersBatch<'info> {
+    #[account(
+        mut,
+        seeds = [b"user", owner.key().as_ref()],
+        bump
+    )]
+    pub user_account: AccountLoader<'info, UserAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"registry_shard".as_ref(), &[shard_id]],
+        bump
+    )]
+    pub registry_shard: AccountLoader<'info, RegistryShard>,
+
+    /// CHECK: The meters' owner, non-signing as in `RegisterMeter`. The handler enforces
+    /// `owner == user_account.authority` and derives every meter PDA from `owner.key()`.
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    // remaining_accounts: one `["meter", owner, meter_id]` PDA (mut) per entry in `meters`.
+}
+
 #[derive(Accounts)]
 pub struct UpdateUserStatus<'info> {
     #[account(mut, seeds = [b"registry"], bump)]
@@ -1517,6 +2249,75 @@ pub struct UpdateMeterReading<'info> {
     pub meter_account: AccountLoader<'info, MeterAccount>,
 
     pub oracle_authority: Signer<'info>,
+
+    /// Optional audit log (see `init_reading_log`); omitted for meters that never opted in.
+    #[account(
+        mut,
+        seeds = [b"reading_log", meter_account.key().as_ref()],
+        bump = reading_log.load()?.bump,
+    )]
+    pub reading_log: Option<AccountLoader<'info, MeterReadingLog>>,
+
+    /// Cumulative register (see `set_meter_reading_mode`); required for absolute-mode meters.
+    #[account(
+        mut,
+        seeds = [b"meter_absolute", meter_account.key().as_ref()],
+        bump = absolute_reading.load()?.bump,
+    )]
+    pub absolute_reading: Option<AccountLoader<'info, MeterAbsoluteReading>>,
+}
+
+#[derive(Accounts)]
+pub struct SetMeterReadingMode<'info> {
+    #[account(seeds = [b"registry"], bump)]
+    pub registry: AccountLoader<'info, Registry>,
+
+    #[account(mut)]
+    pub meter_account: AccountLoader<'info, MeterAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + std::mem::size_of::<MeterAbsoluteReading>(),
+        seeds = [b"meter_absolute", meter_account.key().as_ref()],
+        bump
+    )]
+    pub absolute_reading: AccountLoader<'info, MeterAbsoluteReading>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitReadingLog<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + std::mem::size_of::<MeterReadingLog>(),
+        seeds = [b"reading_log", meter_account.key().as_ref()],
+        bump
+    )]
+    pub reading_log: AccountLoader<'info, MeterReadingLog>,
+
+    pub meter_account: AccountLoader<'info, MeterAccount>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CorrectMeterReading<'info> {
+    #[account(seeds = [b"registry"], bump)]
+    pub registry: AccountLoader<'info, Registry>,
+
+    #[account(mut)]
+    pub meter_account: AccountLoader<'info, MeterAccount>,
+
+    pub authority: Signer<'info>,
 }
 
 #[derive(Accounts)]
@@ -1527,6 +2328,76 @@ pub struct SetOracleAuthority<'info> {
     pub authority: Signer<'info>,
 }
 
+#[derive(Accounts)]
+pub struct ProposeOracleAuthority<'info> {
+    #[account(seeds = [b"registry"], bump)]
+    pub registry: AccountLoader<'info, Registry>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + std::mem::size_of::<OracleAuthorityProposal>(),
+        seeds = [b"oracle_proposal"],
+        bump
+    )]
+    pub oracle_proposal: AccountLoader<'info, OracleAuthorityProposal>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptOracleAuthority<'info> {
+    #[account(mut, seeds = [b"registry"], bump)]
+    pub registry: AccountLoader<'info, Registry>,
+
+    #[account(
+        mut,
+        seeds = [b"oracle_proposal"],
+        bump = oracle_proposal.load()?.bump,
+        has_one = proposer,
+        close = proposer
+    )]
+    pub oracle_proposal: AccountLoader<'info, OracleAuthorityProposal>,
+
+    /// CHECK: rent refund target, pinned to the proposal by `has_one`
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+
+    pub new_oracle: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CancelOracleAuthorityProposal<'info> {
+    #[account(seeds = [b"registry"], bump)]
+    pub registry: AccountLoader<'info, Registry>,
+
+    #[account(
+        mut,
+        seeds = [b"oracle_proposal"],
+        bump = oracle_proposal.load()?.bump,
+        has_one = proposer,
+        close = proposer
+    )]
+    pub oracle_proposal: AccountLoader<'info, OracleAuthorityProposal>,
+
+    /// CHECK: rent refund target, pinned to the proposal by `has_one`
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetMaxPower<'info> {
+    #[account(mut)]
+    pub registry: AccountLoader<'info, Registry>,
+
+    pub authority: Signer<'info>,
+}
+
 #[derive(Accounts)]
 pub struct SetSlashDestination<'info> {
     #[account(mut)]
@@ -1563,6 +2434,78 @@ pub struct SetMeterStatus<'info> {
     pub authority: Signer<'info>,
 }
 
+#[derive(Accounts)]
+pub struct ReactivateMeter<'info> {
+    #[account(seeds = [b"registry"], bump)]
+    pub registry: AccountLoader<'info, Registry>,
+
+    #[account(mut)]
+    pub meter_account: AccountLoader<'info, MeterAccount>,
+
+    // Owner's shard, as in `SetMeterStatus`.
+    #[account(
+        mut,
+        seeds = [b"registry_shard".as_ref(), &[meter_account.load()?.owner.to_bytes()[0] % 16]],
+        bump
+    )]
+    pub registry_shard: AccountLoader<'info, RegistryShard>,
+
+    pub authority: Signer<'info>,
+
+    /// Cumulative register; required for absolute-mode meters, whose baseline is dropped.
+    #[account(
+        mut,
+        seeds = [b"meter_absolute", meter_account.key().as_ref()],
+        bump = absolute_reading.load()?.bump,
+    )]
+    pub absolute_reading: Option<AccountLoader<'info, MeterAbsoluteReading>>,
+}
+
+#[derive(Accounts)]
+#[instruction(new_owner: Pubkey)]
+pub struct TransferMeter<'info> {
+    #[account(seeds = [b"registry"], bump)]
+    pub registry: AccountLoader<'info, Registry>,
+
+    // Address stays `[b"meter", original_owner, meter_id]` after a transfer; only the
+    // stored `owner` changes. Nothing re-derives the meter PDA from `owner` after creation.
+    #[account(mut)]
+    pub meter_account: AccountLoader<'info, MeterAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"user", meter_account.load()?.owner.as_ref()],
+        bump
+    )]
+    pub old_user_account: AccountLoader<'info, UserAccount>,
+
+    // Seeded by `new_owner`, so the new owner must already hold a registered UserAccount.
+    #[account(
+        mut,
+        seeds = [b"user", new_owner.as_ref()],
+        bump
+    )]
+    pub new_user_account: AccountLoader<'info, UserAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"registry_shard".as_ref(), &[meter_account.load()?.owner.to_bytes()[0] % 16]],
+        bump
+    )]
+    pub old_registry_shard: AccountLoader<'info, RegistryShard>,
+
+    // Required only when the new owner lives on a different shard (passing the same shard
+    // twice as `mut` would alias one zero-copy account).
+    #[account(
+        mut,
+        seeds = [b"registry_shard".as_ref(), &[new_owner.to_bytes()[0] % 16]],
+        bump
+    )]
+    pub new_registry_shard: Option<AccountLoader<'info, RegistryShard>>,
+
+    pub authority: Signer<'info>,
+}
+
 #[derive(Accounts)]
 pub struct DeactivateMeter<'info> {
     #[account(mut)]
@@ -1593,6 +2536,31 @@ pub struct DeactivateMeter<'info> {
     pub owner: Signer<'info>,
 }
 
+#[derive(Accounts)]
+pub struct DeregisterUser<'info> {
+    #[account(
+        mut,
+        seeds = [b"user", user_authority.key().as_ref()],
+        bump,
+        close = user_authority
+    )]
+    pub user_account: AccountLoader<'info, UserAccount>,
+
+    #[account(mut, seeds = [b"registry"], bump)]
+    pub registry: AccountLoader<'info, Registry>,
+
+    // Same shard register_user incremented (shard = authority first byte % 16).
+    #[account(
+        mut,
+        seeds = [b"registry_shard".as_ref(), &[user_authority.key().to_bytes()[0] % 16]],
+        bump
+    )]
+    pub registry_shard: AccountLoader<'info, RegistryShard>,
+
+    #[account(mut)]
+    pub user_authority: Signer<'info>,
+}
+
 #[derive(Accounts)]
 pub struct IsValidUser<'info> {
     pub user_account: AccountLoader<'info, UserAccount>,
@@ -1608,6 +2576,28 @@ pub struct GetUnsettledBalance<'info> {
     pub meter_account: AccountLoader<'info, MeterAccount>,
 }
 
+#[derive(Accounts)]
+pub struct GetMeterSummary<'info> {
+    pub meter_account: AccountLoader<'info, MeterAccount>,
+}
+
+// remaining_accounts: 1..=MAX_UNSETTLED_BATCH `MeterAccount`s (read-only).
+#[derive(Accounts)]
+pub struct GetUnsettledBalances<'info> {
+    #[account(
+        seeds = [b"registry"],
+        bump,
+    )]
+    pub registry: AccountLoader<'info, Registry>,
+}
+
+// remaining_accounts: 1..=MAX_METER_BATCH `MeterAccount`s (writable), all owned by
+// `meter_owner`.
+#[derive(Accounts)]
+pub struct SettleMetersBatch<'info> {
+    pub meter_owner: Signer<'info>,
+}
+
 #[derive(Accounts)]
 pub struct SettleMeterBalance<'info> {
     #[account(mut)]
@@ -1664,6 +2654,14 @@ pub struct MarkErcClaimed<'info> {
     pub authority: Signer<'info>,
 }
 #[derive(Accounts)]
+pub struct ReleaseErcClaim<'info> {
+    #[account(mut)]
+    pub meter_account: AccountLoader<'info, MeterAccount>,
+    #[account(seeds = [b"registry"], bump)]
+    pub registry: AccountLoader<'info, Registry>,
+    pub authority: Signer<'info>,
+}
+#[derive(Accounts)]
 pub struct AggregateShards<'info> {
     #[account(mut, seeds = [b"registry"], bump)]
     pub registry: AccountLoader<'info, Registry>,
@@ -2181,3 +3179,74 @@ mod slash_math_tests {
         ).is_ok());
     }
 }
+
+#[cfg(test)]
+mod meter_correction_tests {
+    use super::*;
+
+    // MeterAccount is zero_copy/Pod — start zeroed and set the fields under test.
+    fn meter(generation: u64, consumption: u64, settled: u64, claimed: u64) -> MeterAccount {
+        let mut m: MeterAccount = bytemuck::Zeroable::zeroed();
+        m.total_generation = generation;
+        m.total_consumption = consumption;
+        m.settled_net_generation = settled;
+        m.claimed_erc_generation = claimed;
+        m
+    }
+
+    #[test]
+    fn correction_keeps_watermarks_within_new_net() {
+        // Glitch inflated generation to 10_000; 6_000 settled + 3_000 claimed.
+        let mut m = meter(10_000, 0, 6_000, 3_000);
+        apply_meter_correction(&mut m, 5_000, 1_000); // net 4_000
+        assert_eq!(m.total_generation, 5_000);
+        assert_eq!(m.total_consumption, 1_000);
+        assert_eq!(m.settled_net_generation, 4_000);
+        assert_eq!(m.claimed_erc_generation, 0);
+    }
+
+    #[test]
+    fn correction_clamps_erc_claim_after_settlement() {
+        let mut m = meter(10_000, 0, 2_000, 5_000);
+        apply_meter_correction(&mut m, 6_000, 0);
+        assert_eq!(m.settled_net_generation, 2_000);
+        assert_eq!(m.claimed_erc_generation, 4_000);
+    }
+
+    #[test]
+    fn correction_leaves_watermarks_that_already_fit() {
+        let mut m = meter(1_000, 0, 300, 200);
+        apply_meter_correction(&mut m, 2_000, 500);
+        assert_eq!(m.settled_net_generation, 300);
+        assert_eq!(m.claimed_erc_generation, 200);
+    }
+
+    #[test]
+    fn correction_with_consumption_above_generation_zeroes_watermarks() {
+        let mut m = meter(1_000, 0, 500, 500);
+        apply_meter_correction(&mut m, 100, 400);
+        assert_eq!(m.settled_net_generation, 0);
+        assert_eq!(m.claimed_erc_generation, 0);
+    }
+
+    #[test]
+    fn rebase_absorbs_the_gap_and_keeps_prior_unsettled() {
+        // 1_000 net, 600 settled, 100 claimed -> 300 unsettled before maintenance.
+        let mut m = meter(1_200, 200, 600, 100);
+        apply_meter_rebase(&mut m, 5_000, 700); // net 4_300: 3_300 accrued in the gap
+        assert_eq!(m.total_generation, 5_000);
+        assert_eq!(m.total_consumption, 700);
+        assert_eq!(m.settled_net_generation, 3_900);
+        assert_eq!(m.claimed_erc_generation, 100);
+        assert_eq!(m.unsettled_balance(), 400); // ERC claim not counted by the view
+    }
+
+    #[test]
+    fn rebase_with_net_drop_clamps_watermarks() {
+        // Consumption outran generation during the gap: net falls from 1_000 to 200.
+        let mut m = meter(1_000, 0, 600, 300);
+        apply_meter_rebase(&mut m, 1_500, 1_300);
+        assert_eq!(m.settled_net_generation, 200);
+        assert_eq!(m.claimed_erc_generation, 0);
+    }
+}
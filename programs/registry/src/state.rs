@@ -10,13 +10,27 @@ pub struct Registry {
     pub oracle_authority: Pubkey, // Authorized oracle (Option -> Pubkey for ZeroCopy)
     pub has_oracle_authority: u8, // Track if oracle_authority is valid (u8 for Pod)
     pub has_slash_destination: u8, // Track if slash_destination is configured (u8 for Pod)
-    pub _padding: [u8; 6],        // Alignment
+    pub _padding: [u8; 2],        // Alignment
+    pub max_power: u32,           // Max implied generation per second before a reading is flagged (0 = off)
     pub user_count: u64,
     pub meter_count: u64,
     pub active_meter_count: u64, // Track active meters separately
     pub slash_destination: Pubkey, // Allowed sink for slashed validator bonds (e.g. treasury reward_vault)
 }
 
+/// Pending oracle rotation. PDA seeds `[b"oracle_proposal"]`; created by
+/// `propose_oracle_authority` and closed (rent to `proposer`) when the proposed key accepts
+/// or the authority cancels. Kept out of `Registry`, whose size is fixed.
+#[account(zero_copy)]
+#[repr(C)]
+pub struct OracleAuthorityProposal {
+    pub proposer: Pubkey,        // 32 bytes (0-32)  - registry authority that paid the rent
+    pub proposed_oracle: Pubkey, // 32 bytes (32-64) - must sign accept_oracle_authority
+    pub proposed_at: i64,        // 8 bytes  (64-72)
+    pub bump: u8,                // 1 byte   (72-73)
+    pub _padding: [u8; 7],       // 7 bytes  (73-80)
+}
+
 /// RegistryShard account for distributed counters
 #[account(zero_copy)]
 #[repr(C)]
@@ -82,7 +96,9 @@ pub struct MeterAccount {
     pub owner: Pubkey,         // User who owns this meter
     pub meter_type: MeterType, // Solar, Wind, Battery, or Grid (offset 64)
     pub status: MeterStatus,   // Active, Inactive, or Maintenance (65)
-    pub _pad_a: [u8; 2],       // Alignment to the i32 below (66-68)
+    pub reading_mode: u8,      // READING_MODE_DELTA or READING_MODE_ABSOLUTE (66). Carved from
+                               //   the former _pad_a[2]; existing accounts read 0 (delta).
+    pub _pad_a: [u8; 1],       // Alignment to the i32 below (67-68)
     pub zone_id: i32,          // Microgrid zone this meter belongs to (68-72). Carved from the
                                //   former _padding[6]; binds the meter to one governance zone so
                                //   its vote weight can only affect that zone. Existing accounts
@@ -97,6 +113,167 @@ pub struct MeterAccount {
     pub claimed_erc_generation: u64,
 }
 
+impl MeterAccount {
+    /// Active and reported within `max_age_seconds` of `now`. A meter that has never
+    /// reported (`last_reading_at == 0`) is never fresh.
+    pub fn is_fresh_at(&self, now: i64, max_age_seconds: i64) -> bool {
+        self.status == MeterStatus::Active
+            && self.last_reading_at > 0
+            && now.saturating_sub(self.last_reading_at) <= max_age_seconds
+    }
+
+    /// Generated minus consumed, floored at zero. The base both GRID settlement and ERC
+    /// claims are bounded by.
+    pub fn net_generation(&self) -> u64 {
+        self.total_generation.saturating_sub(self.total_consumption)
+    }
+
+    /// Net generation not yet settled into GRID tokens.
+    pub fn unsettled_balance(&self) -> u64 {
+        self.net_generation().saturating_sub(self.settled_net_generation)
+    }
+
+    /// What `settle_meter_balance` would settle now: net generation less both the GRID
+    /// and the ERC watermarks.
+    pub fn settleable_balance(&self) -> u64 {
+        self.unsettled_balance().saturating_sub(self.claimed_erc_generation)
+    }
+
+    /// Net generation not yet claimed for ERC issuance.
+    pub fn unclaimed_erc(&self) -> u64 {
+        self.net_generation().saturating_sub(self.claimed_erc_generation)
+    }
+
+    pub fn summary(&self) -> MeterSummary {
+        MeterSummary {
+            net_generation: self.net_generation(),
+            unsettled: self.unsettled_balance(),
+            claimed_erc: self.claimed_erc_generation,
+            unclaimed_erc: self.unclaimed_erc(),
+            status: self.status,
+            last_reading_at: self.last_reading_at,
+        }
+    }
+}
+
+/// Derived balances of one meter, returned by `get_meter_summary`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MeterSummary {
+    pub net_generation: u64,
+    pub unsettled: u64,       // net_generation - settled_net_generation
+    pub claimed_erc: u64,
+    pub unclaimed_erc: u64,   // net_generation - claimed_erc_generation
+    pub status: MeterStatus,
+    pub last_reading_at: i64,
+}
+
+/// `MeterAccount::reading_mode`: the oracle submits per-interval deltas (the default)
+pub const READING_MODE_DELTA: u8 = 0;
+/// `MeterAccount::reading_mode`: the oracle submits the meter's cumulative register and the
+/// registry derives deltas from the last value kept in `MeterAbsoluteReading`
+pub const READING_MODE_ABSOLUTE: u8 = 1;
+
+/// Last cumulative register seen for an absolute-mode meter. PDA seeds
+/// `[b"meter_absolute", meter]`; created by `set_meter_reading_mode`.
+#[account(zero_copy)]
+#[repr(C)]
+pub struct MeterAbsoluteReading {
+    pub meter: Pubkey,          // 32 bytes (0-32)  - MeterAccount this register belongs to
+    pub last_generation: u64,   // 8 bytes  (32-40) - last accepted cumulative generation
+    pub last_consumption: u64,  // 8 bytes  (40-48) - last accepted cumulative consumption
+    pub has_baseline: u8,       // 1 byte   (48-49) - 0 until the first absolute reading lands
+    pub bump: u8,               // 1 byte   (49-50) - canonical PDA bump
+    pub _padding: [u8; 6],      // 6 bytes  (50-56)
+}
+
+impl MeterAbsoluteReading {
+    /// Deltas implied by a cumulative reading. The first reading after a (re)baseline only
+    /// records the register and yields zero; a register that went backwards is rejected.
+    pub fn deltas(&self, generation: u64, consumption: u64) -> Result<(u64, u64)> {
+        if self.has_baseline == 0 {
+            return Ok((0, 0));
+        }
+        require!(
+            generation >= self.last_generation && consumption >= self.last_consumption,
+            crate::error::RegistryError::NonMonotonicReading
+        );
+        Ok((generation - self.last_generation, consumption - self.last_consumption))
+    }
+
+    pub fn record(&mut self, generation: u64, consumption: u64) {
+        self.last_generation = generation;
+        self.last_consumption = consumption;
+        self.has_baseline = 1;
+    }
+}
+
+/// Number of discrete readings retained per meter in `MeterReadingLog`
+pub const READING_LOG_CAPACITY: usize = 48;
+
+/// One accepted meter reading, as recorded in `MeterReadingLog`
+#[derive(
+    AnchorSerialize,
+    AnchorDeserialize,
+    Copy,
+    Clone,
+    Default,
+    Debug,
+    PartialEq,
+    Eq,
+    bytemuck::Zeroable,
+    bytemuck::Pod,
+)]
+#[repr(C)]
+pub struct ReadingLogEntry {
+    pub generated: u64,
+    pub consumed: u64,
+    pub timestamp: i64,
+}
+
+/// Audit trail of the last `READING_LOG_CAPACITY` readings for one meter.
+/// Seeds: [b"reading_log", meter_account.key()] — keyed by the meter PDA rather than the raw
+/// meter_id because meter ids are only unique per owner. Opt-in: created by `init_reading_log`
+/// and appended by `update_meter_reading` only when passed.
+#[account(zero_copy)]
+#[repr(C)]
+pub struct MeterReadingLog {
+    pub meter: Pubkey,  // 32 bytes (0-32)   - MeterAccount this log belongs to
+    pub head: u32,      // 4 bytes  (32-36)  - next slot to write
+    pub count: u32,     // 4 bytes  (36-40)  - populated slots (<= READING_LOG_CAPACITY)
+    pub bump: u8,       // 1 byte   (40-41)  - canonical PDA bump
+    pub _padding: [u8; 7], // 7 bytes (41-48) - Alignment for entries
+    pub entries: [ReadingLogEntry; READING_LOG_CAPACITY], // 24 * 48 = 1152 bytes (48-1200)
+}
+
+impl MeterReadingLog {
+    /// Most recently written entry, if any
+    pub fn latest(&self) -> Option<&ReadingLogEntry> {
+        if self.count == 0 {
+            return None;
+        }
+        let idx = (self.head as usize + READING_LOG_CAPACITY - 1) % READING_LOG_CAPACITY;
+        Some(&self.entries[idx])
+    }
+
+    /// Append a reading, overwriting the oldest once full. Entries must arrive in strictly
+    /// increasing timestamp order.
+    pub fn push(&mut self, entry: ReadingLogEntry) -> Result<()> {
+        if let Some(latest) = self.latest() {
+            require!(
+                entry.timestamp > latest.timestamp,
+                crate::error::RegistryError::StaleReading
+            );
+        }
+        let head = self.head as usize % READING_LOG_CAPACITY;
+        self.entries[head] = entry;
+        self.head = ((head + 1) % READING_LOG_CAPACITY) as u32;
+        if (self.count as usize) < READING_LOG_CAPACITY {
+            self.count += 1;
+        }
+        Ok(())
+    }
+}
+
 // Enums
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
 #[repr(u8)]
@@ -131,6 +308,13 @@ pub enum MeterType {
 unsafe impl bytemuck::Zeroable for MeterType {}
 unsafe impl bytemuck::Pod for MeterType {}
 
+/// One entry of a `register_meters_batch` call
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct MeterRegistration {
+    pub meter_id: String,
+    pub meter_type: MeterType,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
 #[repr(u8)]
 pub enum MeterStatus {
@@ -169,6 +353,12 @@ mod layout_tests {
         assert_eq!(std::mem::size_of::<UserAccount>(), 104);
     }
 
+    /// `max_power` was carved from the former `_padding[6]`; `Registry` stays 128 bytes.
+    #[test]
+    fn registry_size_is_stable() {
+        assert_eq!(std::mem::size_of::<Registry>(), 128);
+    }
+
     /// `MeterAccount` is bytemuck-cast cross-program by `governance` (its mirror struct must
     /// match byte-for-byte). `zone_id` was carved from the former `_padding[6]`, so the size
     /// must stay 120 bytes — and `governance::state::meter_account::MeterAccount` must mirror it.
@@ -176,4 +366,94 @@ mod layout_tests {
     fn meter_account_size_is_stable() {
         assert_eq!(std::mem::size_of::<MeterAccount>(), 120);
     }
+
+    #[test]
+    fn unsettled_balance_saturates() {
+        let mut meter: MeterAccount = bytemuck::Zeroable::zeroed();
+        meter.total_generation = 1_000;
+        meter.total_consumption = 200;
+        meter.settled_net_generation = 300;
+        assert_eq!(meter.unsettled_balance(), 500);
+        // Consumption above generation, or a settled mark above net, floors at zero.
+        meter.total_consumption = 1_500;
+        assert_eq!(meter.unsettled_balance(), 0);
+        meter.total_consumption = 200;
+        meter.settled_net_generation = 900;
+        assert_eq!(meter.unsettled_balance(), 0);
+    }
+
+    #[test]
+    fn meter_summary_derives_from_totals() {
+        let mut meter: MeterAccount = bytemuck::Zeroable::zeroed();
+        meter.status = MeterStatus::Active;
+        meter.last_reading_at = 42;
+        meter.total_generation = 1_000;
+        meter.total_consumption = 200;
+        meter.settled_net_generation = 300;
+        meter.claimed_erc_generation = 100;
+        assert_eq!(
+            meter.summary(),
+            MeterSummary {
+                net_generation: 800,
+                unsettled: 500,
+                claimed_erc: 100,
+                unclaimed_erc: 700,
+                status: MeterStatus::Active,
+                last_reading_at: 42,
+            }
+        );
+        assert_eq!(meter.settleable_balance(), 400);
+        meter.total_consumption = 1_500;
+        let summary = meter.summary();
+        assert_eq!((summary.net_generation, summary.unsettled, summary.unclaimed_erc), (0, 0, 0));
+    }
+
+    #[test]
+    fn meter_reading_log_size() {
+        assert_eq!(std::mem::size_of::<MeterReadingLog>(), 48 + 24 * READING_LOG_CAPACITY);
+    }
+
+    fn entry(ts: i64) -> ReadingLogEntry {
+        ReadingLogEntry { generated: ts as u64, consumed: 0, timestamp: ts }
+    }
+
+    #[test]
+    fn reading_log_wraps_and_keeps_latest() {
+        let mut log: MeterReadingLog = bytemuck::Zeroable::zeroed();
+        for ts in 1..=(READING_LOG_CAPACITY as i64 + 2) {
+            log.push(entry(ts)).unwrap();
+        }
+        assert_eq!(log.count as usize, READING_LOG_CAPACITY);
+        assert_eq!(log.head, 2);
+        assert_eq!(log.latest().unwrap().timestamp, READING_LOG_CAPACITY as i64 + 2);
+        // Oldest surviving entry is ts 3 (1 and 2 were overwritten).
+        assert_eq!(log.entries[2].timestamp, 3);
+    }
+
+    #[test]
+    fn reading_log_rejects_out_of_order_entry() {
+        let mut log: MeterReadingLog = bytemuck::Zeroable::zeroed();
+        log.push(entry(100)).unwrap();
+        assert!(log.push(entry(100)).is_err());
+        assert!(log.push(entry(99)).is_err());
+        assert_eq!(log.count, 1);
+    }
+
+    #[test]
+    fn oracle_proposal_size() {
+        assert_eq!(std::mem::size_of::<OracleAuthorityProposal>(), 80);
+    }
+
+    #[test]
+    fn absolute_reading_baselines_then_diffs() {
+        let mut reg: MeterAbsoluteReading = bytemuck::Zeroable::zeroed();
+        assert_eq!(std::mem::size_of::<MeterAbsoluteReading>(), 56);
+        // First reading only sets the baseline.
+        assert_eq!(reg.deltas(5_000, 1_000).unwrap(), (0, 0));
+        reg.record(5_000, 1_000);
+        assert_eq!(reg.deltas(5_400, 1_000).unwrap(), (400, 0));
+        // Either register going backwards is rejected.
+        assert!(reg.deltas(4_999, 1_000).is_err());
+        assert!(reg.deltas(5_400, 999).is_err());
+    }
 }
@@ -149,4 +149,22 @@ pub enum TpcError {
     
     #[msg("Account already in use by concurrent transaction")]
     AccountInUse,
+
+    #[msg("Customer already present in last-name index")]
+    DuplicateCustomerInIndex,
+
+    #[msg("Last-name hash does not match the customer's last name")]
+    InvalidLastNameHash,
+
+    #[msg("Stock quantity update would underflow or overflow")]
+    StockUnderflow,
+
+    #[msg("Stock-Level expects 1-20 district Order accounts followed by Stock accounts of the warehouse")]
+    InvalidStockLevelAccounts,
+
+    #[msg("Order ID must not be below the district's next order ID")]
+    OrderIdNotIncreasing,
+
+    #[msg("Delivery candidate is not the district's oldest undelivered order")]
+    NotOldestOrder,
 }
@@ -5,6 +5,37 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::error::TpcError;
+use gridtokenx_shared::{BenchmarkResult, BenchmarkSuite};
+
+/// Start Benchmark Context
+#[derive(Accounts)]
+pub struct StartBenchmark<'info> {
+    #[account(
+        mut,
+        seeds = [b"benchmark"],
+        bump = benchmark.bump,
+    )]
+    pub benchmark: Account<'info, BenchmarkState>,
+    
+    /// Authority must be the benchmark owner
+    #[account(
+        constraint = authority.key() == benchmark.authority @ TpcError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+/// Mark the start of the measured interval used for tpmC
+pub fn start_benchmark(ctx: Context<StartBenchmark>) -> Result<()> {
+    let benchmark = &mut ctx.accounts.benchmark;
+    require!(!benchmark.is_running, TpcError::BenchmarkAlreadyRunning);
+    
+    benchmark.is_running = true;
+    benchmark.start_time = Clock::get()?.unix_timestamp;
+    benchmark.end_time = 0;
+    
+    msg!("Benchmark started at {}", benchmark.start_time);
+    Ok(())
+}
 
 /// Record Metric Context
 #[derive(Accounts)]
@@ -66,6 +97,93 @@ pub fn record_metric(
         stats.max_latency_us = latency_us;
     }
     
+    let bucket = latency_bucket(latency_us);
+    stats.latency_histogram[bucket] = stats.latency_histogram[bucket].saturating_add(1);
+    
+    Ok(())
+}
+
+/// Compute Percentiles Context
+#[derive(Accounts)]
+pub struct ComputePercentiles<'info> {
+    #[account(
+        seeds = [b"benchmark"],
+        bump = benchmark.bump,
+    )]
+    pub benchmark: Account<'info, BenchmarkState>,
+}
+
+/// Return approximate p50/p90/p99 latency (microseconds) from the recorded histogram
+pub fn compute_percentiles(ctx: Context<ComputePercentiles>) -> Result<LatencyPercentiles> {
+    let percentiles = ctx.accounts.benchmark.stats.percentiles();
+    
+    msg!(
+        "TPC-C percentiles: samples={}, p50={}us, p90={}us, p99={}us",
+        percentiles.samples,
+        percentiles.p50_latency_us,
+        percentiles.p90_latency_us,
+        percentiles.p99_latency_us
+    );
+    
+    Ok(percentiles)
+}
+
+/// Finalize Benchmark Context
+#[derive(Accounts)]
+pub struct FinalizeBenchmark<'info> {
+    #[account(
+        mut,
+        seeds = [b"benchmark"],
+        bump = benchmark.bump,
+    )]
+    pub benchmark: Account<'info, BenchmarkState>,
+    
+    /// Authority must be the benchmark owner
+    #[account(
+        constraint = authority.key() == benchmark.authority @ TpcError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+/// Finalize the run and emit results in the cross-suite summary format
+pub fn finalize_benchmark(ctx: Context<FinalizeBenchmark>) -> Result<()> {
+    let benchmark = &mut ctx.accounts.benchmark;
+    let now = Clock::get()?.unix_timestamp;
+    
+    benchmark.end_time = now;
+    benchmark.is_running = false;
+    
+    // Fall back to the configured duration when the run was never started on-chain
+    let duration_seconds = if benchmark.start_time > 0 {
+        (benchmark.end_time - benchmark.start_time).max(1) as u64
+    } else {
+        benchmark.config.duration_seconds
+    };
+    
+    benchmark.stats.tpm_c = benchmark.stats.compute_tpm_c(duration_seconds);
+    let avg_latency_us = benchmark.stats.avg_success_latency_us();
+    let (latency, throughput) = benchmark.stats.to_common_summary(duration_seconds);
+    
+    msg!(
+        "TPC-C finalized: tpmC={}, TPS={}, avg_latency={}us, success_rate={}bps",
+        benchmark.stats.tpm_c,
+        throughput.tps,
+        avg_latency_us,
+        throughput.success_rate_bps
+    );
+    
+    emit!(BenchmarkFinalized {
+        result: BenchmarkResult {
+            suite: BenchmarkSuite::TpcC,
+            run_id: benchmark.run_id,
+            latency,
+            throughput,
+            timestamp: now,
+        },
+        tpm_c: benchmark.stats.tpm_c,
+        avg_latency_us,
+    });
+    
     Ok(())
 }
 
@@ -94,6 +212,7 @@ pub fn reset_benchmark(ctx: Context<ResetBenchmark>) -> Result<()> {
     benchmark.is_running = false;
     benchmark.start_time = 0;
     benchmark.end_time = 0;
+    benchmark.run_id += 1;
     
     msg!("Benchmark statistics reset");
     Ok(())
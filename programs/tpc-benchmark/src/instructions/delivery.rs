@@ -18,7 +18,8 @@
 //! ## Transaction Flow
 //! 
 //! For each district (or single district):
-//! 1. Find oldest NewOrder (lowest o_id)
+//! 1. Find oldest NewOrder (lowest o_id) - `delivery_district` rejects any
+//!    other candidate with `NotOldestOrder`
 //! 2. Delete NewOrder account (close and reclaim rent)
 //! 3. Update Order (set carrier_id, set delivery_d for all lines)
 //! 4. Update Customer (increment delivery_cnt, update balance)
@@ -109,15 +110,16 @@ pub fn delivery<'info>(
 #[derive(Accounts)]
 #[instruction(w_id: u64, d_id: u64)]
 pub struct DeliveryDistrict<'info> {
-    /// District for reference
+    /// District - advances min_undelivered_o_id (WRITE LOCK)
     #[account(
+        mut,
         seeds = [b"district", w_id.to_le_bytes().as_ref(), d_id.to_le_bytes().as_ref()],
         bump = district.load()?.bump,
     )]
     pub district: AccountLoader<'info, District>,
     
     /// NewOrder to be deleted (oldest in district)
-    /// Note: In practice, the client must find the oldest o_id
+    /// The client finds the oldest o_id; the handler verifies it
     #[account(
         mut,
         close = payer,
@@ -175,6 +177,7 @@ pub fn delivery_district<'info>(
         TpcError::InvalidCarrierId
     );
     
+    let mut district = ctx.accounts.district.load_mut()?;
     let mut order = ctx.accounts.order.load_mut()?;
     let mut customer = ctx.accounts.customer.load_mut()?;
     let clock = Clock::get()?;
@@ -186,7 +189,17 @@ pub fn delivery_district<'info>(
         TpcError::OrderAlreadyDelivered
     );
     
+    // FIFO: o_ids increase per district, so the candidate is the oldest
+    // undelivered order exactly when the order queued before it is delivered
+    require!(
+        ctx.accounts.new_order.prev_o_id < district.min_undelivered_o_id,
+        TpcError::NotOldestOrder
+    );
+    
     let o_id = order.o_id;
+    district.min_undelivered_o_id = o_id
+        .checked_add(1)
+        .ok_or(TpcError::OrderIdOverflow)?;
     
     // ═══════════════════════════════════════════════════════════════════
     // UPDATE ORDER
@@ -41,6 +41,7 @@ pub fn initialize_benchmark(
     benchmark.start_time = 0;
     benchmark.end_time = 0;
     benchmark.bump = ctx.bumps.benchmark;
+    benchmark.run_id = 0;
     
     msg!("TPC-C Benchmark initialized with {} warehouses", benchmark.config.warehouses);
     Ok(())
@@ -156,6 +157,7 @@ pub fn initialize_district<'info>(
     district.tax = tax;
     district.ytd = 30_000_00;
     district.next_o_id = 3001;
+    district.min_undelivered_o_id = district.next_o_id;
     district.bump = ctx.bumps.district;
     
     Ok(())
@@ -166,7 +168,7 @@ pub fn initialize_district<'info>(
 // ═══════════════════════════════════════════════════════════════════════════════
 
 #[derive(Accounts)]
-#[instruction(w_id: u64, d_id: u64, c_id: u64)]
+#[instruction(w_id: u64, d_id: u64, c_id: u64, last_name_hash: [u8; 32])]
 pub struct InitializeCustomer<'info> {
     #[account(
         init,
@@ -189,6 +191,21 @@ pub struct InitializeCustomer<'info> {
     )]
     pub district: AccountLoader<'info, District>,
     
+    /// Last-name index the new customer is added to (created on first use)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = CustomerLastNameIndex::SPACE,
+        seeds = [
+            b"idx_c_last",
+            w_id.to_le_bytes().as_ref(),
+            d_id.to_le_bytes().as_ref(),
+            last_name_hash.as_ref()
+        ],
+        bump
+    )]
+    pub customer_index: Account<'info, CustomerLastNameIndex>,
+    
     #[account(mut)]
     pub authority: Signer<'info>,
     
@@ -200,6 +217,7 @@ pub fn initialize_customer<'info>(
     w_id: u64,
     d_id: u64,
     c_id: u64,
+    last_name_hash: [u8; 32],
     first: String,
     middle: String,
     last: String,
@@ -215,6 +233,18 @@ pub fn initialize_customer<'info>(
 ) -> Result<()> {
     require!(c_id >= 1 && c_id <= 3000, TpcError::InvalidCustomerId);
     require!(discount <= 5000, TpcError::InvalidDiscount);
+    require!(
+        last_name_hash == crate::state::last_name_hash(&last),
+        TpcError::InvalidLastNameHash
+    );
+    
+    // Seeds bind w_id/d_id/hash, so rewriting them is idempotent for existing indexes
+    let index = &mut ctx.accounts.customer_index;
+    index.w_id = w_id;
+    index.d_id = d_id;
+    index.last_name_hash = last_name_hash;
+    index.bump = ctx.bumps.customer_index;
+    index.insert_customer(c_id)?;
     
     let mut customer = ctx.accounts.customer.load_init()?;
     let clock = Clock::get()?;
@@ -182,11 +182,13 @@ pub fn new_order<'info>(
     // ═══════════════════════════════════════════════════════════════════
     
     // We used to use district.next_o_id, but now we use client-provided o_id
-    // for PDA seeds. We still increment district.next_o_id for legacy state
-    // tracking, but the collision is resolved by unique o_ids.
+    // for PDA seeds, so the collision is resolved by unique o_ids. The ids
+    // must still increase per district: delivery dequeues in o_id order.
+    require!(o_id >= district.next_o_id, TpcError::OrderIdNotIncreasing);
+    let prev_o_id = district.next_o_id.saturating_sub(1);
     
-    // Increment next_o_id - THIS IS THE SERIALIZATION POINT
-    district.next_o_id = district.next_o_id
+    // Advance next_o_id past this order - THIS IS THE SERIALIZATION POINT
+    district.next_o_id = o_id
         .checked_add(1)
         .ok_or(TpcError::OrderIdOverflow)?;
     
@@ -242,13 +244,9 @@ pub fn new_order<'info>(
             TpcError::ItemNotFound
         );
         
-        // Check and update stock quantity
+        // Check and update stock quantity (spec restock when fewer than 10 would remain)
         let quantity = ol_input.quantity as u64;
-        if stock.quantity >= quantity + 10 {
-            stock.quantity -= quantity;
-        } else {
-            stock.quantity = stock.quantity + 91 - quantity; // Restock
-        }
+        stock.quantity = Stock::next_quantity(stock.quantity, quantity)?;
         
         // Update stock statistics
         stock.ytd += quantity;
@@ -313,6 +311,7 @@ pub fn new_order<'info>(
     new_order.w_id = w_id;
     new_order.d_id = d_id;
     new_order.o_id = o_id;
+    new_order.prev_o_id = prev_o_id;
     new_order.created_at = clock.unix_timestamp;
     new_order.bump = ctx.bumps.new_order;
     
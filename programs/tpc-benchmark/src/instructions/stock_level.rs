@@ -10,9 +10,10 @@
 //! 
 //! ## Implementation Note
 //! 
-//! TPC-C specifies examining the last 20 orders. In Solana,
-//! this requires passing all relevant Stock accounts.
-//! We simplify by passing the accounts to check directly.
+//! TPC-C examines the lines of the district's last 20 orders. Order IDs are
+//! client-provided here (see new_order.rs), so the program cannot enumerate
+//! them from `next_o_id`; the caller passes the Order accounts, then the
+//! Stock accounts for their items, via `remaining_accounts`.
 
 use anchor_lang::prelude::*;
 use crate::state::*;
@@ -22,30 +23,24 @@ use crate::error::TpcError;
 #[derive(Accounts)]
 #[instruction(w_id: u64, d_id: u64)]
 pub struct StockLevel<'info> {
-    /// District to get next_o_id (determines recent orders)
+    /// District whose recent orders are examined (seeds bind w_id/d_id)
     #[account(
         seeds = [b"district", w_id.to_le_bytes().as_ref(), d_id.to_le_bytes().as_ref()],
         bump = district.load()?.bump,
     )]
     pub district: AccountLoader<'info, District>,
     
-    // Remaining accounts: Stock accounts to check
-    // [stock_1, stock_2, ..., stock_n]
+    // Remaining accounts: Order accounts, then Stock accounts to check
+    // [order_1, ..., order_n, stock_1, ..., stock_m]
 }
 
-/// Stock Level Result
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
-pub struct StockLevelResult {
-    /// Warehouse ID
-    pub w_id: u64,
-    /// District ID
-    pub d_id: u64,
-    /// Threshold used for counting
-    pub threshold: u64,
-    /// Number of distinct items below threshold
-    pub low_stock_count: u64,
-    /// Total items checked
-    pub items_checked: u64,
+/// Orders examined by Stock-Level (TPC-C 2.8.2.2: the district's last 20)
+pub const STOCK_LEVEL_ORDER_WINDOW: usize = 20;
+
+/// True when `info` is a tpc-benchmark account carrying `discriminator`
+fn is_program_account(info: &AccountInfo, discriminator: &[u8], space: usize) -> Result<bool> {
+    let data = info.try_borrow_data()?;
+    Ok(*info.owner == crate::ID && data.len() >= space && data.starts_with(discriminator))
 }
 
 /// Execute Stock-Level Transaction
@@ -56,52 +51,87 @@ pub struct StockLevelResult {
 /// * `threshold` - Stock quantity threshold
 /// 
 /// # Remaining Accounts
-/// Stock accounts to check: ["stock", w_id, i_id] for each item
+/// `[order_1, ..., order_n, stock_1, ..., stock_m]`: 1 to 20 Order accounts of
+/// the district, then the Stock accounts ["stock", w_id, i_id] of their items.
+/// Stock for items outside those orders is ignored; each item counts once.
+/// 
+/// # Returns
+/// Number of distinct items below `threshold`
 pub fn stock_level<'info>(
     ctx: Context<StockLevel<'info>>,
     w_id: u64,
     d_id: u64,
     threshold: u64,
-) -> Result<()> {
+) -> Result<u64> {
     require!(threshold > 0, TpcError::InvalidStockThreshold);
     
-    let district = ctx.accounts.district.load()?;
-    let _next_o_id = district.next_o_id;
+    // Orders lead; the first non-Order account starts the Stock list
+    let accounts = ctx.remaining_accounts;
+    let mut orders_examined = 0;
+    while orders_examined < accounts.len()
+        && is_program_account(&accounts[orders_examined], Order::DISCRIMINATOR, Order::SPACE)?
+    {
+        orders_examined += 1;
+    }
+    require!(
+        orders_examined > 0 && orders_examined <= STOCK_LEVEL_ORDER_WINDOW,
+        TpcError::InvalidStockLevelAccounts
+    );
     
-    // Count items below threshold from remaining accounts
+    // Distinct item IDs across the orders' lines, sorted for lookup
+    let mut items: Vec<u64> = Vec::with_capacity(orders_examined * Order::MAX_ORDER_LINES);
+    for order_account in &accounts[..orders_examined] {
+        let data = order_account.try_borrow_data()?;
+        let order = bytemuck::from_bytes::<Order>(&data[8..Order::SPACE]);
+        require!(
+            order.w_id == w_id && order.d_id == d_id,
+            TpcError::InvalidStockLevelAccounts
+        );
+        let line_count = (order.ol_cnt as usize).min(Order::MAX_ORDER_LINES);
+        items.extend(order.lines[..line_count].iter().map(|line| line.i_id));
+    }
+    items.sort_unstable();
+    items.dedup();
+    
+    // Count each item at most once, however often its Stock account is passed
+    let mut checked = vec![false; items.len()];
     let mut low_stock_count: u64 = 0;
     let mut items_checked: u64 = 0;
     
-    for stock_account in ctx.remaining_accounts.iter() {
+    for stock_account in &accounts[orders_examined..] {
+        require!(
+            is_program_account(stock_account, Stock::DISCRIMINATOR, Stock::SPACE)?,
+            TpcError::InvalidStockLevelAccounts
+        );
         let data = stock_account.try_borrow_data()?;
-        if data.len() >= 8 + std::mem::size_of::<Stock>() {
-            let stock = bytemuck::from_bytes::<Stock>(&data[8..8 + std::mem::size_of::<Stock>()]);
-            items_checked += 1;
-            
-            // Check if stock is below threshold
-            if stock.quantity < threshold {
-                low_stock_count += 1;
+        let stock = bytemuck::from_bytes::<Stock>(&data[8..Stock::SPACE]);
+        require!(stock.w_id == w_id, TpcError::InvalidStockLevelAccounts);
+        
+        if let Ok(idx) = items.binary_search(&stock.i_id) {
+            if !checked[idx] {
+                checked[idx] = true;
+                items_checked += 1;
+                if stock.quantity < threshold {
+                    low_stock_count += 1;
+                }
             }
         }
     }
     
-    // The StockLevelResult is not explicitly returned or emitted as an event
-    // in this benchmark version, but it's good practice to define it.
-    // let _result = StockLevelResult {
-    //     w_id,
-    //     d_id,
-    //     threshold,
-    //     low_stock_count,
-    //     items_checked,
-    // };
-    
     msg!(
         "Stock-Level: W={} D={} threshold={} low_stock={}/{}",
         w_id, d_id, threshold, low_stock_count, items_checked
     );
     
-    // In production, emit as event or return data
-    // For benchmark, we just log the result
+    emit!(StockLevelComputed {
+        w_id,
+        d_id,
+        threshold,
+        low_stock_count,
+        orders_examined: orders_examined as u64,
+        distinct_items: items.len() as u64,
+        items_checked,
+    });
     
-    Ok(())
+    Ok(low_stock_count)
 }
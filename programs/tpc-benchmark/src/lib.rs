@@ -148,6 +148,7 @@ pub mod tpc_benchmark {
         w_id: u64,
         d_id: u64,
         c_id: u64,
+        last_name_hash: [u8; 32],
         first: String,
         middle: String,
         last: String,
@@ -163,7 +164,7 @@ pub mod tpc_benchmark {
     ) -> Result<()> {
         compute_fn!("initialize_customer" => {
             instructions::initialize_customer(
-                ctx, w_id, d_id, c_id, first, middle, last,
+                ctx, w_id, d_id, c_id, last_name_hash, first, middle, last,
                 street_1, street_2, city, state, zip, phone,
                 credit, credit_lim, discount,
             )
@@ -288,12 +289,18 @@ pub mod tpc_benchmark {
         w_id: u64,
         d_id: u64,
         threshold: u64,
-    ) -> Result<()> {
+    ) -> Result<u64> {
         compute_fn!("stock_level" => {
             instructions::stock_level(ctx, w_id, d_id, threshold)
         })
     }
 
+    pub fn start_benchmark(ctx: Context<StartBenchmark>) -> Result<()> {
+        compute_fn!("start_benchmark" => {
+            instructions::start_benchmark(ctx)
+        })
+    }
+
     pub fn record_metric(
         ctx: Context<RecordMetric>,
         tx_type: TransactionType,
@@ -306,6 +313,18 @@ pub mod tpc_benchmark {
         })
     }
 
+    pub fn finalize_benchmark(ctx: Context<FinalizeBenchmark>) -> Result<()> {
+        compute_fn!("finalize_benchmark" => {
+            instructions::finalize_benchmark(ctx)
+        })
+    }
+
+    pub fn compute_percentiles(ctx: Context<ComputePercentiles>) -> Result<LatencyPercentiles> {
+        compute_fn!("compute_percentiles" => {
+            instructions::compute_percentiles(ctx)
+        })
+    }
+
     pub fn reset_benchmark(ctx: Context<ResetBenchmark>) -> Result<()> {
         compute_fn!("reset_benchmark" => {
             instructions::reset_benchmark(ctx)
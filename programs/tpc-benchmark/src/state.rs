@@ -20,6 +20,8 @@
 use anchor_lang::prelude::*;
 use bytemuck::{Pod, Zeroable};
 use crate::error::TpcError;
+use gridtokenx_shared::{BenchmarkResult, LatencySummary, ThroughputSummary};
+use solana_sha256_hasher::hash;
 
 // ═══════════════════════════════════════════════════════════════════════════════
 // UTILS
@@ -39,6 +41,13 @@ pub fn bytes_to_string(bytes: &[u8]) -> String {
     s.to_string()
 }
 
+/// Index key for a customer last name: sha256 of C_LAST as stored (truncated to 16 bytes)
+pub fn last_name_hash(last: &str) -> [u8; 32] {
+    let stored = string_to_bytes::<16>(last);
+    let len = stored.iter().position(|&b| b == 0).unwrap_or(stored.len());
+    hash(&stored[..len]).to_bytes()
+}
+
 // ═══════════════════════════════════════════════════════════════════════════════
 // BENCHMARK CONFIGURATION
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -63,6 +72,9 @@ pub struct BenchmarkState {
     
     /// Bump seed for PDA
     pub bump: u8,
+
+    /// Run counter, advanced by `reset_benchmark`; appended so existing accounts read 0
+    pub run_id: u64,
 }
 
 /// Configuration for benchmark execution
@@ -112,6 +124,116 @@ pub struct BenchmarkStats {
     
     /// Computed tpmC (New-Order transactions per minute)
     pub tpm_c: u64,
+    
+    /// Latency histogram over `LATENCY_BUCKET_EDGES_US` (same buckets as `TransactionMetrics`)
+    pub latency_histogram: [u64; LATENCY_BUCKETS],
+}
+
+impl BenchmarkStats {
+    /// Project the running statistics into the cross-suite summary format
+    pub fn to_common_summary(&self, duration_seconds: u64) -> (LatencySummary, ThroughputSummary) {
+        let samples = self
+            .successful_transactions
+            .saturating_add(self.failed_transactions);
+        (
+            LatencySummary::new(
+                samples,
+                self.total_latency_us,
+                self.min_latency_us,
+                self.max_latency_us,
+            ),
+            ThroughputSummary::new(
+                self.successful_transactions,
+                self.failed_transactions,
+                duration_seconds,
+            ),
+        )
+    }
+    
+    /// tpmC: New-Order transactions per minute over `duration_seconds` (runs under a second count as one)
+    pub fn compute_tpm_c(&self, duration_seconds: u64) -> u64 {
+        self.new_order_count.saturating_mul(60) / duration_seconds.max(1)
+    }
+    
+    /// Average latency of successful transactions, 0 when none succeeded
+    pub fn avg_success_latency_us(&self) -> u64 {
+        self.total_latency_us
+            .checked_div(self.successful_transactions)
+            .unwrap_or(0)
+    }
+    
+    /// Approximate p50/p90/p99 from the running latency histogram
+    pub fn percentiles(&self) -> LatencyPercentiles {
+        LatencyPercentiles::from_histogram(&self.latency_histogram, self.max_latency_us)
+    }
+}
+
+/// Number of latency histogram buckets
+pub const LATENCY_BUCKETS: usize = 10;
+
+/// Exclusive upper edges (microseconds) of the first nine histogram buckets:
+/// <100us, <500us, <1ms, <5ms, <10ms, <50ms, <100ms, <500ms, <1s; the last bucket is >=1s
+pub const LATENCY_BUCKET_EDGES_US: [u64; LATENCY_BUCKETS - 1] = [
+    100, 500, 1_000, 5_000, 10_000, 50_000, 100_000, 500_000, 1_000_000,
+];
+
+/// Histogram bucket index for a latency sample
+pub fn latency_bucket(latency_us: u64) -> usize {
+    LATENCY_BUCKET_EDGES_US
+        .iter()
+        .position(|&edge| latency_us < edge)
+        .unwrap_or(LATENCY_BUCKETS - 1)
+}
+
+/// Approximate latency percentile from a bucket histogram.
+///
+/// Returns the upper edge of the bucket holding the `pct`-th ranked sample, capped at
+/// `max_latency_us` when known. Samples in the open-ended >=1s bucket report `max_latency_us`.
+pub fn histogram_percentile_us(
+    histogram: &[u64; LATENCY_BUCKETS],
+    pct: u64,
+    max_latency_us: u64,
+) -> u64 {
+    let total = histogram.iter().fold(0u64, |acc, &c| acc.saturating_add(c));
+    if total == 0 {
+        return 0;
+    }
+    // Nearest-rank: ceil(total * pct / 100), at least the first sample
+    let rank = ((total as u128 * pct as u128).div_ceil(100) as u64).max(1);
+    
+    let mut cumulative = 0u64;
+    for (i, &count) in histogram.iter().enumerate() {
+        cumulative = cumulative.saturating_add(count);
+        if cumulative >= rank {
+            return match LATENCY_BUCKET_EDGES_US.get(i) {
+                Some(&edge) if max_latency_us > 0 => edge.min(max_latency_us),
+                Some(&edge) => edge,
+                None => max_latency_us.max(LATENCY_BUCKET_EDGES_US[LATENCY_BUCKETS - 2]),
+            };
+        }
+    }
+    max_latency_us
+}
+
+/// Percentile latencies returned by `compute_percentiles` (microseconds)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct LatencyPercentiles {
+    /// Samples in the histogram
+    pub samples: u64,
+    pub p50_latency_us: u64,
+    pub p90_latency_us: u64,
+    pub p99_latency_us: u64,
+}
+
+impl LatencyPercentiles {
+    pub fn from_histogram(histogram: &[u64; LATENCY_BUCKETS], max_latency_us: u64) -> Self {
+        Self {
+            samples: histogram.iter().fold(0u64, |acc, &c| acc.saturating_add(c)),
+            p50_latency_us: histogram_percentile_us(histogram, 50, max_latency_us),
+            p90_latency_us: histogram_percentile_us(histogram, 90, max_latency_us),
+            p99_latency_us: histogram_percentile_us(histogram, 99, max_latency_us),
+        }
+    }
 }
 
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -211,6 +333,10 @@ pub struct District {
     /// Next available order ID (D_NEXT_O_ID)
     pub next_o_id: u64,
     
+    /// Every order with a lower o_id has been delivered (head of the
+    /// delivery queue; see `delivery_district`)
+    pub min_undelivered_o_id: u64,
+    
     /// Bump seed
     pub bump: u8,
     pub _padding_2: [u8; 7],
@@ -352,6 +478,21 @@ impl CustomerLastNameIndex {
         32 +                      // last_name_hash
         4 + (8 * Self::MAX_CUSTOMERS_PER_NAME) + // customer_ids vector
         1;                        // bump
+    
+    /// Insert a customer ID, keeping `customer_ids` sorted and bounded
+    pub fn insert_customer(&mut self, c_id: u64) -> Result<()> {
+        match self.customer_ids.binary_search(&c_id) {
+            Ok(_) => err!(TpcError::DuplicateCustomerInIndex),
+            Err(pos) => {
+                require!(
+                    self.customer_ids.len() < Self::MAX_CUSTOMERS_PER_NAME,
+                    TpcError::CustomerIndexFull
+                );
+                self.customer_ids.insert(pos, c_id);
+                Ok(())
+            }
+        }
+    }
 }
 
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -444,6 +585,24 @@ pub struct Stock {
 
 impl Stock {
     pub const SPACE: usize = 8 + std::mem::size_of::<Stock>();
+    
+    /// Minimum S_QUANTITY left after an order before the spec restock kicks in
+    pub const RESTOCK_THRESHOLD: u64 = 10;
+    /// Quantity added back by the spec restock
+    pub const RESTOCK_AMOUNT: u64 = 91;
+    
+    /// S_QUANTITY after an order line (TPC-C 2.4.2.2): subtract when at least
+    /// `RESTOCK_THRESHOLD` would remain, otherwise subtract and add `RESTOCK_AMOUNT`
+    pub fn next_quantity(current: u64, ordered: u64) -> Result<u64> {
+        let remaining = current.checked_sub(ordered);
+        match remaining {
+            Some(left) if left >= Self::RESTOCK_THRESHOLD => Ok(left),
+            _ => current
+                .checked_add(Self::RESTOCK_AMOUNT)
+                .and_then(|q| q.checked_sub(ordered))
+                .ok_or_else(|| error!(TpcError::StockUnderflow)),
+        }
+    }
 }
 
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -552,6 +711,9 @@ pub struct NewOrderEntry {
     pub d_id: u64,
     /// Order ID (NO_O_ID)
     pub o_id: u64,
+    /// o_id of the order created just before this one in the district
+    /// (links the queue when client-provided o_ids are sparse)
+    pub prev_o_id: u64,
     
     /// Creation timestamp for ordering
     pub created_at: i64,
@@ -563,6 +725,7 @@ pub struct NewOrderEntry {
 impl NewOrderEntry {
     pub const SPACE: usize = 8 +  // discriminator
         8 + 8 + 8 +               // w_id, d_id, o_id
+        8 +                       // prev_o_id
         8 +                       // created_at
         1;                        // bump
 }
@@ -647,7 +810,14 @@ pub struct TransactionMetrics {
     
     /// Latency histogram (for percentile calculation)
     /// Buckets: <100us, <500us, <1ms, <5ms, <10ms, <50ms, <100ms, <500ms, <1s, >1s
-    pub histogram: [u64; 10],
+    pub histogram: [u64; LATENCY_BUCKETS],
+}
+
+impl TransactionMetrics {
+    /// Approximate p50/p90/p99 from the histogram
+    pub fn percentiles(&self) -> LatencyPercentiles {
+        LatencyPercentiles::from_histogram(&self.histogram, self.latency_max)
+    }
 }
 
 /// Order line input for New-Order transaction
@@ -660,3 +830,202 @@ pub struct OrderLineInput {
     /// Quantity ordered
     pub quantity: u8,
 }
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// EVENTS
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Emitted by `finalize_benchmark`; `result` is the type the BLOCKBENCH suite emits too
+#[event]
+pub struct BenchmarkFinalized {
+    pub result: BenchmarkResult,
+    /// New-Order transactions per minute
+    pub tpm_c: u64,
+    /// `total_latency_us / successful_transactions`
+    pub avg_latency_us: u64,
+}
+
+/// Emitted by `stock_level` so the load generator can check the result, not just latency
+#[event]
+pub struct StockLevelComputed {
+    pub w_id: u64,
+    pub d_id: u64,
+    pub threshold: u64,
+    /// Distinct items below `threshold` (the value returned)
+    pub low_stock_count: u64,
+    /// Order accounts passed
+    pub orders_examined: u64,
+    /// Distinct items across those orders' lines
+    pub distinct_items: u64,
+    /// Distinct items whose Stock account was passed
+    pub items_checked: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_project_into_common_format() {
+        let stats = BenchmarkStats {
+            new_order_count: 45,
+            payment_count: 43,
+            successful_transactions: 95,
+            failed_transactions: 5,
+            total_latency_us: 20_000,
+            min_latency_us: 50,
+            max_latency_us: 900,
+            ..Default::default()
+        };
+        let (latency, throughput) = stats.to_common_summary(5);
+        // TPC-C records latency for every attempt, successful or not
+        assert_eq!(latency, LatencySummary::new(100, 20_000, 50, 900));
+        assert_eq!(latency.avg_us, 200);
+        assert_eq!(throughput, ThroughputSummary::new(95, 5, 5));
+        assert_eq!(throughput.tps, 19);
+        assert_eq!(throughput.success_rate_bps, 9_500);
+    }
+
+    fn empty_index() -> CustomerLastNameIndex {
+        CustomerLastNameIndex {
+            w_id: 1,
+            d_id: 1,
+            last_name_hash: last_name_hash("BARBARBAR"),
+            customer_ids: Vec::new(),
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn index_insert_keeps_ids_sorted() {
+        let mut index = empty_index();
+        for c_id in [42, 7, 3000, 8] {
+            index.insert_customer(c_id).unwrap();
+        }
+        assert_eq!(index.customer_ids, vec![7, 8, 42, 3000]);
+    }
+
+    #[test]
+    fn index_insert_rejects_duplicates_and_overflow() {
+        let mut index = empty_index();
+        index.insert_customer(5).unwrap();
+        assert_eq!(
+            index.insert_customer(5).unwrap_err(),
+            TpcError::DuplicateCustomerInIndex.into()
+        );
+
+        for c_id in 100..(100 + CustomerLastNameIndex::MAX_CUSTOMERS_PER_NAME as u64 - 1) {
+            index.insert_customer(c_id).unwrap();
+        }
+        assert_eq!(index.customer_ids.len(), CustomerLastNameIndex::MAX_CUSTOMERS_PER_NAME);
+        assert_eq!(
+            index.insert_customer(1).unwrap_err(),
+            TpcError::CustomerIndexFull.into()
+        );
+    }
+
+    #[test]
+    fn last_name_hash_matches_stored_name() {
+        // Names longer than the 16-byte C_LAST field hash as truncated
+        assert_eq!(
+            last_name_hash("ABCDEFGHIJKLMNOPQRST"),
+            last_name_hash("ABCDEFGHIJKLMNOP")
+        );
+        assert_ne!(last_name_hash("BAR"), last_name_hash("BARBAR"));
+    }
+
+    #[test]
+    fn stock_restocks_instead_of_dropping_below_threshold() {
+        assert_eq!(Stock::next_quantity(50, 10).unwrap(), 40);
+        assert_eq!(Stock::next_quantity(20, 10).unwrap(), 10);
+        // 19 - 10 = 9 < 10, so wrap: 19 - 10 + 91
+        assert_eq!(Stock::next_quantity(19, 10).unwrap(), 100);
+        // Ordering more than is on hand restocks rather than underflowing
+        assert_eq!(Stock::next_quantity(3, 10).unwrap(), 84);
+        assert_eq!(Stock::next_quantity(0, 1).unwrap(), 90);
+    }
+
+    #[test]
+    fn stock_stays_in_range_across_many_orders() {
+        // Drive a stock at the bottom of the initial range down repeatedly
+        let mut quantity = 10u64;
+        for round in 0..500u64 {
+            let ordered = round % 10 + 1;
+            quantity = Stock::next_quantity(quantity, ordered).unwrap();
+            assert!(quantity >= Stock::RESTOCK_THRESHOLD, "round {round}: {quantity}");
+            assert!(quantity <= 100 + Stock::RESTOCK_AMOUNT, "round {round}: {quantity}");
+        }
+    }
+
+    #[test]
+    fn stock_quantity_overflow_is_an_error() {
+        // Ordering more than is on hand takes the restock branch, where the top-up overflows
+        assert!(Stock::next_quantity(u64::MAX - 5, u64::MAX).is_err());
+    }
+
+    #[test]
+    fn tpm_c_scales_new_orders_to_a_minute() {
+        let stats = BenchmarkStats {
+            new_order_count: 450,
+            successful_transactions: 4,
+            total_latency_us: 1_000,
+            ..Default::default()
+        };
+        assert_eq!(stats.compute_tpm_c(60), 450);
+        assert_eq!(stats.compute_tpm_c(30), 900);
+        // Zero-length runs are treated as one second instead of dividing by zero
+        assert_eq!(stats.compute_tpm_c(0), 27_000);
+        assert_eq!(stats.avg_success_latency_us(), 250);
+        assert_eq!(BenchmarkStats::default().avg_success_latency_us(), 0);
+    }
+
+    #[test]
+    fn latency_bucket_uses_exclusive_upper_edges() {
+        assert_eq!(latency_bucket(0), 0);
+        assert_eq!(latency_bucket(99), 0);
+        assert_eq!(latency_bucket(100), 1);
+        assert_eq!(latency_bucket(999_999), 8);
+        assert_eq!(latency_bucket(1_000_000), 9);
+        assert_eq!(latency_bucket(u64::MAX), 9);
+    }
+
+    #[test]
+    fn percentiles_map_cumulative_counts_to_bucket_edges() {
+        // 50 samples <100us, 40 in <1ms, 9 in <10ms, 1 in >=1s
+        let mut histogram = [0u64; LATENCY_BUCKETS];
+        histogram[0] = 50;
+        histogram[2] = 40;
+        histogram[4] = 9;
+        histogram[9] = 1;
+        let p = LatencyPercentiles::from_histogram(&histogram, 2_500_000);
+        assert_eq!(p.samples, 100);
+        assert_eq!(p.p50_latency_us, 100);
+        assert_eq!(p.p90_latency_us, 1_000);
+        assert_eq!(p.p99_latency_us, 10_000);
+        // The open-ended bucket reports the observed max
+        assert_eq!(histogram_percentile_us(&histogram, 100, 2_500_000), 2_500_000);
+    }
+
+    #[test]
+    fn percentiles_are_capped_by_max_and_zero_when_empty() {
+        let mut histogram = [0u64; LATENCY_BUCKETS];
+        assert_eq!(LatencyPercentiles::from_histogram(&histogram, 0), LatencyPercentiles::default());
+
+        histogram[3] = 10; // all in [1ms, 5ms)
+        assert_eq!(histogram_percentile_us(&histogram, 99, 1_200), 1_200);
+        assert_eq!(histogram_percentile_us(&histogram, 99, 0), 5_000);
+    }
+
+    #[test]
+    fn stats_and_transaction_metrics_share_percentiles() {
+        let mut stats = BenchmarkStats { max_latency_us: 40_000, ..Default::default() };
+        stats.latency_histogram[latency_bucket(40_000)] = 3;
+        let metrics = TransactionMetrics {
+            latency_max: 40_000,
+            histogram: stats.latency_histogram,
+            ..Default::default()
+        };
+        assert_eq!(stats.percentiles(), metrics.percentiles());
+        assert_eq!(stats.percentiles().p50_latency_us, 40_000);
+    }
+}
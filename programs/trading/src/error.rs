@@ -88,4 +88,52 @@ pub enum TradingError {
     RecAccountOwnerMismatch,
     #[msg("Seller holds insufficient REC tokens to cover the energy offered")]
     InsufficientRecBalance,
+    #[msg("Clearing price deviates from the last clearing price by more than the market allows")]
+    PriceDeviationExceeded,
+    #[msg("Seller is not an Active registered user")]
+    SellerNotActive,
+    #[msg("Expire batch must hold 1..=MAX_EXPIRE_BATCH writable order accounts")]
+    InvalidExpireBatch,
+    #[msg("Order TTL must be between 60 seconds and 7 days")]
+    InvalidOrderTtl,
+    #[msg("Market is frozen: new orders and matches are blocked")]
+    MarketFrozen,
+    #[msg("Escrowed sell orders must be matched through match_orders with the escrow accounts")]
+    EscrowAccountsRequired,
+    #[msg("Order escrow account does not belong to this order")]
+    InvalidOrderEscrow,
+    #[msg("Order escrow can only be refunded once the order is cancelled, expired or completed")]
+    OrderNotRefundable,
+    #[msg("Market fee is outside the governance fee bounds")]
+    FeeOutOfGovernanceBounds,
+    #[msg("Crossing spread is below the market's minimum price improvement")]
+    InsufficientPriceImprovement,
+    #[msg("Orders backed by an ERC certificate must pass market_authority and governance_program")]
+    MissingErcReservationAccounts,
+    #[msg("Order holds no ERC reservation")]
+    NoErcReservation,
+    #[msg("Fee tiers must have strictly increasing thresholds, come first, and stay within the governance fee bounds")]
+    InvalidFeeTiers,
+    #[msg("seller_stats must be passed for distinct traders and omitted on a self-trade")]
+    InvalidSellerStats,
+    #[msg("Trader already has the market's maximum number of open orders")]
+    TooManyOpenOrders,
+    #[msg("Orders counted against the open-order cap must pass the owner's trader_stats")]
+    MissingTraderStats,
+    #[msg("Order is still open")]
+    OrderStillOpen,
+    #[msg("New market authority must be a different, non-default key")]
+    InvalidMarketAuthority,
+    #[msg("A market authority change is already pending")]
+    MarketAuthorityChangePending,
+    #[msg("No market authority change is pending")]
+    NoMarketAuthorityChangePending,
+    #[msg("Signer is not the pending market authority")]
+    InvalidPendingAuthority,
+    #[msg("Depth levels must be between 1 and MAX_DEPTH_LEVELS")]
+    InvalidDepthLevels,
+    #[msg("VWAP window cannot exceed the price history length")]
+    InvalidVwapWindow,
+    #[msg("ERC certificate is not owned by the seller")]
+    ErcOwnerMismatch,
 }
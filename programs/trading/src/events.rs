@@ -15,6 +15,8 @@ pub struct SellOrderCreated {
     pub amount: u64,
     pub price_per_kwh: u64,
     pub timestamp: i64,
+    /// Backing ERC certificate, or the default key when none was supplied
+    pub erc_certificate: Pubkey,
 }
 
 #[event]
@@ -36,6 +38,11 @@ pub struct OrderMatched {
     pub price: u64,
     pub total_value: u64,
     pub fee_amount: u64,
+    /// Fee rate behind `fee_amount`, in bps (the taker's volume tier in `match_orders`)
+    pub fee_bps: u16,
+    /// Unfilled amount left on each order after this match (`amount - filled_amount`)
+    pub buy_remaining: u64,
+    pub sell_remaining: u64,
     pub timestamp: i64,
 }
 
@@ -46,6 +53,14 @@ pub struct OrderCancelled {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct OrderExpired {
+    pub order_id: Pubkey,
+    pub user: Pubkey,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct MarketParamsUpdated {
     pub authority: Pubkey,
@@ -53,6 +68,9 @@ pub struct MarketParamsUpdated {
     pub clearing_enabled: bool,
     pub min_price_per_kwh: u64,
     pub max_price_per_kwh: u64,
+    pub max_price_deviation_bps: u16,
+    pub order_ttl_seconds: i64,
+    pub min_price_improvement_bps: u16,
     pub timestamp: i64,
 }
 
@@ -63,6 +81,46 @@ pub struct SettlementThbgMintSet {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct MarketAuthorityProposed {
+    pub market: Pubkey,
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MarketAuthorityTransferred {
+    pub market: Pubkey,
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MarketAuthorityProposalCancelled {
+    pub market: Pubkey,
+    pub authority: Pubkey,
+    pub cancelled_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MarketFrozenUpdated {
+    pub authority: Pubkey,
+    pub frozen: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MarketFeeSetByGovernance {
+    pub market: Pubkey,
+    pub authority: Pubkey,
+    pub old_fee_bps: u16,
+    pub new_fee_bps: u16,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct MaintenanceModeChanged {
     pub authority: Pubkey,
@@ -127,3 +185,34 @@ pub struct EscrowWithdrawn {
     pub amount: u64,
     pub timestamp: i64,
 }
+
+#[event]
+pub struct OrderEscrowReleased {
+    pub order: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub refunded: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeeTiersUpdated {
+    pub market: Pubkey,
+    pub authority: Pubkey,
+    pub fee_tiers: [crate::state::FeeTier; crate::state::MAX_FEE_TIERS],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MaxOpenOrdersUpdated {
+    pub authority: Pubkey,
+    pub max_open_orders_per_user: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VwapWindowUpdated {
+    pub authority: Pubkey,
+    pub vwap_window: u16,
+    pub timestamp: i64,
+}
@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use governance::program::Governance;
+use governance::ErcCertificate;
+
+use crate::error::TradingError;
+use crate::state::*;
+
+#[cfg(feature = "localnet")]
+use compute_debug::compute_fn;
+#[cfg(not(feature = "localnet"))]
+use crate::compute_fn;
+
+// ERC capacity reservations. A sell order placed against an ErcCertificate commits its
+// `energy_amount` on the certificate (`reserved_amount`, owned by governance), so the same
+// certified kWh cannot back several orders at once. Only governance can write the
+// certificate, so the change goes through `governance::reserve_erc` / `release_erc`, signed
+// by the `market_authority` PDA. Filled energy stays reserved; the unfilled residual of a
+// cancelled or expired order is handed back by `release_erc_reservation`.
+
+/// Reserve (`reserve == true`) or release `amount` kWh of `erc_certificate` via governance.
+pub fn update_erc_reservation<'info>(
+    governance_program: &Program<'info, Governance>,
+    erc_certificate: AccountInfo<'info>,
+    market_authority: AccountInfo<'info>,
+    amount: u64,
+    reserve: bool,
+) -> Result<()> {
+    let (authority_key, authority_bump) =
+        Pubkey::find_program_address(&[b"market_authority"], &crate::ID);
+    require_keys_eq!(
+        market_authority.key(),
+        authority_key,
+        TradingError::MissingErcReservationAccounts
+    );
+    let authority_seeds = &[b"market_authority".as_ref(), &[authority_bump]];
+    let signer = &[&authority_seeds[..]];
+    let cpi_ctx = CpiContext::new_with_signer(
+        governance_program.key(),
+        governance::cpi::accounts::ReserveErc {
+            erc_certificate,
+            trading_authority: market_authority,
+        },
+        signer,
+    );
+    if reserve {
+        governance::cpi::reserve_erc(cpi_ctx, amount)
+    } else {
+        governance::cpi::release_erc(cpi_ctx, amount)
+    }
+}
+
+// Permissionless: the amount released is fixed by the order and the certificate is pinned
+// to `order.erc_certificate`, so anyone (e.g. the expiry crank) may trigger it.
+#[derive(Accounts)]
+pub struct ReleaseErcReservationContext<'info> {
+    #[account(mut)]
+    pub order: AccountLoader<'info, Order>,
+
+    #[account(mut)]
+    pub erc_certificate: Box<Account<'info, ErcCertificate>>,
+
+    /// CHECK: global market authority PDA — signs the governance CPI.
+    #[account(seeds = [b"market_authority"], bump)]
+    pub market_authority: UncheckedAccount<'info>,
+
+    pub governance_program: Program<'info, Governance>,
+}
+
+pub fn release_erc_reservation(ctx: Context<ReleaseErcReservationContext>) -> Result<()> {
+    compute_fn!("release_erc_reservation" => {
+        let mut order = ctx.accounts.order.load_mut()?;
+        require!(order.erc_reserved == 1, TradingError::NoErcReservation);
+        require!(
+            order.status == OrderStatus::Cancelled as u8
+                || order.status == OrderStatus::Expired as u8,
+            TradingError::OrderNotRefundable
+        );
+        require_keys_eq!(
+            ctx.accounts.erc_certificate.key(),
+            order.erc_certificate,
+            TradingError::InvalidErcCertificate
+        );
+
+        let residual = order.amount.saturating_sub(order.filled_amount);
+        order.erc_reserved = 0;
+        if residual > 0 {
+            update_erc_reservation(
+                &ctx.accounts.governance_program,
+                ctx.accounts.erc_certificate.to_account_info(),
+                ctx.accounts.market_authority.to_account_info(),
+                residual,
+                false,
+            )?;
+        }
+    });
+    Ok(())
+}
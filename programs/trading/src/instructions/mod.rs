@@ -1,5 +1,8 @@
 pub mod settle_offchain;
 pub mod escrow;
+pub mod order_escrow;
+pub mod erc_reservation;
+pub mod open_orders;
 pub mod initialize_shard;
 pub mod initialize_zone_shard;
 pub mod submit_sharded_limit_order;
@@ -7,6 +10,9 @@ pub mod sharded_match_orders;
 
 pub use settle_offchain::*;
 pub use escrow::*;
+pub use order_escrow::*;
+pub use erc_reservation::*;
+pub use open_orders::*;
 pub use initialize_shard::*;
 pub use initialize_zone_shard::*;
 pub use submit_sharded_limit_order::*;
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::error::TradingError;
+use crate::state::*;
+
+#[cfg(feature = "localnet")]
+use compute_debug::compute_fn;
+#[cfg(not(feature = "localnet"))]
+use crate::compute_fn;
+
+// Open-order slots. Creating an order takes a slot on the owner's TraderStats
+// (`max_open_orders_per_user`); `cancel_order` and a filling `match_orders` hand it back
+// in-line. Paths that close orders without the owner's stats in hand (expiry, sharded and
+// atomic settlement) leave the slot taken, and `release_open_order` returns it afterwards.
+
+// Permissionless: the stats account is pinned to the order's owner and only a terminal
+// order can give up its slot, so anyone (e.g. the expiry crank) may trigger it.
+#[derive(Accounts)]
+pub struct ReleaseOpenOrderContext<'info> {
+    #[account(mut)]
+    pub order: AccountLoader<'info, Order>,
+
+    #[account(
+        mut,
+        seeds = [b"trader".as_ref(), &order.load()?.owner().to_bytes()],
+        bump
+    )]
+    pub trader_stats: Box<Account<'info, TraderStats>>,
+}
+
+pub fn release_open_order(ctx: Context<ReleaseOpenOrderContext>) -> Result<()> {
+    compute_fn!("release_open_order" => {
+        let mut order = ctx.accounts.order.load_mut()?;
+        require!(
+            order.status != OrderStatus::Active as u8
+                && order.status != OrderStatus::PartiallyFilled as u8,
+            TradingError::OrderStillOpen
+        );
+        ctx.accounts.trader_stats.close_order(&mut order);
+    });
+    Ok(())
+}
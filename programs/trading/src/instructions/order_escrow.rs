@@ -0,0 +1,334 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+    TransferChecked,
+};
+
+use crate::error::TradingError;
+use crate::events::{OrderEscrowReleased, SellOrderCreated};
+use crate::state::*;
+use crate::utils::get_governance_config;
+
+#[cfg(feature = "localnet")]
+use compute_debug::compute_fn;
+#[cfg(not(feature = "localnet"))]
+use crate::compute_fn;
+
+// Escrowed sell orders. At creation the seller's `energy_amount` is moved into a per-order
+// token account, seeds=[b"order_escrow", order], whose SPL authority is the global
+// `market_authority` PDA (same authority as the per-user escrows in escrow.rs). A match
+// through `match_orders` releases the filled portion to the buyer; once the order is
+// cancelled or expired, `refund_order_escrow` returns what is left and closes the account.
+// Other fill paths (sharded_match_orders, execute_atomic_settlement) refuse escrowed sells,
+// so escrowed tokens only ever leave through these two routes.
+
+/// Seed prefix of the per-order energy escrow token account.
+pub const ORDER_ESCROW_SEED: &[u8] = b"order_escrow";
+
+#[derive(Accounts)]
+#[instruction(order_id_val: u64)]
+pub struct CreateSellOrderEscrowedContext<'info> {
+    pub market: AccountLoader<'info, Market>,
+    #[account(mut, constraint = zone_market.load()?.market == market.key())]
+    pub zone_market: AccountLoader<'info, ZoneMarket>,
+    #[account(init, payer = authority, space = 8 + std::mem::size_of::<Order>(), seeds = [b"order", authority.key().as_ref(), &order_id_val.to_le_bytes()], bump)]
+    pub order: AccountLoader<'info, Order>,
+
+    pub energy_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = energy_mint,
+        token::authority = authority,
+        token::token_program = token_program,
+    )]
+    pub seller_energy_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = authority,
+        seeds = [b"order_escrow", order.key().as_ref()],
+        bump,
+        token::mint = energy_mint,
+        token::authority = market_authority,
+        token::token_program = token_program,
+    )]
+    pub order_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: global escrow authority PDA — only its key is used.
+    #[account(seeds = [b"market_authority"], bump)]
+    pub market_authority: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+    /// CHECK: Manual deserialization to handle length mismatch in localnet
+    pub governance_config: UncheckedAccount<'info>,
+    /// Owner's TraderStats; the order counts towards `max_open_orders_per_user`.
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = TraderStats::LEN,
+        seeds = [b"trader", authority.key().as_ref()],
+        bump
+    )]
+    pub trader_stats: Box<Account<'info, TraderStats>>,
+}
+
+pub fn create_sell_order_escrowed(
+    ctx: Context<CreateSellOrderEscrowedContext>,
+    order_id_val: u64,
+    energy_amount: u64,
+    price_per_kwh: u64,
+) -> Result<()> {
+    compute_fn!("create_sell_order_escrowed" => {
+        require!(
+            get_governance_config(&ctx.accounts.governance_config.to_account_info())?.is_operational(),
+            TradingError::MaintenanceMode
+        );
+        require!(energy_amount > 0, TradingError::InvalidAmount);
+        require!(price_per_kwh > 0, TradingError::InvalidPrice);
+
+        let (order_ttl, max_open_orders) = {
+            let market = ctx.accounts.market.load()?;
+            require!(market.frozen == 0, TradingError::MarketFrozen);
+            require!(
+                price_per_kwh >= market.min_price_per_kwh,
+                TradingError::PriceBelowMinimum
+            );
+            if market.max_price_per_kwh > 0 {
+                require!(
+                    price_per_kwh <= market.max_price_per_kwh,
+                    TradingError::PriceAboveMaximum
+                );
+            }
+            (market.order_ttl(), market.max_open_orders_per_user)
+        };
+
+        // Lock the energy first: transfer_checked fails on an insufficient balance, so an
+        // order can never rest without its tokens behind it.
+        transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.key(),
+                TransferChecked {
+                    from: ctx.accounts.seller_energy_account.to_account_info(),
+                    mint: ctx.accounts.energy_mint.to_account_info(),
+                    to: ctx.accounts.order_escrow.to_account_info(),
+                    authority: ctx.accounts.authority.to_account_info(),
+                },
+            ),
+            energy_amount,
+            ctx.accounts.energy_mint.decimals,
+        )?;
+
+        let clock = Clock::get()?;
+        let mut zone_market = ctx.accounts.zone_market.load_mut()?;
+        let mut order = ctx.accounts.order.load_init()?;
+
+        order.seller = ctx.accounts.authority.key();
+        order.buyer = Pubkey::default();
+        order.order_id = order_id_val;
+        order.amount = energy_amount;
+        order.filled_amount = 0;
+        order.price_per_kwh = price_per_kwh;
+        order.order_type = OrderType::Sell as u8;
+        order.status = OrderStatus::Active as u8;
+        order.escrowed = 1;
+        order.escrow_bump = ctx.bumps.order_escrow;
+        order.created_at = clock.unix_timestamp;
+        order.expires_at = clock.unix_timestamp + order_ttl;
+        ctx.accounts.trader_stats.open_order(ctx.accounts.authority.key(), &mut order, max_open_orders)?;
+
+        zone_market.active_orders += 1;
+        zone_market.add_depth(false, price_per_kwh, energy_amount);
+        emit!(SellOrderCreated {
+            seller: ctx.accounts.authority.key(),
+            order_id: ctx.accounts.order.key(),
+            amount: energy_amount,
+            price_per_kwh,
+            timestamp: clock.unix_timestamp,
+            erc_certificate: Pubkey::default(),
+        });
+    });
+    Ok(())
+}
+
+/// Accounts `match_orders` needs to release an escrowed sell order's fill to the buyer.
+pub struct EscrowRelease<'a, 'info> {
+    pub energy_mint: &'a InterfaceAccount<'info, Mint>,
+    pub order_escrow: &'a InterfaceAccount<'info, TokenAccount>,
+    pub buyer_energy_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub market_authority: &'a AccountInfo<'info>,
+    pub token_program: &'a Interface<'info, TokenInterface>,
+}
+
+/// Move `amount` of an escrowed sell order's energy to the buyer. The escrow must be the
+/// order's own PDA and the destination must be the buyer's account for the escrowed mint.
+pub fn release_order_escrow(
+    accounts: EscrowRelease,
+    sell_order_key: Pubkey,
+    escrow_bump: u8,
+    buyer: Pubkey,
+    amount: u64,
+) -> Result<()> {
+    let expected_escrow = Pubkey::create_program_address(
+        &[ORDER_ESCROW_SEED, sell_order_key.as_ref(), &[escrow_bump]],
+        &crate::ID,
+    )
+    .map_err(|_| error!(TradingError::InvalidOrderEscrow))?;
+    require_keys_eq!(
+        accounts.order_escrow.key(),
+        expected_escrow,
+        TradingError::InvalidOrderEscrow
+    );
+    require_keys_eq!(
+        accounts.energy_mint.key(),
+        accounts.order_escrow.mint,
+        TradingError::InvalidOrderEscrow
+    );
+    require_keys_eq!(
+        accounts.buyer_energy_account.mint,
+        accounts.order_escrow.mint,
+        TradingError::InvalidOrderEscrow
+    );
+    require_keys_eq!(
+        accounts.buyer_energy_account.owner,
+        buyer,
+        TradingError::UnauthorizedAuthority
+    );
+    require!(
+        accounts.order_escrow.amount >= amount,
+        TradingError::InsufficientEscrowBalance
+    );
+
+    let (authority_key, authority_bump) =
+        Pubkey::find_program_address(&[b"market_authority"], &crate::ID);
+    require_keys_eq!(
+        accounts.market_authority.key(),
+        authority_key,
+        TradingError::InvalidOrderEscrow
+    );
+    let authority_seeds = &[b"market_authority".as_ref(), &[authority_bump]];
+    let signer = &[&authority_seeds[..]];
+    transfer_checked(
+        CpiContext::new_with_signer(
+            accounts.token_program.key(),
+            TransferChecked {
+                from: accounts.order_escrow.to_account_info(),
+                mint: accounts.energy_mint.to_account_info(),
+                to: accounts.buyer_energy_account.to_account_info(),
+                authority: accounts.market_authority.clone(),
+            },
+            signer,
+        ),
+        amount,
+        accounts.energy_mint.decimals,
+    )?;
+
+    let now = Clock::get()?.unix_timestamp;
+    emit!(OrderEscrowReleased {
+        order: sell_order_key,
+        recipient: buyer,
+        amount,
+        refunded: false,
+        timestamp: now,
+    });
+    Ok(())
+}
+
+// Permissionless: the escrow is bound to the order by seeds and the refund can only land
+// in the seller's own account, so anyone (e.g. the expiry crank) may trigger it.
+#[derive(Accounts)]
+pub struct RefundOrderEscrowContext<'info> {
+    #[account(mut)]
+    pub order: AccountLoader<'info, Order>,
+
+    pub energy_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [b"order_escrow", order.key().as_ref()],
+        bump,
+        token::mint = energy_mint,
+        token::authority = market_authority,
+        token::token_program = token_program,
+    )]
+    pub order_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = energy_mint,
+        token::authority = seller,
+        token::token_program = token_program,
+    )]
+    pub seller_energy_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: receives the escrow account's rent; must be `order.seller` (checked in handler).
+    #[account(mut)]
+    pub seller: UncheckedAccount<'info>,
+
+    /// CHECK: global escrow authority PDA — signs the transfer and close CPIs.
+    #[account(seeds = [b"market_authority"], bump)]
+    pub market_authority: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn refund_order_escrow(ctx: Context<RefundOrderEscrowContext>) -> Result<()> {
+    compute_fn!("refund_order_escrow" => {
+        let mut order = ctx.accounts.order.load_mut()?;
+        require!(order.escrowed == 1, TradingError::InvalidOrderEscrow);
+        require!(
+            order.status == OrderStatus::Cancelled as u8
+                || order.status == OrderStatus::Expired as u8
+                || order.status == OrderStatus::Completed as u8,
+            TradingError::OrderNotRefundable
+        );
+        require_keys_eq!(
+            ctx.accounts.seller.key(),
+            order.seller,
+            TradingError::UnauthorizedAuthority
+        );
+
+        let authority_seeds = &[b"market_authority".as_ref(), &[ctx.bumps.market_authority]];
+        let signer = &[&authority_seeds[..]];
+        let amount = ctx.accounts.order_escrow.amount;
+        if amount > 0 {
+            transfer_checked(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.key(),
+                    TransferChecked {
+                        from: ctx.accounts.order_escrow.to_account_info(),
+                        mint: ctx.accounts.energy_mint.to_account_info(),
+                        to: ctx.accounts.seller_energy_account.to_account_info(),
+                        authority: ctx.accounts.market_authority.to_account_info(),
+                    },
+                    signer,
+                ),
+                amount,
+                ctx.accounts.energy_mint.decimals,
+            )?;
+        }
+        close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.key(),
+            CloseAccount {
+                account: ctx.accounts.order_escrow.to_account_info(),
+                destination: ctx.accounts.seller.to_account_info(),
+                authority: ctx.accounts.market_authority.to_account_info(),
+            },
+            signer,
+        ))?;
+        order.escrowed = 0;
+
+        let now = Clock::get()?.unix_timestamp;
+        emit!(OrderEscrowReleased {
+            order: ctx.accounts.order.key(),
+            recipient: order.seller,
+            amount,
+            refunded: true,
+            timestamp: now,
+        });
+    });
+    Ok(())
+}
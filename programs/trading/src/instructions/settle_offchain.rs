@@ -669,6 +669,7 @@ pub fn settle_offchain_match<'info>(
     require!(seller_payload.expires_at == 0 || clock.unix_timestamp < seller_payload.expires_at, TradingError::OrderExpired);
 
     let market = ctx.accounts.market.load()?;
+    require!(market.frozen == 0, TradingError::MarketFrozen);
     // Tier-A: zone_market READ-ONLY (capacity + zone_id reads). committed_flow lives on the
     // ZoneCapacity PDA, written only on the cross-zone path via remaining_accounts.
     let zone_market = ctx.accounts.zone_market.load()?;
@@ -885,6 +886,13 @@ pub fn settle_offchain_match<'info>(
         price: match_price,
         total_value: total_currency_value,
         fee_amount: market_fee,
+        fee_bps: market.market_fee_bps,
+        buy_remaining: buyer_payload
+            .energy_amount
+            .saturating_sub(ctx.accounts.buyer_nullifier.filled_amount),
+        sell_remaining: seller_payload
+            .energy_amount
+            .saturating_sub(ctx.accounts.seller_nullifier.filled_amount),
         timestamp: clock.unix_timestamp,
     });
     });
@@ -964,6 +972,7 @@ pub fn batch_settle_offchain_match<'info>(
 
     let clock = Clock::get()?;
     let market = ctx.accounts.market.load()?;
+    require!(market.frozen == 0, TradingError::MarketFrozen);
     // Tier-A: zone_market READ-ONLY; committed_flow on the ZoneCapacity PDA (cross-zone only).
     let zone_market = ctx.accounts.zone_market.load()?;
     let mut market_shard = ctx.accounts.market_shard.load_mut()?;
@@ -1199,6 +1208,15 @@ pub fn batch_settle_offchain_match<'info>(
             price: m.match_price,
             total_value,
             fee_amount: market_fee,
+            fee_bps: market.market_fee_bps,
+            buy_remaining: m
+                .buyer_payload
+                .energy_amount
+                .saturating_sub(buyer_nullifier.filled_amount),
+            sell_remaining: m
+                .seller_payload
+                .energy_amount
+                .saturating_sub(seller_nullifier.filled_amount),
             timestamp: clock.unix_timestamp,
         });
     }
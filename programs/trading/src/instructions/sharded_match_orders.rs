@@ -15,6 +15,11 @@ pub fn sharded_match_orders(ctx: Context<ShardedMatchOrdersContext>, match_amoun
         crate::error::TradingError::MaintenanceMode
     );
 
+    let trade_seq = {
+        let mut market = ctx.accounts.market.load_mut()?;
+        require!(market.frozen == 0, crate::error::TradingError::MarketFrozen);
+        market.take_trade_seq()
+    };
     let mut buy_order = ctx.accounts.buy_order.load_mut()?;
     let mut sell_order = ctx.accounts.sell_order.load_mut()?;
     let mut zone_shard = ctx.accounts.zone_shard.load_mut()?;
@@ -36,6 +41,11 @@ pub fn sharded_match_orders(ctx: Context<ShardedMatchOrdersContext>, match_amoun
         buy_order.price_per_kwh >= sell_order.price_per_kwh,
         crate::error::TradingError::PriceMismatch
     );
+    // Escrowed energy can only be released by match_orders.
+    require!(
+        sell_order.escrowed == 0,
+        crate::error::TradingError::EscrowAccountsRequired
+    );
 
     let clearing_price = sell_order.price_per_kwh;
     let buy_remaining = buy_order.amount.saturating_sub(buy_order.filled_amount);
@@ -68,6 +78,7 @@ pub fn sharded_match_orders(ctx: Context<ShardedMatchOrdersContext>, match_amoun
     trade_record.amount = actual_match_amount;
     trade_record.price_per_kwh = clearing_price;
     trade_record.executed_at = clock.unix_timestamp;
+    trade_record.trade_seq = trade_seq;
 
     emit!(crate::events::OrderMatched {
         buy_order: ctx.accounts.buy_order.key(),
@@ -78,6 +89,9 @@ pub fn sharded_match_orders(ctx: Context<ShardedMatchOrdersContext>, match_amoun
         price: clearing_price,
         total_value: actual_match_amount.saturating_mul(clearing_price),
         fee_amount: 0,
+        fee_bps: 0,
+        buy_remaining: buy_order.amount.saturating_sub(buy_order.filled_amount),
+        sell_remaining: sell_order.amount.saturating_sub(sell_order.filled_amount),
         timestamp: clock.unix_timestamp,
     });
     });
@@ -16,6 +16,10 @@ pub fn submit_limit_order_sharded(
     _shard_id: u8,
 ) -> Result<()> {
     compute_fn!("submit_limit_order_sharded" => {
+    require!(
+        ctx.accounts.market.load()?.frozen == 0,
+        crate::error::TradingError::MarketFrozen
+    );
     let clock = Clock::get()?;
     let mut order = ctx.accounts.order.load_init()?;
     let mut zone_shard = ctx.accounts.zone_shard.load_mut()?;
@@ -30,7 +34,9 @@ pub fn submit_limit_order_sharded(
     order.order_type = order_type as u8;
     order.status = OrderStatus::Active as u8;
     order.created_at = clock.unix_timestamp;
-    order.expires_at = clock.unix_timestamp + 86400;
+    // The sharded path only reads the global Market's freeze flag (never writes it — that
+    // is the point of sharding), so it keeps the default TTL rather than Market::order_ttl_seconds.
+    order.expires_at = clock.unix_timestamp + DEFAULT_ORDER_TTL_SECONDS;
     
     if side == 0 {
         order.buyer = ctx.accounts.authority.key();
@@ -11,12 +11,19 @@ pub use crate::error::TradingError;
 #[allow(ambiguous_glob_reexports)]
 pub use crate::instructions::*;
 pub use crate::state::{
-    BatchConfig, BatchInfo, Market, MarketShard, Order, OrderNullifier, OrderStatus, OrderType,
-    PriceLevel, PricePoint, TradeNullifier, TradeRecord, ZoneCapacity, ZoneMarket, ZoneMarketShard,
-    ZoneConfig, MAX_DEPTH_LEVELS,
+    fee_tiers_well_formed, BatchConfig, BatchInfo, FeeTier, Market, MarketShard, MarketStats, Order,
+    OrderBookSnapshot, OrderNullifier, OrderStatus, OrderType, PriceLevel, PricePoint, TradeNullifier,
+    TradeRecord, TraderStats, ZoneCapacity, ZoneMarket, ZoneMarketShard, ZoneConfig,
+    DEFAULT_ORDER_TTL_SECONDS, MAX_DEPTH_LEVELS, MAX_FEE_TIERS, MAX_ORDER_TTL_SECONDS,
+    MIN_ORDER_TTL_SECONDS, PRICE_HISTORY_LEN,
+};
+pub use crate::utils::{
+    exceeds_price_deviation, get_governance_config,
+    insufficient_price_improvement, saturating_trade_value,
+    sort_orders_for_matching, volume_weighted_price, OrderRef,
 };
-pub use crate::utils::get_governance_config;
 pub use governance::{ErcCertificate, ErcStatus, GovernanceConfig};
+pub use registry::{UserAccount, UserStatus};
 
 /// Divisor that normalizes an energy `amount` (9-decimal atomic, kWh * 1e9)
 /// multiplied by a 6-decimal currency `price` back down to 6-decimal currency
@@ -25,6 +32,9 @@ pub use governance::{ErcCertificate, ErcStatus, GovernanceConfig};
 /// paid the true trade value, not 1e9x it.
 pub const ENERGY_AMOUNT_DECIMALS_DIVISOR: u128 = 1_000_000_000;
 
+/// Maximum number of orders `expire_orders_batch` processes in one call
+pub const MAX_EXPIRE_BATCH: usize = 16;
+
 // ============================================================================
 // AUCTION CLEARING TYPES (Inlined to avoid Anchor macro issues)
 // ============================================================================
@@ -130,8 +140,10 @@ pub mod trading {
         market.created_at = clock.unix_timestamp;
         market.clearing_enabled = 1;
         market.market_fee_bps = 25;
+        market.max_price_deviation_bps = 0; // circuit breaker off until configured
         market.min_price_per_kwh = 1;
         market.max_price_per_kwh = 0;
+        market.order_ttl_seconds = DEFAULT_ORDER_TTL_SECONDS;
         market.num_shards = num_shards;
 
         market.batch_config = BatchConfig {
@@ -163,8 +175,13 @@ pub mod trading {
         zone_id: u32,
         num_shards: u8,
         capacity: u64,
+        max_depth_levels: u8,
     ) -> Result<()> {
         compute_fn!("initialize_zone_market" => {
+        require!(
+            max_depth_levels >= 1 && max_depth_levels as usize <= MAX_DEPTH_LEVELS,
+            TradingError::InvalidDepthLevels
+        );
         let mut zone_market = ctx.accounts.zone_market.load_init()?;
         zone_market.market = ctx.accounts.market.key();
         zone_market.zone_id = zone_id;
@@ -175,6 +192,7 @@ pub mod trading {
         zone_market.active_orders = 0;
         zone_market.buy_side_depth_count = 0;
         zone_market.sell_side_depth_count = 0;
+        zone_market.max_depth_levels = max_depth_levels;
 
         // Zero out the arrays
         zone_market.buy_side_depth = [PriceLevel::default(); MAX_DEPTH_LEVELS];
@@ -218,8 +236,9 @@ pub mod trading {
         require!(energy_amount > 0, TradingError::InvalidAmount);
         require!(price_per_kwh > 0, TradingError::InvalidPrice);
 
-        {
+        let (order_ttl, max_open_orders) = {
             let market_ref = ctx.accounts.market.load()?;
+            require!(market_ref.frozen == 0, TradingError::MarketFrozen);
             require!(
                 price_per_kwh >= market_ref.min_price_per_kwh,
                 TradingError::PriceBelowMinimum
@@ -230,13 +249,21 @@ pub mod trading {
                     TradingError::PriceAboveMaximum
                 );
             }
-        }
+            (market_ref.order_ttl(), market_ref.max_open_orders_per_user)
+        };
 
         // Single Clock::get() syscall hoisted before the ERC block — avoids a second
         // syscall when an ERC certificate is present (previously called twice).
         let clock = Clock::get()?;
 
         if let Some(erc) = &ctx.accounts.erc_certificate {
+            // Only the holder may commit the certificate's energy; otherwise anyone could
+            // reserve (and lock up) another seller's certificate.
+            require_keys_eq!(
+                erc.owner,
+                ctx.accounts.authority.key(),
+                TradingError::ErcOwnerMismatch
+            );
             require!(
                 erc.status == ErcStatus::Valid,
                 TradingError::InvalidErcCertificate
@@ -254,6 +281,21 @@ pub mod trading {
             );
         }
 
+        // Registry standing gate (opt-in via `user_account`): a suspended or inactive seller
+        // cannot keep listing energy on the strength of a stale certificate.
+        if let Some(user_account) = &ctx.accounts.user_account {
+            let user = user_account.load()?;
+            require_keys_eq!(
+                user.authority,
+                ctx.accounts.authority.key(),
+                TradingError::UnauthorizedAuthority
+            );
+            require!(
+                user.status == UserStatus::Active,
+                TradingError::SellerNotActive
+            );
+        }
+
         // Fungible REC provenance gate (opt-in via remaining_accounts[0]): when the seller
         // appends their REC token account, require it to be the real governance rec_mint,
         // owned by the seller, holding at least `energy_amount * 1_000` base units (REC mint
@@ -280,6 +322,26 @@ pub mod trading {
             );
         }
 
+        // Commit the order's energy on the certificate. Governance enforces that the
+        // reservations across all orders stay within the certified amount (ErcOverCommitted).
+        let erc_certificate = match &ctx.accounts.erc_certificate {
+            Some(erc) => {
+                let market_authority = ctx.accounts.market_authority.as_ref()
+                    .ok_or(TradingError::MissingErcReservationAccounts)?;
+                let governance_program = ctx.accounts.governance_program.as_ref()
+                    .ok_or(TradingError::MissingErcReservationAccounts)?;
+                instructions::update_erc_reservation(
+                    governance_program,
+                    erc.to_account_info(),
+                    market_authority.to_account_info(),
+                    energy_amount,
+                    true,
+                )?;
+                erc.key()
+            }
+            None => Pubkey::default(),
+        };
+
         // No redundant market load — price bounds already checked above.
         let mut zone_market = ctx.accounts.zone_market.load_mut()?;
         let mut order = ctx.accounts.order.load_init()?;
@@ -293,15 +355,20 @@ pub mod trading {
         order.order_type = OrderType::Sell as u8;
         order.status = OrderStatus::Active as u8;
         order.created_at = clock.unix_timestamp;
-        order.expires_at = clock.unix_timestamp + 86400;
+        order.expires_at = clock.unix_timestamp + order_ttl;
+        order.erc_certificate = erc_certificate;
+        order.erc_reserved = (erc_certificate != Pubkey::default()) as u8;
+        ctx.accounts.trader_stats.open_order(ctx.accounts.authority.key(), &mut order, max_open_orders)?;
 
         zone_market.active_orders += 1;
+        zone_market.add_depth(false, price_per_kwh, energy_amount);
         emit!(crate::events::SellOrderCreated {
             seller: ctx.accounts.authority.key(),
             order_id: ctx.accounts.order.key(),
             amount: energy_amount,
             price_per_kwh,
             timestamp: clock.unix_timestamp,
+            erc_certificate,
         });
         });
         Ok(())
@@ -321,8 +388,9 @@ pub mod trading {
         require!(energy_amount > 0, TradingError::InvalidAmount);
         require!(max_price_per_kwh > 0, TradingError::InvalidPrice);
 
-        {
+        let (order_ttl, max_open_orders) = {
             let market_ref = ctx.accounts.market.load()?;
+            require!(market_ref.frozen == 0, TradingError::MarketFrozen);
             require!(
                 max_price_per_kwh >= market_ref.min_price_per_kwh,
                 TradingError::PriceBelowMinimum
@@ -333,7 +401,8 @@ pub mod trading {
                     TradingError::PriceAboveMaximum
                 );
             }
-        }
+            (market_ref.order_ttl(), market_ref.max_open_orders_per_user)
+        };
 
         // No redundant market load — price bounds already checked above.
         let mut zone_market = ctx.accounts.zone_market.load_mut()?;
@@ -349,9 +418,11 @@ pub mod trading {
         order.order_type = OrderType::Buy as u8;
         order.status = OrderStatus::Active as u8;
         order.created_at = clock.unix_timestamp;
-        order.expires_at = clock.unix_timestamp + 86400;
+        order.expires_at = clock.unix_timestamp + order_ttl;
+        ctx.accounts.trader_stats.open_order(ctx.accounts.authority.key(), &mut order, max_open_orders)?;
 
         zone_market.active_orders += 1;
+        zone_market.add_depth(true, max_price_per_kwh, energy_amount);
         emit!(crate::events::BuyOrderCreated {
             buyer: ctx.accounts.authority.key(),
             order_id: ctx.accounts.order.key(),
@@ -384,8 +455,9 @@ pub mod trading {
         require!(energy_amount > 0, TradingError::InvalidAmount);
         require!(price_per_kwh > 0, TradingError::InvalidPrice);
 
-        {
+        let (order_ttl, max_open_orders) = {
             let market_ref = ctx.accounts.market.load()?;
+            require!(market_ref.frozen == 0, TradingError::MarketFrozen);
             require!(
                 price_per_kwh >= market_ref.min_price_per_kwh,
                 TradingError::PriceBelowMinimum
@@ -396,7 +468,8 @@ pub mod trading {
                     TradingError::PriceAboveMaximum
                 );
             }
-        }
+            (market_ref.order_ttl(), market_ref.max_open_orders_per_user)
+        };
 
         let mut zone_market = ctx.accounts.zone_market.load_mut()?;
         let mut order = ctx.accounts.order.load_init()?;
@@ -417,9 +490,11 @@ pub mod trading {
         order.price_per_kwh = price_per_kwh;
         order.status = OrderStatus::Active as u8;
         order.created_at = clock.unix_timestamp;
-        order.expires_at = clock.unix_timestamp + 86400;
+        order.expires_at = clock.unix_timestamp + order_ttl;
+        ctx.accounts.trader_stats.open_order(user, &mut order, max_open_orders)?;
 
         zone_market.active_orders += 1;
+        zone_market.add_depth(is_buy, price_per_kwh, energy_amount);
         });
         Ok(())
     }
@@ -432,6 +507,17 @@ pub mod trading {
         );
         require!(match_amount > 0, TradingError::InvalidAmount);
 
+        let (reference_price, max_deviation_bps, min_improvement_bps, trade_seq) = {
+            let mut market = ctx.accounts.market.load_mut()?;
+            require!(market.frozen == 0, TradingError::MarketFrozen);
+            (
+                market.last_clearing_price,
+                market.max_price_deviation_bps,
+                market.min_price_improvement_bps,
+                market.take_trade_seq(),
+            )
+        };
+
         let mut zone_market = ctx.accounts.zone_market.load_mut()?;
         let mut buy_order = ctx.accounts.buy_order.load_mut()?;
         let mut sell_order = ctx.accounts.sell_order.load_mut()?;
@@ -452,13 +538,43 @@ pub mod trading {
             buy_order.price_per_kwh >= sell_order.price_per_kwh,
             TradingError::PriceMismatch
         );
+        require!(
+            !insufficient_price_improvement(
+                buy_order.price_per_kwh,
+                sell_order.price_per_kwh,
+                min_improvement_bps,
+            ),
+            TradingError::InsufficientPriceImprovement
+        );
 
         let buy_remaining = buy_order.amount.saturating_sub(buy_order.filled_amount);
         let sell_remaining = sell_order.amount.saturating_sub(sell_order.filled_amount);
         let actual_match_amount = match_amount.min(buy_remaining).min(sell_remaining);
 
         let clearing_price = sell_order.price_per_kwh;
-        let total_value = actual_match_amount.saturating_mul(clearing_price);
+        // Circuit breaker: a fat-fingered order must not print far from the prevailing
+        // price (skipped on the first trade, when last_clearing_price is still 0).
+        require!(
+            !exceeds_price_deviation(reference_price, clearing_price, max_deviation_bps),
+            TradingError::PriceDeviationExceeded
+        );
+        let total_value = saturating_trade_value(actual_match_amount, clearing_price);
+
+        // A self-trade has one TraderStats account, passed as buyer_stats only: a second
+        // copy of the same account would overwrite the first one's updates on exit.
+        require!(
+            ctx.accounts.seller_stats.is_some() != (buy_order.buyer == sell_order.seller),
+            TradingError::InvalidSellerStats
+        );
+        // The taker is the later of the two orders (ties go to the buyer); its volume
+        // before this fill picks the market's fee tier.
+        let taker_volume = match ctx.accounts.seller_stats.as_ref() {
+            Some(stats) if sell_order.created_at > buy_order.created_at => stats.total_volume,
+            _ => ctx.accounts.buyer_stats.total_volume,
+        };
+        let fee_bps = ctx.accounts.market.load()?.fee_bps_for_volume(taker_volume);
+        let fee_amount = u64::try_from(total_value as u128 * fee_bps as u128 / 10_000)
+            .map_err(|_| TradingError::Overflow)?;
 
         buy_order.filled_amount += actual_match_amount;
         sell_order.filled_amount += actual_match_amount;
@@ -477,6 +593,20 @@ pub mod trading {
             sell_order.status = OrderStatus::PartiallyFilled as u8;
         }
 
+        // Depth tracks only unfilled quantity: take the exact fill off each order's level.
+        zone_market.reduce_depth(
+            true,
+            buy_order.price_per_kwh,
+            actual_match_amount,
+            buy_order.status == OrderStatus::Completed as u8,
+        );
+        zone_market.reduce_depth(
+            false,
+            sell_order.price_per_kwh,
+            actual_match_amount,
+            sell_order.status == OrderStatus::Completed as u8,
+        );
+
         trade_record.sell_order = ctx.accounts.sell_order.key();
         trade_record.buy_order = ctx.accounts.buy_order.key();
         trade_record.seller = sell_order.seller;
@@ -484,12 +614,51 @@ pub mod trading {
         trade_record.amount = actual_match_amount;
         trade_record.price_per_kwh = clearing_price;
         trade_record.total_value = total_value;
-        trade_record.fee_amount = 0;
+        trade_record.fee_amount = fee_amount;
         trade_record.executed_at = clock.unix_timestamp;
+        trade_record.trade_seq = trade_seq;
+
+        ctx.accounts
+            .buyer_stats
+            .record(buy_order.buyer, actual_match_amount, clock.unix_timestamp);
+        if let Some(stats) = ctx.accounts.seller_stats.as_mut() {
+            stats.record(sell_order.seller, actual_match_amount, clock.unix_timestamp);
+        }
+        // A filled order no longer counts towards its owner's open-order cap.
+        if buy_order.status == OrderStatus::Completed as u8 {
+            ctx.accounts.buyer_stats.close_order(&mut buy_order);
+        }
+        if sell_order.status == OrderStatus::Completed as u8 {
+            match ctx.accounts.seller_stats.as_mut() {
+                Some(stats) => stats.close_order(&mut sell_order),
+                None => ctx.accounts.buyer_stats.close_order(&mut sell_order),
+            }
+        }
+
+        // Escrowed sell: hand the filled energy to the buyer out of the order's escrow.
+        if sell_order.escrowed == 1 && actual_match_amount > 0 {
+            let accounts = &ctx.accounts;
+            instructions::release_order_escrow(
+                instructions::EscrowRelease {
+                    energy_mint: accounts.energy_mint.as_ref().ok_or(TradingError::EscrowAccountsRequired)?,
+                    order_escrow: accounts.sell_order_escrow.as_ref().ok_or(TradingError::EscrowAccountsRequired)?,
+                    buyer_energy_account: accounts.buyer_energy_account.as_ref().ok_or(TradingError::EscrowAccountsRequired)?,
+                    market_authority: &accounts.market_authority.as_ref().ok_or(TradingError::EscrowAccountsRequired)?.to_account_info(),
+                    token_program: accounts.token_program.as_ref().ok_or(TradingError::EscrowAccountsRequired)?,
+                },
+                accounts.sell_order.key(),
+                sell_order.escrow_bump,
+                buy_order.buyer,
+                actual_match_amount,
+            )?;
+        }
 
         zone_market.total_volume = zone_market.total_volume.saturating_add(actual_match_amount);
         zone_market.total_trades = zone_market.total_trades.saturating_add(1);
         zone_market.last_clearing_price = clearing_price;
+        // The circuit breaker's reference price: advance it on every fill, or it stays 0 and
+        // the breaker never arms.
+        ctx.accounts.market.load_mut()?.last_clearing_price = clearing_price;
 
         emit!(crate::events::OrderMatched {
             sell_order: ctx.accounts.sell_order.key(),
@@ -499,7 +668,10 @@ pub mod trading {
             amount: actual_match_amount,
             price: clearing_price,
             total_value,
-            fee_amount: 0,
+            fee_amount,
+            fee_bps,
+            buy_remaining: buy_order.amount.saturating_sub(buy_order.filled_amount),
+            sell_remaining: sell_order.amount.saturating_sub(sell_order.filled_amount),
             timestamp: clock.unix_timestamp,
         });
         });
@@ -542,7 +714,20 @@ pub mod trading {
         );
 
         order.status = OrderStatus::Cancelled as u8;
+        if order.open_counted == 1 {
+            ctx.accounts
+                .trader_stats
+                .as_mut()
+                .ok_or(TradingError::MissingTraderStats)?
+                .close_order(&mut order);
+        }
         zone_market.active_orders = zone_market.active_orders.saturating_sub(1);
+        zone_market.reduce_depth(
+            order.order_type == OrderType::Buy as u8,
+            order.price_per_kwh,
+            order.amount.saturating_sub(order.filled_amount),
+            true,
+        );
 
         emit!(crate::events::OrderCancelled {
             order_id: ctx.accounts.order.key(),
@@ -553,6 +738,108 @@ pub mod trading {
         Ok(())
     }
 
+    /// Keeper sweep: flip stale orders to `Expired`.
+    ///
+    /// Orders arrive as `remaining_accounts` (all `mut`, at most `MAX_EXPIRE_BATCH`). An order
+    /// that is `Active`/`PartiallyFilled` and at or past `expires_at` is expired exactly like a
+    /// cancel: the zone's `active_orders` drops by one and its unfilled residual leaves the
+    /// depth snapshot. Anything else (not yet expired, already closed) is skipped, so one
+    /// early order does not sink the sweep. Restricted to the market authority because an
+    /// `Order` does not record its zone; an open caller could pair orders with the wrong
+    /// `zone_market` and drain that zone's counters.
+    pub fn expire_orders_batch<'info>(ctx: Context<'info, ExpireOrdersBatchContext<'info>>) -> Result<u32> {
+        compute_fn!("expire_orders_batch" => {
+        require!(
+            !ctx.remaining_accounts.is_empty() && ctx.remaining_accounts.len() <= MAX_EXPIRE_BATCH,
+            TradingError::InvalidExpireBatch
+        );
+        require_keys_eq!(
+            ctx.accounts.authority.key(),
+            ctx.accounts.market.load()?.authority,
+            TradingError::UnauthorizedAuthority
+        );
+        let mut zone_market = ctx.accounts.zone_market.load_mut()?;
+        let now = Clock::get()?.unix_timestamp;
+        let mut expired = 0u32;
+
+        for info in ctx.remaining_accounts.iter() {
+            require!(info.is_writable, TradingError::InvalidExpireBatch);
+            let loader = AccountLoader::<Order>::try_from(info)?;
+            let mut order = loader.load_mut()?;
+            let open = order.status == OrderStatus::Active as u8
+                || order.status == OrderStatus::PartiallyFilled as u8;
+            if !open || order.expires_at <= 0 || now < order.expires_at {
+                continue;
+            }
+
+            order.status = OrderStatus::Expired as u8;
+            zone_market.active_orders = zone_market.active_orders.saturating_sub(1);
+            zone_market.reduce_depth(
+                order.order_type == OrderType::Buy as u8,
+                order.price_per_kwh,
+                order.amount.saturating_sub(order.filled_amount),
+                true,
+            );
+            expired += 1;
+
+            emit!(crate::events::OrderExpired {
+                order_id: info.key(),
+                user: if order.order_type == OrderType::Buy as u8 { order.buyer } else { order.seller },
+                expires_at: order.expires_at,
+                timestamp: now,
+            });
+        }
+        Ok(expired)
+        })
+    }
+
+    /// View: market counters and prices plus one zone's summed bid/ask depth
+    pub fn get_market_stats(ctx: Context<GetMarketStats>) -> Result<MarketStats> {
+        let res = compute_fn!("get_market_stats" => {
+            let market = ctx.accounts.market.load()?;
+            let zone_market = ctx.accounts.zone_market.load()?;
+            MarketStats {
+                active_orders: market.active_orders,
+                total_volume: market.total_volume,
+                total_trades: market.total_trades,
+                last_clearing_price: market.last_clearing_price,
+                volume_weighted_price: market.volume_weighted_price,
+                zone_id: zone_market.zone_id,
+                zone_active_orders: zone_market.active_orders,
+                buy_side_depth: zone_market.total_depth(true),
+                sell_side_depth: zone_market.total_depth(false),
+            }
+        });
+        Ok(res)
+    }
+
+    /// View: both sides of one zone's depth snapshot and its last clearing price
+    pub fn get_order_book(ctx: Context<GetOrderBook>) -> Result<OrderBookSnapshot> {
+        let res = compute_fn!("get_order_book" => {
+            let zone_market = ctx.accounts.zone_market.load()?;
+            let bids = (zone_market.buy_side_depth_count as usize).min(MAX_DEPTH_LEVELS);
+            let asks = (zone_market.sell_side_depth_count as usize).min(MAX_DEPTH_LEVELS);
+            let now = Clock::get()?.unix_timestamp;
+            OrderBookSnapshot {
+                bids: zone_market.buy_side_depth[..bids].to_vec(),
+                asks: zone_market.sell_side_depth[..asks].to_vec(),
+                last_clearing_price: zone_market.last_clearing_price,
+                captured_at: now,
+            }
+        });
+        Ok(res)
+    }
+
+    /// View: `count` price points from `start` (0 = oldest retained), oldest first, clamped
+    /// to the valid history
+    pub fn get_price_history(ctx: Context<GetPriceHistory>, start: u16, count: u16) -> Result<Vec<PricePoint>> {
+        let res = compute_fn!("get_price_history" => {
+            let market = ctx.accounts.market.load()?;
+            market.price_history_window(start, count)
+        });
+        Ok(res)
+    }
+
     pub fn batch_settle_offchain_match<'info>(
         ctx: Context<'info, SettleOffchainMatchBatchContext<'info>>,
         matches: Vec<BatchMatchPair>,
@@ -592,6 +879,7 @@ pub mod trading {
 
         let clock = Clock::get()?;
         let mut market = ctx.accounts.market.load_mut()?;
+        require!(market.frozen == 0, TradingError::MarketFrozen);
 
         // Price bounds check
         require!(
@@ -627,7 +915,7 @@ pub mod trading {
         order.order_type = order_type as u8;
         order.status = OrderStatus::Active as u8;
         order.created_at = clock.unix_timestamp;
-        order.expires_at = clock.unix_timestamp + 86400;
+        order.expires_at = clock.unix_timestamp + market.order_ttl();
 
         market.active_orders += 1;
 
@@ -653,6 +941,7 @@ pub mod trading {
                 amount,
                 price_per_kwh: price,
                 timestamp: clock.unix_timestamp,
+                erc_certificate: Pubkey::default(),
             });
         }
 
@@ -692,6 +981,10 @@ pub mod trading {
             TradingError::MaintenanceMode
         );
         require!(amount > 0, TradingError::InvalidAmount);
+        require!(
+            ctx.accounts.market.load()?.frozen == 0,
+            TradingError::MarketFrozen
+        );
 
         let clock = Clock::get()?;
         let zone_market = ctx.accounts.zone_market.load()?;
@@ -739,15 +1032,16 @@ pub mod trading {
         );
 
         let mut zone_market = ctx.accounts.zone_market.load_mut()?;
+        let depth_levels = zone_market.depth_levels();
 
-        // Validate input lengths — capped at MAX_DEPTH_LEVELS to stay within
-        // Solana's 1,232-byte transaction size limit for Vec payload
+        // Validate input lengths — capped at the zone's depth_levels() (at most
+        // MAX_DEPTH_LEVELS, to stay within Solana's 1,232-byte transaction size limit)
         require!(
-            buy_prices.len() <= MAX_DEPTH_LEVELS,
+            buy_prices.len() <= depth_levels,
             TradingError::BatchTooLarge
         );
         require!(
-            sell_prices.len() <= MAX_DEPTH_LEVELS,
+            sell_prices.len() <= depth_levels,
             TradingError::BatchTooLarge
         );
         require!(
@@ -765,7 +1059,7 @@ pub mod trading {
 
         // Update buy side depth (bids sorted by price DESC)
         for (i, (price, amount)) in buy_prices.iter().zip(buy_amounts.iter()).enumerate() {
-            if i >= MAX_DEPTH_LEVELS {
+            if i >= depth_levels {
                 break;
             }
             zone_market.buy_side_depth[i] = PriceLevel {
@@ -779,7 +1073,7 @@ pub mod trading {
 
         // Update sell side depth (asks sorted by price ASC)
         for (i, (price, amount)) in sell_prices.iter().zip(sell_amounts.iter()).enumerate() {
-            if i >= MAX_DEPTH_LEVELS {
+            if i >= depth_levels {
                 break;
             }
             zone_market.sell_side_depth[i] = PriceLevel {
@@ -845,20 +1139,10 @@ pub mod trading {
             market.price_history_count = market.price_history_count.saturating_add(1);
         }
 
-        // Update volume-weighted price (VWAP)
-        let mut total_volume: u64 = 0;
-        let mut total_value: u64 = 0;
-
-        for i in 0..market.price_history_count as usize {
-            let point = market.price_history[i];
-            if point.volume > 0 {
-                total_volume = total_volume.saturating_add(point.volume);
-                total_value = total_value.saturating_add(point.volume.saturating_mul(point.price));
-            }
-        }
-
-        if total_volume > 0 {
-            market.volume_weighted_price = total_value / total_volume;
+        // Update volume-weighted price (VWAP) over the market's vwap_window — u128
+        // accumulators, see volume_weighted_price
+        if let Some(vwap) = market.recent_vwap() {
+            market.volume_weighted_price = vwap;
         }
 
         market.last_clearing_price = trade_price;
@@ -993,6 +1277,9 @@ pub mod trading {
                     price: clearing_price,
                     total_value: match_amount.saturating_mul(clearing_price),
                     fee_amount: 0,
+                    fee_bps: 0,
+                    buy_remaining: buy_rem.saturating_sub(match_amount),
+                    sell_remaining: sell_rem.saturating_sub(match_amount),
                     timestamp: clock.unix_timestamp,
                 });
 
@@ -1056,6 +1343,7 @@ pub mod trading {
 
         let market_fee_bps = {
             let market = ctx.accounts.market.load()?;
+            require!(market.frozen == 0, TradingError::MarketFrozen);
             market.market_fee_bps as u64
         };
         let clock = Clock::get()?;
@@ -1082,6 +1370,10 @@ pub mod trading {
                 price: clearing_price,
                 total_value: trade_value,
                 fee_amount: market_fee,
+                fee_bps: market_fee_bps as u16,
+                // Order accounts aren't loaded here; clear_auction's event carries them
+                buy_remaining: 0,
+                sell_remaining: 0,
                 timestamp: clock.unix_timestamp,
             });
         }
@@ -1112,6 +1404,7 @@ pub mod trading {
             TradingError::MaintenanceMode
         );
         let mut market = ctx.accounts.market.load_mut()?;
+        require!(market.frozen == 0, TradingError::MarketFrozen);
         require_keys_eq!(
             market.authority,
             ctx.accounts.market_authority.key(),
@@ -1120,6 +1413,8 @@ pub mod trading {
         let mut buy_order = ctx.accounts.buy_order.load_mut()?;
         let mut sell_order = ctx.accounts.sell_order.load_mut()?;
         let clock = Clock::get()?;
+        // Escrowed energy can only be released by match_orders.
+        require!(sell_order.escrowed == 0, TradingError::EscrowAccountsRequired);
 
         // Slippage Protection: Ensure match price is within limits of both orders
         require!(
@@ -1338,18 +1633,25 @@ pub mod trading {
             price,
             total_value: total_currency_value,
             fee_amount: market_fee,
+            fee_bps: market.market_fee_bps,
+            buy_remaining: buy_order.amount.saturating_sub(buy_order.filled_amount),
+            sell_remaining: sell_order.amount.saturating_sub(sell_order.filled_amount),
             timestamp: clock.unix_timestamp,
         });
         });
         Ok(())
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn update_market_params(
         ctx: Context<UpdateMarketParamsContext>,
         fee_bps: u16,
         clearing: bool,
         min_price: u64,
         max_price: u64,
+        max_price_deviation_bps: u16,
+        order_ttl_seconds: i64,
+        min_price_improvement_bps: u16,
     ) -> Result<()> {
         compute_fn!("update_market_params" => {
         require!(
@@ -1359,6 +1661,10 @@ pub mod trading {
         // Authority is enforced by `has_one = authority` on the market account in
         // UpdateMarketParamsContext (fires in account validation, before this body), so an
         // explicit `authority == market.authority` require here is dead — removed.
+        require!(
+            ctx.accounts.governance_config.fee_within_bounds(fee_bps),
+            TradingError::FeeOutOfGovernanceBounds
+        );
         let mut market = ctx.accounts.market.load_mut()?;
         market.market_fee_bps = fee_bps;
         market.clearing_enabled = if clearing { 1 } else { 0 };
@@ -1366,6 +1672,16 @@ pub mod trading {
             market.min_price_per_kwh = min_price;
         }
         market.max_price_per_kwh = max_price;
+        market.max_price_deviation_bps = max_price_deviation_bps;
+        market.min_price_improvement_bps = min_price_improvement_bps;
+        // 0 leaves the TTL as is, like `min_price` above.
+        if order_ttl_seconds != 0 {
+            require!(
+                (MIN_ORDER_TTL_SECONDS..=MAX_ORDER_TTL_SECONDS).contains(&order_ttl_seconds),
+                TradingError::InvalidOrderTtl
+            );
+            market.order_ttl_seconds = order_ttl_seconds;
+        }
         // Hoist Clock::get() before emit! — avoids an inline syscall inside the macro
         // expansion which is harder for the compiler to optimise away.
         let now = Clock::get()?.unix_timestamp;
@@ -1375,6 +1691,9 @@ pub mod trading {
             clearing_enabled: clearing,
             min_price_per_kwh: market.min_price_per_kwh,
             max_price_per_kwh: market.max_price_per_kwh,
+            max_price_deviation_bps,
+            order_ttl_seconds: market.order_ttl(),
+            min_price_improvement_bps,
             timestamp: now,
         });
         });
@@ -1405,6 +1724,200 @@ pub mod trading {
         Ok(())
     }
 
+    /// Freeze or unfreeze the market (admin only). A frozen market rejects new orders and
+    /// matches with `MarketFrozen`; `cancel_order` stays open so users can still exit.
+    /// Deliberately not behind the governance maintenance gate, so an incident response
+    /// is never blocked by a paused platform.
+    pub fn set_market_frozen(ctx: Context<SetMarketFrozenContext>, frozen: bool) -> Result<()> {
+        compute_fn!("set_market_frozen" => {
+            let mut market = ctx.accounts.market.load_mut()?;
+            market.frozen = if frozen { 1 } else { 0 };
+            let now = Clock::get()?.unix_timestamp;
+            emit!(crate::events::MarketFrozenUpdated {
+                authority: ctx.accounts.authority.key(),
+                frozen,
+                timestamp: now,
+            });
+        });
+        Ok(())
+    }
+
+    /// Propose a new market authority (step 1 of 2, admin only). Nothing changes until the
+    /// proposed key signs `accept_market_authority`, so a mistyped key cannot lock the
+    /// market. Only one proposal may be pending; cancel it to propose another.
+    pub fn propose_market_authority(
+        ctx: Context<MarketAuthorityContext>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        compute_fn!("propose_market_authority" => {
+            let mut market = ctx.accounts.market.load_mut()?;
+            require!(
+                new_authority != market.authority && new_authority != Pubkey::default(),
+                TradingError::InvalidMarketAuthority
+            );
+            require!(
+                market.pending_authority == Pubkey::default(),
+                TradingError::MarketAuthorityChangePending
+            );
+            market.pending_authority = new_authority;
+            let now = Clock::get()?.unix_timestamp;
+            emit!(crate::events::MarketAuthorityProposed {
+                market: ctx.accounts.market.key(),
+                authority: ctx.accounts.authority.key(),
+                pending_authority: new_authority,
+                timestamp: now,
+            });
+        });
+        Ok(())
+    }
+
+    /// Take over the market (step 2 of 2); must be signed by the pending authority.
+    pub fn accept_market_authority(ctx: Context<AcceptMarketAuthorityContext>) -> Result<()> {
+        compute_fn!("accept_market_authority" => {
+            let mut market = ctx.accounts.market.load_mut()?;
+            require!(
+                market.pending_authority != Pubkey::default(),
+                TradingError::NoMarketAuthorityChangePending
+            );
+            require_keys_eq!(
+                ctx.accounts.new_authority.key(),
+                market.pending_authority,
+                TradingError::InvalidPendingAuthority
+            );
+            let old_authority = market.authority;
+            market.authority = market.pending_authority;
+            market.pending_authority = Pubkey::default();
+            let now = Clock::get()?.unix_timestamp;
+            emit!(crate::events::MarketAuthorityTransferred {
+                market: ctx.accounts.market.key(),
+                old_authority,
+                new_authority: market.authority,
+                timestamp: now,
+            });
+        });
+        Ok(())
+    }
+
+    /// Withdraw a pending market authority proposal (admin only).
+    pub fn cancel_market_authority_proposal(ctx: Context<MarketAuthorityContext>) -> Result<()> {
+        compute_fn!("cancel_market_authority_proposal" => {
+            let mut market = ctx.accounts.market.load_mut()?;
+            let cancelled_authority = market.pending_authority;
+            require!(
+                cancelled_authority != Pubkey::default(),
+                TradingError::NoMarketAuthorityChangePending
+            );
+            market.pending_authority = Pubkey::default();
+            let now = Clock::get()?.unix_timestamp;
+            emit!(crate::events::MarketAuthorityProposalCancelled {
+                market: ctx.accounts.market.key(),
+                authority: ctx.accounts.authority.key(),
+                cancelled_authority,
+                timestamp: now,
+            });
+        });
+        Ok(())
+    }
+
+    /// Cap the resting orders each trader may hold (admin only); 0 lifts the cap. Orders
+    /// already on the book keep their slots, so a lowered cap only blocks new orders.
+    pub fn set_max_open_orders_per_user(
+        ctx: Context<SetMaxOpenOrdersContext>,
+        max_open_orders_per_user: u32,
+    ) -> Result<()> {
+        compute_fn!("set_max_open_orders_per_user" => {
+            let mut market = ctx.accounts.market.load_mut()?;
+            market.max_open_orders_per_user = max_open_orders_per_user;
+            let now = Clock::get()?.unix_timestamp;
+            emit!(crate::events::MaxOpenOrdersUpdated {
+                authority: ctx.accounts.authority.key(),
+                max_open_orders_per_user,
+                timestamp: now,
+            });
+        });
+        Ok(())
+    }
+
+    /// Limit the VWAP to the newest `vwap_window` price points (admin only); 0 restores the
+    /// full history. Takes effect on the next `update_price_history`.
+    pub fn set_vwap_window(ctx: Context<SetVwapWindowContext>, vwap_window: u16) -> Result<()> {
+        compute_fn!("set_vwap_window" => {
+            require!(
+                vwap_window as usize <= PRICE_HISTORY_LEN,
+                TradingError::InvalidVwapWindow
+            );
+            let mut market = ctx.accounts.market.load_mut()?;
+            market.vwap_window = vwap_window;
+            let now = Clock::get()?.unix_timestamp;
+            emit!(crate::events::VwapWindowUpdated {
+                authority: ctx.accounts.authority.key(),
+                vwap_window,
+                timestamp: now,
+            });
+        });
+        Ok(())
+    }
+
+    /// Set `market_fee_bps` as the governance authority, within the governance fee bounds.
+    /// Lets the regulator move a market's fee without the market authority's key; the
+    /// market authority stays bound by the same range in `update_market_params`.
+    pub fn set_market_fee_via_governance(
+        ctx: Context<SetMarketFeeViaGovernanceContext>,
+        market_fee_bps: u16,
+    ) -> Result<()> {
+        compute_fn!("set_market_fee_via_governance" => {
+            require!(
+                ctx.accounts.governance_config.fee_within_bounds(market_fee_bps),
+                TradingError::FeeOutOfGovernanceBounds
+            );
+            let mut market = ctx.accounts.market.load_mut()?;
+            let old_fee_bps = market.market_fee_bps;
+            market.market_fee_bps = market_fee_bps;
+            let now = Clock::get()?.unix_timestamp;
+            emit!(crate::events::MarketFeeSetByGovernance {
+                market: ctx.accounts.market.key(),
+                authority: ctx.accounts.authority.key(),
+                old_fee_bps,
+                new_fee_bps: market_fee_bps,
+                timestamp: now,
+            });
+        });
+        Ok(())
+    }
+
+    /// Replace the market's volume fee tiers (admin only). Configured tiers come first in
+    /// ascending threshold order and each fee must sit within the governance fee bounds;
+    /// all-zero tiers are unused, so an all-zero table restores the flat `market_fee_bps`.
+    pub fn set_fee_tiers(
+        ctx: Context<SetFeeTiersContext>,
+        fee_tiers: [FeeTier; MAX_FEE_TIERS],
+    ) -> Result<()> {
+        compute_fn!("set_fee_tiers" => {
+            require!(
+                get_governance_config(&ctx.accounts.governance_config.to_account_info())?.is_operational(),
+                TradingError::MaintenanceMode
+            );
+            require!(
+                fee_tiers_well_formed(&fee_tiers)
+                    && fee_tiers
+                        .iter()
+                        .filter(|t| t.is_configured())
+                        .all(|t| ctx.accounts.governance_config.fee_within_bounds(t.fee_bps)),
+                TradingError::InvalidFeeTiers
+            );
+            let mut market = ctx.accounts.market.load_mut()?;
+            market.fee_tiers = fee_tiers;
+            let now = Clock::get()?.unix_timestamp;
+            emit!(crate::events::FeeTiersUpdated {
+                market: ctx.accounts.market.key(),
+                authority: ctx.accounts.authority.key(),
+                fee_tiers,
+                timestamp: now,
+            });
+        });
+        Ok(())
+    }
+
     pub fn settle_offchain_match<'info>(
         ctx: Context<'info, SettleOffchainMatchContext<'info>>,
         buyer_payload: OffchainOrderPayload,
@@ -1464,6 +1977,35 @@ pub mod trading {
         instructions::withdraw_escrow(ctx, amount)
     }
 
+    /// Create a sell order whose energy is locked in a per-order escrow until it is matched,
+    /// cancelled or expired.
+    pub fn create_sell_order_escrowed(
+        ctx: Context<CreateSellOrderEscrowedContext>,
+        order_id_val: u64,
+        energy_amount: u64,
+        price_per_kwh: u64,
+    ) -> Result<()> {
+        instructions::create_sell_order_escrowed(ctx, order_id_val, energy_amount, price_per_kwh)
+    }
+
+    /// Return the unfilled energy of a cancelled/expired escrowed sell order to the seller
+    /// and close its escrow (permissionless).
+    pub fn refund_order_escrow(ctx: Context<RefundOrderEscrowContext>) -> Result<()> {
+        instructions::refund_order_escrow(ctx)
+    }
+
+    /// Hand the unfilled energy of a cancelled/expired ERC-backed sell order back to its
+    /// certificate (permissionless).
+    pub fn release_erc_reservation(ctx: Context<ReleaseErcReservationContext>) -> Result<()> {
+        instructions::release_erc_reservation(ctx)
+    }
+
+    /// Give back the open-order slot of an order that left the book without passing its
+    /// owner's TraderStats (expiry, sharded or atomic settlement). Permissionless.
+    pub fn release_open_order(ctx: Context<ReleaseOpenOrderContext>) -> Result<()> {
+        instructions::release_open_order(ctx)
+    }
+
     /// Custodial escrow funding (Option A): platform funds `user`'s escrow on their
     /// behalf. `user` is a non-signing instruction arg; the platform `funder` signs.
     /// Off-chain authorization is enforced by Chain Bridge RBAC.
@@ -1538,12 +2080,29 @@ pub mod trading {
         pub zone_market: AccountLoader<'info, ZoneMarket>,
         #[account(init, payer = authority, space = 8 + std::mem::size_of::<Order>(), seeds = [b"order", authority.key().as_ref(), &order_id_val.to_le_bytes()], bump)]
         pub order: AccountLoader<'info, Order>,
+        #[account(mut)]
         pub erc_certificate: Option<Box<Account<'info, ErcCertificate>>>,
         #[account(mut)]
         pub authority: Signer<'info>,
         pub system_program: Program<'info, System>,
         /// CHECK: Manual deserialization to handle length mismatch in localnet
         pub governance_config: UncheckedAccount<'info>,
+        /// Optional registry `UserAccount` of the seller; when supplied it must belong to
+        /// `authority` and be `Active`. Appended last so existing account indices are unchanged.
+        pub user_account: Option<AccountLoader<'info, UserAccount>>,
+        /// CHECK: `market_authority` PDA (verified in `update_erc_reservation`); required with
+        /// `erc_certificate` so the order's energy can be reserved on it.
+        pub market_authority: Option<UncheckedAccount<'info>>,
+        pub governance_program: Option<Program<'info, governance::program::Governance>>,
+        /// Owner's TraderStats; the order counts towards `max_open_orders_per_user`.
+        #[account(
+            init_if_needed,
+            payer = authority,
+            space = TraderStats::LEN,
+            seeds = [b"trader", authority.key().as_ref()],
+            bump
+        )]
+        pub trader_stats: Box<Account<'info, TraderStats>>,
         // OPTIONAL (remaining_accounts[0]): the seller's fungible REC token account
         // (Token-2022, governance rec_mint). When appended, the provenance gate fires —
         // see the handler. Kept out of the named context to avoid forcing every existing
@@ -1563,6 +2122,15 @@ pub mod trading {
         pub system_program: Program<'info, System>,
         /// CHECK: Manual deserialization to handle length mismatch in localnet
         pub governance_config: UncheckedAccount<'info>,
+        /// Owner's TraderStats; the order counts towards `max_open_orders_per_user`.
+        #[account(
+            init_if_needed,
+            payer = authority,
+            space = TraderStats::LEN,
+            seeds = [b"trader", authority.key().as_ref()],
+            bump
+        )]
+        pub trader_stats: Box<Account<'info, TraderStats>>,
     }
 
     #[derive(Accounts)]
@@ -1578,10 +2146,20 @@ pub mod trading {
         pub system_program: Program<'info, System>,
         /// CHECK: Manual deserialization to handle length mismatch in localnet
         pub governance_config: UncheckedAccount<'info>,
+        /// Owner's TraderStats; the order counts towards `max_open_orders_per_user`.
+        #[account(
+            init_if_needed,
+            payer = funder,
+            space = TraderStats::LEN,
+            seeds = [b"trader", user.as_ref()],
+            bump
+        )]
+        pub trader_stats: Box<Account<'info, TraderStats>>,
     }
 
     #[derive(Accounts)]
     pub struct MatchOrdersContext<'info> {
+        #[account(mut)]
         pub market: AccountLoader<'info, Market>,
         #[account(mut)]
         pub zone_market: AccountLoader<'info, ZoneMarket>,
@@ -1589,13 +2167,41 @@ pub mod trading {
         pub buy_order: AccountLoader<'info, Order>,
         #[account(mut)]
         pub sell_order: AccountLoader<'info, Order>,
-        #[account(init, payer = authority, space = 8 + std::mem::size_of::<TradeRecord>(), seeds = [b"trade", buy_order.key().as_ref(), sell_order.key().as_ref()], bump)]
+        #[account(init, payer = authority, space = 8 + std::mem::size_of::<TradeRecord>(), seeds = [b"trade", market.key().as_ref(), &market.load()?.trade_seq.to_le_bytes()], bump)]
         pub trade_record: AccountLoader<'info, TradeRecord>,
         #[account(mut)]
         pub authority: Signer<'info>,
         pub system_program: Program<'info, System>,
         /// CHECK: Manual deserialization to handle length mismatch in localnet
         pub governance_config: UncheckedAccount<'info>,
+        // Required only when `sell_order` is escrowed (create_sell_order_escrowed); appended
+        // last so existing callers are unchanged. Validated in `release_order_escrow`.
+        pub energy_mint: Option<Box<InterfaceAccount<'info, anchor_spl::token_interface::Mint>>>,
+        #[account(mut)]
+        pub sell_order_escrow: Option<Box<InterfaceAccount<'info, anchor_spl::token_interface::TokenAccount>>>,
+        #[account(mut)]
+        pub buyer_energy_account: Option<Box<InterfaceAccount<'info, anchor_spl::token_interface::TokenAccount>>>,
+        /// CHECK: global escrow authority PDA; key checked in `release_order_escrow`.
+        pub market_authority: Option<UncheckedAccount<'info>>,
+        pub token_program: Option<Interface<'info, anchor_spl::token_interface::TokenInterface>>,
+        // Per-trader volume, created on first match. seller_stats may be omitted only on a
+        // self-trade, where it would be the same account as buyer_stats.
+        #[account(
+            init_if_needed,
+            payer = authority,
+            space = TraderStats::LEN,
+            seeds = [b"trader".as_ref(), &buy_order.load()?.buyer.to_bytes()],
+            bump
+        )]
+        pub buyer_stats: Box<Account<'info, TraderStats>>,
+        #[account(
+            init_if_needed,
+            payer = authority,
+            space = TraderStats::LEN,
+            seeds = [b"trader".as_ref(), &sell_order.load()?.seller.to_bytes()],
+            bump
+        )]
+        pub seller_stats: Option<Box<Account<'info, TraderStats>>>,
     }
 
     #[derive(Accounts)]
@@ -1603,7 +2209,7 @@ pub mod trading {
     pub struct ShardedMatchOrdersContext<'info> {
         #[account(mut)]
         pub market: AccountLoader<'info, Market>,
-        #[account(mut)]
+        #[account(mut, constraint = zone_market.load()?.market == market.key())]
         pub zone_market: AccountLoader<'info, ZoneMarket>,
         #[account(mut, seeds = [b"zone_shard", zone_market.key().as_ref(), &[shard_id]], bump)]
         pub zone_shard: AccountLoader<'info, ZoneMarketShard>,
@@ -1611,7 +2217,7 @@ pub mod trading {
         pub buy_order: AccountLoader<'info, Order>,
         #[account(mut)]
         pub sell_order: AccountLoader<'info, Order>,
-        #[account(init, payer = authority, space = 8 + std::mem::size_of::<TradeRecord>(), seeds = [b"trade", buy_order.key().as_ref(), sell_order.key().as_ref()], bump)]
+        #[account(init, payer = authority, space = 8 + std::mem::size_of::<TradeRecord>(), seeds = [b"trade", market.key().as_ref(), &market.load()?.trade_seq.to_le_bytes()], bump)]
         pub trade_record: AccountLoader<'info, TradeRecord>,
         #[account(mut)]
         pub authority: Signer<'info>,
@@ -1630,6 +2236,37 @@ pub mod trading {
         #[account(mut)]
         pub authority: Signer<'info>,
         pub governance_config: Account<'info, GovernanceConfig>,
+        /// Owner's TraderStats; required when the order counts towards the open-order cap.
+        #[account(mut, seeds = [b"trader", authority.key().as_ref()], bump)]
+        pub trader_stats: Option<Box<Account<'info, TraderStats>>>,
+    }
+
+    #[derive(Accounts)]
+    pub struct GetMarketStats<'info> {
+        pub market: AccountLoader<'info, Market>,
+        #[account(constraint = zone_market.load()?.market == market.key())]
+        pub zone_market: AccountLoader<'info, ZoneMarket>,
+    }
+
+    #[derive(Accounts)]
+    pub struct GetOrderBook<'info> {
+        pub market: AccountLoader<'info, Market>,
+        #[account(constraint = zone_market.load()?.market == market.key())]
+        pub zone_market: AccountLoader<'info, ZoneMarket>,
+    }
+
+    #[derive(Accounts)]
+    pub struct GetPriceHistory<'info> {
+        pub market: AccountLoader<'info, Market>,
+    }
+
+    #[derive(Accounts)]
+    pub struct ExpireOrdersBatchContext<'info> {
+        pub market: AccountLoader<'info, Market>,
+        #[account(mut, constraint = zone_market.load()?.market == market.key())]
+        pub zone_market: AccountLoader<'info, ZoneMarket>,
+        pub authority: Signer<'info>,
+        // remaining_accounts: the `Order` accounts to sweep (mut), all resting in `zone_market`.
     }
 
     #[derive(Accounts)]
@@ -1700,12 +2337,71 @@ pub mod trading {
         pub authority: Signer<'info>,
     }
 
+    #[derive(Accounts)]
+    pub struct SetMarketFrozenContext<'info> {
+        #[account(mut, has_one = authority)]
+        pub market: AccountLoader<'info, Market>,
+        pub authority: Signer<'info>,
+    }
+
+    #[derive(Accounts)]
+    pub struct MarketAuthorityContext<'info> {
+        #[account(mut, has_one = authority)]
+        pub market: AccountLoader<'info, Market>,
+        pub authority: Signer<'info>,
+    }
+
+    #[derive(Accounts)]
+    pub struct AcceptMarketAuthorityContext<'info> {
+        #[account(mut)]
+        pub market: AccountLoader<'info, Market>,
+        pub new_authority: Signer<'info>,
+    }
+
+    #[derive(Accounts)]
+    pub struct SetMaxOpenOrdersContext<'info> {
+        #[account(mut, has_one = authority)]
+        pub market: AccountLoader<'info, Market>,
+        pub authority: Signer<'info>,
+    }
+
+    #[derive(Accounts)]
+    pub struct SetVwapWindowContext<'info> {
+        #[account(mut, has_one = authority)]
+        pub market: AccountLoader<'info, Market>,
+        pub authority: Signer<'info>,
+    }
+
+    #[derive(Accounts)]
+    pub struct SetMarketFeeViaGovernanceContext<'info> {
+        #[account(mut)]
+        pub market: AccountLoader<'info, Market>,
+        #[account(
+            seeds = [b"poa_config"],
+            bump,
+            seeds::program = governance::ID,
+            constraint = governance_config.authority == authority.key() @ TradingError::UnauthorizedAuthority,
+        )]
+        pub governance_config: Account<'info, GovernanceConfig>,
+        pub authority: Signer<'info>,
+    }
+
+    #[derive(Accounts)]
+    pub struct SetFeeTiersContext<'info> {
+        #[account(mut, has_one = authority)]
+        pub market: AccountLoader<'info, Market>,
+        pub authority: Signer<'info>,
+        pub governance_config: Account<'info, GovernanceConfig>,
+    }
+
     #[derive(Accounts)]
     #[instruction(order_id_val: u64, side: u8, amount: u64, price: u64, shard_id: u8)]
     pub struct SubmitLimitOrderShardedContext<'info> {
+        /// Read-only (freeze flag), so sharded submits never contend for the Market write lock.
+        pub market: AccountLoader<'info, Market>,
         #[account(init, payer = authority, space = 8 + std::mem::size_of::<Order>(), seeds = [b"order", authority.key().as_ref(), &order_id_val.to_le_bytes()], bump)]
         pub order: AccountLoader<'info, Order>,
-        #[account(mut)]
+        #[account(mut, constraint = zone_market.load()?.market == market.key())]
         pub zone_market: AccountLoader<'info, ZoneMarket>,
         #[account(mut, seeds = [b"zone_shard", zone_market.key().as_ref(), &[shard_id]], bump)]
         pub zone_shard: AccountLoader<'info, ZoneMarketShard>,
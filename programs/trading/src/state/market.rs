@@ -19,7 +19,13 @@ pub struct Market {
     // already forbids CPI re-entrancy, so the flag was never read or written).
     // Kept to preserve the on-chain layout.
     pub _reserved_guard: u8,        // 1
-    pub _padding1: [u8; 4],         // 4 -> 80
+    // Circuit breaker: max deviation of a match's clearing price from
+    // `last_clearing_price`, in bps (0 = disabled). Carved from `_padding1`.
+    pub max_price_deviation_bps: u16, // 2
+    // Kill switch: when 1, no new orders and no matches (cancels still allowed).
+    // Carved from `_padding1`.
+    pub frozen: u8,                 // 1
+    pub _padding1: [u8; 1],         // 1 -> 80
     pub min_price_per_kwh: u64,     // 8 — minimum allowed price (must be > 0)
     pub max_price_per_kwh: u64,     // 8 — maximum allowed price (0 = no cap)
 
@@ -38,7 +44,18 @@ pub struct Market {
     // === MARKET DEPTH (Moved to ZoneMarket) — reserved bytes repurposed below ===
     pub _padding_depth_1: [u8; 512],
     pub _padding_depth_2: [u8; 256],
-    pub _padding_depth_3: [u8; 128],
+    // Volume-based fee schedule for `match_orders` (see `fee_bps_for_volume`). Carved from
+    // `_padding_depth_3`; existing accounts read all-zero tiers, i.e. the flat `market_fee_bps`.
+    pub fee_tiers: [FeeTier; MAX_FEE_TIERS], // 48
+    // Cap on a trader's open orders (`TraderStats.open_orders`), 0 = unlimited. Carved from
+    // `_padding_depth_3`; existing accounts read 0.
+    pub max_open_orders_per_user: u32,       // 4
+    // Authority proposed by `propose_market_authority`, waiting for its own signature in
+    // `accept_market_authority`; default key = none pending. Carved from `_padding_depth_3`.
+    pub pending_authority: Pubkey,           // 32
+    // Split in two: bytemuck has no Pod/Zeroable impl for [u8; 44].
+    pub _padding_depth_3: [u8; 32],          // 32
+    pub _padding_depth_3b: [u8; 12],         // 48 + 4 + 32 + 32 + 12 = 128
     // Settlement recording policy: when `has_settlement_thbg_mint == 1` and a match
     // settles in `settlement_thbg_mint`, the treasury accounts MUST be passed to the
     // settle instruction (recording is no longer optional for THBG-denominated trades).
@@ -46,8 +63,21 @@ pub struct Market {
     // off) so the layout/size is unchanged and backward compatible.
     pub settlement_thbg_mint: Pubkey,  // 32
     pub has_settlement_thbg_mint: u8,  // 1
-    pub _padding_depth_4: [u8; 31],    // 32 + 1 + 31 = 64 (was [u8; 64])
-    pub _padding_depth_5: [u8; 6], // 512+256+128+64+6 = 966
+    pub _pad_ttl: [u8; 7],             // 7 — aligns order_ttl_seconds to 8
+    // Lifetime of new orders in seconds (`expires_at = created_at + ttl`). Carved from
+    // `_padding_depth_4`; existing accounts read 0, which `order_ttl()` maps to the default.
+    pub order_ttl_seconds: i64,        // 8
+    // Minimum crossing spread `match_orders` requires, in bps of the ask (0 = disabled).
+    // Carved from `_padding_depth_4`; existing accounts read 0.
+    pub min_price_improvement_bps: u16, // 2
+    pub _padding_depth_4: [u8; 6],     // 6 — aligns trade_seq to 8
+    // Sequence number of the next `TradeRecord`, seeds=[b"trade", market, trade_seq (le)].
+    // Carved from `_padding_depth_4`; existing accounts start paging from 0.
+    pub trade_seq: u64,                // 8 — 32 + 1 + 7 + 8 + 2 + 6 + 8 = 64 (was [u8; 64])
+    // Number of most recent price points the VWAP covers (0 = all of `price_history`).
+    // Carved from `_padding_depth_5`; existing accounts read 0.
+    pub vwap_window: u16,          // 2
+    pub _padding_depth_5: [u8; 4], // 512+256+128+64+2+4 = 966
     pub price_history_count: u8,   // 1 — number of valid entries (0..=24)
     pub price_history_head: u8,    // 1 — ring-buffer write head (next slot to overwrite)
 
@@ -61,6 +91,112 @@ pub struct Market {
     pub _padding_sharding: [u8; 3], // 8+4+1+3 = 16
 }
 
+/// Order lifetime used when a market has none configured (and by the sharded path).
+pub const DEFAULT_ORDER_TTL_SECONDS: i64 = 86_400;
+/// Bounds accepted by `update_market_params` for `order_ttl_seconds`: 1 minute to 7 days.
+pub const MIN_ORDER_TTL_SECONDS: i64 = 60;
+pub const MAX_ORDER_TTL_SECONDS: i64 = 7 * 86_400;
+
+/// Slots in `Market.price_history`; also the largest accepted `vwap_window`.
+pub const PRICE_HISTORY_LEN: usize = 24;
+
+/// Number of volume tiers a market can configure on top of its base `market_fee_bps`.
+pub const MAX_FEE_TIERS: usize = 3;
+
+impl Market {
+    /// Sequence number for the trade being recorded now; advances the counter.
+    pub fn take_trade_seq(&mut self) -> u64 {
+        let seq = self.trade_seq;
+        self.trade_seq = seq.saturating_add(1);
+        seq
+    }
+
+    /// VWAP over the newest `vwap_window` valid price points (all of them when the window
+    /// is 0 or wider than the history). `None` while those points carry no volume.
+    pub fn recent_vwap(&self) -> Option<u64> {
+        let count = (self.price_history_count as usize).min(PRICE_HISTORY_LEN);
+        let n = match self.vwap_window as usize {
+            0 => count,
+            window => window.min(count),
+        };
+        let head = self.price_history_head as usize;
+        let mut points = [PricePoint::default(); PRICE_HISTORY_LEN];
+        for (i, point) in points[..n].iter_mut().enumerate() {
+            *point = self.price_history[(head + PRICE_HISTORY_LEN - 1 - i) % PRICE_HISTORY_LEN];
+        }
+        crate::utils::volume_weighted_price(&points[..n])
+    }
+
+    /// Up to `count` valid price points starting `start` entries after the oldest one, oldest
+    /// first. Both are clamped to `price_history_count`, so an out-of-range window is empty.
+    pub fn price_history_window(&self, start: u16, count: u16) -> Vec<PricePoint> {
+        let len = (self.price_history_count as usize).min(PRICE_HISTORY_LEN);
+        let start = (start as usize).min(len);
+        let n = (count as usize).min(len - start);
+        let oldest = (self.price_history_head as usize + PRICE_HISTORY_LEN - len) % PRICE_HISTORY_LEN;
+        (start..start + n)
+            .map(|i| self.price_history[(oldest + i) % PRICE_HISTORY_LEN])
+            .collect()
+    }
+
+    /// TTL for new orders; 0 (a market created before the field existed) means the default.
+    pub fn order_ttl(&self) -> i64 {
+        if self.order_ttl_seconds == 0 {
+            DEFAULT_ORDER_TTL_SECONDS
+        } else {
+            self.order_ttl_seconds
+        }
+    }
+
+    /// Fee charged to a taker with `volume` cumulative traded energy. `market_fee_bps` is the
+    /// base tier; each configured tier (`volume_threshold > 0`) the volume has reached
+    /// replaces it. Tiers are stored in ascending threshold order, so the last match wins.
+    pub fn fee_bps_for_volume(&self, volume: u64) -> u16 {
+        self.fee_tiers
+            .iter()
+            .rev()
+            .find(|t| t.is_configured() && volume >= t.volume_threshold)
+            .map_or(self.market_fee_bps, |t| t.fee_bps)
+    }
+}
+
+/// One step of a market's volume fee schedule. An all-zero tier is unused.
+#[derive(
+    AnchorSerialize,
+    AnchorDeserialize,
+    Copy,
+    Clone,
+    InitSpace,
+    Default,
+    bytemuck::Zeroable,
+    bytemuck::Pod,
+)]
+#[repr(C)]
+pub struct FeeTier {
+    pub volume_threshold: u64, // minimum cumulative taker volume (energy units)
+    pub fee_bps: u16,
+    pub _padding: [u8; 6],
+}
+
+impl FeeTier {
+    pub fn is_configured(&self) -> bool {
+        self.volume_threshold > 0
+    }
+}
+
+/// Check a fee schedule for `set_fee_tiers`: configured tiers come first, with strictly
+/// increasing thresholds, and every unused tier after them is all-zero.
+pub fn fee_tiers_well_formed(tiers: &[FeeTier]) -> bool {
+    let configured = tiers.iter().take_while(|t| t.is_configured()).count();
+    let ascending = tiers[..configured]
+        .windows(2)
+        .all(|w| w[0].volume_threshold < w[1].volume_threshold);
+    let rest_empty = tiers[configured..]
+        .iter()
+        .all(|t| !t.is_configured() && t.fee_bps == 0);
+    ascending && rest_empty
+}
+
 /// Batch configuration for batch processing
 #[derive(
     AnchorSerialize, AnchorDeserialize, Copy, Clone, InitSpace, bytemuck::Zeroable, bytemuck::Pod,
@@ -140,6 +276,32 @@ pub struct PricePoint {
     pub timestamp: i64,
 }
 
+/// Aggregate market figures returned by `get_market_stats`. Counters and prices come from
+/// `Market`; the depth sums come from the requested `ZoneMarket`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct MarketStats {
+    pub active_orders: u32,
+    pub total_volume: u64,
+    pub total_trades: u32,
+    pub last_clearing_price: u64,
+    pub volume_weighted_price: u64,
+    pub zone_id: u32,
+    pub zone_active_orders: u32,
+    pub buy_side_depth: u64,
+    pub sell_side_depth: u64,
+}
+
+/// Both sides of one zone's depth snapshot returned by `get_order_book`. Bids are sorted
+/// best (highest) first and asks best (lowest) first; only populated levels are included.
+/// Read from a single account in one instruction, so the two sides are always consistent.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct OrderBookSnapshot {
+    pub bids: Vec<PriceLevel>,
+    pub asks: Vec<PriceLevel>,
+    pub last_clearing_price: u64,
+    pub captured_at: i64,
+}
+
 /// Sharded market statistics for reduced contention
 /// Each shard tracks independent volume/order counts that can be aggregated
 /// This allows parallel writes without MVCC conflicts on the main Market account
@@ -161,3 +323,110 @@ pub fn get_shard_id(authority: &Pubkey, num_shards: u8) -> u8 {
     // Use first byte of pubkey for simple sharding
     authority.to_bytes()[0] % num_shards
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tier(volume_threshold: u64, fee_bps: u16) -> FeeTier {
+        FeeTier { volume_threshold, fee_bps, _padding: [0; 6] }
+    }
+
+    fn market(tiers: [FeeTier; MAX_FEE_TIERS]) -> Market {
+        let mut m: Market = bytemuck::Zeroable::zeroed();
+        m.market_fee_bps = 25;
+        m.fee_tiers = tiers;
+        m
+    }
+
+    #[test]
+    fn unconfigured_schedule_charges_the_flat_fee() {
+        let m = market([FeeTier::default(); MAX_FEE_TIERS]);
+        assert_eq!(m.fee_bps_for_volume(0), 25);
+        assert_eq!(m.fee_bps_for_volume(u64::MAX), 25);
+    }
+
+    #[test]
+    fn highest_reached_tier_applies() {
+        let m = market([tier(1_000, 20), tier(10_000, 10), FeeTier::default()]);
+        assert_eq!(m.fee_bps_for_volume(999), 25);
+        assert_eq!(m.fee_bps_for_volume(1_000), 20);
+        assert_eq!(m.fee_bps_for_volume(9_999), 20);
+        assert_eq!(m.fee_bps_for_volume(10_000), 10);
+    }
+
+    fn record(m: &mut Market, price: u64, volume: u64) {
+        let head = m.price_history_head as usize;
+        m.price_history[head] = PricePoint { price, volume, timestamp: 0 };
+        m.price_history_head = ((head + 1) % PRICE_HISTORY_LEN) as u8;
+        if (m.price_history_count as usize) < PRICE_HISTORY_LEN {
+            m.price_history_count += 1;
+        }
+    }
+
+    #[test]
+    fn vwap_window_covers_only_the_newest_points() {
+        let mut m = market([FeeTier::default(); MAX_FEE_TIERS]);
+        assert_eq!(m.recent_vwap(), None);
+        record(&mut m, 100, 10);
+        record(&mut m, 50, 10);
+        record(&mut m, 70, 10);
+        // 0 = full history
+        assert_eq!(m.recent_vwap(), Some(73));
+        m.vwap_window = 2;
+        assert_eq!(m.recent_vwap(), Some(60));
+        m.vwap_window = 1;
+        assert_eq!(m.recent_vwap(), Some(70));
+        m.vwap_window = PRICE_HISTORY_LEN as u16;
+        assert_eq!(m.recent_vwap(), Some(73));
+    }
+
+    #[test]
+    fn vwap_window_follows_the_ring_buffer_across_the_wrap() {
+        let mut m = market([FeeTier::default(); MAX_FEE_TIERS]);
+        for _ in 0..PRICE_HISTORY_LEN {
+            record(&mut m, 10, 1);
+        }
+        record(&mut m, 40, 1); // overwrites slot 0
+        record(&mut m, 20, 1); // overwrites slot 1
+        m.vwap_window = 3;
+        assert_eq!(m.recent_vwap(), Some(23)); // (40 + 20 + 10) / 3
+        m.vwap_window = 0;
+        assert_eq!(m.recent_vwap(), Some(11)); // (22 * 10 + 40 + 20) / 24
+    }
+
+    #[test]
+    fn price_history_window_reads_oldest_first_and_clamps() {
+        let mut m = market([FeeTier::default(); MAX_FEE_TIERS]);
+        assert!(m.price_history_window(0, 10).is_empty());
+        for price in 1..=3 {
+            record(&mut m, price, 1);
+        }
+        let prices = |w: Vec<PricePoint>| w.iter().map(|p| p.price).collect::<Vec<_>>();
+        assert_eq!(prices(m.price_history_window(0, 10)), [1, 2, 3]);
+        assert_eq!(prices(m.price_history_window(1, 1)), [2]);
+        assert_eq!(prices(m.price_history_window(2, u16::MAX)), [3]);
+        assert!(m.price_history_window(3, 1).is_empty());
+        assert!(m.price_history_window(u16::MAX, 1).is_empty());
+
+        // After the wrap the oldest surviving point sits at the head slot
+        for price in 4..=(PRICE_HISTORY_LEN as u64 + 2) {
+            record(&mut m, price, 1);
+        }
+        let all = prices(m.price_history_window(0, u16::MAX));
+        assert_eq!(all, (3..=PRICE_HISTORY_LEN as u64 + 2).collect::<Vec<_>>());
+        assert_eq!(prices(m.price_history_window(22, 5)), [25, 26]);
+    }
+
+    #[test]
+    fn fee_tier_validation() {
+        assert!(fee_tiers_well_formed(&[FeeTier::default(); MAX_FEE_TIERS]));
+        assert!(fee_tiers_well_formed(&[tier(1, 20), tier(2, 10), tier(3, 5)]));
+        // Thresholds must strictly increase
+        assert!(!fee_tiers_well_formed(&[tier(2, 20), tier(2, 10), FeeTier::default()]));
+        // No configured tier after a gap, and no fee on an unused tier
+        assert!(!fee_tiers_well_formed(&[tier(1, 20), FeeTier::default(), tier(3, 5)]));
+        assert!(!fee_tiers_well_formed(&[tier(1, 20), FeeTier::default(), tier(3, 0)]));
+        assert!(!fee_tiers_well_formed(&[tier(0, 20), FeeTier::default(), FeeTier::default()]));
+    }
+}
@@ -6,9 +6,11 @@ pub mod order;
 pub mod zone_market;
 pub mod nullifier;
 pub mod zone_config;
+pub mod trader_stats;
 
 pub use market::*;
 pub use order::*;
 pub use zone_market::*;
 pub use nullifier::*;
 pub use zone_config::*;
+pub use trader_stats::*;
@@ -14,9 +14,33 @@ pub struct Order {
     pub price_per_kwh: u64,     // 8
     pub order_type: u8,         // 1 (OrderType)
     pub status: u8,             // 1 (OrderStatus)
-    pub _padding: [u8; 6],      // 6
+    // 1 when the sell side's energy is locked in the `["order_escrow", order]` token
+    // account (create_sell_order_escrowed). Carved from `_padding`; 0 for legacy orders.
+    pub escrowed: u8,           // 1
+    pub escrow_bump: u8,        // 1 — canonical bump of the order escrow PDA
+    // 1 while `amount` is reserved on `erc_certificate`; cleared by release_erc_reservation.
+    // Carved from `_padding`.
+    pub erc_reserved: u8,       // 1
+    // 1 while the order counts towards its owner's `TraderStats.open_orders`; cleared when
+    // the count is released (fill, cancel or release_open_order). Carved from `_padding`.
+    pub open_counted: u8,       // 1
+    pub _padding: [u8; 2],      // 2
     pub created_at: i64,        // 8
     pub expires_at: i64,        // 8
+    // ERC certificate that backed a sell order at creation (create_sell_order); zero when
+    // none was supplied and for orders from other entry points.
+    pub erc_certificate: Pubkey, // 32
+}
+
+impl Order {
+    /// The trader who placed the order: the buyer of a buy order, the seller of a sell.
+    pub fn owner(&self) -> Pubkey {
+        if self.order_type == OrderType::Buy as u8 {
+            self.buyer
+        } else {
+            self.seller
+        }
+    }
 }
 
 #[account(zero_copy)]
@@ -31,6 +55,8 @@ pub struct TradeRecord {
     pub total_value: u64,
     pub fee_amount: u64,
     pub executed_at: i64,
+    /// Position in the market's trade sequence (the `trade_seq` seed)
+    pub trade_seq: u64,
 }
 
 // Enums (keep for logic, but don't put in zero_copy directly if Pod errors persist)
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::error::TradingError;
+use crate::state::Order;
+
+/// Per-trader running totals, seeds = [b"trader", authority]. Created on a trader's first
+/// order or match (`init_if_needed`) and updated for both sides of every match.
+/// Query-only: nothing is emitted, but `match_orders` prices the taker's fee tier from it
+/// (`Market::fee_bps_for_volume`) and order creation enforces `max_open_orders_per_user`.
+#[account]
+pub struct TraderStats {
+    pub authority: Pubkey,
+    pub total_volume: u64,  // energy matched across both sides
+    pub trade_count: u64,
+    pub last_trade_at: i64,
+    pub open_orders: u32,   // orders with `open_counted == 1`
+}
+
+impl TraderStats {
+    pub const LEN: usize = 8 + 32 + 8 + 8 + 8 + 4; // Discriminator + authority + volume + count + last_trade_at + open_orders
+
+    /// Add one fill. `authority` is (re)written so a freshly created account is tagged.
+    pub fn record(&mut self, authority: Pubkey, amount: u64, now: i64) {
+        self.authority = authority;
+        self.total_volume = self.total_volume.saturating_add(amount);
+        self.trade_count = self.trade_count.saturating_add(1);
+        self.last_trade_at = now;
+    }
+
+    /// Count a new resting order, rejecting it once `max_open` (0 = unlimited) is reached.
+    pub fn open_order(&mut self, authority: Pubkey, order: &mut Order, max_open: u32) -> Result<()> {
+        require!(
+            max_open == 0 || self.open_orders < max_open,
+            TradingError::TooManyOpenOrders
+        );
+        self.authority = authority;
+        self.open_orders = self.open_orders.saturating_add(1);
+        order.open_counted = 1;
+        Ok(())
+    }
+
+    /// Release `order`'s slot if it still holds one; a no-op for uncounted orders.
+    pub fn close_order(&mut self, order: &mut Order) {
+        if order.open_counted == 1 {
+            order.open_counted = 0;
+            self.open_orders = self.open_orders.saturating_sub(1);
+        }
+    }
+}
@@ -21,7 +21,8 @@ pub struct ZoneMarket {
     pub total_trades: u32,                  // 4
     pub buy_side_depth_count: u8,           // 1
     pub sell_side_depth_count: u8,          // 1
-    pub _padding2: [u8; 6],                 // 6
+    pub max_depth_levels: u8,               // 1 - Levels kept per side (0 = MAX_DEPTH_LEVELS)
+    pub _padding2: [u8; 5],                 // 5
     pub last_clearing_price: u64,           // 8
     pub capacity: u64,                      // 8 - Total transmission capacity in base units
     pub committed_flow: u64,                // 8 - Currently committed flow from matches
@@ -31,6 +32,106 @@ pub struct ZoneMarket {
     pub sell_side_depth: [PriceLevel; MAX_DEPTH_LEVELS],  // 240
 }
 
+impl ZoneMarket {
+    /// Levels kept per side of the snapshot. Zones created before the setting existed
+    /// read 0 and keep the full MAX_DEPTH_LEVELS.
+    pub fn depth_levels(&self) -> usize {
+        match self.max_depth_levels as usize {
+            0 => MAX_DEPTH_LEVELS,
+            n => n.min(MAX_DEPTH_LEVELS),
+        }
+    }
+
+    /// Rest `amount` at `price` on one side of the depth snapshot
+    pub fn add_depth(&mut self, is_buy: bool, price: u64, amount: u64) {
+        let cap = self.depth_levels();
+        if is_buy {
+            add_level(&mut self.buy_side_depth, &mut self.buy_side_depth_count, cap, price, amount, true);
+        } else {
+            add_level(&mut self.sell_side_depth, &mut self.sell_side_depth_count, cap, price, amount, false);
+        }
+    }
+
+    /// Take `amount` off the level at `price`; `order_closed` also drops the order from the
+    /// level's count. Empty levels are removed. Prices not in the snapshot are ignored.
+    pub fn reduce_depth(&mut self, is_buy: bool, price: u64, amount: u64, order_closed: bool) {
+        if is_buy {
+            reduce_level(&mut self.buy_side_depth, &mut self.buy_side_depth_count, price, amount, order_closed);
+        } else {
+            reduce_level(&mut self.sell_side_depth, &mut self.sell_side_depth_count, price, amount, order_closed);
+        }
+    }
+
+    /// Sum of `total_amount` over one side of the depth snapshot, saturating
+    pub fn total_depth(&self, is_buy: bool) -> u64 {
+        let (levels, count) = if is_buy {
+            (&self.buy_side_depth, self.buy_side_depth_count)
+        } else {
+            (&self.sell_side_depth, self.sell_side_depth_count)
+        };
+        levels[..(count as usize).min(MAX_DEPTH_LEVELS)]
+            .iter()
+            .fold(0u64, |sum, level| sum.saturating_add(level.total_amount))
+    }
+}
+
+/// Bids are kept price-descending and asks ascending. With every level taken, a better
+/// price evicts the worst level and a worse one is left out of the snapshot. `cap` is the
+/// zone's `depth_levels()`.
+fn add_level(
+    levels: &mut [PriceLevel; MAX_DEPTH_LEVELS],
+    count: &mut u8,
+    cap: usize,
+    price: u64,
+    amount: u64,
+    descending: bool,
+) {
+    let n = (*count as usize).min(cap);
+    if let Some(level) = levels[..n].iter_mut().find(|level| level.price == price) {
+        level.total_amount = level.total_amount.saturating_add(amount);
+        level.order_count = level.order_count.saturating_add(1);
+        return;
+    }
+
+    let better = |level: &PriceLevel| if descending { price > level.price } else { price < level.price };
+    let pos = levels[..n].iter().position(better).unwrap_or(n);
+    if pos >= cap {
+        return;
+    }
+    let end = n.min(cap - 1);
+    levels.copy_within(pos..end, pos + 1);
+    levels[pos] = PriceLevel {
+        price,
+        total_amount: amount,
+        order_count: 1,
+        _padding: [0; 6],
+    };
+    *count = (end + 1) as u8;
+}
+
+fn reduce_level(
+    levels: &mut [PriceLevel; MAX_DEPTH_LEVELS],
+    count: &mut u8,
+    price: u64,
+    amount: u64,
+    order_closed: bool,
+) {
+    let n = (*count as usize).min(MAX_DEPTH_LEVELS);
+    let Some(pos) = levels[..n].iter().position(|level| level.price == price) else {
+        return;
+    };
+    let level = &mut levels[pos];
+    level.total_amount = level.total_amount.saturating_sub(amount);
+    if order_closed {
+        level.order_count = level.order_count.saturating_sub(1);
+    }
+    if level.total_amount == 0 || level.order_count == 0 {
+        levels.copy_within(pos + 1..n, pos);
+        levels[n - 1] = PriceLevel::default();
+        *count = (n - 1) as u8;
+    }
+}
+
 /// Sharded zone market statistics for reduced contention
 /// Tracks volume and trades on a per-shard basis within a zone
 /// Per-zone transmission-capacity counter, split OUT of `ZoneMarket` so the hot settle
@@ -60,3 +161,94 @@ pub struct ZoneMarketShard {
     pub last_clearing_price: u64,        // Latest clearing price in this shard
     pub last_update: i64,                // Last update timestamp
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn zone() -> ZoneMarket {
+        bytemuck::Zeroable::zeroed()
+    }
+
+    fn asks(zm: &ZoneMarket) -> Vec<(u64, u64, u16)> {
+        zm.sell_side_depth[..zm.sell_side_depth_count as usize]
+            .iter()
+            .map(|l| (l.price, l.total_amount, l.order_count))
+            .collect()
+    }
+
+    #[test]
+    fn depth_levels_stay_sorted_and_aggregate() {
+        let mut zm = zone();
+        zm.add_depth(false, 60, 100);
+        zm.add_depth(false, 50, 200);
+        zm.add_depth(false, 60, 30);
+        assert_eq!(asks(&zm), vec![(50, 200, 1), (60, 130, 2)]);
+
+        zm.add_depth(true, 40, 10);
+        zm.add_depth(true, 45, 10);
+        assert_eq!(zm.buy_side_depth[0].price, 45);
+        assert_eq!(zm.buy_side_depth[1].price, 40);
+    }
+
+    #[test]
+    fn partial_fill_leaves_residual_and_full_fill_drops_level() {
+        let mut zm = zone();
+        zm.add_depth(false, 50, 100);
+        zm.add_depth(false, 55, 40);
+
+        zm.reduce_depth(false, 50, 30, false);
+        assert_eq!(asks(&zm), vec![(50, 70, 1), (55, 40, 1)]);
+
+        zm.reduce_depth(false, 50, 70, true);
+        assert_eq!(asks(&zm), vec![(55, 40, 1)]);
+
+        // Unknown price (e.g. evicted level) is a no-op
+        zm.reduce_depth(false, 99, 10, true);
+        assert_eq!(asks(&zm), vec![(55, 40, 1)]);
+    }
+
+    #[test]
+    fn full_book_evicts_worst_level_only_for_better_prices() {
+        let mut zm = zone();
+        for i in 0..MAX_DEPTH_LEVELS as u64 {
+            zm.add_depth(false, 100 + i, 1);
+        }
+        zm.add_depth(false, 200, 1);
+        assert_eq!(zm.sell_side_depth_count as usize, MAX_DEPTH_LEVELS);
+        assert_eq!(zm.sell_side_depth[MAX_DEPTH_LEVELS - 1].price, 109);
+
+        zm.add_depth(false, 99, 1);
+        assert_eq!(zm.sell_side_depth[0].price, 99);
+        assert_eq!(zm.sell_side_depth[MAX_DEPTH_LEVELS - 1].price, 108);
+    }
+
+    #[test]
+    fn configured_depth_levels_cap_the_snapshot() {
+        let mut zm = zone();
+        assert_eq!(zm.depth_levels(), MAX_DEPTH_LEVELS);
+
+        zm.max_depth_levels = 2;
+        zm.add_depth(false, 60, 1);
+        zm.add_depth(false, 50, 1);
+        zm.add_depth(false, 70, 1);
+        assert_eq!(asks(&zm), vec![(50, 1, 1), (60, 1, 1)]);
+
+        zm.add_depth(false, 40, 1);
+        assert_eq!(asks(&zm), vec![(40, 1, 1), (50, 1, 1)]);
+    }
+
+    #[test]
+    fn total_depth_sums_each_side_and_saturates() {
+        let mut zm = zone();
+        assert_eq!(zm.total_depth(true), 0);
+        zm.add_depth(false, 50, 100);
+        zm.add_depth(false, 55, 40);
+        zm.add_depth(true, 45, 25);
+        assert_eq!(zm.total_depth(false), 140);
+        assert_eq!(zm.total_depth(true), 25);
+
+        zm.add_depth(true, 44, u64::MAX);
+        assert_eq!(zm.total_depth(true), u64::MAX);
+    }
+}
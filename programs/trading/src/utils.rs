@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use governance::GovernanceConfig;
 use crate::error::TradingError;
+use crate::state::PricePoint;
 
 pub fn get_governance_config(info: &AccountInfo) -> Result<GovernanceConfig> {
     let data = info.try_borrow_data()?;
@@ -10,3 +11,168 @@ pub fn get_governance_config(info: &AccountInfo) -> Result<GovernanceConfig> {
     let mut ptr = &data[8..];
     GovernanceConfig::deserialize(&mut ptr).map_err(|_| TradingError::InvalidGovernanceAccount.into())
 }
+
+/// Circuit-breaker check: true when `price` deviates from `reference` by more than
+/// `max_bps` basis points. A zero `reference` (no trade yet) or zero `max_bps`
+/// (breaker disabled) never trips. u128 so `diff * 10_000` can't overflow.
+pub fn exceeds_price_deviation(reference: u64, price: u64, max_bps: u16) -> bool {
+    if reference == 0 || max_bps == 0 {
+        return false;
+    }
+    let diff = reference.abs_diff(price) as u128;
+    diff * 10_000 > (reference as u128) * (max_bps as u128)
+}
+
+/// Maker/taker check: true when the crossing spread `buy_limit - sell_limit` is less
+/// than `min_bps` basis points of the ask. A zero `min_bps` (requirement disabled)
+/// never trips. Callers must have checked `buy_limit >= sell_limit` first.
+pub fn insufficient_price_improvement(buy_limit: u64, sell_limit: u64, min_bps: u16) -> bool {
+    if min_bps == 0 {
+        return false;
+    }
+    let spread = buy_limit.saturating_sub(sell_limit) as u128;
+    spread * 10_000 < (sell_limit as u128) * (min_bps as u128)
+}
+
+/// `amount * price` widened to u128, saturating at `u64::MAX` on narrowing.
+pub fn saturating_trade_value(amount: u64, price: u64) -> u64 {
+    u64::try_from(amount as u128 * price as u128).unwrap_or(u64::MAX)
+}
+
+/// VWAP over `points` (zero-volume slots skipped). u128 accumulators so
+/// `price * volume` summed across the history cannot overflow; the result is
+/// saturated back to u64. `None` when there is no volume.
+pub fn volume_weighted_price(points: &[PricePoint]) -> Option<u64> {
+    let mut total_volume: u128 = 0;
+    let mut total_value: u128 = 0;
+    for point in points.iter().filter(|p| p.volume > 0) {
+        total_volume += point.volume as u128;
+        total_value += point.volume as u128 * point.price as u128;
+    }
+    if total_volume == 0 {
+        return None;
+    }
+    Some(u64::try_from(total_value / total_volume).unwrap_or(u64::MAX))
+}
+
+/// The fields price-time priority needs from an order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OrderRef {
+    pub order_key: Pubkey,
+    pub price_per_kwh: u64,
+    pub created_at: i64,
+}
+
+/// Sort one side of a batch into price-time priority: buys by descending price,
+/// sells by ascending price, earlier `created_at` first within a price. The sort is
+/// stable, so orders equal on both keep their input order.
+pub fn sort_orders_for_matching(orders: &mut [OrderRef], is_buy: bool) {
+    orders.sort_by(|a, b| {
+        let by_price = if is_buy {
+            b.price_per_kwh.cmp(&a.price_per_kwh)
+        } else {
+            a.price_per_kwh.cmp(&b.price_per_kwh)
+        };
+        by_price.then(a.created_at.cmp(&b.created_at))
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_trade_skips_breaker() {
+        assert!(!exceeds_price_deviation(0, 1_000_000, 100));
+    }
+
+    #[test]
+    fn disabled_breaker_never_trips() {
+        assert!(!exceeds_price_deviation(100, 1_000_000, 0));
+    }
+
+    #[test]
+    fn deviation_at_limit_passes() {
+        // 5% of 1_000 = 50 — exactly at a 500 bps limit on both sides.
+        assert!(!exceeds_price_deviation(1_000, 1_050, 500));
+        assert!(!exceeds_price_deviation(1_000, 950, 500));
+    }
+
+    #[test]
+    fn deviation_over_limit_trips() {
+        assert!(exceeds_price_deviation(1_000, 1_051, 500));
+        assert!(exceeds_price_deviation(1_000, 949, 500));
+    }
+
+    #[test]
+    fn disabled_price_improvement_never_trips() {
+        assert!(!insufficient_price_improvement(1_000, 1_000, 0));
+    }
+
+    #[test]
+    fn price_improvement_at_threshold_passes() {
+        // 1% of a 1_000 ask = 10
+        assert!(!insufficient_price_improvement(1_010, 1_000, 100));
+        assert!(insufficient_price_improvement(1_009, 1_000, 100));
+        assert!(insufficient_price_improvement(1_000, 1_000, 1));
+    }
+
+    #[test]
+    fn large_prices_do_not_overflow() {
+        assert!(exceeds_price_deviation(u64::MAX / 2, u64::MAX, 100));
+    }
+
+    #[test]
+    fn trade_value_large_amount_does_not_overflow() {
+        // 10^12 Wh at 10^6 price = 10^18, fits; 10x that saturates instead of wrapping.
+        assert_eq!(saturating_trade_value(1_000_000_000_000, 1_000_000), 1_000_000_000_000_000_000);
+        assert_eq!(saturating_trade_value(10_000_000_000_000, 10_000_000), u64::MAX);
+    }
+
+    #[test]
+    fn vwap_large_history_does_not_overflow() {
+        let point = PricePoint { price: 1_000_000, volume: 1_000_000_000_000, timestamp: 0 };
+        // 24 * 10^18 overflows u64 in a u64 accumulator.
+        assert_eq!(volume_weighted_price(&[point; 24]), Some(1_000_000));
+    }
+
+    #[test]
+    fn vwap_weights_by_volume() {
+        let points = [
+            PricePoint { price: 50, volume: 100, timestamp: 0 },
+            PricePoint { price: 80, volume: 200, timestamp: 1 },
+            PricePoint { price: 999, volume: 0, timestamp: 2 },
+        ];
+        assert_eq!(volume_weighted_price(&points), Some(70));
+        assert_eq!(volume_weighted_price(&[]), None);
+    }
+
+    fn order(tag: u8, price: u64, created_at: i64) -> OrderRef {
+        OrderRef { order_key: Pubkey::new_from_array([tag; 32]), price_per_kwh: price, created_at }
+    }
+
+    fn tags(orders: &[OrderRef]) -> Vec<u8> {
+        orders.iter().map(|o| o.order_key.to_bytes()[0]).collect()
+    }
+
+    #[test]
+    fn buys_sort_by_descending_price_then_time() {
+        let mut orders = vec![order(1, 100, 30), order(2, 120, 50), order(3, 100, 10), order(4, 120, 20)];
+        sort_orders_for_matching(&mut orders, true);
+        assert_eq!(tags(&orders), vec![4, 2, 3, 1]);
+    }
+
+    #[test]
+    fn sells_sort_by_ascending_price_then_time() {
+        let mut orders = vec![order(1, 100, 30), order(2, 120, 50), order(3, 100, 10), order(4, 120, 20)];
+        sort_orders_for_matching(&mut orders, false);
+        assert_eq!(tags(&orders), vec![3, 1, 4, 2]);
+    }
+
+    #[test]
+    fn full_ties_keep_input_order() {
+        let mut orders = vec![order(7, 100, 5), order(8, 100, 5), order(9, 100, 5)];
+        sort_orders_for_matching(&mut orders, true);
+        assert_eq!(tags(&orders), vec![7, 8, 9]);
+    }
+}
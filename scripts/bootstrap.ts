@@ -70,7 +70,12 @@ async function main() {
       .rpc();
     console.log('  ✅ Oracle Authority set on Registry to:', authority.toBase58());
   } catch (e: any) {
-    console.log('  ❌ Failed to set Registry Oracle Authority:', e.message);
+    if (String(e.message).includes('OracleAlreadySet')) {
+      // Only the first oracle is set directly; rotations use propose/accept_oracle_authority
+      console.log('  ℹ️  Registry Oracle Authority already set');
+    } else {
+      console.log('  ❌ Failed to set Registry Oracle Authority:', e.message);
+    }
   }
 
   // 2. Initialize Energy Token
@@ -88,7 +93,7 @@ async function main() {
 
   try {
     const tx = await energyTokenProgram.methods
-      .initializeToken(registryProgram.programId, registryPda)
+      .initializeToken(registryProgram.programId, registryPda, new BN(0))
       .accounts({
         tokenInfo: tokenInfoPda,
         mint: mintPda,
@@ -114,7 +119,7 @@ async function main() {
        console.log('  ⚠️  Token-2022 might be required or standard Token failed. Attempting with Token-2022...');
        try {
          const tx = await energyTokenProgram.methods
-          .initializeToken(registryProgram.programId, authority)
+          .initializeToken(registryProgram.programId, authority, new BN(0))
           .accounts({
             tokenInfo: tokenInfoPda,
             mint: mintPda,
@@ -280,7 +285,7 @@ async function main() {
     console.log(`     Zone ${zoneId} PDA: ${zoneMarketPda.toBase58()}`); // Log zoneMarketPda unconditionally
     try {
       const tx = await tradingProgram.methods
-        .initializeZoneMarket(zoneId, 1, new BN(0)) // 1 shard per zone, 0 capacity (infinite)
+        .initializeZoneMarket(zoneId, 1, new BN(0), 10) // 1 shard per zone, 0 capacity (infinite)
         .accounts({
           market: marketPda,
           zoneMarket: zoneMarketPda,
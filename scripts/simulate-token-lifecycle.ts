@@ -156,7 +156,7 @@ async function main() {
     const balBefore = await provider.connection.getTokenAccountBalance(userAta);
     console.log(`   Balance before settlement: ${balBefore.value.uiAmount} GRX`);
 
-    const tx = await registryProgram.methods.settleAndMintTokens()
+    const tx = await registryProgram.methods.settleAndMintTokens(new BN(0))
       .accounts({
         registry: registryPda,
         meterAccount: registryMeterPda,
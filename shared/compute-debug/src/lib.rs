@@ -26,6 +26,11 @@
 //! Program consumption: YYYYY units remaining
 //! Program log: <<< my_instruction: end
 //! ```
+//!
+//! `compute_fn_measured!` additionally returns the consumed units as a value, so
+//! benchmark instructions can store them instead of relying on log scraping.
+//! `compute_span!` logs the units a block consumed as one number, for spans inside a
+//! handler (e.g. a token CPI) where subtracting two checkpoint lines gets tedious.
 
 /// Log the remaining compute units using the syscall directly.
 /// This is only active when the `localnet` feature is enabled.
@@ -47,6 +52,30 @@ pub fn log_compute_units_syscall() {
     // No-op when not on localnet
 }
 
+/// Read the remaining compute units using the value-returning syscall.
+/// Returns 0 off-chain and when the `localnet` feature is disabled.
+#[cfg(feature = "localnet")]
+#[inline(always)]
+pub fn remaining_compute_units() -> u64 {
+    #[cfg(target_os = "solana")]
+    unsafe {
+        extern "C" {
+            fn sol_remaining_compute_units() -> u64;
+        }
+        sol_remaining_compute_units()
+    }
+    #[cfg(not(target_os = "solana"))]
+    {
+        0
+    }
+}
+
+#[cfg(not(feature = "localnet"))]
+#[inline(always)]
+pub fn remaining_compute_units() -> u64 {
+    0
+}
+
 /// A macro for measuring compute units consumed by a block of code.
 ///
 /// This macro logs the compute units at the start and end of the block,
@@ -128,6 +157,51 @@ macro_rules! compute_fn_with_msg {
     }};
 }
 
+/// A macro that measures a block like `compute_fn!` and also returns the units it consumed.
+///
+/// Evaluates to `(result, consumed_cu)`. On `localnet` the remaining units are read before
+/// and after the block via `sol_remaining_compute_units`; otherwise it yields `(result, 0)`
+/// with no syscalls.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let (res, cu) = compute_fn_measured!("analytics_aggregate" => {
+///     instructions::analytics_aggregate(ctx, aggregation_type)
+/// });
+/// ```
+///
+/// # Output (localnet only)
+///
+/// ```text
+/// Program log: >>> analytics_aggregate: start
+/// ...
+/// Program log: <<< analytics_aggregate: end (1234 CU)
+/// ```
+#[macro_export]
+macro_rules! compute_fn_measured {
+    ($name:expr => $block:block) => {{
+        #[cfg(feature = "localnet")]
+        let __cu_start = {
+            anchor_lang::prelude::msg!(concat!(">>> ", $name, ": start"));
+            $crate::remaining_compute_units()
+        };
+
+        let result = $block;
+
+        #[cfg(feature = "localnet")]
+        let __cu_consumed = {
+            let consumed = __cu_start.saturating_sub($crate::remaining_compute_units());
+            anchor_lang::prelude::msg!(concat!("<<< ", $name, ": end ({} CU)"), consumed);
+            consumed
+        };
+        #[cfg(not(feature = "localnet"))]
+        let __cu_consumed: u64 = 0;
+
+        (result, __cu_consumed)
+    }};
+}
+
 /// Log a checkpoint with compute units.
 /// Use this to mark specific points in your code for CU measurement.
 ///
@@ -150,6 +224,44 @@ macro_rules! compute_checkpoint {
     }};
 }
 
+/// Log the compute units consumed inside a block as a single number.
+///
+/// Unlike a pair of `compute_checkpoint!`s there is nothing to subtract by hand: the
+/// remaining units are read before and after the block and the difference is logged.
+/// The figure includes one `sol_remaining_compute_units` call. Outside `localnet` this
+/// is the bare block. A `?` that returns early from the block skips the log line.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// compute_span!("stake_transfer_cpi" => {
+///     token_interface::transfer_checked(cpi_ctx, amount, decimals)?;
+/// });
+/// ```
+///
+/// # Output (localnet only)
+///
+/// ```text
+/// Program log: --- stake_transfer_cpi: 6123 CU
+/// ```
+#[macro_export]
+macro_rules! compute_span {
+    ($name:expr => $block:block) => {{
+        #[cfg(feature = "localnet")]
+        let __span_start = $crate::remaining_compute_units();
+
+        let result = $block;
+
+        #[cfg(feature = "localnet")]
+        {
+            let consumed = __span_start.saturating_sub($crate::remaining_compute_units());
+            anchor_lang::prelude::msg!(concat!("--- ", $name, ": {} CU"), consumed);
+        }
+
+        result
+    }};
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -168,4 +280,22 @@ mod tests {
         });
         assert_eq!(result, 4);
     }
+
+    #[test]
+    fn test_compute_fn_measured_returns_result_and_units() {
+        let (result, consumed) = compute_fn_measured!("measured_block" => {
+            3 + 3
+        });
+        assert_eq!(result, 6);
+        // Off-chain there is no compute meter to read.
+        assert_eq!(consumed, 0);
+    }
+
+    #[test]
+    fn test_compute_span_evaluates_to_block() {
+        let result = compute_span!("span_block" => {
+            4 + 4
+        });
+        assert_eq!(result, 8);
+    }
 }
@@ -0,0 +1,198 @@
+//! Common benchmark result format shared by the TPC-C and BLOCKBENCH programs.
+//!
+//! Each suite keeps its own running metrics account; at finalize time both
+//! project those metrics into `LatencySummary` + `ThroughputSummary` and emit
+//! them as one `BenchmarkResult` (the payload of each `BenchmarkFinalized`) so results
+//! from the two suites can be compared (and merged) without off-chain schema
+//! translation. Raw aggregates are carried alongside the derived values so a
+//! merge recomputes averages/rates instead of averaging averages.
+
+use anchor_lang::prelude::*;
+
+/// Which benchmark suite produced a summary
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BenchmarkSuite {
+    #[default]
+    Blockbench,
+    TpcC,
+}
+
+/// Latency distribution summary (microseconds)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LatencySummary {
+    /// Number of latency samples
+    pub samples: u64,
+
+    /// Sum of all sampled latencies
+    pub total_us: u64,
+
+    /// Smallest sampled latency (0 when there are no samples)
+    pub min_us: u64,
+
+    /// Largest sampled latency
+    pub max_us: u64,
+
+    /// Mean latency, `total_us / samples`
+    pub avg_us: u64,
+}
+
+impl LatencySummary {
+    pub const LEN: usize = 8 * 5;
+
+    /// Build a summary from raw aggregates. `min_us` is normalized to 0 when
+    /// there are no samples (suites seed their running minimum with `u64::MAX`).
+    pub fn new(samples: u64, total_us: u64, min_us: u64, max_us: u64) -> Self {
+        Self {
+            samples,
+            total_us,
+            min_us: if samples == 0 { 0 } else { min_us },
+            max_us,
+            avg_us: total_us.checked_div(samples).unwrap_or(0),
+        }
+    }
+
+    /// Combine two summaries as if their samples came from one run
+    pub fn merge(&self, other: &Self) -> Self {
+        let min_us = match (self.samples, other.samples) {
+            (0, _) => other.min_us,
+            (_, 0) => self.min_us,
+            _ => self.min_us.min(other.min_us),
+        };
+        Self::new(
+            self.samples.saturating_add(other.samples),
+            self.total_us.saturating_add(other.total_us),
+            min_us,
+            self.max_us.max(other.max_us),
+        )
+    }
+}
+
+/// Throughput summary for a finalized run
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ThroughputSummary {
+    /// Successful transactions
+    pub successful: u64,
+
+    /// Failed transactions
+    pub failed: u64,
+
+    /// Wall-clock duration of the run (at least 1 second)
+    pub duration_seconds: u64,
+
+    /// Successful transactions per second
+    pub tps: u64,
+
+    /// Success rate (basis points, 10000 = 100%)
+    pub success_rate_bps: u16,
+}
+
+impl ThroughputSummary {
+    pub const LEN: usize = 8 * 4 + 2;
+
+    /// Build a summary from raw counts. A zero duration is clamped to 1 second.
+    pub fn new(successful: u64, failed: u64, duration_seconds: u64) -> Self {
+        let duration_seconds = duration_seconds.max(1);
+        let total = successful.saturating_add(failed) as u128;
+        let success_rate_bps = (successful as u128 * 10_000)
+            .checked_div(total)
+            .unwrap_or(0) as u16;
+        Self {
+            successful,
+            failed,
+            duration_seconds,
+            tps: successful / duration_seconds,
+            success_rate_bps,
+        }
+    }
+
+    /// Combine two runs executed back to back (durations add)
+    pub fn merge(&self, other: &Self) -> Self {
+        Self::new(
+            self.successful.saturating_add(other.successful),
+            self.failed.saturating_add(other.failed),
+            self.duration_seconds.saturating_add(other.duration_seconds),
+        )
+    }
+}
+
+/// One finalized run in the cross-suite format. Both suites emit exactly this type as the
+/// payload of their `BenchmarkFinalized` event, so consumers decode a single layout.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BenchmarkResult {
+    /// Suite that produced the run
+    pub suite: BenchmarkSuite,
+
+    /// The suite's run counter, advanced by its reset instruction
+    pub run_id: u64,
+
+    pub latency: LatencySummary,
+    pub throughput: ThroughputSummary,
+
+    /// Finalization time
+    pub timestamp: i64,
+}
+
+/// Merge two suite summaries into one `(latency, throughput)` pair
+pub fn merge_summaries(
+    a: (&LatencySummary, &ThroughputSummary),
+    b: (&LatencySummary, &ThroughputSummary),
+) -> (LatencySummary, ThroughputSummary) {
+    (a.0.merge(b.0), a.1.merge(b.1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn latency_without_samples_is_zeroed() {
+        let l = LatencySummary::new(0, 0, u64::MAX, 0);
+        assert_eq!(l.min_us, 0);
+        assert_eq!(l.avg_us, 0);
+    }
+
+    #[test]
+    fn latency_merge_recomputes_average() {
+        let a = LatencySummary::new(2, 200, 50, 150);
+        let b = LatencySummary::new(3, 900, 100, 500);
+        let m = a.merge(&b);
+        assert_eq!(m.samples, 5);
+        assert_eq!(m.total_us, 1_100);
+        assert_eq!(m.min_us, 50);
+        assert_eq!(m.max_us, 500);
+        assert_eq!(m.avg_us, 220);
+    }
+
+    #[test]
+    fn latency_merge_ignores_empty_side_minimum() {
+        let a = LatencySummary::new(0, 0, u64::MAX, 0);
+        let b = LatencySummary::new(1, 70, 70, 70);
+        assert_eq!(a.merge(&b).min_us, 70);
+        assert_eq!(b.merge(&a).min_us, 70);
+    }
+
+    #[test]
+    fn throughput_rates() {
+        let t = ThroughputSummary::new(90, 10, 10);
+        assert_eq!(t.tps, 9);
+        assert_eq!(t.success_rate_bps, 9_000);
+    }
+
+    #[test]
+    fn throughput_zero_duration_clamped() {
+        let t = ThroughputSummary::new(5, 0, 0);
+        assert_eq!(t.duration_seconds, 1);
+        assert_eq!(t.tps, 5);
+    }
+
+    #[test]
+    fn merge_summaries_combines_both_halves() {
+        let (l, t) = merge_summaries(
+            (&LatencySummary::new(1, 10, 10, 10), &ThroughputSummary::new(10, 0, 10)),
+            (&LatencySummary::new(1, 30, 30, 30), &ThroughputSummary::new(30, 10, 10)),
+        );
+        assert_eq!(l.avg_us, 20);
+        assert_eq!(t.tps, 2);
+        assert_eq!(t.success_rate_bps, 8_000);
+    }
+}
@@ -4,13 +4,17 @@
 //!
 //! ## Modules
 //!
-//! - `version`: Program version tracking for upgradeable programs
+//! - `version`: Program version tracking for upgradeable programs, plus the
+//!   `SemanticVersion` compatibility check
+//! - `benchmark`: Common latency/throughput summary format for the benchmark suites
 
 use anchor_lang::prelude::*;
 
 // Required by #[account] macro — placeholder ID for shared library
 declare_id!("GTXShared1111111111111111111111111111111111");
 
+pub mod benchmark;
 pub mod version;
 
+pub use benchmark::*;
 pub use version::*;
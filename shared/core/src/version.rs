@@ -1,5 +1,39 @@
 use anchor_lang::prelude::*;
 
+/// Semantic `major.minor.patch` version, small enough to embed in a PDA or CPI payload
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SemanticVersion {
+    pub major: u16,
+    pub minor: u16,
+    pub patch: u16,
+}
+
+impl SemanticVersion {
+    pub const LEN: usize = 2 * 3;
+
+    /// Version of this shared crate; kept in sync with Cargo.toml (see tests)
+    pub const CURRENT: Self = Self::new(0, 1, 0);
+
+    pub const fn new(major: u16, minor: u16, patch: u16) -> Self {
+        Self { major, minor, patch }
+    }
+
+    /// Semver-style compatibility: majors must match, and while the major is 0
+    /// every minor bump is breaking too. Patch releases are always compatible.
+    pub fn is_compatible(&self, other: &SemanticVersion) -> bool {
+        if self.major != other.major {
+            return false;
+        }
+        self.major != 0 || self.minor == other.minor
+    }
+
+    /// `is_compatible` as a `Result`, for rejecting CPIs from incompatible callers
+    pub fn require_compatible(&self, other: &SemanticVersion) -> Result<()> {
+        require!(self.is_compatible(other), VersionError::IncompatibleVersion);
+        Ok(())
+    }
+}
+
 /// Program version tracking for upgradeable programs
 /// This account stores version information and migration state
 #[account]
@@ -16,8 +50,11 @@ pub struct ProgramVersion {
     /// Whether the program is currently paused for migration
     pub is_migrating: bool,
 
+    /// Semantic version of the deployed program (carved from `_reserved`)
+    pub semver: SemanticVersion,
+
     /// Reserved for future use
-    pub _reserved: [u8; 64],
+    pub _reserved: [u8; 58],
 }
 
 impl Default for ProgramVersion {
@@ -27,7 +64,8 @@ impl Default for ProgramVersion {
             last_upgrade: 0,
             upgrade_authority: Pubkey::default(),
             is_migrating: false,
-            _reserved: [0u8; 64],
+            semver: SemanticVersion::default(),
+            _reserved: [0u8; 58],
         }
     }
 }
@@ -38,7 +76,8 @@ impl ProgramVersion {
         8 +  // last_upgrade
         32 + // upgrade_authority
         1 +  // is_migrating
-        64; // reserved
+        SemanticVersion::LEN + // semver
+        58; // reserved
 }
 
 /// Version history entry for audit trail
@@ -132,6 +171,9 @@ pub enum VersionError {
 
     #[msg("Cannot downgrade version")]
     CannotDowngrade,
+
+    #[msg("Incompatible program version")]
+    IncompatibleVersion,
 }
 
 /// Events for version tracking
@@ -161,3 +203,45 @@ pub struct MigrationCompleted {
     pub migrated_accounts: u64,
     pub duration_seconds: i64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn current_matches_crate_version() {
+        let v = SemanticVersion::CURRENT;
+        assert_eq!(
+            format!("{}.{}.{}", v.major, v.minor, v.patch),
+            env!("CARGO_PKG_VERSION")
+        );
+    }
+
+    #[test]
+    fn patch_bumps_are_compatible() {
+        let base = SemanticVersion::new(1, 2, 3);
+        assert!(base.is_compatible(&SemanticVersion::new(1, 2, 0)));
+        assert!(base.is_compatible(&SemanticVersion::new(1, 2, 9)));
+        assert!(SemanticVersion::new(0, 1, 0).is_compatible(&SemanticVersion::new(0, 1, 7)));
+    }
+
+    #[test]
+    fn minor_bumps_break_only_pre_1_0() {
+        assert!(SemanticVersion::new(1, 2, 0).is_compatible(&SemanticVersion::new(1, 5, 0)));
+        assert!(!SemanticVersion::new(0, 1, 0).is_compatible(&SemanticVersion::new(0, 2, 0)));
+    }
+
+    #[test]
+    fn major_bumps_are_incompatible() {
+        assert!(!SemanticVersion::new(1, 0, 0).is_compatible(&SemanticVersion::new(2, 0, 0)));
+        assert!(!SemanticVersion::new(1, 9, 9).is_compatible(&SemanticVersion::new(0, 9, 9)));
+        assert!(SemanticVersion::new(2, 0, 0)
+            .require_compatible(&SemanticVersion::new(3, 0, 0))
+            .is_err());
+    }
+
+    #[test]
+    fn program_version_len_is_unchanged() {
+        assert_eq!(ProgramVersion::LEN, 8 + 2 + 8 + 32 + 1 + 64);
+    }
+}
@@ -110,7 +110,7 @@ describe("execute_atomic_settlement REC leg (litesvm)", () => {
     [cfgPda] = PublicKey.findProgramAddressSync([Buffer.from("poa_config")], governanceId);
 
     await send([await trading.methods.initializeMarket(16).accounts({ market: marketPda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()]);
-    await send([await trading.methods.initializeZoneMarket(ZONE, 16, new BN(1_000_000)).accounts({ market: marketPda, zoneMarket: zoneMarketPda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()]);
+    await send([await trading.methods.initializeZoneMarket(ZONE, 16, new BN(1_000_000), 10).accounts({ market: marketPda, zoneMarket: zoneMarketPda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()]);
 
     // Fabricate the governance poa_config (operational) so the gate + create-order pass.
     const now = 1_000_000;
@@ -123,7 +123,7 @@ describe("execute_atomic_settlement REC leg (litesvm)", () => {
       totalErcsIssued: new BN(0), totalErcsValidated: new BN(0), totalErcsRevoked: new BN(0),
       totalEnergyCertified: new BN(0), createdAt: new BN(now), lastUpdated: new BN(now), lastErcIssuedAt: new BN(0),
       pendingAuthority: PublicKey.default, pendingAuthorityProposedAt: new BN(0), pendingAuthorityExpiresAt: new BN(0),
-      reserved: Array(5).fill(0),
+      minFeeBps: 0, maxFeeBps: 0, reserved: Array(1).fill(0),
     };
     const data = await governance.coder.accounts.encode("governanceConfig", cfg as any);
     svm.setAccount(cfgPda, {
@@ -104,7 +104,7 @@ describe("trading call-auction instruction wrappers (litesvm)", () => {
       pendingAuthority: PublicKey.default,
       pendingAuthorityProposedAt: new BN(0),
       pendingAuthorityExpiresAt: new BN(0),
-      reserved: Array(5).fill(0),
+      minFeeBps: 0, maxFeeBps: 0, reserved: Array(1).fill(0),
     };
     const data = await governance.coder.accounts.encode("governanceConfig", cfg as any);
     svm.setAccount(key, {
@@ -180,7 +180,7 @@ describe("trading call-auction instruction wrappers (litesvm)", () => {
       [Buffer.from("zone_market"), marketPda.toBuffer(), new BN(ZONE).toArrayLike(Buffer, "le", 4)], tradingId);
 
     send([await trading.methods.initializeMarket(16).accounts({ market: marketPda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()], []);
-    send([await trading.methods.initializeZoneMarket(ZONE, 16, new BN(1_000_000)).accounts({ market: marketPda, zoneMarket: zoneMarketPda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()], []);
+    send([await trading.methods.initializeZoneMarket(ZONE, 16, new BN(1_000_000), 10).accounts({ market: marketPda, zoneMarket: zoneMarketPda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()], []);
 
     svm.setClock(new Clock(svm.getClock().slot, 0n, 0n, 0n, BigInt(NOW)));
   });
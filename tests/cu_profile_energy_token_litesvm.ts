@@ -93,7 +93,7 @@ describe("energy-token CU profile (litesvm)", () => {
   });
 
   it("energy_token.initialize_token", async () => {
-    const ix = await program.methods.initializeToken(PublicKey.default, payer.publicKey).accounts({
+    const ix = await program.methods.initializeToken(PublicKey.default, payer.publicKey, new BN(0)).accounts({
       tokenInfo: infoPda, mint: mintPda, authority: payer.publicKey,
       systemProgram: SystemProgram.programId, tokenProgram: TOKEN_2022_PROGRAM_ID, rent: anchorPkg.web3.SYSVAR_RENT_PUBKEY,
     } as any).instruction();
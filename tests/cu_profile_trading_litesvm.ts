@@ -87,7 +87,7 @@ describe("trading CDA order-path CU profile (litesvm)", () => {
       ercValidityPeriod: new BN(0), requireOracleValidation: false, oracleAuthority: PublicKey.default, minOracleConfidence: 0,
       allowCertificateTransfers: true, minQuorumVotes: new BN(0), totalErcsIssued: new BN(0), totalErcsValidated: new BN(0),
       totalErcsRevoked: new BN(0), totalEnergyCertified: new BN(0), createdAt: new BN(0), lastUpdated: new BN(0), lastErcIssuedAt: new BN(0),
-      pendingAuthority: PublicKey.default, pendingAuthorityProposedAt: new BN(0), pendingAuthorityExpiresAt: new BN(0), reserved: Array(5).fill(0),
+      pendingAuthority: PublicKey.default, pendingAuthorityProposedAt: new BN(0), pendingAuthorityExpiresAt: new BN(0), minFeeBps: 0, maxFeeBps: 0, reserved: Array(1).fill(0),
     };
     const data = await governance.coder.accounts.encode("governanceConfig", c as any);
     svm.setAccount(key, { lamports: Number(svm.minimumBalanceForRentExemption(BigInt(data.length))), data, owner: governanceId, executable: false, rentEpoch: 0 } as any);
@@ -95,12 +95,17 @@ describe("trading CDA order-path CU profile (litesvm)", () => {
   }
 
   async function installErc(energyAmount: number): Promise<PublicKey> {
-    const key = fixedKeypair(11).publicKey;
+    // Real ["erc_certificate", id] PDA: governance re-derives it when the order reserves energy.
+    const id = Buffer.from("CU-ERC");
+    const [key] = PublicKey.findProgramAddressSync([Buffer.from("erc_certificate"), id], governanceId);
+    const certificateId = Array(64).fill(0);
+    id.forEach((b, i) => (certificateId[i] = b));
     const e = {
-      certificateId: Array(64).fill(0), idLen: 0, authority: payer.publicKey, owner: payer.publicKey, energyAmount: new BN(energyAmount),
+      certificateId, idLen: id.length, authority: payer.publicKey, owner: payer.publicKey, energyAmount: new BN(energyAmount),
       renewableSource: Array(64).fill(0), sourceLen: 0, validationData: Array(256).fill(0), dataLen: 0, issuedAt: new BN(0),
       expiresAt: new BN(FUTURE), status: { valid: {} }, validatedForTrading: true, tradingValidatedAt: null,
       revocationReason: Array(128).fill(0), reasonLen: 0, revokedAt: null, transferCount: 0, lastTransferredAt: null,
+      meterAccount: PublicKey.default, reservedAmount: new BN(0),
     };
     const data = await governance.coder.accounts.encode("ercCertificate", e as any);
     svm.setAccount(key, { lamports: Number(svm.minimumBalanceForRentExemption(BigInt(data.length))), data, owner: governanceId, executable: false, rentEpoch: 0 } as any);
@@ -109,11 +114,17 @@ describe("trading CDA order-path CU profile (litesvm)", () => {
 
   const sellIx = (id: number, amt: number, price: number) =>
     trading.methods.createSellOrder(new BN(id), new BN(amt), new BN(price))
-      .accounts({ market: marketPda, zoneMarket: zoneMarketPda, order: orderPda(payer.publicKey, id), ercCertificate: erc, authority: payer.publicKey, systemProgram: SystemProgram.programId, governanceConfig: cfg } as any).instruction();
+      .accounts({ market: marketPda, zoneMarket: zoneMarketPda, order: orderPda(payer.publicKey, id), ercCertificate: erc, authority: payer.publicKey, systemProgram: SystemProgram.programId, governanceConfig: cfg, marketAuthority: marketAuthorityPda, governanceProgram: governanceId } as any).instruction();
   const buyIx = (id: number, amt: number, maxPrice: number) =>
     trading.methods.createBuyOrder(new BN(id), new BN(amt), new BN(maxPrice))
       .accounts({ market: marketPda, zoneMarket: zoneMarketPda, order: orderPda(payer.publicKey, id), authority: payer.publicKey, systemProgram: SystemProgram.programId, governanceConfig: cfg } as any).instruction();
 
+  const tradePda = () => {
+    const m: any = trading.coder.accounts.decode("market", Buffer.from(svm.getAccount(marketPda)!.data));
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("trade"), marketPda.toBuffer(), m.tradeSeq.toArrayLike(Buffer, "le", 8)], tradingId)[0];
+  };
+
   before(async () => {
     svm = new LiteSVM().withDefaultPrograms();
     trading = new Program(tradingIdl, { connection: {}, publicKey: PublicKey.default } as any);
@@ -147,7 +158,7 @@ describe("trading CDA order-path CU profile (litesvm)", () => {
   });
 
   it("trading.initialize_zone_market", async () => {
-    const ix = await trading.methods.initializeZoneMarket(ZONE, 16, new BN(1_000_000)).accounts({ market: marketPda, zoneMarket: zoneMarketPda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction();
+    const ix = await trading.methods.initializeZoneMarket(ZONE, 16, new BN(1_000_000), 10).accounts({ market: marketPda, zoneMarket: zoneMarketPda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction();
     expect(cu("trading.initialize_zone_market", ix)).to.be.below(BUDGET);
   });
 
@@ -179,10 +190,11 @@ describe("trading CDA order-path CU profile (litesvm)", () => {
 
   it("trading.match_orders (CDA)", async () => {
     const buy = orderPda(payer.publicKey, 2), sell = orderPda(payer.publicKey, 1);
-    const tradeRecord = PublicKey.findProgramAddressSync([Buffer.from("trade"), buy.toBuffer(), sell.toBuffer()], tradingId)[0];
+    const tradeRecord = tradePda();
     const ix = await trading.methods.matchOrders(new BN(100)).accounts({
       market: marketPda, zoneMarket: zoneMarketPda, buyOrder: buy, sellOrder: sell, tradeRecord,
       authority: payer.publicKey, governanceConfig: cfg, systemProgram: SystemProgram.programId,
+      buyerStats: PublicKey.findProgramAddressSync([Buffer.from("trader"), payer.publicKey.toBuffer()], tradingId)[0],
     } as any).instruction();
     expect(cu("trading.match_orders", ix)).to.be.below(BUDGET);
   });
@@ -191,6 +203,7 @@ describe("trading CDA order-path CU profile (litesvm)", () => {
     send([await sellIx(3, 100, 50)]); // fresh Active order
     const ix = await trading.methods.cancelOrder().accounts({
       market: marketPda, zoneMarket: zoneMarketPda, order: orderPda(payer.publicKey, 3), authority: payer.publicKey, governanceConfig: cfg,
+      traderStats: PublicKey.findProgramAddressSync([Buffer.from("trader"), payer.publicKey.toBuffer()], tradingId)[0],
     } as any).instruction();
     expect(cu("trading.cancel_order", ix)).to.be.below(BUDGET);
   });
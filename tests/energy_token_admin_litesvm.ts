@@ -25,6 +25,7 @@ import {
   SYSVAR_INSTRUCTIONS_PUBKEY,
 } from "@solana/web3.js";
 import { TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
+import BN from "bn.js";
 import { createRequire } from "module";
 
 const require = createRequire(import.meta.url);
@@ -86,7 +87,7 @@ describe("energy-token admin: set_registry_authority + create_token_mint (litesv
     [mintPda] = PublicKey.findProgramAddressSync([Buffer.from("mint_2022")], programId);
     [infoPda] = PublicKey.findProgramAddressSync([Buffer.from("token_info_2022")], programId);
 
-    send([await program.methods.initializeToken(PublicKey.default, payer.publicKey).accounts({
+    send([await program.methods.initializeToken(PublicKey.default, payer.publicKey, new BN(0)).accounts({
       tokenInfo: infoPda, mint: mintPda, authority: payer.publicKey,
       systemProgram: SystemProgram.programId, tokenProgram: TOKEN_2022_PROGRAM_ID, rent: anchorPkg.web3.SYSVAR_RENT_PUBKEY,
     } as any).instruction()]);
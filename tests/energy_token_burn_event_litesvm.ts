@@ -0,0 +1,93 @@
+// Litesvm coverage for the energy-token TokensBurned event: burn_tokens emits the burned
+// token account, its owner wallet, and the amount, so indexers can follow supply changes
+// from logs alone (mints already emit TokensMinted / GridTokensMinted).
+
+import { LiteSVM, FailedTransactionMetadata, TransactionMetadata } from "litesvm";
+import * as anchorPkg from "@anchor-lang/core";
+import { Program, EventParser } from "@anchor-lang/core";
+import { EnergyToken } from "../target/types/energy_token";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  Transaction,
+  SystemProgram,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import {
+  TOKEN_2022_PROGRAM_ID,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  createAssociatedTokenAccountInstruction,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+import BN from "bn.js";
+import { createRequire } from "module";
+
+const require = createRequire(import.meta.url);
+const idl = require("../target/idl/energy_token.json");
+
+describe("energy-token burn_tokens event (litesvm)", () => {
+  let svm: LiteSVM;
+  let program: Program<EnergyToken>;
+  let programId: PublicKey;
+
+  const payer = Keypair.generate(); // token authority (admin) + funder
+  const holder = Keypair.generate();
+  const rec = Keypair.generate(); // registered REC validator
+
+  let mintPda: PublicKey;
+  let infoPda: PublicKey;
+  let holderAta: PublicKey;
+
+  function sendWithLogs(ixs: TransactionInstruction[], signers: Keypair[] = []): string[] {
+    const tx = new Transaction();
+    tx.recentBlockhash = svm.latestBlockhash();
+    tx.feePayer = payer.publicKey;
+    ixs.forEach((ix) => tx.add(ix));
+    tx.sign(payer, ...signers);
+    const res = svm.sendTransaction(tx);
+    svm.expireBlockhash();
+    if (res instanceof FailedTransactionMetadata) {
+      throw new Error("tx failed: " + res.err().toString() + "\n" + res.meta().logs().join("\n"));
+    }
+    return (res as TransactionMetadata).logs();
+  }
+
+  before(async () => {
+    svm = new LiteSVM().withDefaultPrograms();
+    program = new Program(idl, { connection: {}, publicKey: PublicKey.default } as any);
+    programId = program.programId;
+    svm.addProgramFromFile(programId, "target/deploy/energy_token.so");
+    svm.airdrop(payer.publicKey, BigInt(1_000_000_000_000));
+
+    [mintPda] = PublicKey.findProgramAddressSync([Buffer.from("mint_2022")], programId);
+    [infoPda] = PublicKey.findProgramAddressSync([Buffer.from("token_info_2022")], programId);
+
+    sendWithLogs([await program.methods.initializeToken(PublicKey.default, payer.publicKey, new BN(0)).accounts({
+      tokenInfo: infoPda, mint: mintPda, authority: payer.publicKey,
+      systemProgram: SystemProgram.programId, tokenProgram: TOKEN_2022_PROGRAM_ID, rent: anchorPkg.web3.SYSVAR_RENT_PUBKEY,
+    } as any).instruction()]);
+
+    holderAta = getAssociatedTokenAddressSync(mintPda, holder.publicKey, false, TOKEN_2022_PROGRAM_ID);
+    sendWithLogs([createAssociatedTokenAccountInstruction(payer.publicKey, holderAta, holder.publicKey, mintPda, TOKEN_2022_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID)]);
+    sendWithLogs([await program.methods.addRecValidator(rec.publicKey, "rec").accounts({ tokenInfo: infoPda, authority: payer.publicKey } as any).instruction()]);
+    sendWithLogs([await program.methods.mintToWallet(new BN(1_000)).accounts({
+      mint: mintPda, tokenInfo: infoPda, destination: holderAta, destinationOwner: holder.publicKey,
+      authority: payer.publicKey, recValidator: rec.publicKey, payer: payer.publicKey,
+      tokenProgram: TOKEN_2022_PROGRAM_ID, associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID, systemProgram: SystemProgram.programId,
+    } as any).instruction()], [rec]);
+  });
+
+  it("emits TokensBurned with owner, token account, and amount", async () => {
+    const logs = sendWithLogs([await program.methods.burnTokens(new BN(250)).accounts({
+      mint: mintPda, tokenAccount: holderAta, authority: holder.publicKey, tokenProgram: TOKEN_2022_PROGRAM_ID,
+    } as any).instruction()], [holder]);
+
+    const parser = new EventParser(programId, (program as any).coder);
+    const ev = [...parser.parseLogs(logs)].find((e) => e.name === "TokensBurned" || e.name === "tokensBurned");
+    expect(ev, "TokensBurned emitted").to.not.be.undefined;
+    expect(ev!.data.owner.toBase58()).to.equal(holder.publicKey.toBase58());
+    expect(ev!.data.tokenAccount.toBase58()).to.equal(holderAta.toBase58());
+    expect(ev!.data.amount.toNumber()).to.equal(250);
+  });
+});
@@ -0,0 +1,128 @@
+// Litesvm coverage for energy-token set_mint_paused: while paused, mint_to_wallet rejects
+// with MintingPaused (mint_generation and mint_tokens_direct share the guard), but
+// transfer_tokens and burn_tokens keep working. Only token_info.authority may toggle it.
+
+import { LiteSVM, FailedTransactionMetadata } from "litesvm";
+import * as anchorPkg from "@anchor-lang/core";
+import { Program } from "@anchor-lang/core";
+import { EnergyToken } from "../target/types/energy_token";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  Transaction,
+  SystemProgram,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import {
+  TOKEN_2022_PROGRAM_ID,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  createAssociatedTokenAccountInstruction,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+import BN from "bn.js";
+import { createRequire } from "module";
+
+const require = createRequire(import.meta.url);
+const idl = require("../target/idl/energy_token.json");
+
+describe("energy-token mint pause (litesvm)", () => {
+  let svm: LiteSVM;
+  let program: Program<EnergyToken>;
+  let programId: PublicKey;
+
+  const payer = Keypair.generate(); // token authority (admin) + funder
+  const holder = Keypair.generate();
+  const other = Keypair.generate();
+  const rec = Keypair.generate(); // registered REC validator
+  const attacker = Keypair.generate();
+
+  let mintPda: PublicKey;
+  let infoPda: PublicKey;
+  let holderAta: PublicKey;
+  let otherAta: PublicKey;
+
+  function trySend(ixs: TransactionInstruction[], signers: Keypair[]): FailedTransactionMetadata | null {
+    const tx = new Transaction();
+    tx.recentBlockhash = svm.latestBlockhash();
+    tx.feePayer = payer.publicKey;
+    ixs.forEach((ix) => tx.add(ix));
+    tx.sign(payer, ...signers);
+    const res = svm.sendTransaction(tx);
+    svm.expireBlockhash();
+    return res instanceof FailedTransactionMetadata ? res : null;
+  }
+  function send(ixs: TransactionInstruction[], signers: Keypair[] = []) {
+    const f = trySend(ixs, signers);
+    if (f) throw new Error("tx failed: " + f.err().toString() + "\n" + f.meta().logs().join("\n"));
+  }
+  function sendExpectFail(ixs: TransactionInstruction[], signers: Keypair[] = []): string {
+    const f = trySend(ixs, signers);
+    if (!f) throw new Error("expected tx to fail but it succeeded");
+    return f.err().toString() + "\n" + f.meta().logs().join("\n");
+  }
+
+  const pauseIx = (paused: boolean, authority: PublicKey = payer.publicKey) =>
+    program.methods.setMintPaused(paused).accounts({ tokenInfo: infoPda, authority } as any).instruction();
+  const mintIx = (amount: number) =>
+    program.methods.mintToWallet(new BN(amount)).accounts({
+      mint: mintPda, tokenInfo: infoPda, destination: holderAta, destinationOwner: holder.publicKey,
+      authority: payer.publicKey, recValidator: rec.publicKey, payer: payer.publicKey,
+      tokenProgram: TOKEN_2022_PROGRAM_ID, associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID, systemProgram: SystemProgram.programId,
+    } as any).instruction();
+  const decodeInfo = (): any =>
+    program.coder.accounts.decode("tokenInfo", Buffer.from(svm.getAccount(infoPda)!.data));
+
+  before(async () => {
+    svm = new LiteSVM().withDefaultPrograms();
+    program = new Program(idl, { connection: {}, publicKey: PublicKey.default } as any);
+    programId = program.programId;
+    svm.addProgramFromFile(programId, "target/deploy/energy_token.so");
+    svm.airdrop(payer.publicKey, BigInt(1_000_000_000_000));
+
+    [mintPda] = PublicKey.findProgramAddressSync([Buffer.from("mint_2022")], programId);
+    [infoPda] = PublicKey.findProgramAddressSync([Buffer.from("token_info_2022")], programId);
+
+    send([await program.methods.initializeToken(PublicKey.default, payer.publicKey, new BN(0)).accounts({
+      tokenInfo: infoPda, mint: mintPda, authority: payer.publicKey,
+      systemProgram: SystemProgram.programId, tokenProgram: TOKEN_2022_PROGRAM_ID, rent: anchorPkg.web3.SYSVAR_RENT_PUBKEY,
+    } as any).instruction()]);
+
+    holderAta = getAssociatedTokenAddressSync(mintPda, holder.publicKey, false, TOKEN_2022_PROGRAM_ID);
+    otherAta = getAssociatedTokenAddressSync(mintPda, other.publicKey, false, TOKEN_2022_PROGRAM_ID);
+    send([
+      createAssociatedTokenAccountInstruction(payer.publicKey, holderAta, holder.publicKey, mintPda, TOKEN_2022_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID),
+      createAssociatedTokenAccountInstruction(payer.publicKey, otherAta, other.publicKey, mintPda, TOKEN_2022_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID),
+    ]);
+    send([await program.methods.addRecValidator(rec.publicKey, "rec").accounts({ tokenInfo: infoPda, authority: payer.publicKey } as any).instruction()]);
+    send([await mintIx(1_000)], [rec]);
+  });
+
+  it("rejects a pause from a non-authority signer (UnauthorizedAuthority)", async () => {
+    const blob = sendExpectFail([await pauseIx(true, attacker.publicKey)], [attacker]);
+    expect(blob, blob).to.match(/UnauthorizedAuthority/);
+  });
+
+  it("rejects mints while paused (MintingPaused)", async () => {
+    send([await pauseIx(true)]);
+    expect(decodeInfo().mintPaused).to.equal(1);
+    const blob = sendExpectFail([await mintIx(100)], [rec]);
+    expect(blob, blob).to.match(/MintingPaused/);
+  });
+
+  it("still allows transfers and burns while paused", async () => {
+    send([await program.methods.transferTokens(new BN(300)).accounts({
+      fromTokenAccount: holderAta, toTokenAccount: otherAta, mint: mintPda,
+      fromAuthority: holder.publicKey, tokenProgram: TOKEN_2022_PROGRAM_ID,
+    } as any).instruction()], [holder]);
+    send([await program.methods.burnTokens(new BN(100)).accounts({
+      mint: mintPda, tokenAccount: otherAta, authority: other.publicKey, tokenProgram: TOKEN_2022_PROGRAM_ID,
+    } as any).instruction()], [other]);
+  });
+
+  it("resumes minting after unpause", async () => {
+    send([await pauseIx(false)]);
+    expect(decodeInfo().mintPaused).to.equal(0);
+    send([await mintIx(100)], [rec]);
+  });
+});
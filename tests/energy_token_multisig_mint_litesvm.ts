@@ -0,0 +1,228 @@
+// Litesvm coverage for the energy-token M-of-N mint authority (2-of-3 here):
+//   - set_authority_config: first call by token_info.authority only; afterwards the
+//     current threshold must co-sign (InsufficientApprovals), and mint_to_wallet,
+//     mint_generation, set_authority and set_registry_authority are locked out
+//     (MultisigRequired). Delegated minters are cleared and can no longer use
+//     mint_tokens_direct.
+//   - propose_mint / approve_mint: only listed signers (NotConfiguredSigner), no double
+//     approval (AlreadyApproved), the executing approval needs the REC co-signer, and an
+//     executed proposal cannot mint again (ProposalAlreadyExecuted).
+
+import { LiteSVM, FailedTransactionMetadata } from "litesvm";
+import * as anchorPkg from "@anchor-lang/core";
+import { Program } from "@anchor-lang/core";
+import { EnergyToken } from "../target/types/energy_token";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  Transaction,
+  SystemProgram,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import {
+  TOKEN_2022_PROGRAM_ID,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  createAssociatedTokenAccountInstruction,
+  getAssociatedTokenAddressSync,
+  unpackAccount,
+} from "@solana/spl-token";
+import BN from "bn.js";
+import { createRequire } from "module";
+
+const require = createRequire(import.meta.url);
+const idl = require("../target/idl/energy_token.json");
+
+describe("energy-token multi-signer mint authority (litesvm)", () => {
+  let svm: LiteSVM;
+  let program: Program<EnergyToken>;
+  let programId: PublicKey;
+
+  const payer = Keypair.generate(); // token authority (admin) + funder
+  const holder = Keypair.generate();
+  const rec = Keypair.generate(); // registered REC validator
+  const [s1, s2, s3] = [Keypair.generate(), Keypair.generate(), Keypair.generate()];
+  const outsider = Keypair.generate();
+  const delegate = Keypair.generate(); // delegated minter added before the config
+
+  let mintPda: PublicKey;
+  let infoPda: PublicKey;
+  let configPda: PublicKey;
+  let minterConfigPda: PublicKey;
+  let holderAta: PublicKey;
+
+  function trySend(ixs: TransactionInstruction[], signers: Keypair[]): FailedTransactionMetadata | null {
+    const tx = new Transaction();
+    tx.recentBlockhash = svm.latestBlockhash();
+    tx.feePayer = payer.publicKey;
+    ixs.forEach((ix) => tx.add(ix));
+    tx.sign(payer, ...signers);
+    const res = svm.sendTransaction(tx);
+    svm.expireBlockhash();
+    return res instanceof FailedTransactionMetadata ? res : null;
+  }
+  function send(ixs: TransactionInstruction[], signers: Keypair[] = []) {
+    const f = trySend(ixs, signers);
+    if (f) throw new Error("tx failed: " + f.err().toString() + "\n" + f.meta().logs().join("\n"));
+  }
+  function sendExpectFail(ixs: TransactionInstruction[], signers: Keypair[] = []): string {
+    const f = trySend(ixs, signers);
+    if (!f) throw new Error("expected tx to fail but it succeeded");
+    return f.err().toString() + "\n" + f.meta().logs().join("\n");
+  }
+
+  const proposalPda = (nonce: number) =>
+    PublicKey.findProgramAddressSync([Buffer.from("mint_proposal"), new BN(nonce).toArrayLike(Buffer, "le", 8)], programId)[0];
+  const balance = (): bigint => {
+    const acct = svm.getAccount(holderAta)!;
+    return unpackAccount(holderAta, { ...acct, data: Buffer.from(acct.data) } as any, TOKEN_2022_PROGRAM_ID).amount;
+  };
+
+  const configIx = (signers: PublicKey[], threshold: number, authority: PublicKey, coSigners: PublicKey[] = []) =>
+    program.methods.setAuthorityConfig(signers, threshold).accounts({
+      tokenInfo: infoPda, authorityConfig: configPda, authority, payer: payer.publicKey, systemProgram: SystemProgram.programId,
+      minterConfig: minterConfigPda,
+    } as any).remainingAccounts(coSigners.map((pubkey) => ({ pubkey, isSigner: true, isWritable: false }))).instruction();
+  const proposeIx = (proposer: PublicKey, amount: number, nonce: number) =>
+    program.methods.proposeMint(holderAta, new BN(amount), new BN(nonce)).accounts({
+      authorityConfig: configPda, mintProposal: proposalPda(nonce), proposer, payer: payer.publicKey, systemProgram: SystemProgram.programId,
+    } as any).instruction();
+  const approveIx = (approver: PublicKey, nonce: number, recValidator: PublicKey | null = rec.publicKey) =>
+    program.methods.approveMint(new BN(nonce)).accounts({
+      mint: mintPda, tokenInfo: infoPda, authorityConfig: configPda, mintProposal: proposalPda(nonce),
+      destination: holderAta, approver, recValidator, tokenProgram: TOKEN_2022_PROGRAM_ID,
+    } as any).instruction();
+  const delegateMintIx = (amount: number) =>
+    program.methods.mintTokensDirect(new BN(amount)).accounts({
+      tokenInfo: infoPda, mint: mintPda, userTokenAccount: holderAta, authority: delegate.publicKey,
+      registryAuthority: PublicKey.default, recValidator: rec.publicKey,
+      tokenProgram: TOKEN_2022_PROGRAM_ID, minterConfig: minterConfigPda,
+    } as any).instruction();
+  const walletMintIx = (amount: number) =>
+    program.methods.mintToWallet(new BN(amount)).accounts({
+      mint: mintPda, tokenInfo: infoPda, destination: holderAta, destinationOwner: holder.publicKey,
+      authority: payer.publicKey, recValidator: rec.publicKey, payer: payer.publicKey,
+      tokenProgram: TOKEN_2022_PROGRAM_ID, associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID, systemProgram: SystemProgram.programId,
+    } as any).instruction();
+  const generationMintIx = (amount: number) => {
+    const meterId = Buffer.alloc(16, 7);
+    const windowStartMs = new BN(900_000);
+    const [mintRecord] = PublicKey.findProgramAddressSync(
+      [Buffer.from("gen_mint"), meterId, windowStartMs.toArrayLike(Buffer, "le", 8)], programId);
+    return program.methods.mintGeneration(Array.from(meterId), windowStartMs, new BN(amount)).accounts({
+      mint: mintPda, tokenInfo: infoPda, destination: holderAta, destinationOwner: holder.publicKey, mintRecord,
+      authority: payer.publicKey, recValidator: rec.publicKey, payer: payer.publicKey,
+      tokenProgram: TOKEN_2022_PROGRAM_ID, associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID, systemProgram: SystemProgram.programId,
+    } as any).instruction();
+  };
+
+  before(async () => {
+    svm = new LiteSVM().withDefaultPrograms();
+    program = new Program(idl, { connection: {}, publicKey: PublicKey.default } as any);
+    programId = program.programId;
+    svm.addProgramFromFile(programId, "target/deploy/energy_token.so");
+    svm.airdrop(payer.publicKey, BigInt(1_000_000_000_000));
+
+    [mintPda] = PublicKey.findProgramAddressSync([Buffer.from("mint_2022")], programId);
+    [infoPda] = PublicKey.findProgramAddressSync([Buffer.from("token_info_2022")], programId);
+    [configPda] = PublicKey.findProgramAddressSync([Buffer.from("authority_config")], programId);
+    [minterConfigPda] = PublicKey.findProgramAddressSync([Buffer.from("minter_config")], programId);
+
+    send([await program.methods.initializeToken(PublicKey.default, payer.publicKey, new BN(0)).accounts({
+      tokenInfo: infoPda, mint: mintPda, authority: payer.publicKey,
+      systemProgram: SystemProgram.programId, tokenProgram: TOKEN_2022_PROGRAM_ID, rent: anchorPkg.web3.SYSVAR_RENT_PUBKEY,
+    } as any).instruction()]);
+
+    holderAta = getAssociatedTokenAddressSync(mintPda, holder.publicKey, false, TOKEN_2022_PROGRAM_ID);
+    send([createAssociatedTokenAccountInstruction(payer.publicKey, holderAta, holder.publicKey, mintPda, TOKEN_2022_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID)]);
+    send([await program.methods.addRecValidator(rec.publicKey, "rec").accounts({ tokenInfo: infoPda, authority: payer.publicKey } as any).instruction()]);
+    send([await program.methods.addMinter(delegate.publicKey).accounts({
+      tokenInfo: infoPda, minterConfig: minterConfigPda, authority: payer.publicKey, payer: payer.publicKey,
+      systemProgram: SystemProgram.programId,
+    } as any).instruction()]);
+  });
+
+  it("keeps the single-authority path until a config exists (control)", async () => {
+    send([await walletMintIx(60)], [rec]);
+    send([await delegateMintIx(40)], [delegate, rec]);
+    expect(balance()).to.equal(100n);
+  });
+
+  it("rejects the first config from a non-authority signer (UnauthorizedAuthority)", async () => {
+    const blob = sendExpectFail([await configIx([s1.publicKey, s2.publicKey, s3.publicKey], 2, outsider.publicKey)], [outsider]);
+    expect(blob, blob).to.match(/UnauthorizedAuthority/);
+  });
+
+  it("rejects a threshold above the signer count (InvalidAuthorityConfig)", async () => {
+    const blob = sendExpectFail([await configIx([s1.publicKey, s2.publicKey], 3, payer.publicKey)]);
+    expect(blob, blob).to.match(/InvalidAuthorityConfig/);
+  });
+
+  it("installs a 2-of-3 config and locks out mint_to_wallet (MultisigRequired)", async () => {
+    send([await configIx([s1.publicKey, s2.publicKey, s3.publicKey], 2, payer.publicKey)]);
+    const blob = sendExpectFail([await walletMintIx(100)], [rec]);
+    expect(blob, blob).to.match(/MultisigRequired/);
+  });
+
+  it("locks out the other single-key paths around the threshold (MultisigRequired)", async () => {
+    let blob = sendExpectFail([await generationMintIx(1_000)], [rec]);
+    expect(blob, blob).to.match(/MultisigRequired/);
+    blob = sendExpectFail([await program.methods.setRegistryAuthority(payer.publicKey).accounts({
+      tokenInfo: infoPda, authority: payer.publicKey,
+    } as any).instruction()]);
+    expect(blob, blob).to.match(/MultisigRequired/);
+    blob = sendExpectFail([await program.methods.setAuthority(outsider.publicKey).accounts({
+      tokenInfo: infoPda, authority: payer.publicKey,
+    } as any).instruction()]);
+    expect(blob, blob).to.match(/MultisigRequired/);
+    expect(balance()).to.equal(100n);
+  });
+
+  it("clears the delegated minters and refuses their mints (MultisigRequired)", async () => {
+    const minters: any = program.coder.accounts.decode("minterConfig", Buffer.from(svm.getAccount(minterConfigPda)!.data));
+    expect(minters.minterCount).to.equal(0);
+    const blob = sendExpectFail([await delegateMintIx(40)], [delegate, rec]);
+    expect(blob, blob).to.match(/UnauthorizedAuthority|MultisigRequired/);
+    expect(balance()).to.equal(100n);
+  });
+
+  it("rejects a proposal from an unlisted signer (NotConfiguredSigner)", async () => {
+    const blob = sendExpectFail([await proposeIx(outsider.publicKey, 500, 1)], [outsider]);
+    expect(blob, blob).to.match(/NotConfiguredSigner/);
+  });
+
+  it("rejects a repeat approval below threshold (AlreadyApproved)", async () => {
+    send([await proposeIx(s1.publicKey, 500, 1)], [s1]);
+    const blob = sendExpectFail([await approveIx(s1.publicKey, 1)], [s1]);
+    expect(blob, blob).to.match(/AlreadyApproved/);
+  });
+
+  it("requires the REC co-signer on the executing approval (RecValidatorNotFound)", async () => {
+    const blob = sendExpectFail([await approveIx(s2.publicKey, 1, null)], [s2]);
+    expect(blob, blob).to.match(/RecValidatorNotFound/);
+    expect(balance()).to.equal(100n);
+  });
+
+  it("mints once the second signer approves", async () => {
+    send([await approveIx(s2.publicKey, 1)], [s2, rec]);
+    expect(balance()).to.equal(600n);
+    const proposal: any = program.coder.accounts.decode("mintProposal", Buffer.from(svm.getAccount(proposalPda(1))!.data));
+    expect(proposal.executed).to.equal(true);
+    expect(proposal.approvalCount).to.equal(2);
+  });
+
+  it("rejects approvals on an executed proposal (ProposalAlreadyExecuted)", async () => {
+    const blob = sendExpectFail([await approveIx(s3.publicKey, 1)], [s3, rec]);
+    expect(blob, blob).to.match(/ProposalAlreadyExecuted/);
+    expect(balance()).to.equal(600n);
+  });
+
+  it("needs the current threshold to change the config (InsufficientApprovals)", async () => {
+    const blob = sendExpectFail([await configIx([s1.publicKey], 1, s1.publicKey)], [s1]);
+    expect(blob, blob).to.match(/InsufficientApprovals/);
+    send([await configIx([s1.publicKey, s3.publicKey], 2, s1.publicKey, [s2.publicKey])], [s1, s2]);
+    const config: any = program.coder.accounts.decode("authorityConfig", Buffer.from(svm.getAccount(configPda)!.data));
+    expect(config.signerCount).to.equal(2);
+    expect(config.version).to.equal(2);
+  });
+});
@@ -88,7 +88,7 @@ describe("energy-token REC-validator gating guards (litesvm)", () => {
     [mintPda] = PublicKey.findProgramAddressSync([Buffer.from("mint_2022")], programId);
     [infoPda] = PublicKey.findProgramAddressSync([Buffer.from("token_info_2022")], programId);
 
-    send([await program.methods.initializeToken(PublicKey.default, payer.publicKey).accounts({
+    send([await program.methods.initializeToken(PublicKey.default, payer.publicKey, new BN(0)).accounts({
       tokenInfo: infoPda, mint: mintPda, authority: payer.publicKey,
       systemProgram: SystemProgram.programId, tokenProgram: TOKEN_2022_PROGRAM_ID, rent: anchorPkg.web3.SYSVAR_RENT_PUBKEY,
     } as any).instruction()]);
@@ -0,0 +1,178 @@
+// Litesvm coverage for energy-token reconcile_consumption: the meter owner burns the
+// consumption their registry MeterAccount recorded since the last reconciliation, and the
+// ConsumptionMarker PDA (["consumption", meter]) catches up to total_consumption.
+//
+// Real registry flow (initialize -> shard -> register_user -> register_meter ->
+// update_meter_reading) so the meter is a genuine registry-owned zero-copy account.
+// Guards: NothingToReconcile (nothing new), UnauthorizedAuthority (signer is not the meter
+// owner) and InvalidMeterAccount (account not owned by the recorded registry program).
+
+import { LiteSVM, FailedTransactionMetadata } from "litesvm";
+import * as anchorPkg from "@anchor-lang/core";
+import { Program } from "@anchor-lang/core";
+import { EnergyToken } from "../target/types/energy_token";
+import { Registry } from "../target/types/registry";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  Transaction,
+  SystemProgram,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import {
+  TOKEN_2022_PROGRAM_ID,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  createAssociatedTokenAccountInstruction,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+import BN from "bn.js";
+import { createRequire } from "module";
+
+const require = createRequire(import.meta.url);
+const energyIdl = require("../target/idl/energy_token.json");
+const registryIdl = require("../target/idl/registry.json");
+
+const METER_ID = "MTR-CONSUME-1";
+
+describe("energy-token reconcile_consumption (litesvm)", () => {
+  let svm: LiteSVM;
+  let token: Program<EnergyToken>;
+  let reg: Program<Registry>;
+  let tokenId: PublicKey;
+  let regId: PublicKey;
+
+  const payer = Keypair.generate(); // token + registry authority + fee payer
+  const user = Keypair.generate(); // meter owner
+  const outsider = Keypair.generate();
+  const oracle = Keypair.generate();
+  const rec = Keypair.generate(); // registered REC validator
+
+  let mintPda: PublicKey;
+  let infoPda: PublicKey;
+  let registryPda: PublicKey;
+  let meterPda: PublicKey;
+  let userAta: PublicKey;
+  let readingTs = 1_000;
+
+  function trySend(ixs: TransactionInstruction[], signers: Keypair[] = []): FailedTransactionMetadata | null {
+    const tx = new Transaction();
+    tx.recentBlockhash = svm.latestBlockhash();
+    tx.feePayer = payer.publicKey;
+    ixs.forEach((ix) => tx.add(ix));
+    tx.sign(payer, ...signers);
+    const res = svm.sendTransaction(tx);
+    svm.expireBlockhash();
+    return res instanceof FailedTransactionMetadata ? res : null;
+  }
+  function send(ixs: TransactionInstruction[], signers: Keypair[] = []) {
+    const f = trySend(ixs, signers);
+    if (f) throw new Error("tx failed: " + f.err().toString() + "\n" + f.meta().logs().join("\n"));
+  }
+  function sendExpectFail(ixs: TransactionInstruction[], signers: Keypair[] = []): string {
+    const f = trySend(ixs, signers);
+    if (!f) throw new Error("expected tx to fail but it succeeded");
+    return f.err().toString() + "\n" + f.meta().logs().join("\n");
+  }
+
+  const markerPda = (meter: PublicKey) =>
+    PublicKey.findProgramAddressSync([Buffer.from("consumption"), meter.toBuffer()], tokenId)[0];
+  const marker = (): any =>
+    token.coder.accounts.decode("consumptionMarker", Buffer.from(svm.getAccount(markerPda(meterPda))!.data));
+  const balance = (): number => {
+    const data = Buffer.from(svm.getAccount(userAta)!.data);
+    return Number(data.readBigUInt64LE(64));
+  };
+
+  const readingIx = (consumed: number) => {
+    readingTs += 120;
+    return reg.methods.updateMeterReading(new BN(0), new BN(consumed), new BN(readingTs)).accounts({
+      registry: registryPda, meterAccount: meterPda, oracleAuthority: oracle.publicKey, readingLog: null,
+    } as any).instruction();
+  };
+  const reconcileIx = (meter: PublicKey, owner: PublicKey = user.publicKey, tokenAccount: PublicKey = userAta) =>
+    token.methods.reconcileConsumption().accounts({
+      tokenInfo: infoPda, mint: mintPda, meterAccount: meter, consumptionMarker: markerPda(meter),
+      tokenAccount, owner, payer: payer.publicKey,
+      tokenProgram: TOKEN_2022_PROGRAM_ID, systemProgram: SystemProgram.programId,
+    } as any).instruction();
+
+  before(async () => {
+    svm = new LiteSVM().withDefaultPrograms();
+    token = new Program(energyIdl, { connection: {}, publicKey: PublicKey.default } as any);
+    reg = new Program(registryIdl, { connection: {}, publicKey: PublicKey.default } as any);
+    tokenId = token.programId;
+    regId = reg.programId;
+    svm.addProgramFromFile(tokenId, "target/deploy/energy_token.so");
+    svm.addProgramFromFile(regId, "target/deploy/registry.so");
+    svm.airdrop(payer.publicKey, BigInt(1_000_000_000_000));
+
+    [mintPda] = PublicKey.findProgramAddressSync([Buffer.from("mint_2022")], tokenId);
+    [infoPda] = PublicKey.findProgramAddressSync([Buffer.from("token_info_2022")], tokenId);
+    send([await token.methods.initializeToken(regId, payer.publicKey, new BN(0)).accounts({
+      tokenInfo: infoPda, mint: mintPda, authority: payer.publicKey,
+      systemProgram: SystemProgram.programId, tokenProgram: TOKEN_2022_PROGRAM_ID, rent: anchorPkg.web3.SYSVAR_RENT_PUBKEY,
+    } as any).instruction()]);
+    send([await token.methods.addRecValidator(rec.publicKey, "rec").accounts({ tokenInfo: infoPda, authority: payer.publicKey } as any).instruction()]);
+
+    userAta = getAssociatedTokenAddressSync(mintPda, user.publicKey, false, TOKEN_2022_PROGRAM_ID);
+    send([createAssociatedTokenAccountInstruction(payer.publicKey, userAta, user.publicKey, mintPda, TOKEN_2022_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID)]);
+    send([await token.methods.mintToWallet(new BN(1_000)).accounts({
+      mint: mintPda, tokenInfo: infoPda, destination: userAta, destinationOwner: user.publicKey,
+      authority: payer.publicKey, recValidator: rec.publicKey, payer: payer.publicKey,
+      tokenProgram: TOKEN_2022_PROGRAM_ID, associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID, systemProgram: SystemProgram.programId,
+    } as any).instruction()], [rec]);
+
+    const shardId = user.publicKey.toBytes()[0] % 16;
+    [registryPda] = PublicKey.findProgramAddressSync([Buffer.from("registry")], regId);
+    const [shardPda] = PublicKey.findProgramAddressSync([Buffer.from("registry_shard"), Buffer.from([shardId])], regId);
+    const [userPda] = PublicKey.findProgramAddressSync([Buffer.from("user"), user.publicKey.toBuffer()], regId);
+    meterPda = PublicKey.findProgramAddressSync([Buffer.from("meter"), user.publicKey.toBuffer(), Buffer.from(METER_ID)], regId)[0];
+
+    send([
+      await reg.methods.initialize().accounts({ registry: registryPda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction(),
+      await reg.methods.initializeShard(shardId).accounts({ shard: shardPda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction(),
+    ]);
+    send([await reg.methods.registerUser({ consumer: {} }, 0, 0, new BN(0), shardId).accounts({
+      userAccount: userPda, registryShard: shardPda, registry: registryPda, authority: user.publicKey, payer: payer.publicKey, systemProgram: SystemProgram.programId,
+    } as any).instruction()]);
+    send([await reg.methods.registerMeter(METER_ID, { grid: {} }, shardId, 0).accounts({
+      meterAccount: meterPda, userAccount: userPda, registryShard: shardPda, registry: registryPda,
+      owner: user.publicKey, payer: payer.publicKey, systemProgram: SystemProgram.programId,
+    } as any).instruction()]);
+    send([await reg.methods.setOracleAuthority(oracle.publicKey).accounts({ registry: registryPda, authority: payer.publicKey } as any).instruction()]);
+    send([await readingIx(300)], [oracle]);
+  });
+
+  it("rejects a signer who does not own the meter (UnauthorizedAuthority)", async () => {
+    const outsiderAta = getAssociatedTokenAddressSync(mintPda, outsider.publicKey, false, TOKEN_2022_PROGRAM_ID);
+    send([createAssociatedTokenAccountInstruction(payer.publicKey, outsiderAta, outsider.publicKey, mintPda, TOKEN_2022_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID)]);
+    const blob = sendExpectFail([await reconcileIx(meterPda, outsider.publicKey, outsiderAta)], [outsider]);
+    expect(blob, blob).to.match(/UnauthorizedAuthority/);
+  });
+
+  it("rejects an account not owned by the registry (InvalidMeterAccount)", async () => {
+    const blob = sendExpectFail([await reconcileIx(infoPda)], [user]);
+    expect(blob, blob).to.match(/InvalidMeterAccount/);
+  });
+
+  it("burns the consumption recorded so far and records the marker", async () => {
+    send([await reconcileIx(meterPda)], [user]);
+    expect(balance()).to.equal(700);
+    const m = marker();
+    expect(m.meter.toBase58()).to.equal(meterPda.toBase58());
+    expect(m.settledConsumption.toNumber()).to.equal(300);
+  });
+
+  it("rejects a second call with no new consumption (NothingToReconcile)", async () => {
+    const blob = sendExpectFail([await reconcileIx(meterPda)], [user]);
+    expect(blob, blob).to.match(/NothingToReconcile/);
+  });
+
+  it("burns only the consumption added since the last reconciliation", async () => {
+    send([await readingIx(150)], [oracle]);
+    send([await reconcileIx(meterPda)], [user]);
+    expect(balance()).to.equal(550);
+    expect(marker().settledConsumption.toNumber()).to.equal(450);
+  });
+});
@@ -0,0 +1,140 @@
+// Litesvm coverage for the energy-token supply cap: a token initialized with a nonzero
+// max_supply rejects any mint that would push mint.supply past it (SupplyCapExceeded),
+// and a mint landing exactly on the cap is still allowed. Shown on mint_to_wallet; the
+// same within_supply_cap guard runs in mint_generation and mint_tokens_direct. A TokenInfo
+// created before max_supply existed loads again after migrate_token_info, with no cap.
+
+import { LiteSVM, FailedTransactionMetadata } from "litesvm";
+import * as anchorPkg from "@anchor-lang/core";
+import { Program } from "@anchor-lang/core";
+import { EnergyToken } from "../target/types/energy_token";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  Transaction,
+  SystemProgram,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import {
+  TOKEN_2022_PROGRAM_ID,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  createAssociatedTokenAccountInstruction,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+import BN from "bn.js";
+import { createRequire } from "module";
+
+const require = createRequire(import.meta.url);
+const idl = require("../target/idl/energy_token.json");
+
+const MAX_SUPPLY = 150;
+
+describe("energy-token supply cap (litesvm)", () => {
+  let svm: LiteSVM;
+  let program: Program<EnergyToken>;
+  let programId: PublicKey;
+
+  const payer = Keypair.generate(); // token authority (admin) + funder
+  const destOwner = Keypair.generate();
+  const rec = Keypair.generate(); // registered REC validator
+
+  let mintPda: PublicKey;
+  let infoPda: PublicKey;
+  let destAta: PublicKey;
+
+  function trySend(ixs: TransactionInstruction[], signers: Keypair[]): FailedTransactionMetadata | null {
+    const tx = new Transaction();
+    tx.recentBlockhash = svm.latestBlockhash();
+    tx.feePayer = payer.publicKey;
+    ixs.forEach((ix) => tx.add(ix));
+    tx.sign(payer, ...signers);
+    const res = svm.sendTransaction(tx);
+    svm.expireBlockhash();
+    return res instanceof FailedTransactionMetadata ? res : null;
+  }
+  function send(ixs: TransactionInstruction[], signers: Keypair[] = []) {
+    const f = trySend(ixs, signers);
+    if (f) throw new Error("tx failed: " + f.err().toString() + "\n" + f.meta().logs().join("\n"));
+  }
+  function sendExpectFail(ixs: TransactionInstruction[], signers: Keypair[] = []): string {
+    const f = trySend(ixs, signers);
+    if (!f) throw new Error("expected tx to fail but it succeeded");
+    return f.err().toString() + "\n" + f.meta().logs().join("\n");
+  }
+
+  const mintIx = (amount: number) =>
+    program.methods.mintToWallet(new BN(amount)).accounts({
+      mint: mintPda, tokenInfo: infoPda, destination: destAta, destinationOwner: destOwner.publicKey,
+      authority: payer.publicKey, recValidator: rec.publicKey, payer: payer.publicKey,
+      tokenProgram: TOKEN_2022_PROGRAM_ID, associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID, systemProgram: SystemProgram.programId,
+    } as any).instruction();
+
+  before(async () => {
+    svm = new LiteSVM().withDefaultPrograms();
+    program = new Program(idl, { connection: {}, publicKey: PublicKey.default } as any);
+    programId = program.programId;
+    svm.addProgramFromFile(programId, "target/deploy/energy_token.so");
+    svm.airdrop(payer.publicKey, BigInt(1_000_000_000_000));
+
+    [mintPda] = PublicKey.findProgramAddressSync([Buffer.from("mint_2022")], programId);
+    [infoPda] = PublicKey.findProgramAddressSync([Buffer.from("token_info_2022")], programId);
+
+    send([await program.methods.initializeToken(PublicKey.default, payer.publicKey, new BN(MAX_SUPPLY)).accounts({
+      tokenInfo: infoPda, mint: mintPda, authority: payer.publicKey,
+      systemProgram: SystemProgram.programId, tokenProgram: TOKEN_2022_PROGRAM_ID, rent: anchorPkg.web3.SYSVAR_RENT_PUBKEY,
+    } as any).instruction()]);
+
+    destAta = getAssociatedTokenAddressSync(mintPda, destOwner.publicKey, false, TOKEN_2022_PROGRAM_ID);
+    send([createAssociatedTokenAccountInstruction(payer.publicKey, destAta, destOwner.publicKey, mintPda, TOKEN_2022_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID)]);
+    send([await program.methods.addRecValidator(rec.publicKey, "rec").accounts({ tokenInfo: infoPda, authority: payer.publicKey } as any).instruction()]);
+  });
+
+  it("stores max_supply on initialize_token", () => {
+    const info: any = program.coder.accounts.decode("tokenInfo", Buffer.from(svm.getAccount(infoPda)!.data));
+    expect(info.maxSupply.toNumber()).to.equal(MAX_SUPPLY);
+  });
+
+  it("mints below the cap (control)", async () => {
+    send([await mintIx(100)], [rec]);
+  });
+
+  it("rejects a mint that would exceed max_supply (SupplyCapExceeded)", async () => {
+    const blob = sendExpectFail([await mintIx(51)], [rec]);
+    expect(blob, blob).to.match(/SupplyCapExceeded/);
+  });
+
+  it("allows a mint that lands exactly on the cap", async () => {
+    send([await mintIx(50)], [rec]);
+    const blob = sendExpectFail([await mintIx(1)], [rec]);
+    expect(blob, blob).to.match(/SupplyCapExceeded/);
+  });
+
+  it("migrate_token_info grows a pre-max_supply TokenInfo so it loads again, uncapped", async () => {
+    // Lay the account out as it was before max_supply: discriminator + 312-byte payload.
+    const current = svm.getAccount(infoPda)!;
+    const legacy = Buffer.from(current.data).subarray(0, 8 + 312);
+    svm.setAccount(infoPda, {
+      ...current, data: legacy, lamports: Number(svm.minimumBalanceForRentExemption(BigInt(legacy.length))),
+    } as any);
+    let blob = sendExpectFail([await mintIx(1)], [rec]);
+    expect(blob, blob).to.match(/AccountDidNotDeserialize|3003/);
+
+    const migrateIx = () =>
+      program.methods.migrateTokenInfo().accounts({
+        tokenInfo: infoPda, payer: payer.publicKey, systemProgram: SystemProgram.programId,
+      } as any).instruction();
+    send([await migrateIx()]);
+
+    const migrated = svm.getAccount(infoPda)!;
+    expect(migrated.data.length).to.equal(current.data.length);
+    expect(BigInt(migrated.lamports)).to.equal(svm.minimumBalanceForRentExemption(BigInt(migrated.data.length)));
+    const info: any = program.coder.accounts.decode("tokenInfo", Buffer.from(migrated.data));
+    expect(info.maxSupply.toNumber()).to.equal(0);
+    expect(info.authority.toBase58()).to.equal(payer.publicKey.toBase58());
+
+    send([await mintIx(1)], [rec]); // past the old cap: a legacy token was never capped
+    blob = sendExpectFail([await migrateIx()]);
+    expect(blob, blob).to.match(/AccountAlreadyMigrated/);
+  });
+});
@@ -0,0 +1,134 @@
+// Litesvm coverage for energy-token transfer_tokens_memo: the transfer moves the tokens like
+// transfer_tokens and emits TokensTransferred carrying the memo, since the SPL transfer has
+// no memo of its own. A memo over MAX_TRANSFER_MEMO_LEN (64 bytes) fails with MemoTooLong.
+
+import { LiteSVM, FailedTransactionMetadata, TransactionMetadata } from "litesvm";
+import * as anchorPkg from "@anchor-lang/core";
+import { Program, EventParser } from "@anchor-lang/core";
+import { EnergyToken } from "../target/types/energy_token";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  Transaction,
+  SystemProgram,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import {
+  TOKEN_2022_PROGRAM_ID,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  createAssociatedTokenAccountInstruction,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+import BN from "bn.js";
+import { createRequire } from "module";
+
+const require = createRequire(import.meta.url);
+const idl = require("../target/idl/energy_token.json");
+
+describe("energy-token transfer_tokens_memo (litesvm)", () => {
+  let svm: LiteSVM;
+  let program: Program<EnergyToken>;
+  let programId: PublicKey;
+
+  const payer = Keypair.generate(); // token authority (admin) + funder
+  const holder = Keypair.generate();
+  const recipient = Keypair.generate();
+  const rec = Keypair.generate(); // registered REC validator
+
+  let mintPda: PublicKey;
+  let infoPda: PublicKey;
+  let holderAta: PublicKey;
+  let recipientAta: PublicKey;
+
+  function trySend(ixs: TransactionInstruction[], signers: Keypair[] = []): FailedTransactionMetadata | null {
+    const tx = new Transaction();
+    tx.recentBlockhash = svm.latestBlockhash();
+    tx.feePayer = payer.publicKey;
+    ixs.forEach((ix) => tx.add(ix));
+    tx.sign(payer, ...signers);
+    const res = svm.sendTransaction(tx);
+    svm.expireBlockhash();
+    return res instanceof FailedTransactionMetadata ? res : null;
+  }
+  function sendExpectFail(ixs: TransactionInstruction[], signers: Keypair[] = []): string {
+    const f = trySend(ixs, signers);
+    if (!f) throw new Error("expected tx to fail but it succeeded");
+    return f.err().toString() + "\n" + f.meta().logs().join("\n");
+  }
+  const amountOf = (ata: PublicKey): number => Number(Buffer.from(svm.getAccount(ata)!.data).readBigUInt64LE(64));
+  const memoIx = (amount: number, memo: string) =>
+    program.methods.transferTokensMemo(new BN(amount), memo).accounts({
+      fromTokenAccount: holderAta, toTokenAccount: recipientAta, mint: mintPda,
+      fromAuthority: holder.publicKey, tokenProgram: TOKEN_2022_PROGRAM_ID,
+    } as any).instruction();
+
+  function sendWithLogs(ixs: TransactionInstruction[], signers: Keypair[] = []): string[] {
+    const tx = new Transaction();
+    tx.recentBlockhash = svm.latestBlockhash();
+    tx.feePayer = payer.publicKey;
+    ixs.forEach((ix) => tx.add(ix));
+    tx.sign(payer, ...signers);
+    const res = svm.sendTransaction(tx);
+    svm.expireBlockhash();
+    if (res instanceof FailedTransactionMetadata) {
+      throw new Error("tx failed: " + res.err().toString() + "\n" + res.meta().logs().join("\n"));
+    }
+    return (res as TransactionMetadata).logs();
+  }
+
+  before(async () => {
+    svm = new LiteSVM().withDefaultPrograms();
+    program = new Program(idl, { connection: {}, publicKey: PublicKey.default } as any);
+    programId = program.programId;
+    svm.addProgramFromFile(programId, "target/deploy/energy_token.so");
+    svm.airdrop(payer.publicKey, BigInt(1_000_000_000_000));
+
+    [mintPda] = PublicKey.findProgramAddressSync([Buffer.from("mint_2022")], programId);
+    [infoPda] = PublicKey.findProgramAddressSync([Buffer.from("token_info_2022")], programId);
+
+    sendWithLogs([await program.methods.initializeToken(PublicKey.default, payer.publicKey, new BN(0)).accounts({
+      tokenInfo: infoPda, mint: mintPda, authority: payer.publicKey,
+      systemProgram: SystemProgram.programId, tokenProgram: TOKEN_2022_PROGRAM_ID, rent: anchorPkg.web3.SYSVAR_RENT_PUBKEY,
+    } as any).instruction()]);
+
+    holderAta = getAssociatedTokenAddressSync(mintPda, holder.publicKey, false, TOKEN_2022_PROGRAM_ID);
+    recipientAta = getAssociatedTokenAddressSync(mintPda, recipient.publicKey, false, TOKEN_2022_PROGRAM_ID);
+    sendWithLogs([
+      createAssociatedTokenAccountInstruction(payer.publicKey, holderAta, holder.publicKey, mintPda, TOKEN_2022_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID),
+      createAssociatedTokenAccountInstruction(payer.publicKey, recipientAta, recipient.publicKey, mintPda, TOKEN_2022_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID),
+    ]);
+    sendWithLogs([await program.methods.addRecValidator(rec.publicKey, "rec").accounts({ tokenInfo: infoPda, authority: payer.publicKey } as any).instruction()]);
+    sendWithLogs([await program.methods.mintToWallet(new BN(1_000)).accounts({
+      mint: mintPda, tokenInfo: infoPda, destination: holderAta, destinationOwner: holder.publicKey,
+      authority: payer.publicKey, recValidator: rec.publicKey, payer: payer.publicKey,
+      tokenProgram: TOKEN_2022_PROGRAM_ID, associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID, systemProgram: SystemProgram.programId,
+    } as any).instruction()], [rec]);
+  });
+
+  it("transfers the tokens and emits TokensTransferred with the memo", async () => {
+    const memo = "INV-2026-0042 / settlement batch 7";
+    const logs = sendWithLogs([await memoIx(250, memo)], [holder]);
+    expect(amountOf(holderAta)).to.equal(750);
+    expect(amountOf(recipientAta)).to.equal(250);
+
+    const parser = new EventParser(programId, (program as any).coder);
+    const ev = [...parser.parseLogs(logs)].find((e) => e.name === "TokensTransferred" || e.name === "tokensTransferred");
+    expect(ev, "TokensTransferred emitted").to.not.be.undefined;
+    expect(ev!.data.from.toBase58()).to.equal(holderAta.toBase58());
+    expect(ev!.data.to.toBase58()).to.equal(recipientAta.toBase58());
+    expect(ev!.data.amount.toNumber()).to.equal(250);
+    expect(ev!.data.memo).to.equal(memo);
+  });
+
+  it("accepts a memo of exactly 64 bytes", async () => {
+    sendWithLogs([await memoIx(1, "m".repeat(64))], [holder]);
+    expect(amountOf(recipientAta)).to.equal(251);
+  });
+
+  it("rejects a memo over 64 bytes (MemoTooLong)", async () => {
+    const blob = sendExpectFail([await memoIx(1, "m".repeat(65))], [holder]);
+    expect(blob, blob).to.match(/MemoTooLong/);
+    expect(amountOf(recipientAta)).to.equal(251);
+  });
+});
@@ -432,7 +432,7 @@ describe("Governance Program", () => {
       );
     } catch (e: any) {
       if (
-        e.message?.includes("InsufficientUnclaimedGeneration") ||
+        e.message?.includes("ExceedsClaimableGeneration") ||
         e.message?.includes("InvalidMeterAccount")
       ) {
         console.log(`⚠ ERC issuance skipped — ${e.message}`);
@@ -515,7 +515,7 @@ describe("Governance Program", () => {
         .rpc();
     } catch (e: any) {
       if (
-        e.message?.includes("InsufficientUnclaimedGeneration") ||
+        e.message?.includes("ExceedsClaimableGeneration") ||
         e.message?.includes("InvalidMeterAccount")
       ) {
         console.log(`⚠ ERC2 issuance skipped — ${e.message}`);
@@ -530,8 +530,16 @@ describe("Governance Program", () => {
       .accounts({
         governanceConfig: governanceConfigPda,
         ercCertificate: erc2Pda,
+        meterAccount: meterAccountPda,
+        registry: registryPda,
+        registryProgram: regProgram.programId,
         authority: authority.publicKey,
-      })
+        // No REC burn accounts: the certificate is revoked but its meter claim stays.
+        recMint: null,
+        recHolder: null,
+        recTokenAccount: null,
+        tokenProgram: null,
+      } as any)
       .rpc();
 
     const cert: any = await govProgram.account.ercCertificate.fetch(erc2Pda);
@@ -563,8 +571,15 @@ describe("Governance Program", () => {
         .accounts({
           governanceConfig: governanceConfigPda,
           ercCertificate: erc2Pda,
+          meterAccount: meterAccountPda,
+          registry: registryPda,
+          registryProgram: regProgram.programId,
           authority: authority.publicKey,
-        })
+          recMint: null,
+          recHolder: null,
+          recTokenAccount: null,
+          tokenProgram: null,
+        } as any)
         .rpc();
       assert.fail("Should have been rejected");
     } catch (e: any) {
@@ -0,0 +1,188 @@
+// Litesvm coverage for governance `issue_erc_batch`: several certificates in one instruction,
+// each entry taking [erc_certificate, meter_account, owner, rec_token_account] from
+// remaining_accounts. Entries get the same checks as `issue_erc` and share the meter's
+// claimable generation; an existing certificate id (CertificateAlreadyExists), an
+// over-claim or a malformed batch (InvalidErcBatch) rolls the whole batch back.
+//
+// Setup mirrors governance_rec_token_litesvm.ts: one meter with net generation 800 kWh,
+// the producer's REC ATA created up front (the batch does not init token accounts).
+
+import { LiteSVM, FailedTransactionMetadata } from "litesvm";
+import { Program } from "@anchor-lang/core";
+import { Governance } from "../target/types/governance";
+import { Registry } from "../target/types/registry";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  Transaction,
+  SystemProgram,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import {
+  TOKEN_2022_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+  createAssociatedTokenAccountIdempotentInstruction,
+  unpackAccount,
+} from "@solana/spl-token";
+import BN from "bn.js";
+import { createRequire } from "module";
+
+const require = createRequire(import.meta.url);
+const governanceIdl = require("../target/idl/governance.json");
+const registryIdl = require("../target/idl/registry.json");
+
+const METER_ID = "MTR-BATCH-001";
+
+describe("governance issue_erc_batch (litesvm)", () => {
+  let svm: LiteSVM;
+  let gov: Program<Governance>;
+  let reg: Program<Registry>;
+  let govId: PublicKey;
+  let regId: PublicKey;
+
+  const payer = Keypair.generate(); // registry + governance authority + fee payer
+  const user = Keypair.generate(); // meter owner / REC producer
+  const oracle = Keypair.generate();
+
+  let registryPda: PublicKey;
+  let meterPda: PublicKey;
+  let poaPda: PublicKey;
+  let recMint: PublicKey;
+  let userRecAta: PublicKey;
+
+  function trySend(ixs: TransactionInstruction[], extra: Keypair[] = []): FailedTransactionMetadata | null {
+    const tx = new Transaction();
+    tx.recentBlockhash = svm.latestBlockhash();
+    tx.feePayer = payer.publicKey;
+    ixs.forEach((ix) => tx.add(ix));
+    tx.sign(payer, ...extra);
+    const res = svm.sendTransaction(tx);
+    svm.expireBlockhash();
+    return res instanceof FailedTransactionMetadata ? res : null;
+  }
+  function send(ixs: TransactionInstruction[], extra: Keypair[] = []) {
+    const f = trySend(ixs, extra);
+    if (f) throw new Error("tx failed: " + f.err().toString() + "\n" + f.meta().logs().join("\n"));
+  }
+  function sendExpectFail(ixs: TransactionInstruction[], extra: Keypair[] = []): string {
+    const f = trySend(ixs, extra);
+    if (!f) throw new Error("expected tx to fail but it succeeded");
+    return f.err().toString() + "\n" + f.meta().logs().join("\n");
+  }
+
+  const ercPda = (id: string) =>
+    PublicKey.findProgramAddressSync([Buffer.from("erc_certificate"), Buffer.from(id)], govId)[0];
+  const claimed = () =>
+    (reg.coder.accounts.decode("meterAccount", Buffer.from(svm.getAccount(meterPda)!.data)) as any)
+      .claimedErcGeneration.toNumber();
+  const recBalance = (): bigint => {
+    const acct = svm.getAccount(userRecAta)!;
+    return unpackAccount(userRecAta, { ...acct, data: Buffer.from(acct.data) } as any, TOKEN_2022_PROGRAM_ID).amount;
+  };
+
+  const batchIx = (entries: Array<[string, number]>, accountsPerEntry = 4) =>
+    gov.methods.issueErcBatch(entries.map(([certificateId, kwh]) => ({
+      certificateId, energyAmount: new BN(kwh), renewableSource: "Solar",
+    }))).accounts({
+      governanceConfig: poaPda, registry: registryPda, registryProgram: regId, recMint,
+      tokenProgram: TOKEN_2022_PROGRAM_ID, authority: payer.publicKey, systemProgram: SystemProgram.programId,
+    } as any).remainingAccounts(entries.flatMap(([id]) => [
+      { pubkey: ercPda(id), isWritable: true, isSigner: false },
+      { pubkey: meterPda, isWritable: true, isSigner: false },
+      { pubkey: user.publicKey, isWritable: false, isSigner: true },
+      { pubkey: userRecAta, isWritable: true, isSigner: false },
+    ].slice(0, accountsPerEntry))).instruction();
+
+  before(async () => {
+    svm = new LiteSVM().withDefaultPrograms();
+    reg = new Program(registryIdl, { connection: {}, publicKey: PublicKey.default } as any);
+    gov = new Program(governanceIdl, { connection: {}, publicKey: PublicKey.default } as any);
+    regId = reg.programId;
+    govId = gov.programId;
+    svm.addProgramFromFile(regId, "target/deploy/registry.so");
+    svm.addProgramFromFile(govId, "target/deploy/governance.so");
+    svm.airdrop(payer.publicKey, BigInt(1_000_000_000_000));
+
+    [registryPda] = PublicKey.findProgramAddressSync([Buffer.from("registry")], regId);
+    const [userPda] = PublicKey.findProgramAddressSync([Buffer.from("user"), user.publicKey.toBuffer()], regId);
+    const shardId = user.publicKey.toBytes()[0] % 16;
+    const [shardPda] = PublicKey.findProgramAddressSync([Buffer.from("registry_shard"), Buffer.from([shardId])], regId);
+    [meterPda] = PublicKey.findProgramAddressSync([Buffer.from("meter"), user.publicKey.toBuffer(), Buffer.from(METER_ID)], regId);
+    [poaPda] = PublicKey.findProgramAddressSync([Buffer.from("poa_config")], govId);
+    [recMint] = PublicKey.findProgramAddressSync([Buffer.from("rec_mint")], govId);
+    userRecAta = getAssociatedTokenAddressSync(recMint, user.publicKey, false, TOKEN_2022_PROGRAM_ID);
+
+    send([
+      await reg.methods.initialize().accounts({ registry: registryPda, authority: payer.publicKey, systemProgram: SystemProgram.programId }).instruction(),
+      await reg.methods.initializeShard(shardId).accounts({ shard: shardPda, authority: payer.publicKey, systemProgram: SystemProgram.programId }).instruction(),
+    ]);
+    send([await reg.methods.registerUser({ prosumer: {} }, 0, 0, new BN(0), shardId).accounts({
+      userAccount: userPda, registryShard: shardPda, registry: registryPda, authority: user.publicKey, payer: payer.publicKey, systemProgram: SystemProgram.programId,
+    }).instruction()]);
+    send([await reg.methods.registerMeter(METER_ID, { solar: {} }, shardId, 0).accounts({
+      meterAccount: meterPda, userAccount: userPda, registryShard: shardPda, registry: registryPda,
+      owner: user.publicKey, payer: payer.publicKey, systemProgram: SystemProgram.programId,
+    }).instruction()]);
+    send([await reg.methods.setOracleAuthority(oracle.publicKey).accounts({ registry: registryPda, authority: payer.publicKey }).instruction()]);
+    send([await reg.methods.updateMeterReading(new BN(1000), new BN(200), new BN(1000)).accounts({
+      registry: registryPda, meterAccount: meterPda, oracleAuthority: oracle.publicKey,
+    } as any).instruction()], [oracle]);
+
+    send([await gov.methods.initializeGovernance().accounts({
+      governanceConfig: poaPda, authority: payer.publicKey, systemProgram: SystemProgram.programId,
+    }).instruction()]);
+    send([await gov.methods.initRecMint().accounts({
+      governanceConfig: poaPda, recMint, authority: payer.publicKey,
+      tokenProgram: TOKEN_2022_PROGRAM_ID, systemProgram: SystemProgram.programId,
+    } as any).instruction()]);
+    send([createAssociatedTokenAccountIdempotentInstruction(payer.publicKey, userRecAta, user.publicKey, recMint, TOKEN_2022_PROGRAM_ID)]);
+  });
+
+  it("rejects an empty batch and a short account list (InvalidErcBatch)", async () => {
+    let blob = sendExpectFail([await batchIx([])]);
+    expect(blob, blob).to.match(/InvalidErcBatch/);
+    blob = sendExpectFail([await batchIx([["B-SHORT", 100]], 3)], [user]);
+    expect(blob, blob).to.match(/InvalidErcBatch/);
+  });
+
+  it("issues every certificate, bumps the meter claim and mints REC per entry", async () => {
+    send([await batchIx([["B-1", 300], ["B-2", 200]])], [user]);
+
+    expect(claimed()).to.equal(500);
+    expect(recBalance()).to.equal(500_000n);
+    for (const [id, kwh] of [["B-1", 300], ["B-2", 200]] as const) {
+      const cert: any = gov.coder.accounts.decode("ercCertificate", Buffer.from(svm.getAccount(ercPda(id))!.data));
+      expect(Buffer.from(cert.certificateId.slice(0, cert.idLen)).toString()).to.equal(id);
+      expect(cert.energyAmount.toNumber()).to.equal(kwh);
+      expect(cert.owner.toBase58()).to.equal(user.publicKey.toBase58());
+      expect(cert.meterAccount.toBase58()).to.equal(meterPda.toBase58());
+      expect(cert.status).to.have.property("valid");
+      expect(cert.validatedForTrading).to.equal(false);
+    }
+    const cfg: any = gov.coder.accounts.decode("governanceConfig", Buffer.from(svm.getAccount(poaPda)!.data));
+    expect(cfg.totalErcsIssued.toNumber()).to.equal(2);
+    expect(cfg.totalEnergyCertified.toNumber()).to.equal(500);
+  });
+
+  it("rolls back the whole batch when a certificate id already exists", async () => {
+    const blob = sendExpectFail([await batchIx([["B-3", 100], ["B-1", 100]])], [user]);
+    expect(blob, blob).to.match(/CertificateAlreadyExists/);
+    expect(svm.getAccount(ercPda("B-3"))?.lamports ?? 0).to.equal(0);
+    expect(claimed()).to.equal(500);
+  });
+
+  it("rejects a repeated id inside one batch", async () => {
+    const blob = sendExpectFail([await batchIx([["B-4", 100], ["B-4", 100]])], [user]);
+    expect(blob, blob).to.match(/CertificateAlreadyExists/);
+  });
+
+  it("bounds the entries together by the meter's claimable generation", async () => {
+    // 300 kWh left: the second entry over-claims and takes the first down with it.
+    const blob = sendExpectFail([await batchIx([["B-5", 200], ["B-6", 200]])], [user]);
+    expect(blob, blob).to.match(/ExceedsClaimableGeneration/);
+    expect(svm.getAccount(ercPda("B-5"))?.lamports ?? 0).to.equal(0);
+    expect(claimed()).to.equal(500);
+    expect(recBalance()).to.equal(500_000n);
+  });
+});
@@ -0,0 +1,162 @@
+// Litesvm coverage for governance expire_erc: a permissionless keeper call that flips a
+// Valid certificate to Expired once the clock reaches `expires_at` (issued_at + the 1-year
+// default `erc_validity_period`), clearing its trading validation and emitting ErcExpired.
+//
+// Guards: ErcNotExpired (before the deadline) and InvalidErcStatus (already expired).
+
+import { LiteSVM, Clock, FailedTransactionMetadata } from "litesvm";
+import { Program } from "@anchor-lang/core";
+import { Governance } from "../target/types/governance";
+import { Registry } from "../target/types/registry";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  Transaction,
+  SystemProgram,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import {
+  TOKEN_2022_PROGRAM_ID,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+import BN from "bn.js";
+import { createRequire } from "module";
+
+const require = createRequire(import.meta.url);
+const governanceIdl = require("../target/idl/governance.json");
+const registryIdl = require("../target/idl/registry.json");
+
+const METER_ID = "MTR-EXPIRE-1";
+const CERT_ID = "EXPIRE-A";
+const NOW = 1_700_000_000;
+const VALIDITY = 31_536_000; // initialize_governance default
+
+describe("governance expire_erc (litesvm)", () => {
+  let svm: LiteSVM;
+  let gov: Program<Governance>;
+  let reg: Program<Registry>;
+  let govId: PublicKey;
+  let regId: PublicKey;
+
+  const payer = Keypair.generate(); // registry + governance authority + fee payer
+  const user = Keypair.generate(); // meter owner
+  const oracle = Keypair.generate();
+  const keeper = Keypair.generate(); // unprivileged caller
+
+  let registryPda: PublicKey;
+  let poaPda: PublicKey;
+  let recMint: PublicKey;
+  let userRecAta: PublicKey;
+
+  function trySend(ixs: TransactionInstruction[], signers: Keypair[] = []): FailedTransactionMetadata | null {
+    const tx = new Transaction();
+    tx.recentBlockhash = svm.latestBlockhash();
+    tx.feePayer = payer.publicKey;
+    ixs.forEach((ix) => tx.add(ix));
+    tx.sign(payer, ...signers);
+    const res = svm.sendTransaction(tx);
+    svm.expireBlockhash();
+    return res instanceof FailedTransactionMetadata ? res : null;
+  }
+  function send(ixs: TransactionInstruction[], signers: Keypair[] = []) {
+    const f = trySend(ixs, signers);
+    if (f) throw new Error("tx failed: " + f.err().toString() + "\n" + f.meta().logs().join("\n"));
+  }
+  function sendExpectFail(ixs: TransactionInstruction[], signers: Keypair[] = []): string {
+    const f = trySend(ixs, signers);
+    if (!f) throw new Error("expected tx to fail but it succeeded");
+    return f.err().toString() + "\n" + f.meta().logs().join("\n");
+  }
+
+  const meterPda = (id: string) =>
+    PublicKey.findProgramAddressSync([Buffer.from("meter"), user.publicKey.toBuffer(), Buffer.from(id)], regId)[0];
+  const ercPda = (certId: string) =>
+    PublicKey.findProgramAddressSync([Buffer.from("erc_certificate"), Buffer.from(certId)], govId)[0];
+  const cert = (): any =>
+    gov.coder.accounts.decode("ercCertificate", Buffer.from(svm.getAccount(ercPda(CERT_ID))!.data));
+  function warpClock(unixTs: number) {
+    const c = svm.getClock();
+    svm.setClock(new Clock(c.slot, 0n, 0n, 0n, BigInt(unixTs)));
+  }
+
+  const issueIx = (certId: string, amount: number) =>
+    gov.methods.issueErc(certId, new BN(amount), "Solar", "oracle-validated").accounts({
+      governanceConfig: poaPda, ercCertificate: ercPda(certId), meterAccount: meterPda(METER_ID), owner: user.publicKey,
+      registry: registryPda, registryProgram: regId, recMint, recTokenAccount: userRecAta,
+      tokenProgram: TOKEN_2022_PROGRAM_ID, associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      authority: payer.publicKey, systemProgram: SystemProgram.programId,
+    } as any).instruction();
+  const expireIx = () =>
+    gov.methods.expireErc().accounts({ ercCertificate: ercPda(CERT_ID) } as any).instruction();
+
+  before(async () => {
+    svm = new LiteSVM().withDefaultPrograms();
+    reg = new Program(registryIdl, { connection: {}, publicKey: PublicKey.default } as any);
+    gov = new Program(governanceIdl, { connection: {}, publicKey: PublicKey.default } as any);
+    regId = reg.programId;
+    govId = gov.programId;
+    svm.addProgramFromFile(regId, "target/deploy/registry.so");
+    svm.addProgramFromFile(govId, "target/deploy/governance.so");
+    svm.airdrop(payer.publicKey, BigInt(1_000_000_000_000));
+    svm.airdrop(keeper.publicKey, BigInt(1_000_000_000));
+    warpClock(NOW);
+
+    const shardId = user.publicKey.toBytes()[0] % 16;
+    [registryPda] = PublicKey.findProgramAddressSync([Buffer.from("registry")], regId);
+    const [shardPda] = PublicKey.findProgramAddressSync([Buffer.from("registry_shard"), Buffer.from([shardId])], regId);
+    const [userPda] = PublicKey.findProgramAddressSync([Buffer.from("user"), user.publicKey.toBuffer()], regId);
+    [poaPda] = PublicKey.findProgramAddressSync([Buffer.from("poa_config")], govId);
+    [recMint] = PublicKey.findProgramAddressSync([Buffer.from("rec_mint")], govId);
+    userRecAta = getAssociatedTokenAddressSync(recMint, user.publicKey, false, TOKEN_2022_PROGRAM_ID);
+
+    send([
+      await reg.methods.initialize().accounts({ registry: registryPda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction(),
+      await reg.methods.initializeShard(shardId).accounts({ shard: shardPda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction(),
+    ]);
+    send([await reg.methods.registerUser({ prosumer: {} }, 0, 0, new BN(0), shardId).accounts({
+      userAccount: userPda, registryShard: shardPda, registry: registryPda, authority: user.publicKey, payer: payer.publicKey, systemProgram: SystemProgram.programId,
+    } as any).instruction()]);
+    send([await reg.methods.registerMeter(METER_ID, { solar: {} }, shardId, 0).accounts({
+      meterAccount: meterPda(METER_ID), userAccount: userPda, registryShard: shardPda, registry: registryPda,
+      owner: user.publicKey, payer: payer.publicKey, systemProgram: SystemProgram.programId,
+    } as any).instruction()]);
+    send([await reg.methods.setOracleAuthority(oracle.publicKey).accounts({ registry: registryPda, authority: payer.publicKey } as any).instruction()]);
+    send([await reg.methods.updateMeterReading(new BN(1000), new BN(200), new BN(1000)).accounts({
+      registry: registryPda, meterAccount: meterPda(METER_ID), oracleAuthority: oracle.publicKey,
+    } as any).instruction()], [oracle]);
+
+    send([await gov.methods.initializeGovernance().accounts({
+      governanceConfig: poaPda, authority: payer.publicKey, systemProgram: SystemProgram.programId,
+    } as any).instruction()]);
+    send([await gov.methods.initRecMint().accounts({
+      governanceConfig: poaPda, recMint, authority: payer.publicKey,
+      tokenProgram: TOKEN_2022_PROGRAM_ID, systemProgram: SystemProgram.programId,
+    } as any).instruction()]);
+
+    send([await issueIx(CERT_ID, 500)], [user]);
+    send([await gov.methods.validateErcForTrading().accounts({
+      governanceConfig: poaPda, ercCertificate: ercPda(CERT_ID), authority: payer.publicKey,
+    } as any).instruction()]);
+    expect(cert().expiresAt.toNumber()).to.equal(NOW + VALIDITY);
+  });
+
+  it("rejects expiry before the deadline (ErcNotExpired)", async () => {
+    warpClock(NOW + VALIDITY - 1);
+    const blob = sendExpectFail([await expireIx()], [keeper]);
+    expect(blob, blob).to.match(/ErcNotExpired/);
+  });
+
+  it("lets any caller expire the certificate once the deadline passes", async () => {
+    warpClock(NOW + VALIDITY);
+    send([await expireIx()], [keeper]);
+    expect(cert().status).to.have.property("expired");
+    expect(cert().validatedForTrading).to.equal(false);
+  });
+
+  it("rejects expiring a certificate that is no longer Valid (InvalidErcStatus)", async () => {
+    const blob = sendExpectFail([await expireIx()], [keeper]);
+    expect(blob, blob).to.match(/InvalidErcStatus/);
+  });
+});
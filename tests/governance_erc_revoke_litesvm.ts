@@ -0,0 +1,230 @@
+// Litesvm coverage for governance revoke_erc: the certificate flips to Revoked and loses its
+// trading validation. The meter's claim is released (registry CPI release_erc_claim) only
+// when the certificate's RECs (energy_amount * 1000 base units) are burned in the same
+// instruction, so revoke -> re-issue never leaves two REC batches for one kWh.
+//
+// Flow: meter with net generation 800 -> issue_erc(500) -> revoke without the burn accounts
+// keeps the claim (re-issuing 800 fails) -> issue_erc(300) -> revoke with the burn releases
+// 300 and burns 300_000 RECs -> issue_erc(300) succeeds, RECs outstanding == claimed * 1000.
+// Guards: InvalidMeterAccount (meter other than the one the certificate was issued against),
+// IncompleteRecBurnAccounts and AlreadyRevoked. Last, migrate_erc_certificate brings a
+// certificate truncated to the pre-meter_account layout back to a revocable one.
+
+import { LiteSVM, FailedTransactionMetadata } from "litesvm";
+import { Program } from "@anchor-lang/core";
+import { Governance } from "../target/types/governance";
+import { Registry } from "../target/types/registry";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  Transaction,
+  SystemProgram,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import {
+  TOKEN_2022_PROGRAM_ID,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+import BN from "bn.js";
+import { createRequire } from "module";
+
+const require = createRequire(import.meta.url);
+const governanceIdl = require("../target/idl/governance.json");
+const registryIdl = require("../target/idl/registry.json");
+
+const METER_ID = "MTR-REVOKE-1";
+const OTHER_METER_ID = "MTR-REVOKE-2";
+
+describe("governance revoke_erc (litesvm)", () => {
+  let svm: LiteSVM;
+  let gov: Program<Governance>;
+  let reg: Program<Registry>;
+  let govId: PublicKey;
+  let regId: PublicKey;
+
+  const payer = Keypair.generate(); // registry + governance authority + fee payer
+  const user = Keypair.generate(); // meter owner
+  const oracle = Keypair.generate();
+
+  let registryPda: PublicKey;
+  let poaPda: PublicKey;
+  let recMint: PublicKey;
+  let userRecAta: PublicKey;
+
+  function trySend(ixs: TransactionInstruction[], signers: Keypair[] = []): FailedTransactionMetadata | null {
+    const tx = new Transaction();
+    tx.recentBlockhash = svm.latestBlockhash();
+    tx.feePayer = payer.publicKey;
+    ixs.forEach((ix) => tx.add(ix));
+    tx.sign(payer, ...signers);
+    const res = svm.sendTransaction(tx);
+    svm.expireBlockhash();
+    return res instanceof FailedTransactionMetadata ? res : null;
+  }
+  function send(ixs: TransactionInstruction[], signers: Keypair[] = []) {
+    const f = trySend(ixs, signers);
+    if (f) throw new Error("tx failed: " + f.err().toString() + "\n" + f.meta().logs().join("\n"));
+  }
+  function sendExpectFail(ixs: TransactionInstruction[], signers: Keypair[] = []): string {
+    const f = trySend(ixs, signers);
+    if (!f) throw new Error("expected tx to fail but it succeeded");
+    return f.err().toString() + "\n" + f.meta().logs().join("\n");
+  }
+
+  const meterPda = (id: string) =>
+    PublicKey.findProgramAddressSync([Buffer.from("meter"), user.publicKey.toBuffer(), Buffer.from(id)], regId)[0];
+  const ercPda = (certId: string) =>
+    PublicKey.findProgramAddressSync([Buffer.from("erc_certificate"), Buffer.from(certId)], govId)[0];
+  const claimed = (): number =>
+    (reg.coder.accounts.decode("meterAccount", Buffer.from(svm.getAccount(meterPda(METER_ID))!.data)) as any)
+      .claimedErcGeneration.toNumber();
+
+  const issueIx = (certId: string, amount: number) =>
+    gov.methods.issueErc(certId, new BN(amount), "Solar", "oracle-validated").accounts({
+      governanceConfig: poaPda, ercCertificate: ercPda(certId), meterAccount: meterPda(METER_ID), owner: user.publicKey,
+      registry: registryPda, registryProgram: regId, recMint, recTokenAccount: userRecAta,
+      tokenProgram: TOKEN_2022_PROGRAM_ID, associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      authority: payer.publicKey, systemProgram: SystemProgram.programId,
+    } as any).instruction();
+  const recBalance = (): number =>
+    Number(Buffer.from(svm.getAccount(userRecAta)!.data).readBigUInt64LE(64));
+  const revokeIx = (certId: string, meter: PublicKey, burn: any = {}) =>
+    gov.methods.revokeErc("Fraudulent reading").accounts({
+      governanceConfig: poaPda, ercCertificate: ercPda(certId), meterAccount: meter,
+      registry: registryPda, registryProgram: regId, authority: payer.publicKey,
+      recMint: null, recHolder: null, recTokenAccount: null, tokenProgram: null,
+      ...burn,
+    } as any).instruction();
+  const burnAccounts = () => ({
+    recMint, recHolder: user.publicKey, recTokenAccount: userRecAta, tokenProgram: TOKEN_2022_PROGRAM_ID,
+  });
+
+  before(async () => {
+    svm = new LiteSVM().withDefaultPrograms();
+    reg = new Program(registryIdl, { connection: {}, publicKey: PublicKey.default } as any);
+    gov = new Program(governanceIdl, { connection: {}, publicKey: PublicKey.default } as any);
+    regId = reg.programId;
+    govId = gov.programId;
+    svm.addProgramFromFile(regId, "target/deploy/registry.so");
+    svm.addProgramFromFile(govId, "target/deploy/governance.so");
+    svm.airdrop(payer.publicKey, BigInt(1_000_000_000_000));
+
+    const shardId = user.publicKey.toBytes()[0] % 16;
+    [registryPda] = PublicKey.findProgramAddressSync([Buffer.from("registry")], regId);
+    const [shardPda] = PublicKey.findProgramAddressSync([Buffer.from("registry_shard"), Buffer.from([shardId])], regId);
+    const [userPda] = PublicKey.findProgramAddressSync([Buffer.from("user"), user.publicKey.toBuffer()], regId);
+    [poaPda] = PublicKey.findProgramAddressSync([Buffer.from("poa_config")], govId);
+    [recMint] = PublicKey.findProgramAddressSync([Buffer.from("rec_mint")], govId);
+    userRecAta = getAssociatedTokenAddressSync(recMint, user.publicKey, false, TOKEN_2022_PROGRAM_ID);
+
+    send([
+      await reg.methods.initialize().accounts({ registry: registryPda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction(),
+      await reg.methods.initializeShard(shardId).accounts({ shard: shardPda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction(),
+    ]);
+    send([await reg.methods.registerUser({ prosumer: {} }, 0, 0, new BN(0), shardId).accounts({
+      userAccount: userPda, registryShard: shardPda, registry: registryPda, authority: user.publicKey, payer: payer.publicKey, systemProgram: SystemProgram.programId,
+    } as any).instruction()]);
+    for (const id of [METER_ID, OTHER_METER_ID]) {
+      send([await reg.methods.registerMeter(id, { solar: {} }, shardId, 0).accounts({
+        meterAccount: meterPda(id), userAccount: userPda, registryShard: shardPda, registry: registryPda,
+        owner: user.publicKey, payer: payer.publicKey, systemProgram: SystemProgram.programId,
+      } as any).instruction()]);
+    }
+    send([await reg.methods.setOracleAuthority(oracle.publicKey).accounts({ registry: registryPda, authority: payer.publicKey } as any).instruction()]);
+    send([await reg.methods.updateMeterReading(new BN(1000), new BN(200), new BN(1000)).accounts({
+      registry: registryPda, meterAccount: meterPda(METER_ID), oracleAuthority: oracle.publicKey,
+    } as any).instruction()], [oracle]);
+
+    send([await gov.methods.initializeGovernance().accounts({
+      governanceConfig: poaPda, authority: payer.publicKey, systemProgram: SystemProgram.programId,
+    } as any).instruction()]);
+    send([await gov.methods.initRecMint().accounts({
+      governanceConfig: poaPda, recMint, authority: payer.publicKey,
+      tokenProgram: TOKEN_2022_PROGRAM_ID, systemProgram: SystemProgram.programId,
+    } as any).instruction()]);
+
+    send([await issueIx("REVOKE-A", 500)], [user]);
+    expect(claimed()).to.equal(500);
+  });
+
+  it("rejects a meter other than the one the certificate was issued against", async () => {
+    const blob = sendExpectFail([await revokeIx("REVOKE-A", meterPda(OTHER_METER_ID))]);
+    expect(blob, blob).to.match(/InvalidMeterAccount/);
+  });
+
+  it("rejects a partial set of REC burn accounts (IncompleteRecBurnAccounts)", async () => {
+    const blob = sendExpectFail([await revokeIx("REVOKE-A", meterPda(METER_ID), { recMint })]);
+    expect(blob, blob).to.match(/IncompleteRecBurnAccounts/);
+  });
+
+  it("revokes without the burn but keeps the claim, so the energy cannot be re-certified", async () => {
+    send([await revokeIx("REVOKE-A", meterPda(METER_ID))]);
+
+    const cert: any = gov.coder.accounts.decode("ercCertificate", Buffer.from(svm.getAccount(ercPda("REVOKE-A"))!.data));
+    expect(cert.status).to.have.property("revoked");
+    expect(cert.validatedForTrading).to.equal(false);
+    expect(cert.meterAccount.toBase58()).to.equal(meterPda(METER_ID).toBase58());
+    expect(claimed()).to.equal(500);
+    expect(recBalance()).to.equal(500_000);
+
+    const blob = sendExpectFail([await issueIx("REVOKE-B", 800)], [user]);
+    expect(blob, blob).to.match(/ExceedsClaimableGeneration/);
+  });
+
+  it("burns the certificate's RECs and releases its claim when the holder co-signs", async () => {
+    send([await issueIx("REVOKE-C", 300)], [user]);
+    expect(claimed()).to.equal(800);
+    expect(recBalance()).to.equal(800_000);
+
+    send([await revokeIx("REVOKE-C", meterPda(METER_ID), burnAccounts())], [user]);
+    expect(claimed()).to.equal(500);
+    expect(recBalance()).to.equal(500_000);
+  });
+
+  it("rejects a second revocation (AlreadyRevoked)", async () => {
+    const blob = sendExpectFail([await revokeIx("REVOKE-C", meterPda(METER_ID), burnAccounts())], [user]);
+    expect(blob, blob).to.match(/AlreadyRevoked/);
+  });
+
+  it("re-certifies the released energy without double-minting RECs", async () => {
+    send([await issueIx("REVOKE-B", 300)], [user]);
+    expect(claimed()).to.equal(800);
+    expect(recBalance()).to.equal(claimed() * 1000);
+  });
+
+  it("migrate_erc_certificate grows a pre-meter_account certificate and records its meter", async () => {
+    // Lay REVOKE-B out as it was issued before meter_account/reserved_amount: drop the
+    // trailing 40 bytes and zero the meter key, which sits right after the Borsh payload.
+    const current = svm.getAccount(ercPda("REVOKE-B"))!;
+    const data = Buffer.from(current.data);
+    data.fill(0, data.indexOf(meterPda(METER_ID).toBuffer()));
+    const legacy = data.subarray(0, data.length - 40);
+    svm.setAccount(ercPda("REVOKE-B"), {
+      ...current, data: legacy, lamports: Number(svm.minimumBalanceForRentExemption(BigInt(legacy.length))),
+    } as any);
+    let blob = sendExpectFail([await revokeIx("REVOKE-B", meterPda(METER_ID), burnAccounts())], [user]);
+    expect(blob, blob).to.match(/AccountDidNotDeserialize|3003/);
+
+    const migrateIx = () =>
+      gov.methods.migrateErcCertificate("REVOKE-B").accounts({
+        governanceConfig: poaPda, ercCertificate: ercPda("REVOKE-B"), meterAccount: meterPda(METER_ID),
+        authority: payer.publicKey, systemProgram: SystemProgram.programId,
+      } as any).instruction();
+    send([await migrateIx()]);
+
+    const migrated = svm.getAccount(ercPda("REVOKE-B"))!;
+    expect(migrated.data.length).to.equal(current.data.length);
+    const cert: any = gov.coder.accounts.decode("ercCertificate", Buffer.from(migrated.data));
+    expect(cert.meterAccount.toBase58()).to.equal(meterPda(METER_ID).toBase58());
+    expect(cert.reservedAmount.toNumber()).to.equal(0);
+    expect(cert.energyAmount.toNumber()).to.equal(300);
+    blob = sendExpectFail([await migrateIx()]);
+    expect(blob, blob).to.match(/AccountAlreadyMigrated/);
+
+    send([await revokeIx("REVOKE-B", meterPda(METER_ID), burnAccounts())], [user]);
+    expect(claimed()).to.equal(500);
+    expect(recBalance()).to.equal(500_000);
+  });
+});
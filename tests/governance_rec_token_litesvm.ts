@@ -11,6 +11,9 @@
 //   issue_erc(500 kWh) -> producer REC ATA balance == 500 * 1_000 == 500_000 base units
 //   retire_rec(200_000)  -> balance == 300_000
 //   retire_rec(0)        -> rejected (InvalidAmount)
+//   set_maintenance_mode(true) -> issue_erc / validate_erc_for_trading / update_erc_limits
+//                                 rejected (MaintenanceMode),
+//                                 get_governance_stats still readable
 //
 // Source: programs/governance/src/handlers/erc.rs (issue / init_rec_mint / retire_rec),
 //         contexts.rs (IssueErc / InitRecMint / RetireRec).
@@ -192,4 +195,38 @@ describe("governance fungible REC token (litesvm)", () => {
     } as any).instruction()], [user]);
     expect(blob, blob).to.match(/InvalidAmount/);
   });
+
+  it("maintenance mode blocks issuance, trading validation and ERC limit changes but not reads", async () => {
+    const maintenance = (on: boolean) =>
+      gov.methods.setMaintenanceMode(on).accounts({ governanceConfig: poaPda, authority: payer.publicKey } as any).instruction();
+    const limits = () =>
+      gov.methods.updateErcLimits(new BN(100), new BN(1_000_000), new BN(31_536_000))
+        .accounts({ governanceConfig: poaPda, authority: payer.publicKey } as any).instruction();
+    const validate = () =>
+      gov.methods.validateErcForTrading().accounts({ governanceConfig: poaPda, ercCertificate: ercPda, authority: payer.publicKey } as any).instruction();
+    const certId = "REC-CERT-0002";
+    const issue = () =>
+      gov.methods.issueErc(certId, new BN(100), "Solar", "").accounts({
+        governanceConfig: poaPda,
+        ercCertificate: PublicKey.findProgramAddressSync([Buffer.from("erc_certificate"), Buffer.from(certId)], govId)[0],
+        meterAccount: meterPda, owner: user.publicKey, registry: registryPda, registryProgram: regId, recMint,
+        recTokenAccount: userRecAta, tokenProgram: TOKEN_2022_PROGRAM_ID, associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        authority: payer.publicKey, systemProgram: SystemProgram.programId,
+      } as any).instruction();
+
+    await send([maintenance(true)]);
+    let blob = await sendExpectFail([issue()], [user]);
+    expect(blob, blob).to.match(/MaintenanceMode/);
+    blob = await sendExpectFail([validate()]);
+    expect(blob, blob).to.match(/MaintenanceMode/);
+    blob = await sendExpectFail([limits()]);
+    expect(blob, blob).to.match(/MaintenanceMode/);
+    await send([gov.methods.getGovernanceStats().accounts({ governanceConfig: poaPda } as any).instruction()]);
+
+    await send([maintenance(false)]);
+    await send([limits()]);
+    await send([validate()]);
+    await send([issue()], [user]);
+    expect(recBalance()).to.equal(400_000n); // 300_000 left after the retire + 100 kWh
+  });
 });
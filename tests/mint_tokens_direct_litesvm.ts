@@ -12,6 +12,8 @@
 //                            the validator set is empty. (mint_to_wallet/mint_generation reject at
 //                            count==0 outright; this keeps only the registry bootstrap path.)
 //   control (count>0)       — admin + a registered REC co-signer → mint succeeds, balance grows.
+//   delegated minters       — a key added via add_minter mints when it passes the MinterConfig;
+//                            add/remove are admin-only, and a removed minter is refused again.
 //
 // mint_tokens_direct mints to a pre-existing Token-2022 ATA bound to the canonical mint.
 
@@ -51,6 +53,7 @@ describe("energy-token mint_tokens_direct guards (litesvm)", () => {
   const v1 = Keypair.generate();      // a registered REC validator
   const v2 = Keypair.generate();      // a non-registered validator
   const attacker = Keypair.generate();
+  const minter = Keypair.generate();  // delegated minter (stands in for the registry PDA)
 
   let mintPda: PublicKey;
   let infoPda: PublicKey;
@@ -90,6 +93,19 @@ describe("energy-token mint_tokens_direct guards (litesvm)", () => {
       tokenProgram: TOKEN_2022_PROGRAM_ID,
     } as any).instruction();
 
+  const minterConfigPda = () => PublicKey.findProgramAddressSync([Buffer.from("minter_config")], programId)[0];
+  const minterIx = (add: boolean, key: PublicKey, authority: PublicKey = payer.publicKey) =>
+    (add ? program.methods.addMinter(key) : program.methods.removeMinter(key)).accounts({
+      tokenInfo: infoPda, minterConfig: minterConfigPda(), authority, payer: payer.publicKey,
+      systemProgram: SystemProgram.programId,
+    } as any).instruction();
+  const minterMintIx = (amount: number) =>
+    program.methods.mintTokensDirect(new BN(amount)).accounts({
+      tokenInfo: infoPda, mint: mintPda, userTokenAccount: destAta, authority: minter.publicKey,
+      registryAuthority: registryAuth.publicKey, recValidator: v1.publicKey,
+      tokenProgram: TOKEN_2022_PROGRAM_ID, minterConfig: minterConfigPda(),
+    } as any).instruction();
+
   function destBalance(): bigint {
     const acct = svm.getAccount(destAta)!;
     return unpackAccount(destAta, { ...acct, data: Buffer.from(acct.data) } as any, TOKEN_2022_PROGRAM_ID).amount;
@@ -107,7 +123,7 @@ describe("energy-token mint_tokens_direct guards (litesvm)", () => {
 
     // admin authority = payer; registry_authority = a DISTINCT key so the admin vs registry
     // distinction is testable (count==0 REC-skip is registry-only after the hardening).
-    send([await program.methods.initializeToken(PublicKey.default, registryAuth.publicKey).accounts({
+    send([await program.methods.initializeToken(PublicKey.default, registryAuth.publicKey, new BN(0)).accounts({
       tokenInfo: infoPda, mint: mintPda, authority: payer.publicKey,
       systemProgram: SystemProgram.programId, tokenProgram: TOKEN_2022_PROGRAM_ID, rent: anchorPkg.web3.SYSVAR_RENT_PUBKEY,
     } as any).instruction()]);
@@ -153,4 +169,35 @@ describe("energy-token mint_tokens_direct guards (litesvm)", () => {
     send([await directIx(payer.publicKey, v1.publicKey, 250)], [v1]);
     expect(destBalance() - before).to.equal(250n);
   });
+
+  // --- delegated minters ---
+
+  it("rejects add_minter from a non-authority signer (UnauthorizedAuthority)", async () => {
+    const blob = sendExpectFail([await minterIx(true, attacker.publicKey, attacker.publicKey)], [attacker]);
+    expect(blob, blob).to.match(/UnauthorizedAuthority/);
+  });
+
+  it("lets a configured minter mint with a registered REC co-signer", async () => {
+    send([await minterIx(true, minter.publicKey)]);
+    const cfg: any = program.coder.accounts.decode("minterConfig", Buffer.from(svm.getAccount(minterConfigPda())!.data));
+    expect(cfg.minterCount).to.equal(1);
+    expect(cfg.minters[0].toBase58()).to.equal(minter.publicKey.toBase58());
+
+    const before = destBalance();
+    send([await minterMintIx(75)], [minter, v1]);
+    expect(destBalance() - before).to.equal(75n);
+  });
+
+  it("rejects a duplicate minter (MinterAlreadyExists)", async () => {
+    const blob = sendExpectFail([await minterIx(true, minter.publicKey)]);
+    expect(blob, blob).to.match(/MinterAlreadyExists/);
+  });
+
+  it("refuses the minter once removed (UnauthorizedAuthority)", async () => {
+    send([await minterIx(false, minter.publicKey)]);
+    const blob = sendExpectFail([await minterMintIx(75)], [minter, v1]);
+    expect(blob, blob).to.match(/UnauthorizedAuthority/);
+    const again = sendExpectFail([await minterIx(false, minter.publicKey)]);
+    expect(again, again).to.match(/MinterNotFound/);
+  });
 });
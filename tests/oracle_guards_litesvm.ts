@@ -13,6 +13,11 @@
 //     governance::admit_aggregator can drive node-facing oracle instructions by passing its
 //     AggregatorEntry PDA; once revoked it is rejected (AggregatorNotAdmitted, lib.rs:398).
 //   - admin guards: UnauthorizedAuthority + InvalidConfiguration (lib.rs:225-335).
+//   - consensus path: submit_meter_reading_consensus needs consensus_threshold distinct
+//     bridge/backup co-signers (InsufficientConsensus), and require_consensus closes the
+//     single-signer path (ConsensusRequired).
+//   - migrate_oracle_data: a singleton truncated to the pre-consensus layout fails to load,
+//     migrates to the current size with consensus off, and a second migrate is rejected.
 //
 // Sources: programs/oracle/src/lib.rs + error.rs.
 
@@ -49,6 +54,7 @@ describe("oracle validation/gateway/epoch/aggregator guards (litesvm)", () => {
   const chainBridge = Keypair.generate(); // the configured gateway (oracle_data.chain_bridge)
   const aggregator = Keypair.generate(); // PoA-admitted aggregator
   const outsider = Keypair.generate(); // neither bridge nor admitted aggregator
+  const backup = Keypair.generate(); // configured backup oracle (consensus co-signer)
 
   let oracleData: PublicKey;
   let governanceConfig: PublicKey;
@@ -118,7 +124,7 @@ describe("oracle validation/gateway/epoch/aggregator guards (litesvm)", () => {
     svm.addProgramFromFile(oracleId, "target/deploy/oracle.so");
     svm.addProgramFromFile(governanceId, "target/deploy/governance.so");
 
-    for (const kp of [payer, chainBridge, aggregator, outsider]) {
+    for (const kp of [payer, chainBridge, aggregator, outsider, backup]) {
       svm.airdrop(kp.publicKey, BigInt(1_000_000_000_000));
     }
 
@@ -260,9 +266,105 @@ describe("oracle validation/gateway/epoch/aggregator guards (litesvm)", () => {
     expect(blob, blob).to.match(/InvalidConfiguration/);
   });
 
+  it("rejects a jump beyond max_reading_deviation_percent (ReadingDeviationExceeded)", async () => {
+    await send([await oracle.methods.updateDeviationConfig(50)
+      .accounts({ oracleData, authority: payer.publicKey } as any).instruction()]);
+    // m-ok last accepted 500/500; 800 is a 60% jump on production.
+    const blob = await sendExpectFail([submitIx(chainBridge.publicKey, "m-ok", 800, 500, NOW)], [payer, chainBridge]);
+    expect(blob, blob).to.match(/ReadingDeviationExceeded/);
+    await send([await oracle.methods.updateDeviationConfig(0)
+      .accounts({ oracleData, authority: payer.publicKey } as any).instruction()]);
+  });
+
   it("rejects a zero production/consumption ratio (InvalidConfiguration)", async () => {
     const blob = await sendExpectFail([await oracle.methods.updateProductionRatioConfig(0)
       .accounts({ oracleData, authority: payer.publicKey } as any).instruction()]);
     expect(blob, blob).to.match(/InvalidConfiguration/);
   });
+
+  // ===== consensus path =====
+
+  const consensusIx = (auth: PublicKey, coSigners: PublicKey[], meterId: string, ts: number) =>
+    oracle.methods.submitMeterReadingConsensus(meterId, new BN(500), new BN(500), new BN(ts), 1)
+      .accounts({
+        oracleData,
+        meterState: meterPda(meterId),
+        authority: auth,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .remainingAccounts(coSigners.map((pubkey) => ({ pubkey, isSigner: true, isWritable: false })))
+      .instruction();
+
+  const consensusConfigIx = (backups: PublicKey[], threshold: number, required: boolean) =>
+    oracle.methods.updateConsensusConfig(backups, threshold, required)
+      .accounts({ oracleData, authority: payer.publicKey } as any).instruction();
+
+  it("rejects an unreachable consensus threshold (InvalidConfiguration)", async () => {
+    // bridge + 1 backup can supply at most 2 signers.
+    const blob = await sendExpectFail([consensusConfigIx([backup.publicKey], 3, true)]);
+    expect(blob, blob).to.match(/InvalidConfiguration/);
+  });
+
+  it("rejects a single-signer submit once consensus is required (ConsensusRequired)", async () => {
+    await send([consensusConfigIx([backup.publicKey], 2, true)]);
+    const blob = await sendExpectFail([submitIx(chainBridge.publicKey, "m-cons", 500, 500, NOW - 100)], [payer, chainBridge]);
+    expect(blob, blob).to.match(/ConsensusRequired/);
+  });
+
+  it("rejects a consensus reading below the threshold (InsufficientConsensus)", async () => {
+    // The bridge listed twice still counts once.
+    const blob = await sendExpectFail(
+      [consensusIx(chainBridge.publicKey, [chainBridge.publicKey], "m-cons", NOW - 100)],
+      [payer, chainBridge],
+    );
+    expect(blob, blob).to.match(/InsufficientConsensus/);
+  });
+
+  it("rejects an unlisted co-signer (UnauthorizedGateway)", async () => {
+    const blob = await sendExpectFail(
+      [consensusIx(chainBridge.publicKey, [outsider.publicKey], "m-cons", NOW - 100)],
+      [payer, chainBridge, outsider],
+    );
+    expect(blob, blob).to.match(/UnauthorizedGateway/);
+  });
+
+  it("accepts a reading co-signed by the bridge and a backup oracle", async () => {
+    await send([consensusIx(chainBridge.publicKey, [backup.publicKey], "m-cons", NOW - 100)], [payer, chainBridge, backup]);
+    const data: any = oracle.coder.accounts.decode("oracleData", Buffer.from(svm.getAccount(oracleData)!.data));
+    expect(data.lastConsensusTimestamp.toNumber()).to.equal(NOW);
+    await send([consensusConfigIx([], 1, false)]); // restore single-signer mode
+  });
+
+  // ===== layout migration =====
+
+  it("migrate_oracle_data grows a pre-consensus OracleData so it loads again", async () => {
+    // Lay the singleton out as it was before the consensus block: discriminator + 168 bytes.
+    const current = svm.getAccount(oracleData)!;
+    const legacy = Buffer.from(current.data).subarray(0, 8 + 168);
+    svm.setAccount(oracleData, {
+      ...current, data: legacy, lamports: Number(svm.minimumBalanceForRentExemption(BigInt(legacy.length))),
+    } as any);
+    let blob = await sendExpectFail([statusIx(true, payer.publicKey)]);
+    expect(blob, blob).to.match(/AccountDidNotDeserialize|3003/);
+
+    const migrateIx = () =>
+      oracle.methods.migrateOracleData()
+        .accounts({ oracleData, payer: payer.publicKey, systemProgram: SystemProgram.programId } as any)
+        .instruction();
+    await send([migrateIx()]);
+
+    const migrated = svm.getAccount(oracleData)!;
+    expect(migrated.data.length).to.equal(current.data.length);
+    expect(BigInt(migrated.lamports)).to.equal(svm.minimumBalanceForRentExemption(BigInt(migrated.data.length)));
+    const data: any = oracle.coder.accounts.decode("oracleData", Buffer.from(migrated.data));
+    expect(data.chainBridge.toBase58()).to.equal(chainBridge.publicKey.toBase58());
+    expect(data.consensusThreshold).to.equal(1);
+    expect(data.backupOracleCount).to.equal(0);
+    expect(data.requireConsensus).to.equal(0);
+    expect(data.maxReadingDeviationPercent).to.equal(0);
+
+    await send([statusIx(true, payer.publicKey)]); // loads again
+    blob = await sendExpectFail([migrateIx()]);
+    expect(blob, blob).to.match(/AccountAlreadyMigrated/);
+  });
 });
@@ -177,7 +177,7 @@ describe("oracle-metering-integration", () => {
     const userAta = await ensureAta(energyMintPda, user.publicKey, TOKEN_2022_PROGRAM_ID);
     
     await registryProgram.methods
-      .settleAndMintTokens()
+      .settleAndMintTokens(new BN(0))
       .accounts({
         meterAccount: meterAccountPda,
         meterOwner: user.publicKey,
@@ -0,0 +1,134 @@
+// Litesvm coverage for the per-meter MeterOracleStats PDA (["meter_stats", meter_id]):
+// submit_meter_reading counts accepted readings, records the last accepted timestamp and keeps
+// a running mean of the gaps between them;
+// a rejected reading reverts and leaves the counters alone; record_meter_rejections lets the
+// gateway add the rejections it observed for that meter. Counters of different meters are
+// independent.
+
+import { LiteSVM, Clock, FailedTransactionMetadata } from "litesvm";
+import { Program } from "@anchor-lang/core";
+import { Oracle } from "../target/types/oracle";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  Transaction,
+  SystemProgram,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import BN from "bn.js";
+import { createRequire } from "module";
+
+const require = createRequire(import.meta.url);
+const oracleIdl = require("../target/idl/oracle.json");
+
+const NOW = 1_800_000;
+
+describe("oracle per-meter stats (litesvm)", () => {
+  let svm: LiteSVM;
+  let oracle: Program<Oracle>;
+  let oracleId: PublicKey;
+
+  const payer = Keypair.generate(); // oracle admin + fee payer
+  const chainBridge = Keypair.generate(); // configured gateway
+  const outsider = Keypair.generate();
+
+  let oracleData: PublicKey;
+
+  function trySend(ixs: TransactionInstruction[], signers: Keypair[] = []): FailedTransactionMetadata | null {
+    const tx = new Transaction();
+    tx.recentBlockhash = svm.latestBlockhash();
+    tx.feePayer = payer.publicKey;
+    ixs.forEach((ix) => tx.add(ix));
+    tx.sign(payer, ...signers);
+    const res = svm.sendTransaction(tx);
+    svm.expireBlockhash();
+    return res instanceof FailedTransactionMetadata ? res : null;
+  }
+  function send(ixs: TransactionInstruction[], signers: Keypair[] = []) {
+    const f = trySend(ixs, signers);
+    if (f) throw new Error("tx failed: " + f.err().toString() + "\n" + f.meta().logs().join("\n"));
+  }
+  function sendExpectFail(ixs: TransactionInstruction[], signers: Keypair[] = []): string {
+    const f = trySend(ixs, signers);
+    if (!f) throw new Error("expected tx to fail but it succeeded");
+    return f.err().toString() + "\n" + f.meta().logs().join("\n");
+  }
+
+  const meterPda = (id: string) =>
+    PublicKey.findProgramAddressSync([Buffer.from("meter"), Buffer.from(id)], oracleId)[0];
+  const statsPda = (id: string) =>
+    PublicKey.findProgramAddressSync([Buffer.from("meter_stats"), Buffer.from(id)], oracleId)[0];
+  const stats = (id: string): any =>
+    oracle.coder.accounts.decode("meterOracleStats", Buffer.from(svm.getAccount(statsPda(id))!.data));
+
+  const submitIx = (meterId: string, produced: number, consumed: number, ts: number) =>
+    oracle.methods.submitMeterReading(meterId, new BN(produced), new BN(consumed), new BN(ts), 1)
+      .accounts({
+        oracleData, meterState: meterPda(meterId), meterStats: statsPda(meterId),
+        authority: chainBridge.publicKey, systemProgram: SystemProgram.programId,
+      } as any).instruction();
+  const rejectionsIx = (meterId: string, count: number, auth: PublicKey = chainBridge.publicKey) =>
+    oracle.methods.recordMeterRejections(meterId, new BN(count))
+      .accounts({
+        oracleData, meterStats: statsPda(meterId), authority: auth,
+        aggregatorEntry: null, systemProgram: SystemProgram.programId,
+      } as any).instruction();
+
+  before(async () => {
+    svm = new LiteSVM().withDefaultPrograms();
+    oracle = new Program(oracleIdl, { connection: {}, publicKey: PublicKey.default } as any);
+    oracleId = oracle.programId;
+    svm.addProgramFromFile(oracleId, "target/deploy/oracle.so");
+    for (const kp of [payer, chainBridge, outsider]) svm.airdrop(kp.publicKey, BigInt(1_000_000_000_000));
+
+    oracleData = PublicKey.findProgramAddressSync([Buffer.from("oracle_data")], oracleId)[0];
+    svm.setClock(new Clock(svm.getClock().slot, 0n, 0n, 0n, BigInt(NOW)));
+
+    send([await oracle.methods.initialize(chainBridge.publicKey)
+      .accounts({ oracleData, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any)
+      .instruction()]);
+  });
+
+  it("counts accepted readings per meter", async () => {
+    send([await submitIx("m-a", 500, 500, NOW - 300)], [chainBridge]);
+    send([await submitIx("m-a", 600, 600, NOW - 200)], [chainBridge]);
+    send([await submitIx("m-b", 100, 100, NOW - 200)], [chainBridge]);
+
+    const a = stats("m-a");
+    expect(Buffer.from(a.meterId).subarray(0, a.meterIdLen).toString()).to.equal("m-a");
+    expect(a.totalReadings.toNumber()).to.equal(2);
+    expect(a.lastTimestamp.toNumber()).to.equal(NOW - 200);
+    expect(a.rejectedCount.toNumber()).to.equal(0);
+    expect(a.averageReadingInterval.toNumber()).to.equal(100);
+    expect(stats("m-b").totalReadings.toNumber()).to.equal(1);
+    expect(stats("m-b").averageReadingInterval.toNumber()).to.equal(0);
+  });
+
+  it("leaves the counters alone when a reading is rejected", async () => {
+    // produced*100 > ratio(1000)*consumed → AnomalousReading, the submission reverts.
+    const blob = sendExpectFail([await submitIx("m-a", 2000, 10, NOW - 100)], [chainBridge]);
+    expect(blob, blob).to.match(/AnomalousReading/);
+    expect(stats("m-a").totalReadings.toNumber()).to.equal(2);
+    expect(stats("m-a").lastTimestamp.toNumber()).to.equal(NOW - 200);
+  });
+
+  it("records gateway-reported rejections against the meter", async () => {
+    send([await rejectionsIx("m-a", 1)], [chainBridge]);
+    send([await rejectionsIx("m-a", 2)], [chainBridge]);
+    expect(stats("m-a").rejectedCount.toNumber()).to.equal(3);
+    expect(stats("m-b").rejectedCount.toNumber()).to.equal(0);
+  });
+
+  it("creates the stats PDA for a meter whose only readings were rejected", async () => {
+    send([await rejectionsIx("m-new", 4)], [chainBridge]);
+    const s = stats("m-new");
+    expect(s.totalReadings.toNumber()).to.equal(0);
+    expect(s.rejectedCount.toNumber()).to.equal(4);
+  });
+
+  it("rejects rejection reports from a non-gateway signer (UnauthorizedGateway)", async () => {
+    const blob = sendExpectFail([await rejectionsIx("m-a", 1, outsider.publicKey)], [outsider]);
+    expect(blob, blob).to.match(/UnauthorizedGateway/);
+  });
+});
@@ -92,6 +92,8 @@ describe("trading order-path validation guards (litesvm)", () => {
       [Buffer.from("order"), auth.toBuffer(), new BN(orderId).toArrayLike(Buffer, "le", 8)],
       tradingId
     )[0];
+  const traderStatsPda = (trader: PublicKey) =>
+    PublicKey.findProgramAddressSync([Buffer.from("trader"), trader.toBuffer()], tradingId)[0];
 
   // Fabricate a governance GovernanceConfig account with the given maintenance flag (all other
   // fields zero/default — only maintenance_mode gates create_sell_order's MaintenanceMode).
@@ -124,7 +126,7 @@ describe("trading order-path validation guards (litesvm)", () => {
       pendingAuthority: PublicKey.default,
       pendingAuthorityProposedAt: new BN(0),
       pendingAuthorityExpiresAt: new BN(0),
-      reserved: Array(5).fill(0),
+      minFeeBps: 0, maxFeeBps: 0, reserved: Array(1).fill(0),
     };
     const data = await governance.coder.accounts.encode("governanceConfig", cfg as any);
     svm.setAccount(key, {
@@ -137,19 +139,26 @@ describe("trading order-path validation guards (litesvm)", () => {
     return key;
   }
 
-  // Fabricate an ErcCertificate with the exact fields a guard keys on.
+  // Fabricate an ErcCertificate with the exact fields a guard keys on. Each one sits at its
+  // real ["erc_certificate", id] PDA, because governance re-derives it when trading reserves
+  // the order's energy on the certificate.
+  let ercSeq = 0;
   async function installErc(o: {
     status: string; // "valid" | "expired" | "revoked" | "pending"
     expiresAt: number | null;
     validated: boolean;
     energyAmount: number;
+    owner?: PublicKey;
   }): Promise<PublicKey> {
-    const key = Keypair.generate().publicKey;
+    const id = Buffer.from(`ERC-${++ercSeq}`);
+    const [key] = PublicKey.findProgramAddressSync([Buffer.from("erc_certificate"), id], governanceId);
+    const certificateId = Array(64).fill(0);
+    id.forEach((b, i) => (certificateId[i] = b));
     const erc = {
-      certificateId: Array(64).fill(0),
-      idLen: 0,
+      certificateId,
+      idLen: id.length,
       authority: payer.publicKey,
-      owner: payer.publicKey,
+      owner: o.owner ?? payer.publicKey,
       energyAmount: new BN(o.energyAmount),
       renewableSource: Array(64).fill(0),
       sourceLen: 0,
@@ -165,6 +174,8 @@ describe("trading order-path validation guards (litesvm)", () => {
       revokedAt: null,
       transferCount: 0,
       lastTransferredAt: null,
+      meterAccount: PublicKey.default,
+      reservedAmount: new BN(0),
     };
     const data = await governance.coder.accounts.encode("ercCertificate", erc as any);
     svm.setAccount(key, {
@@ -188,6 +199,8 @@ describe("trading order-path validation guards (litesvm)", () => {
         authority: payer.publicKey,
         systemProgram: SystemProgram.programId,
         governanceConfig: cfgKey,
+        marketAuthority: ercKey ? marketAuthorityPda : null,
+        governanceProgram: ercKey ? governanceId : null,
       } as any)
       .instruction();
   }
@@ -215,10 +228,29 @@ describe("trading order-path validation guards (litesvm)", () => {
         order: orderPda(payer.publicKey, orderId),
         authority: payer.publicKey,
         governanceConfig: cfgKey,
+        traderStats: traderStatsPda(payer.publicKey),
       } as any)
       .instruction();
   }
 
+  const ercAccount = (key: PublicKey): any =>
+    governance.coder.accounts.decode("ercCertificate", Buffer.from(svm.getAccount(key)!.data));
+
+  const releaseIx = (orderId: number, ercKey: PublicKey) =>
+    trading.methods.releaseErcReservation().accounts({
+      order: orderPda(payer.publicKey, orderId), ercCertificate: ercKey,
+      marketAuthority: marketAuthorityPda, governanceProgram: governanceId,
+    } as any).instruction();
+
+  const order = (orderId: number): any =>
+    trading.coder.accounts.decode("order", Buffer.from(svm.getAccount(orderPda(payer.publicKey, orderId))!.data));
+
+  const tradePda = () => {
+    const m: any = trading.coder.accounts.decode("market", Buffer.from(svm.getAccount(marketPda)!.data));
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("trade"), marketPda.toBuffer(), m.tradeSeq.toArrayLike(Buffer, "le", 8)], tradingId)[0];
+  };
+
   before(async () => {
     svm = new LiteSVM().withDefaultPrograms();
     trading = new Program(tradingIdl, { connection: {}, publicKey: PublicKey.default } as any);
@@ -238,7 +270,7 @@ describe("trading order-path validation guards (litesvm)", () => {
     [recMintPda] = PublicKey.findProgramAddressSync([Buffer.from("rec_mint")], governanceId);
 
     send([await trading.methods.initializeMarket(16).accounts({ market: marketPda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()], []);
-    send([await trading.methods.initializeZoneMarket(ZONE, 16, new BN(1_000_000)).accounts({ market: marketPda, zoneMarket: zoneMarketPda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()], []);
+    send([await trading.methods.initializeZoneMarket(ZONE, 16, new BN(1_000_000), 10).accounts({ market: marketPda, zoneMarket: zoneMarketPda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()], []);
 
     // Currency mint + collectors + a funded escrow (for the withdraw_escrow guard).
     const mintRent = Number(svm.minimumBalanceForRentExemption(BigInt(MINT_SIZE)));
@@ -264,8 +296,15 @@ describe("trading order-path validation guards (litesvm)", () => {
     const cfg = await installConfig(false);
     const erc = await installErc({ status: "valid", expiresAt: FUTURE, validated: true, energyAmount: 100 });
     send([await sellOrderIx(1, 100, 50, erc, cfg)], []);
-    // Order PDA now exists (created).
+    // Order PDA now exists (created) and records the certificate that backs it.
     expect(svm.getAccount(orderPda(payer.publicKey, 1))).to.not.be.null;
+    expect(order(1).ercCertificate.toBase58()).to.equal(erc.toBase58());
+  });
+
+  it("leaves erc_certificate as the default key when no certificate is supplied", async () => {
+    const cfg = await installConfig(false);
+    send([await sellOrderIx(8, 100, 50, null, cfg)], []);
+    expect(order(8).ercCertificate.toBase58()).to.equal(PublicKey.default.toBase58());
   });
 
   it("rejects order creation while in maintenance mode (MaintenanceMode)", async () => {
@@ -302,6 +341,16 @@ describe("trading order-path validation guards (litesvm)", () => {
     expect(blob, blob).to.match(/ExceedsErcAmount/);
   });
 
+  it("rejects a certificate owned by someone else (ErcOwnerMismatch)", async () => {
+    const cfg = await installConfig(false);
+    const erc = await installErc({
+      status: "valid", expiresAt: FUTURE, validated: true, energyAmount: 100, owner: Keypair.generate().publicKey,
+    });
+    const blob = sendExpectFail([await sellOrderIx(9, 100, 50, erc, cfg)], []);
+    expect(blob, blob).to.match(/ErcOwnerMismatch/);
+    expect(ercAccount(erc).reservedAmount.toNumber()).to.equal(0);
+  });
+
   // ── Fungible REC provenance gate (opt-in via remaining_accounts[0]) ──────────
   // The gate reads the seller's REC token account from remaining_accounts. We
   // fabricate that SPL token account directly (mint/owner/amount set by hand) — no
@@ -352,6 +401,8 @@ describe("trading order-path validation guards (litesvm)", () => {
         authority: payer.publicKey,
         systemProgram: SystemProgram.programId,
         governanceConfig: cfgKey,
+        marketAuthority: ercKey ? marketAuthorityPda : null,
+        governanceProgram: ercKey ? governanceId : null,
       } as any)
       .remainingAccounts([{ pubkey: recAccount, isSigner: false, isWritable: false }])
       .instruction();
@@ -394,6 +445,48 @@ describe("trading order-path validation guards (litesvm)", () => {
     expect(blob, blob).to.match(/RecAccountOwnerMismatch/);
   });
 
+  it("reserves the order's energy on the certificate and refuses to over-commit it (ErcOverCommitted)", async () => {
+    const cfg = await installConfig(false);
+    const cert = await installErc({ status: "valid", expiresAt: FUTURE, validated: true, energyAmount: 100 });
+    send([await sellOrderIx(40, 60, 50, cert, cfg)], []);
+    expect(ercAccount(cert).reservedAmount.toNumber()).to.equal(60);
+    expect(order(40).ercReserved).to.equal(1);
+    // 60 + 50 > 100, even though each order alone fits the certificate.
+    const blob = sendExpectFail([await sellOrderIx(41, 50, 50, cert, cfg)], []);
+    expect(blob, blob).to.match(/ErcOverCommitted/);
+    send([await sellOrderIx(42, 40, 50, cert, cfg)], []);
+    expect(ercAccount(cert).reservedAmount.toNumber()).to.equal(100);
+  });
+
+  it("requires market_authority and governance_program with a certificate (MissingErcReservationAccounts)", async () => {
+    const cfg = await installConfig(false);
+    const cert = await installErc({ status: "valid", expiresAt: FUTURE, validated: true, energyAmount: 100 });
+    const ix = await trading.methods.createSellOrder(new BN(43), new BN(10), new BN(50)).accounts({
+      market: marketPda, zoneMarket: zoneMarketPda, order: orderPda(payer.publicKey, 43), ercCertificate: cert,
+      authority: payer.publicKey, systemProgram: SystemProgram.programId, governanceConfig: cfg,
+    } as any).instruction();
+    const blob = sendExpectFail([ix], []);
+    expect(blob, blob).to.match(/MissingErcReservationAccounts/);
+  });
+
+  it("releases a cancelled order's reservation exactly once", async () => {
+    const cfg = await installConfig(false);
+    const cert = await installErc({ status: "valid", expiresAt: FUTURE, validated: true, energyAmount: 100 });
+    send([await sellOrderIx(44, 100, 50, cert, cfg)], []);
+    let blob = sendExpectFail([await releaseIx(44, cert)], []); // still open
+    expect(blob, blob).to.match(/OrderNotRefundable/);
+
+    send([await cancelIx(44, cfg)], []);
+    send([await releaseIx(44, cert)], []);
+    expect(ercAccount(cert).reservedAmount.toNumber()).to.equal(0);
+    expect(order(44).ercReserved).to.equal(0);
+    expect(order(44).ercCertificate.toBase58()).to.equal(cert.toBase58()); // provenance kept
+
+    blob = sendExpectFail([await releaseIx(44, cert)], []);
+    expect(blob, blob).to.match(/NoErcReservation/);
+    send([await sellOrderIx(45, 100, 50, cert, cfg)], []); // capacity is usable again
+  });
+
   it("rejects cancelling an order that is no longer cancellable (OrderNotCancellable)", async () => {
     const cfg = await installConfig(false);
     const erc = await installErc({ status: "valid", expiresAt: FUTURE, validated: true, energyAmount: 100 });
@@ -409,18 +502,66 @@ describe("trading order-path validation guards (litesvm)", () => {
     send([await buyOrderIx(10, 100, 40, cfg)], []);       // buy max 40
     send([await sellOrderIx(11, 100, 50, erc, cfg)], []); // sell at 50
     const buy = orderPda(payer.publicKey, 10), sell = orderPda(payer.publicKey, 11);
-    const tradeRecord = PublicKey.findProgramAddressSync([Buffer.from("trade"), buy.toBuffer(), sell.toBuffer()], tradingId)[0];
+    const tradeRecord = tradePda();
     const ix = await trading.methods
       .matchOrders(new BN(100))
       .accounts({
         market: marketPda, zoneMarket: zoneMarketPda, buyOrder: buy, sellOrder: sell,
         tradeRecord, authority: payer.publicKey, governanceConfig: cfg, systemProgram: SystemProgram.programId,
+        buyerStats: traderStatsPda(payer.publicKey),
       } as any)
       .instruction();
     const blob = sendExpectFail([ix], []); // buy_price 40 < sell_price 50
     expect(blob, blob).to.match(/PriceMismatch/);
   });
 
+  // --- set_market_frozen (kill switch) ---
+
+  const frozenIx = (frozen: boolean, auth: PublicKey = payer.publicKey) =>
+    trading.methods.setMarketFrozen(frozen).accounts({ market: marketPda, authority: auth } as any).instruction();
+
+  it("rejects set_market_frozen from a non-authority (has_one)", async () => {
+    const outsider = Keypair.generate();
+    svm.airdrop(outsider.publicKey, BigInt(1_000_000_000));
+    const blob = sendExpectFail([await frozenIx(true, outsider.publicKey)], [outsider]);
+    expect(blob, blob).to.match(/has_one|2001|ConstraintHasOne/);
+  });
+
+  it("blocks order creation and matching while frozen but still allows cancels (MarketFrozen)", async () => {
+    const cfg = await installConfig(false);
+    const erc = await installErc({ status: "valid", expiresAt: FUTURE, validated: true, energyAmount: 100 });
+    send([await buyOrderIx(20, 100, 60, cfg)], []);
+    send([await sellOrderIx(21, 100, 50, erc, cfg)], []);
+    send([await frozenIx(true)], []);
+
+    let blob = sendExpectFail([await sellOrderIx(22, 100, 50, erc, cfg)], []);
+    expect(blob, blob).to.match(/MarketFrozen/);
+    blob = sendExpectFail([await buyOrderIx(23, 100, 60, cfg)], []);
+    expect(blob, blob).to.match(/MarketFrozen/);
+
+    const buy = orderPda(payer.publicKey, 20), sell = orderPda(payer.publicKey, 21);
+    const tradeRecord = tradePda();
+    const matchIx = await trading.methods
+      .matchOrders(new BN(100))
+      .accounts({
+        market: marketPda, zoneMarket: zoneMarketPda, buyOrder: buy, sellOrder: sell,
+        tradeRecord, authority: payer.publicKey, governanceConfig: cfg, systemProgram: SystemProgram.programId,
+        buyerStats: traderStatsPda(payer.publicKey),
+      } as any)
+      .instruction();
+    blob = sendExpectFail([matchIx], []);
+    expect(blob, blob).to.match(/MarketFrozen/);
+
+    send([await cancelIx(20, cfg)], []); // users can still exit
+  });
+
+  it("accepts orders again once unfrozen (control)", async () => {
+    const cfg = await installConfig(false);
+    send([await frozenIx(false)], []);
+    send([await buyOrderIx(24, 100, 60, cfg)], []);
+    expect(svm.getAccount(orderPda(payer.publicKey, 24))).to.not.be.null;
+  });
+
   it("rejects withdrawing more than the escrow balance (InsufficientEscrowBalance)", async () => {
     const escrow = PublicKey.findProgramAddressSync([Buffer.from("escrow"), payer.publicKey.toBuffer(), currencyMint.toBuffer()], tradingId)[0];
     const payerAta = getAssociatedTokenAddressSync(currencyMint, payer.publicKey, false, TOKEN_PROGRAM_ID);
@@ -0,0 +1,214 @@
+// Litesvm coverage for absolute-mode meters: after set_meter_reading_mode(1) the oracle submits
+// cumulative registers and update_meter_reading credits the difference from the value kept in
+// the meter_absolute PDA. The first reading only sets the baseline, a register that goes
+// backwards fails with NonMonotonicReading, and delta-mode meters are unaffected.
+// reactivate_meter drops the register, so the register jump over a maintenance gap becomes the
+// new baseline instead of being credited.
+
+import { LiteSVM, Clock, FailedTransactionMetadata } from "litesvm";
+import { Program } from "@anchor-lang/core";
+import { Registry } from "../target/types/registry";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  Transaction,
+  SystemProgram,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import BN from "bn.js";
+import { createRequire } from "module";
+
+const require = createRequire(import.meta.url);
+const idl = require("../target/idl/registry.json");
+
+const NOW = 10_000;
+const DELTA = 0;
+const ABSOLUTE = 1;
+
+describe("registry absolute meter readings (litesvm)", () => {
+  let svm: LiteSVM;
+  let program: Program<Registry>;
+  let programId: PublicKey;
+
+  const payer = Keypair.generate();     // registry authority + funder
+  const user = Keypair.generate();      // meter owner
+  const oracle = Keypair.generate();    // configured oracle authority
+  const attacker = Keypair.generate();
+
+  let registryPda: PublicKey;
+  let userPda: PublicKey;
+  let shardPda: PublicKey;
+  let shardId: number;
+  let readingTs = 5_000;
+
+  function trySend(ixs: TransactionInstruction[], signers: Keypair[]): FailedTransactionMetadata | null {
+    const tx = new Transaction();
+    tx.recentBlockhash = svm.latestBlockhash();
+    tx.feePayer = payer.publicKey;
+    ixs.forEach((ix) => tx.add(ix));
+    tx.sign(payer, ...signers);
+    const res = svm.sendTransaction(tx);
+    svm.expireBlockhash();
+    return res instanceof FailedTransactionMetadata ? res : null;
+  }
+  function send(ixs: TransactionInstruction[], signers: Keypair[] = []) {
+    const f = trySend(ixs, signers);
+    if (f) throw new Error("tx failed: " + f.err().toString() + "\n" + f.meta().logs().join("\n"));
+  }
+  function sendExpectFail(ixs: TransactionInstruction[], signers: Keypair[] = []): string {
+    const f = trySend(ixs, signers);
+    if (!f) throw new Error("expected tx to fail but it succeeded");
+    return f.err().toString() + "\n" + f.meta().logs().join("\n");
+  }
+
+  const meterPda = (meterId: string) =>
+    PublicKey.findProgramAddressSync([Buffer.from("meter"), user.publicKey.toBuffer(), Buffer.from(meterId)], programId)[0];
+  const absolutePda = (meter: PublicKey) =>
+    PublicKey.findProgramAddressSync([Buffer.from("meter_absolute"), meter.toBuffer()], programId)[0];
+  const meter = (pda: PublicKey): any =>
+    program.coder.accounts.decode("meterAccount", Buffer.from(svm.getAccount(pda)!.data));
+  const register = (pda: PublicKey): any =>
+    program.coder.accounts.decode("meterAbsoluteReading", Buffer.from(svm.getAccount(absolutePda(pda))!.data));
+
+  // Each reading lands 100 s after the previous one.
+  const readingIx = (pda: PublicKey, generated: number, consumed: number, withRegister = true) => {
+    readingTs += 100;
+    return program.methods.updateMeterReading(new BN(generated), new BN(consumed), new BN(readingTs)).accounts({
+      registry: registryPda, meterAccount: pda, oracleAuthority: oracle.publicKey,
+      readingLog: null, absoluteReading: withRegister ? absolutePda(pda) : null,
+    } as any).instruction();
+  };
+  const modeIx = (pda: PublicKey, auth: PublicKey, mode: number) =>
+    program.methods.setMeterReadingMode(mode).accounts({
+      registry: registryPda, meterAccount: pda, absoluteReading: absolutePda(pda),
+      authority: auth, systemProgram: SystemProgram.programId,
+    } as any).instruction();
+
+  async function registerMeter(meterId: string): Promise<PublicKey> {
+    const pda = meterPda(meterId);
+    send([await program.methods.registerMeter(meterId, { solar: {} }, shardId, 0).accounts({
+      meterAccount: pda, userAccount: userPda, registryShard: shardPda, registry: registryPda,
+      owner: user.publicKey, payer: payer.publicKey, systemProgram: SystemProgram.programId,
+    }).instruction()]);
+    return pda;
+  }
+
+  before(async () => {
+    svm = new LiteSVM().withDefaultPrograms();
+    program = new Program(idl, { connection: {}, publicKey: PublicKey.default } as any);
+    programId = program.programId;
+    svm.addProgramFromFile(programId, "target/deploy/registry.so");
+    svm.airdrop(payer.publicKey, BigInt(1_000_000_000_000));
+    svm.airdrop(user.publicKey, BigInt(10_000_000_000));
+    svm.airdrop(attacker.publicKey, BigInt(10_000_000_000));
+    const c = svm.getClock();
+    svm.setClock(new Clock(c.slot, 0n, 0n, 0n, BigInt(NOW)));
+
+    [registryPda] = PublicKey.findProgramAddressSync([Buffer.from("registry")], programId);
+    [userPda] = PublicKey.findProgramAddressSync([Buffer.from("user"), user.publicKey.toBuffer()], programId);
+    shardId = user.publicKey.toBytes()[0] % 16;
+    [shardPda] = PublicKey.findProgramAddressSync([Buffer.from("registry_shard"), Buffer.from([shardId])], programId);
+
+    send([
+      await program.methods.initialize().accounts({ registry: registryPda, authority: payer.publicKey, systemProgram: SystemProgram.programId }).instruction(),
+      await program.methods.initializeShard(shardId).accounts({ shard: shardPda, authority: payer.publicKey, systemProgram: SystemProgram.programId }).instruction(),
+    ]);
+    send([await program.methods.registerUser({ prosumer: {} }, 0, 0, new BN(0), shardId).accounts({
+      userAccount: userPda, registryShard: shardPda, registry: registryPda, authority: user.publicKey, payer: payer.publicKey, systemProgram: SystemProgram.programId,
+    }).instruction()]);
+    send([await program.methods.setOracleAuthority(oracle.publicKey).accounts({ registry: registryPda, authority: payer.publicKey }).instruction()]);
+  });
+
+  it("keeps new meters in delta mode", async () => {
+    const pda = await registerMeter("METER-DELTA-1");
+    expect(meter(pda).readingMode).to.equal(DELTA);
+    send([await readingIx(pda, 300, 100, false)], [oracle]);
+    send([await readingIx(pda, 300, 100, false)], [oracle]);
+    expect(meter(pda).totalGeneration.toNumber()).to.equal(600);
+    expect(meter(pda).totalConsumption.toNumber()).to.equal(200);
+  });
+
+  it("rejects a mode change from anyone but the registry authority, and an unknown mode", async () => {
+    const pda = await registerMeter("METER-ABS-0");
+    let blob = sendExpectFail([await modeIx(pda, attacker.publicKey, ABSOLUTE)], [attacker]);
+    expect(blob, blob).to.match(/UnauthorizedAuthority/);
+    // the meter owner cannot re-baseline its own register either
+    blob = sendExpectFail([await modeIx(pda, user.publicKey, ABSOLUTE)], [user]);
+    expect(blob, blob).to.match(/UnauthorizedAuthority/);
+    expect(meter(pda).readingMode).to.equal(DELTA);
+    blob = sendExpectFail([await modeIx(pda, payer.publicKey, 2)]);
+    expect(blob, blob).to.match(/InvalidReadingMode/);
+  });
+
+  it("baselines the first absolute reading and credits differences after it", async () => {
+    const pda = await registerMeter("METER-ABS-1");
+    send([await modeIx(pda, payer.publicKey, ABSOLUTE)]);
+    expect(meter(pda).readingMode).to.equal(ABSOLUTE);
+    expect(register(pda).hasBaseline).to.equal(0);
+
+    send([await readingIx(pda, 50_000, 20_000)], [oracle]);
+    expect(meter(pda).totalGeneration.toNumber()).to.equal(0);
+    expect(register(pda).lastGeneration.toNumber()).to.equal(50_000);
+
+    send([await readingIx(pda, 50_400, 20_150)], [oracle]);
+    send([await readingIx(pda, 50_400, 20_200)], [oracle]); // flat generation is fine
+    expect(meter(pda).totalGeneration.toNumber()).to.equal(400);
+    expect(meter(pda).totalConsumption.toNumber()).to.equal(200);
+    expect(register(pda).lastConsumption.toNumber()).to.equal(20_200);
+  });
+
+  it("rejects a register that goes backwards (NonMonotonicReading)", async () => {
+    const pda = meterPda("METER-ABS-1");
+    let blob = sendExpectFail([await readingIx(pda, 50_399, 20_200)], [oracle]);
+    expect(blob, blob).to.match(/NonMonotonicReading/);
+    blob = sendExpectFail([await readingIx(pda, 50_500, 20_199)], [oracle]);
+    expect(blob, blob).to.match(/NonMonotonicReading/);
+    expect(meter(pda).totalGeneration.toNumber()).to.equal(400);
+  });
+
+  it("requires the register account for absolute meters (MissingAbsoluteReading)", async () => {
+    const blob = sendExpectFail([await readingIx(meterPda("METER-ABS-1"), 50_500, 20_300, false)], [oracle]);
+    expect(blob, blob).to.match(/MissingAbsoluteReading/);
+  });
+
+  it("re-baselines when the admin switches modes again", async () => {
+    const pda = meterPda("METER-ABS-1");
+    send([await modeIx(pda, payer.publicKey, DELTA)]);
+    send([await readingIx(pda, 10, 0, false)], [oracle]);
+    expect(meter(pda).totalGeneration.toNumber()).to.equal(410);
+
+    send([await modeIx(pda, payer.publicKey, ABSOLUTE)]);
+    send([await readingIx(pda, 1_000, 0)], [oracle]); // new baseline, even though lower than before
+    send([await readingIx(pda, 1_090, 0)], [oracle]);
+    expect(meter(pda).totalGeneration.toNumber()).to.equal(500);
+  });
+
+  it("clears the register on reactivate_meter so the maintenance gap is not credited", async () => {
+    const pda = await registerMeter("METER-ABS-2");
+    send([await modeIx(pda, payer.publicKey, ABSOLUTE)]);
+    send([await readingIx(pda, 70_000, 5_000)], [oracle]);
+    send([await readingIx(pda, 70_300, 5_000)], [oracle]);
+    expect(meter(pda).totalGeneration.toNumber()).to.equal(300);
+
+    send([await program.methods.setMeterStatus({ maintenance: {} }).accounts({
+      registry: registryPda, meterAccount: pda, registryShard: shardPda, authority: user.publicKey,
+    } as any).instruction()], [user]);
+    const reactivateIx = (withRegister: boolean) =>
+      program.methods.reactivateMeter(new BN(300), new BN(0)).accounts({
+        registry: registryPda, meterAccount: pda, registryShard: shardPda, authority: payer.publicKey,
+        absoluteReading: withRegister ? absolutePda(pda) : null,
+      } as any).instruction();
+    const blob = sendExpectFail([await reactivateIx(false)]);
+    expect(blob, blob).to.match(/MissingAbsoluteReading/);
+    send([await reactivateIx(true)]);
+    expect(register(pda).hasBaseline).to.equal(0);
+
+    // The register moved 19_700 while the meter was down; that reading only re-baselines.
+    readingTs = NOW;
+    send([await readingIx(pda, 90_000, 5_000)], [oracle]);
+    expect(meter(pda).totalGeneration.toNumber()).to.equal(300);
+    send([await readingIx(pda, 90_200, 5_000)], [oracle]);
+    expect(meter(pda).totalGeneration.toNumber()).to.equal(500);
+  });
+});
@@ -0,0 +1,150 @@
+// Litesvm coverage for the registry implied-power check: once the authority sets
+// registry.max_power, update_meter_reading emits AnomalousMeterReading for any reading whose
+// energy_generated / (reading_timestamp - last_reading_at) exceeds it. The reading is still
+// recorded. max_power == 0 (the default) disables the check; set_max_power is authority-only.
+
+import { LiteSVM, Clock, FailedTransactionMetadata, TransactionMetadata } from "litesvm";
+import { Program, EventParser } from "@anchor-lang/core";
+import { Registry } from "../target/types/registry";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  Transaction,
+  SystemProgram,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import BN from "bn.js";
+import { createRequire } from "module";
+
+const require = createRequire(import.meta.url);
+const idl = require("../target/idl/registry.json");
+
+const METER_ID = "METER-PWR-1";
+const NOW = 10_000;
+
+describe("registry anomalous meter reading (litesvm)", () => {
+  let svm: LiteSVM;
+  let program: Program<Registry>;
+  let programId: PublicKey;
+
+  const payer = Keypair.generate();     // registry authority + funder
+  const user = Keypair.generate();      // meter owner
+  const oracle = Keypair.generate();    // configured oracle authority
+  const attacker = Keypair.generate();
+
+  let registryPda: PublicKey;
+  let meterPda: PublicKey;
+  let readingTs = 5_000;
+
+  function trySend(ixs: TransactionInstruction[], signers: Keypair[]): FailedTransactionMetadata | null {
+    const tx = new Transaction();
+    tx.recentBlockhash = svm.latestBlockhash();
+    tx.feePayer = payer.publicKey;
+    ixs.forEach((ix) => tx.add(ix));
+    tx.sign(payer, ...signers);
+    const res = svm.sendTransaction(tx);
+    svm.expireBlockhash();
+    return res instanceof FailedTransactionMetadata ? res : null;
+  }
+  function send(ixs: TransactionInstruction[], signers: Keypair[] = []) {
+    const f = trySend(ixs, signers);
+    if (f) throw new Error("tx failed: " + f.err().toString() + "\n" + f.meta().logs().join("\n"));
+  }
+  function sendExpectFail(ixs: TransactionInstruction[], signers: Keypair[] = []): string {
+    const f = trySend(ixs, signers);
+    if (!f) throw new Error("expected tx to fail but it succeeded");
+    return f.err().toString() + "\n" + f.meta().logs().join("\n");
+  }
+  function sendWithLogs(ixs: TransactionInstruction[], signers: Keypair[] = []): string[] {
+    const tx = new Transaction();
+    tx.recentBlockhash = svm.latestBlockhash();
+    tx.feePayer = payer.publicKey;
+    ixs.forEach((ix) => tx.add(ix));
+    tx.sign(payer, ...signers);
+    const res = svm.sendTransaction(tx);
+    svm.expireBlockhash();
+    if (res instanceof FailedTransactionMetadata) {
+      throw new Error("tx failed: " + res.err().toString() + "\n" + res.meta().logs().join("\n"));
+    }
+    return (res as TransactionMetadata).logs();
+  }
+
+  const anomalies = (logs: string[]) => {
+    const parser = new EventParser(programId, (program as any).coder);
+    return [...parser.parseLogs(logs)].filter((e) => e.name === "AnomalousMeterReading" || e.name === "anomalousMeterReading");
+  };
+  const meter = (): any =>
+    program.coder.accounts.decode("meterAccount", Buffer.from(svm.getAccount(meterPda)!.data));
+  const registry = (): any =>
+    program.coder.accounts.decode("registry", Buffer.from(svm.getAccount(registryPda)!.data));
+
+  // Each reading lands 100 s after the previous one.
+  const readingIx = (generated: number) => {
+    readingTs += 100;
+    return program.methods.updateMeterReading(new BN(generated), new BN(0), new BN(readingTs)).accounts({
+      registry: registryPda, meterAccount: meterPda, oracleAuthority: oracle.publicKey,
+    } as any).instruction();
+  };
+  const maxPowerIx = (auth: PublicKey, maxPower: number) =>
+    program.methods.setMaxPower(maxPower).accounts({ registry: registryPda, authority: auth } as any).instruction();
+
+  before(async () => {
+    svm = new LiteSVM().withDefaultPrograms();
+    program = new Program(idl, { connection: {}, publicKey: PublicKey.default } as any);
+    programId = program.programId;
+    svm.addProgramFromFile(programId, "target/deploy/registry.so");
+    svm.airdrop(payer.publicKey, BigInt(1_000_000_000_000));
+    const c = svm.getClock();
+    svm.setClock(new Clock(c.slot, 0n, 0n, 0n, BigInt(NOW)));
+
+    [registryPda] = PublicKey.findProgramAddressSync([Buffer.from("registry")], programId);
+    const [userPda] = PublicKey.findProgramAddressSync([Buffer.from("user"), user.publicKey.toBuffer()], programId);
+    const shardId = user.publicKey.toBytes()[0] % 16;
+    const [shardPda] = PublicKey.findProgramAddressSync([Buffer.from("registry_shard"), Buffer.from([shardId])], programId);
+    [meterPda] = PublicKey.findProgramAddressSync([Buffer.from("meter"), user.publicKey.toBuffer(), Buffer.from(METER_ID)], programId);
+
+    send([
+      await program.methods.initialize().accounts({ registry: registryPda, authority: payer.publicKey, systemProgram: SystemProgram.programId }).instruction(),
+      await program.methods.initializeShard(shardId).accounts({ shard: shardPda, authority: payer.publicKey, systemProgram: SystemProgram.programId }).instruction(),
+    ]);
+    send([await program.methods.registerUser({ prosumer: {} }, 0, 0, new BN(0), shardId).accounts({
+      userAccount: userPda, registryShard: shardPda, registry: registryPda, authority: user.publicKey, payer: payer.publicKey, systemProgram: SystemProgram.programId,
+    }).instruction()]);
+    send([await program.methods.registerMeter(METER_ID, { solar: {} }, shardId, 0).accounts({
+      meterAccount: meterPda, userAccount: userPda, registryShard: shardPda, registry: registryPda,
+      owner: user.publicKey, payer: payer.publicKey, systemProgram: SystemProgram.programId,
+    }).instruction()]);
+    send([await program.methods.setOracleAuthority(oracle.publicKey).accounts({ registry: registryPda, authority: payer.publicKey }).instruction()]);
+    send([await readingIx(1_000)], [oracle]); // first reading: never checked
+  });
+
+  it("does not flag anything while max_power is unset", async () => {
+    expect(registry().maxPower).to.equal(0);
+    expect(anomalies(sendWithLogs([await readingIx(100_000)], [oracle]))).to.have.length(0);
+  });
+
+  it("rejects set_max_power from a non-authority (UnauthorizedAuthority)", async () => {
+    const blob = sendExpectFail([await maxPowerIx(attacker.publicKey, 5)], [attacker]);
+    expect(blob, blob).to.match(/UnauthorizedAuthority/);
+  });
+
+  it("accepts a reading at or below max_power without an event", async () => {
+    send([await maxPowerIx(payer.publicKey, 5)]);
+    expect(registry().maxPower).to.equal(5);
+    expect(anomalies(sendWithLogs([await readingIx(500)], [oracle]))).to.have.length(0); // 5 per second
+  });
+
+  it("flags a reading above max_power and still records it", async () => {
+    const before = meter().totalGeneration.toNumber();
+    const ev = anomalies(sendWithLogs([await readingIx(1_200)], [oracle])); // 12 per second
+    expect(ev).to.have.length(1);
+    const d: any = ev[0].data;
+    expect(d.impliedPower.toNumber()).to.equal(12);
+    expect(d.maxPower).to.equal(5);
+    expect(d.elapsedSeconds.toNumber()).to.equal(100);
+    expect(d.owner.toBase58()).to.equal(user.publicKey.toBase58());
+    expect(meter().totalGeneration.toNumber()).to.equal(before + 1_200);
+    expect(meter().lastReadingAt.toNumber()).to.equal(readingTs);
+  });
+});
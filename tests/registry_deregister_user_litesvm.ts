@@ -0,0 +1,115 @@
+// Litesvm coverage for registry deregister_user: the UserAccount is closed back to the
+// user, and the shard user_count drops. Closure is refused with ActiveMetersExist while
+// a meter is still registered, so no MeterAccount is orphaned.
+
+import { LiteSVM, FailedTransactionMetadata } from "litesvm";
+import { Program } from "@anchor-lang/core";
+import { Registry } from "../target/types/registry";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  Transaction,
+  SystemProgram,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import BN from "bn.js";
+import { createRequire } from "module";
+
+const require = createRequire(import.meta.url);
+const idl = require("../target/idl/registry.json");
+
+const METER_ID = "METER-DEREG";
+
+describe("registry deregister_user (litesvm)", () => {
+  let svm: LiteSVM;
+  let program: Program<Registry>;
+  let programId: PublicKey;
+
+  const payer = Keypair.generate(); // registry authority + funder
+  const user = Keypair.generate();
+
+  let registryPda: PublicKey;
+  let userPda: PublicKey;
+  let shardPda: PublicKey;
+  let meterPda: PublicKey;
+  let shardId: number;
+
+  function trySend(ixs: TransactionInstruction[], signers: Keypair[]): FailedTransactionMetadata | null {
+    const tx = new Transaction();
+    tx.recentBlockhash = svm.latestBlockhash();
+    tx.feePayer = payer.publicKey;
+    ixs.forEach((ix) => tx.add(ix));
+    tx.sign(payer, ...signers);
+    const res = svm.sendTransaction(tx);
+    svm.expireBlockhash();
+    return res instanceof FailedTransactionMetadata ? res : null;
+  }
+  function send(ixs: TransactionInstruction[], signers: Keypair[] = []) {
+    const f = trySend(ixs, signers);
+    if (f) throw new Error("tx failed: " + f.err().toString() + "\n" + f.meta().logs().join("\n"));
+  }
+  function sendExpectFail(ixs: TransactionInstruction[], signers: Keypair[] = []): string {
+    const f = trySend(ixs, signers);
+    if (!f) throw new Error("expected tx to fail but it succeeded");
+    return f.err().toString() + "\n" + f.meta().logs().join("\n");
+  }
+
+  const deregisterIx = () =>
+    program.methods.deregisterUser().accounts({
+      userAccount: userPda,
+      registry: registryPda,
+      registryShard: shardPda,
+      userAuthority: user.publicKey,
+    } as any).instruction();
+
+  before(async () => {
+    svm = new LiteSVM().withDefaultPrograms();
+    program = new Program(idl, { connection: {}, publicKey: PublicKey.default } as any);
+    programId = program.programId;
+    svm.addProgramFromFile(programId, "target/deploy/registry.so");
+    svm.airdrop(payer.publicKey, BigInt(1_000_000_000_000));
+    svm.airdrop(user.publicKey, BigInt(1_000_000_000));
+
+    shardId = user.publicKey.toBytes()[0] % 16;
+    [registryPda] = PublicKey.findProgramAddressSync([Buffer.from("registry")], programId);
+    [userPda] = PublicKey.findProgramAddressSync([Buffer.from("user"), user.publicKey.toBuffer()], programId);
+    [shardPda] = PublicKey.findProgramAddressSync([Buffer.from("registry_shard"), Buffer.from([shardId])], programId);
+    [meterPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("meter"), user.publicKey.toBuffer(), Buffer.from(METER_ID)], programId);
+
+    send([await program.methods.initialize().accounts({ registry: registryPda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()]);
+    send([await program.methods.initializeShard(shardId).accounts({ shard: shardPda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()]);
+    send([await program.methods.registerUser({ prosumer: {} }, 0, 0, new BN(0), shardId).accounts({
+      userAccount: userPda, registryShard: shardPda, registry: registryPda,
+      authority: user.publicKey, payer: payer.publicKey, systemProgram: SystemProgram.programId,
+    } as any).instruction()], [user]);
+    send([await program.methods.registerMeter(METER_ID, { solar: {} }, shardId, 0).accounts({
+      meterAccount: meterPda, userAccount: userPda, registryShard: shardPda,
+      registry: registryPda, owner: user.publicKey, payer: payer.publicKey, systemProgram: SystemProgram.programId,
+    } as any).instruction()], [user]);
+  });
+
+  it("rejects deregistration while a meter is still registered (ActiveMetersExist)", async () => {
+    const blob = sendExpectFail([await deregisterIx()], [user]);
+    expect(blob, blob).to.match(/ActiveMetersExist/);
+  });
+
+  it("closes the UserAccount once every meter is deactivated", async () => {
+    send([await program.methods.deactivateMeter().accounts({
+      meterAccount: meterPda, userAccount: userPda, registry: registryPda,
+      registryShard: shardPda, owner: user.publicKey,
+    } as any).instruction()], [user]);
+
+    const rent = svm.getAccount(userPda)!.lamports;
+    const before = svm.getBalance(user.publicKey)!;
+    send([await deregisterIx()], [user]);
+
+    const closed = svm.getAccount(userPda);
+    expect(closed === null || closed.lamports === 0).to.equal(true);
+    expect(Number(svm.getBalance(user.publicKey)! - before)).to.equal(Number(rent));
+
+    const shard: any = program.coder.accounts.decode("registryShard", Buffer.from(svm.getAccount(shardPda)!.data));
+    expect(shard.userCount.toNumber()).to.equal(0);
+  });
+});
@@ -0,0 +1,152 @@
+// Litesvm coverage for registry register_meters_batch: every MeterAccount PDA in
+// remaining_accounts is created and initialised, and the user/shard counters move by the batch
+// size. A failing batch (over MAX_METER_BATCH, an id repeated in the batch, or an id that is
+// already registered) reverts as a whole: none of its meters exist afterwards. A PDA that
+// already holds lamports is still registered, as Anchor's `init` would.
+
+import { LiteSVM, FailedTransactionMetadata } from "litesvm";
+import { Program } from "@anchor-lang/core";
+import { Registry } from "../target/types/registry";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  Transaction,
+  SystemProgram,
+  TransactionInstruction,
+  ComputeBudgetProgram,
+} from "@solana/web3.js";
+import BN from "bn.js";
+import { createRequire } from "module";
+
+const require = createRequire(import.meta.url);
+const registryIdl = require("../target/idl/registry.json");
+
+const ZONE = 3;
+const MAX_METER_BATCH = 10;
+
+describe("registry register_meters_batch (litesvm)", () => {
+  let svm: LiteSVM;
+  let reg: Program<Registry>;
+  let regId: PublicKey;
+
+  const payer = Keypair.generate(); // registry authority + fee payer
+  const user = Keypair.generate(); // meter owner (non-signing)
+
+  let registryPda: PublicKey;
+  let shardPda: PublicKey;
+  let userPda: PublicKey;
+  let shardId: number;
+
+  function trySend(ixs: TransactionInstruction[]): FailedTransactionMetadata | null {
+    const tx = new Transaction();
+    tx.recentBlockhash = svm.latestBlockhash();
+    tx.feePayer = payer.publicKey;
+    ixs.forEach((ix) => tx.add(ix));
+    tx.sign(payer);
+    const res = svm.sendTransaction(tx);
+    svm.expireBlockhash();
+    return res instanceof FailedTransactionMetadata ? res : null;
+  }
+  function send(ixs: TransactionInstruction[]) {
+    const f = trySend(ixs);
+    if (f) throw new Error("tx failed: " + f.err().toString() + "\n" + f.meta().logs().join("\n"));
+  }
+  function sendExpectFail(ixs: TransactionInstruction[]): string {
+    const f = trySend(ixs);
+    if (!f) throw new Error("expected tx to fail but it succeeded");
+    return f.err().toString() + "\n" + f.meta().logs().join("\n");
+  }
+
+  const meterPda = (id: string) =>
+    PublicKey.findProgramAddressSync([Buffer.from("meter"), user.publicKey.toBuffer(), Buffer.from(id)], regId)[0];
+
+  const batchIx = (ids: string[]) =>
+    reg.methods.registerMetersBatch(ids.map((meterId) => ({ meterId, meterType: { solar: {} } })), shardId, ZONE)
+      .accounts({
+        userAccount: userPda, registryShard: shardPda, owner: user.publicKey,
+        payer: payer.publicKey, systemProgram: SystemProgram.programId,
+      } as any)
+      .remainingAccounts(ids.map((id) => ({ pubkey: meterPda(id), isWritable: true, isSigner: false })))
+      .instruction();
+  const cu = ComputeBudgetProgram.setComputeUnitLimit({ units: 1_400_000 });
+
+  const decode = (name: string, key: PublicKey): any =>
+    reg.coder.accounts.decode(name, Buffer.from(svm.getAccount(key)!.data));
+
+  before(async () => {
+    svm = new LiteSVM().withDefaultPrograms();
+    reg = new Program(registryIdl, { connection: {}, publicKey: PublicKey.default } as any);
+    regId = reg.programId;
+    svm.addProgramFromFile(regId, "target/deploy/registry.so");
+    svm.airdrop(payer.publicKey, BigInt(1_000_000_000_000));
+
+    shardId = user.publicKey.toBytes()[0] % 16;
+    [registryPda] = PublicKey.findProgramAddressSync([Buffer.from("registry")], regId);
+    [shardPda] = PublicKey.findProgramAddressSync([Buffer.from("registry_shard"), Buffer.from([shardId])], regId);
+    [userPda] = PublicKey.findProgramAddressSync([Buffer.from("user"), user.publicKey.toBuffer()], regId);
+
+    send([
+      await reg.methods.initialize().accounts({ registry: registryPda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction(),
+      await reg.methods.initializeShard(shardId).accounts({ shard: shardPda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction(),
+    ]);
+    send([await reg.methods.registerUser({ prosumer: {} }, 0, 0, new BN(0), shardId).accounts({
+      userAccount: userPda, registryShard: shardPda, registry: registryPda, authority: user.publicKey, payer: payer.publicKey, systemProgram: SystemProgram.programId,
+    } as any).instruction()]);
+  });
+
+  it("registers every meter in the batch and bumps the counters", async () => {
+    const ids = ["FLEET-1", "FLEET-2", "FLEET-3"];
+    send([cu, await batchIx(ids)]);
+
+    for (const id of ids) {
+      const m = decode("meterAccount", meterPda(id));
+      expect(Buffer.from(m.meterId).toString().replace(/\0+$/, "")).to.equal(id);
+      expect(m.owner.toBase58()).to.equal(user.publicKey.toBase58());
+      expect(m.status).to.have.property("active");
+      expect(m.meterType).to.have.property("solar");
+      expect(m.zoneId).to.equal(ZONE);
+    }
+    expect(decode("userAccount", userPda).meterCount).to.equal(3);
+    const shard = decode("registryShard", shardPda);
+    expect(shard.meterCount.toNumber()).to.equal(3);
+    expect(shard.activeMeterCount.toNumber()).to.equal(3);
+  });
+
+  it("rejects a batch over MAX_METER_BATCH (InvalidMeterBatch)", async () => {
+    const ids = Array.from({ length: MAX_METER_BATCH + 1 }, (_, i) => `BIG-${i}`);
+    const blob = sendExpectFail([cu, await batchIx(ids)]);
+    expect(blob, blob).to.match(/InvalidMeterBatch/);
+  });
+
+  it("rejects an id repeated within the batch and creates none of it", async () => {
+    const blob = sendExpectFail([cu, await batchIx(["DUP-A", "DUP-B", "DUP-A"])]);
+    expect(blob, blob).to.match(/MeterAlreadyRegistered/);
+    expect(svm.getAccount(meterPda("DUP-A"))).to.be.null;
+    expect(svm.getAccount(meterPda("DUP-B"))).to.be.null;
+  });
+
+  it("rejects a collision with an existing meter and leaves the counters alone", async () => {
+    const blob = sendExpectFail([cu, await batchIx(["FLEET-4", "FLEET-2"])]);
+    expect(blob, blob).to.match(/MeterAlreadyRegistered/);
+    expect(svm.getAccount(meterPda("FLEET-4"))).to.be.null;
+    expect(decode("userAccount", userPda).meterCount).to.equal(3);
+    expect(decode("registryShard", shardPda).meterCount.toNumber()).to.equal(3);
+  });
+
+  it("registers a meter whose PDA was pre-funded, short of rent or above it", async () => {
+    const ids = ["GRIEF-1", "GRIEF-2"];
+    svm.airdrop(meterPda("GRIEF-1"), BigInt(1_000));           // below rent exemption
+    svm.airdrop(meterPda("GRIEF-2"), BigInt(5_000_000_000));   // well above it
+    send([cu, await batchIx(ids)]);
+
+    for (const id of ids) {
+      const acct = svm.getAccount(meterPda(id))!;
+      expect(acct.owner.toBase58()).to.equal(regId.toBase58());
+      expect(BigInt(acct.lamports)).to.be.at.least(svm.minimumBalanceForRentExemption(BigInt(acct.data.length)));
+      expect(Buffer.from(decode("meterAccount", meterPda(id)).meterId).toString().replace(/\0+$/, "")).to.equal(id);
+    }
+    expect(svm.getAccount(meterPda("GRIEF-2"))!.lamports).to.equal(5_000_000_000); // no top-up needed
+    expect(decode("userAccount", userPda).meterCount).to.equal(5);
+  });
+});
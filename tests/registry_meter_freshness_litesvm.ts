@@ -0,0 +1,133 @@
+// Litesvm coverage for registry is_meter_fresh: a meter counts as fresh only while it is
+// Active and its last reading is at most `max_age_seconds` older than the bank clock.
+// Never-read meters are stale; a negative max age is rejected with InvalidMaxAge.
+
+import { LiteSVM, Clock, FailedTransactionMetadata } from "litesvm";
+import { Program } from "@anchor-lang/core";
+import { Registry } from "../target/types/registry";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  Transaction,
+  SystemProgram,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import BN from "bn.js";
+import { createRequire } from "module";
+
+const require = createRequire(import.meta.url);
+const idl = require("../target/idl/registry.json");
+
+const METER_ID = "METER001";
+const NOW = 10_000;
+const READ_AT = 9_000;
+const MAX_AGE = 3_600;
+
+describe("registry is_meter_fresh (litesvm)", () => {
+  let svm: LiteSVM;
+  let program: Program<Registry>;
+  let programId: PublicKey;
+
+  const payer = Keypair.generate();   // registry authority + funder
+  const user = Keypair.generate();    // meter owner
+  const oracle = Keypair.generate();  // configured oracle authority
+
+  let registryPda: PublicKey;
+  let userPda: PublicKey;
+  let shardPda: PublicKey;
+  let meterPda: PublicKey;
+  let shardId: number;
+
+  function trySend(ixs: TransactionInstruction[], signers: Keypair[]): FailedTransactionMetadata | null {
+    const tx = new Transaction();
+    tx.recentBlockhash = svm.latestBlockhash();
+    tx.feePayer = payer.publicKey;
+    ixs.forEach((ix) => tx.add(ix));
+    tx.sign(payer, ...signers);
+    const res = svm.sendTransaction(tx);
+    svm.expireBlockhash();
+    return res instanceof FailedTransactionMetadata ? res : null;
+  }
+  function send(ixs: TransactionInstruction[], signers: Keypair[] = []) {
+    const f = trySend(ixs, signers);
+    if (f) throw new Error("tx failed: " + f.err().toString() + "\n" + f.meta().logs().join("\n"));
+  }
+  function sendExpectFail(ixs: TransactionInstruction[], signers: Keypair[] = []): string {
+    const f = trySend(ixs, signers);
+    if (!f) throw new Error("expected tx to fail but it succeeded");
+    return f.err().toString() + "\n" + f.meta().logs().join("\n");
+  }
+
+  // Capture an instruction's Anchor return value (read-only getters).
+  function sendRaw(ixs: TransactionInstruction[], signers: Keypair[] = []) {
+    const tx = new Transaction();
+    tx.recentBlockhash = svm.latestBlockhash();
+    tx.feePayer = payer.publicKey;
+    ixs.forEach((ix) => tx.add(ix));
+    tx.sign(payer, ...signers);
+    const res = svm.sendTransaction(tx);
+    svm.expireBlockhash();
+    if (res instanceof FailedTransactionMetadata) throw new Error("tx failed: " + res.err().toString());
+    return res;
+  }
+  const retBool = (res: any) => { const d = res.returnData().data(); return d.length > 0 && d[0] === 1; };
+  function warpClock(unixTs: number) {
+    const c = svm.getClock();
+    svm.setClock(new Clock(c.slot, 0n, 0n, 0n, BigInt(unixTs)));
+  }
+
+  const freshIx = (maxAge: number) =>
+    program.methods.isMeterFresh(new BN(maxAge)).accounts({ meterAccount: meterPda } as any).instruction();
+
+  before(async () => {
+    svm = new LiteSVM().withDefaultPrograms();
+    program = new Program(idl, { connection: {}, publicKey: PublicKey.default } as any);
+    programId = program.programId;
+    svm.addProgramFromFile(programId, "target/deploy/registry.so");
+    svm.airdrop(payer.publicKey, BigInt(1_000_000_000_000));
+    warpClock(NOW);
+
+    [registryPda] = PublicKey.findProgramAddressSync([Buffer.from("registry")], programId);
+    [userPda] = PublicKey.findProgramAddressSync([Buffer.from("user"), user.publicKey.toBuffer()], programId);
+    shardId = user.publicKey.toBytes()[0] % 16;
+    [shardPda] = PublicKey.findProgramAddressSync([Buffer.from("registry_shard"), Buffer.from([shardId])], programId);
+    [meterPda] = PublicKey.findProgramAddressSync([Buffer.from("meter"), user.publicKey.toBuffer(), Buffer.from(METER_ID)], programId);
+
+    send([
+      await program.methods.initialize().accounts({ registry: registryPda, authority: payer.publicKey, systemProgram: SystemProgram.programId }).instruction(),
+      await program.methods.initializeShard(shardId).accounts({ shard: shardPda, authority: payer.publicKey, systemProgram: SystemProgram.programId }).instruction(),
+    ]);
+    send([await program.methods.registerUser({ prosumer: {} }, 0, 0, new BN(0), shardId).accounts({
+      userAccount: userPda, registryShard: shardPda, registry: registryPda, authority: user.publicKey, payer: payer.publicKey, systemProgram: SystemProgram.programId,
+    }).instruction()]);
+    send([await program.methods.registerMeter(METER_ID, { solar: {} }, shardId, 0).accounts({
+      meterAccount: meterPda, userAccount: userPda, registryShard: shardPda, registry: registryPda,
+      owner: user.publicKey, payer: payer.publicKey, systemProgram: SystemProgram.programId,
+    }).instruction()]);
+    send([await program.methods.setOracleAuthority(oracle.publicKey).accounts({ registry: registryPda, authority: payer.publicKey }).instruction()]);
+  });
+
+  it("treats a meter that has never reported as stale", async () => {
+    expect(retBool(sendRaw([await freshIx(MAX_AGE)]))).to.equal(false);
+  });
+
+  it("reports a recent reading as fresh", async () => {
+    send([await program.methods.updateMeterReading(new BN(1000), new BN(200), new BN(READ_AT)).accounts({
+      registry: registryPda, meterAccount: meterPda, oracleAuthority: oracle.publicKey,
+    } as any).instruction()], [oracle]);
+    expect(retBool(sendRaw([await freshIx(MAX_AGE)]))).to.equal(true); // age 1_000
+  });
+
+  it("stays fresh at exactly max age and goes stale one second later", async () => {
+    warpClock(READ_AT + MAX_AGE);
+    expect(retBool(sendRaw([await freshIx(MAX_AGE)]))).to.equal(true);
+    warpClock(READ_AT + MAX_AGE + 1);
+    expect(retBool(sendRaw([await freshIx(MAX_AGE)]))).to.equal(false);
+  });
+
+  it("rejects a negative max age (InvalidMaxAge)", async () => {
+    const blob = sendExpectFail([await freshIx(-1)]);
+    expect(blob, blob).to.match(/InvalidMaxAge/);
+  });
+});
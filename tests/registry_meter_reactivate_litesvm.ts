@@ -0,0 +1,160 @@
+// Litesvm coverage for registry reactivate_meter: a meter taken into Maintenance comes back
+// Active at a caller-supplied cumulative baseline. Net generation accrued over the gap is
+// rebased into settled_net_generation (so the unsettled balance is unchanged) and
+// last_reading_at moves to the reactivation time (so a reading from the gap is StaleReading).
+// Guards: owner/authority only, Maintenance only, baseline not below the recorded totals, and
+// set_meter_status cannot take the Maintenance -> Active shortcut.
+
+import { LiteSVM, Clock, FailedTransactionMetadata } from "litesvm";
+import { Program } from "@anchor-lang/core";
+import { Registry } from "../target/types/registry";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  Transaction,
+  SystemProgram,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import BN from "bn.js";
+import { createRequire } from "module";
+
+const require = createRequire(import.meta.url);
+const idl = require("../target/idl/registry.json");
+
+const METER_ID = "METER-REACT-1";
+const NOW = 10_000;
+
+describe("registry reactivate_meter (litesvm)", () => {
+  let svm: LiteSVM;
+  let program: Program<Registry>;
+  let programId: PublicKey;
+
+  const payer = Keypair.generate();     // registry authority + funder
+  const user = Keypair.generate();      // meter owner
+  const oracle = Keypair.generate();    // configured oracle authority
+  const attacker = Keypair.generate();
+
+  let registryPda: PublicKey;
+  let shardPda: PublicKey;
+  let meterPda: PublicKey;
+
+  function trySend(ixs: TransactionInstruction[], signers: Keypair[]): FailedTransactionMetadata | null {
+    const tx = new Transaction();
+    tx.recentBlockhash = svm.latestBlockhash();
+    tx.feePayer = payer.publicKey;
+    ixs.forEach((ix) => tx.add(ix));
+    tx.sign(payer, ...signers);
+    const res = svm.sendTransaction(tx);
+    svm.expireBlockhash();
+    return res instanceof FailedTransactionMetadata ? res : null;
+  }
+  function send(ixs: TransactionInstruction[], signers: Keypair[] = []) {
+    const f = trySend(ixs, signers);
+    if (f) throw new Error("tx failed: " + f.err().toString() + "\n" + f.meta().logs().join("\n"));
+  }
+  function sendExpectFail(ixs: TransactionInstruction[], signers: Keypair[] = []): string {
+    const f = trySend(ixs, signers);
+    if (!f) throw new Error("expected tx to fail but it succeeded");
+    return f.err().toString() + "\n" + f.meta().logs().join("\n");
+  }
+
+  const setTime = (ts: number) => {
+    const c = svm.getClock();
+    svm.setClock(new Clock(c.slot + 1n, 0n, 0n, 0n, BigInt(ts)));
+  };
+  const meter = (): any =>
+    program.coder.accounts.decode("meterAccount", Buffer.from(svm.getAccount(meterPda)!.data));
+  const unsettled = () => {
+    const m = meter();
+    return Math.max(0, m.totalGeneration.toNumber() - m.totalConsumption.toNumber() - m.settledNetGeneration.toNumber());
+  };
+
+  const readingIx = (generated: number, consumed: number, ts: number) =>
+    program.methods.updateMeterReading(new BN(generated), new BN(consumed), new BN(ts)).accounts({
+      registry: registryPda, meterAccount: meterPda, oracleAuthority: oracle.publicKey,
+    } as any).instruction();
+  const statusIx = (status: any) =>
+    program.methods.setMeterStatus(status).accounts({
+      registry: registryPda, meterAccount: meterPda, registryShard: shardPda, authority: user.publicKey,
+    } as any).instruction();
+  const reactivateIx = (auth: PublicKey, gen: number, cons: number) =>
+    program.methods.reactivateMeter(new BN(gen), new BN(cons)).accounts({
+      registry: registryPda, meterAccount: meterPda, registryShard: shardPda, authority: auth,
+      absoluteReading: null,
+    } as any).instruction();
+
+  before(async () => {
+    svm = new LiteSVM().withDefaultPrograms();
+    program = new Program(idl, { connection: {}, publicKey: PublicKey.default } as any);
+    programId = program.programId;
+    svm.addProgramFromFile(programId, "target/deploy/registry.so");
+    svm.airdrop(payer.publicKey, BigInt(1_000_000_000_000));
+    setTime(NOW);
+
+    [registryPda] = PublicKey.findProgramAddressSync([Buffer.from("registry")], programId);
+    const [userPda] = PublicKey.findProgramAddressSync([Buffer.from("user"), user.publicKey.toBuffer()], programId);
+    const shardId = user.publicKey.toBytes()[0] % 16;
+    [shardPda] = PublicKey.findProgramAddressSync([Buffer.from("registry_shard"), Buffer.from([shardId])], programId);
+    [meterPda] = PublicKey.findProgramAddressSync([Buffer.from("meter"), user.publicKey.toBuffer(), Buffer.from(METER_ID)], programId);
+
+    send([
+      await program.methods.initialize().accounts({ registry: registryPda, authority: payer.publicKey, systemProgram: SystemProgram.programId }).instruction(),
+      await program.methods.initializeShard(shardId).accounts({ shard: shardPda, authority: payer.publicKey, systemProgram: SystemProgram.programId }).instruction(),
+    ]);
+    send([await program.methods.registerUser({ prosumer: {} }, 0, 0, new BN(0), shardId).accounts({
+      userAccount: userPda, registryShard: shardPda, registry: registryPda, authority: user.publicKey, payer: payer.publicKey, systemProgram: SystemProgram.programId,
+    }).instruction()]);
+    send([await program.methods.registerMeter(METER_ID, { solar: {} }, shardId, 0).accounts({
+      meterAccount: meterPda, userAccount: userPda, registryShard: shardPda, registry: registryPda,
+      owner: user.publicKey, payer: payer.publicKey, systemProgram: SystemProgram.programId,
+    }).instruction()]);
+    send([await program.methods.setOracleAuthority(oracle.publicKey).accounts({ registry: registryPda, authority: payer.publicKey }).instruction()]);
+
+    // 1000 generated, 200 consumed -> 800 unsettled going into maintenance.
+    send([await readingIx(1_000, 200, 9_000)], [oracle]);
+  });
+
+  it("rejects reactivating a meter that is not in Maintenance (InvalidMeterStatusTransition)", async () => {
+    const blob = sendExpectFail([await reactivateIx(user.publicKey, 1_000, 200)], [user]);
+    expect(blob, blob).to.match(/InvalidMeterStatusTransition/);
+  });
+
+  it("rejects an outsider (UnauthorizedUser)", async () => {
+    send([await statusIx({ maintenance: {} })], [user]);
+    const blob = sendExpectFail([await reactivateIx(attacker.publicKey, 5_000, 700)], [attacker]);
+    expect(blob, blob).to.match(/UnauthorizedUser/);
+  });
+
+  it("rejects leaving Maintenance through set_meter_status (InvalidMeterStatusTransition)", async () => {
+    const blob = sendExpectFail([await statusIx({ active: {} })], [user]);
+    expect(blob, blob).to.match(/InvalidMeterStatusTransition/);
+    expect(meter().status).to.deep.equal({ maintenance: {} });
+  });
+
+  it("rejects a baseline below the recorded totals (InvalidMeterBaseline)", async () => {
+    const blob = sendExpectFail([await reactivateIx(user.publicKey, 900, 200)], [user]);
+    expect(blob, blob).to.match(/InvalidMeterBaseline/);
+  });
+
+  it("reactivates at the baseline without creating unsettled balance from the gap", async () => {
+    const before = unsettled();
+    expect(before).to.equal(800);
+    setTime(20_000);
+    send([await reactivateIx(payer.publicKey, 5_000, 700)]);
+
+    const m = meter();
+    expect(m.status).to.deep.equal({ active: {} });
+    expect(m.totalGeneration.toNumber()).to.equal(5_000);
+    expect(m.totalConsumption.toNumber()).to.equal(700);
+    expect(m.lastReadingAt.toNumber()).to.equal(20_000);
+    expect(unsettled()).to.equal(before);
+  });
+
+  it("rejects a reading timestamped inside the maintenance gap (StaleReading)", async () => {
+    const blob = sendExpectFail([await readingIx(50, 0, 15_000)], [oracle]);
+    expect(blob, blob).to.match(/StaleReading/);
+    send([await readingIx(50, 0, 20_100)], [oracle]);
+    expect(unsettled()).to.equal(850);
+  });
+});
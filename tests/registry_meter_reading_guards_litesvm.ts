@@ -3,6 +3,7 @@
 // all previously untested:
 //   OracleNotConfigured → UnauthorizedOracle → InvalidMeterStatus → StaleReading
 //   → ReadingTooFrequent → ReadingTooHigh  (guard order, lib.rs:451-485)
+// plus the opt-in MeterReadingLog audit trail written by update_meter_reading.
 //
 // reading_timestamp is an explicit arg (not the bank clock), so the stale/rate-limit
 // cases need no setClock — they compare the arg against the meter's stored last_reading_at.
@@ -127,6 +128,37 @@ describe("registry meter-reading validation guards (litesvm)", () => {
     expect(blob, blob).to.match(/ReadingTooFrequent/);
   });
 
+  it("appends to the reading log once one is initialized", async () => {
+    const [logPda] = PublicKey.findProgramAddressSync([Buffer.from("reading_log"), meterPda.toBuffer()], programId);
+    send([await program.methods.initReadingLog().accounts({
+      readingLog: logPda, meterAccount: meterPda, payer: payer.publicKey, systemProgram: SystemProgram.programId,
+    } as any).instruction()]);
+    send([await program.methods.updateMeterReading(new BN(70), new BN(20), new BN(1060)).accounts({
+      registry: registryPda, meterAccount: meterPda, oracleAuthority: oracle.publicKey, readingLog: logPda,
+    } as any).instruction()], [oracle]);
+    const log: any = program.coder.accounts.decode("meterReadingLog", Buffer.from(svm.getAccount(logPda)!.data));
+    expect(log.count).to.equal(1);
+    expect(log.head).to.equal(1);
+    expect(log.entries[0].generated.toNumber()).to.equal(70);
+    expect(log.entries[0].timestamp.toNumber()).to.equal(1060);
+  });
+
+  it("correct_meter_reading is admin-only and bounds the reason", async () => {
+    const correctIx = (auth: PublicKey, reason: string) =>
+      program.methods.correctMeterReading(new BN(60), new BN(10), reason)
+        .accounts({ registry: registryPda, meterAccount: meterPda, authority: auth } as any).instruction();
+
+    let blob = sendExpectFail([await correctIx(oracle.publicKey, "meter reset")], [oracle]);
+    expect(blob, blob).to.match(/UnauthorizedAuthority/);
+    blob = sendExpectFail([await correctIx(payer.publicKey, "x".repeat(101))]);
+    expect(blob, blob).to.match(/ReasonTooLong/);
+
+    send([await correctIx(payer.publicKey, "meter reset")]);
+    const meter: any = program.coder.accounts.decode("meterAccount", Buffer.from(svm.getAccount(meterPda)!.data));
+    expect(meter.totalGeneration.toNumber()).to.equal(60);
+    expect(meter.totalConsumption.toNumber()).to.equal(10);
+  });
+
   it("set_meter_status cannot set Inactive — that is deactivate_meter's job (InvalidMeterStatusTransition)", async () => {
     // Setting Inactive here would drop active_meter_count but leave meter_count/user.meter_count
     // overcounted. Inactive is reachable only via deactivate_meter. Meter stays Active (reverts).
@@ -0,0 +1,152 @@
+// Litesvm coverage for registry transfer_meter: ownership moves to another registered user,
+// both users' meter_count and (cross-shard) the shard counters follow, and the settlement
+// watermarks stay on the meter so the new owner cannot re-mint settled generation.
+//
+// Guards: UnauthorizedUser (signer is neither owner nor registry authority) and
+// AccountNotInitialized (new owner has no UserAccount).
+
+import { LiteSVM, FailedTransactionMetadata } from "litesvm";
+import { Program } from "@anchor-lang/core";
+import { Registry } from "../target/types/registry";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  Transaction,
+  SystemProgram,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import BN from "bn.js";
+import { createRequire } from "module";
+
+const require = createRequire(import.meta.url);
+const idl = require("../target/idl/registry.json");
+
+const METER_ID = "METER-XFER";
+
+describe("registry transfer_meter (litesvm)", () => {
+  let svm: LiteSVM;
+  let program: Program<Registry>;
+  let programId: PublicKey;
+
+  const payer = Keypair.generate(); // registry authority + funder
+  const seller = Keypair.generate(); // current meter owner
+  const oracle = Keypair.generate();
+  const outsider = Keypair.generate();
+  let buyer = Keypair.generate(); // regenerated until it lands on a different shard
+
+  let registryPda: PublicKey;
+  let meterPda: PublicKey;
+
+  function trySend(ixs: TransactionInstruction[], signers: Keypair[]): FailedTransactionMetadata | null {
+    const tx = new Transaction();
+    tx.recentBlockhash = svm.latestBlockhash();
+    tx.feePayer = payer.publicKey;
+    ixs.forEach((ix) => tx.add(ix));
+    tx.sign(payer, ...signers);
+    const res = svm.sendTransaction(tx);
+    svm.expireBlockhash();
+    return res instanceof FailedTransactionMetadata ? res : null;
+  }
+  function send(ixs: TransactionInstruction[], signers: Keypair[] = []) {
+    const f = trySend(ixs, signers);
+    if (f) throw new Error("tx failed: " + f.err().toString() + "\n" + f.meta().logs().join("\n"));
+  }
+  function sendExpectFail(ixs: TransactionInstruction[], signers: Keypair[] = []): string {
+    const f = trySend(ixs, signers);
+    if (!f) throw new Error("expected tx to fail but it succeeded");
+    return f.err().toString() + "\n" + f.meta().logs().join("\n");
+  }
+
+  const shardOf = (k: PublicKey) => k.toBytes()[0] % 16;
+  const userPda = (k: PublicKey) =>
+    PublicKey.findProgramAddressSync([Buffer.from("user"), k.toBuffer()], programId)[0];
+  const shardPda = (id: number) =>
+    PublicKey.findProgramAddressSync([Buffer.from("registry_shard"), Buffer.from([id])], programId)[0];
+  const decode = (name: string, pda: PublicKey): any =>
+    program.coder.accounts.decode(name, Buffer.from(svm.getAccount(pda)!.data));
+
+  const transferIx = (authority: PublicKey, newOwner: PublicKey) =>
+    program.methods.transferMeter(newOwner).accounts({
+      registry: registryPda,
+      meterAccount: meterPda,
+      oldUserAccount: userPda(seller.publicKey),
+      newUserAccount: userPda(newOwner),
+      oldRegistryShard: shardPda(shardOf(seller.publicKey)),
+      newRegistryShard: shardPda(shardOf(newOwner)),
+      authority,
+    } as any).instruction();
+
+  async function registerUser(k: PublicKey) {
+    send([await program.methods.registerUser({ prosumer: {} }, 0, 0, new BN(0), shardOf(k)).accounts({
+      userAccount: userPda(k), registryShard: shardPda(shardOf(k)), registry: registryPda,
+      authority: k, payer: payer.publicKey, systemProgram: SystemProgram.programId,
+    } as any).instruction()]);
+  }
+
+  before(async () => {
+    svm = new LiteSVM().withDefaultPrograms();
+    program = new Program(idl, { connection: {}, publicKey: PublicKey.default } as any);
+    programId = program.programId;
+    svm.addProgramFromFile(programId, "target/deploy/registry.so");
+    svm.airdrop(payer.publicKey, BigInt(1_000_000_000_000));
+
+    while (shardOf(buyer.publicKey) === shardOf(seller.publicKey)) buyer = Keypair.generate();
+
+    [registryPda] = PublicKey.findProgramAddressSync([Buffer.from("registry")], programId);
+    [meterPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("meter"), seller.publicKey.toBuffer(), Buffer.from(METER_ID)], programId);
+
+    send([await program.methods.initialize().accounts({ registry: registryPda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()]);
+    for (const id of [shardOf(seller.publicKey), shardOf(buyer.publicKey)]) {
+      send([await program.methods.initializeShard(id).accounts({ shard: shardPda(id), authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()]);
+    }
+    await registerUser(seller.publicKey);
+    send([await program.methods.registerMeter(METER_ID, { solar: {} }, shardOf(seller.publicKey), 0).accounts({
+      meterAccount: meterPda, userAccount: userPda(seller.publicKey), registryShard: shardPda(shardOf(seller.publicKey)),
+      registry: registryPda, owner: seller.publicKey, payer: payer.publicKey, systemProgram: SystemProgram.programId,
+    } as any).instruction()]);
+
+    // Give the meter settled generation so the watermark carry-over is observable.
+    send([await program.methods.setOracleAuthority(oracle.publicKey).accounts({ registry: registryPda, authority: payer.publicKey } as any).instruction()]);
+    send([await program.methods.updateMeterReading(new BN(1000), new BN(200), new BN(1000)).accounts({
+      registry: registryPda, meterAccount: meterPda, oracleAuthority: oracle.publicKey,
+    } as any).instruction()], [oracle]);
+    send([await program.methods.settleMeterBalance(new BN(0)).accounts({ meterAccount: meterPda, meterOwner: seller.publicKey } as any).instruction()], [seller]);
+  });
+
+  it("rejects a transfer to a user with no UserAccount", async () => {
+    const blob = sendExpectFail([await transferIx(seller.publicKey, buyer.publicKey)], [seller]);
+    expect(blob, blob).to.match(/AccountNotInitialized|3012/);
+  });
+
+  it("rejects a transfer signed by neither owner nor authority (UnauthorizedUser)", async () => {
+    await registerUser(buyer.publicKey);
+    const blob = sendExpectFail([await transferIx(outsider.publicKey, buyer.publicKey)], [outsider]);
+    expect(blob, blob).to.match(/UnauthorizedUser/);
+  });
+
+  it("moves ownership, meter counts, and shard counts; keeps watermarks", async () => {
+    send([await transferIx(seller.publicKey, buyer.publicKey)], [seller]);
+
+    const meter = decode("meterAccount", meterPda);
+    expect(meter.owner.toBase58()).to.equal(buyer.publicKey.toBase58());
+    expect(meter.settledNetGeneration.toNumber()).to.equal(800);
+
+    expect(decode("userAccount", userPda(seller.publicKey)).meterCount).to.equal(0);
+    expect(decode("userAccount", userPda(buyer.publicKey)).meterCount).to.equal(1);
+
+    const oldShard = decode("registryShard", shardPda(shardOf(seller.publicKey)));
+    const newShard = decode("registryShard", shardPda(shardOf(buyer.publicKey)));
+    expect(oldShard.meterCount.toNumber()).to.equal(0);
+    expect(oldShard.activeMeterCount.toNumber()).to.equal(0);
+    expect(newShard.meterCount.toNumber()).to.equal(1);
+    expect(newShard.activeMeterCount.toNumber()).to.equal(1);
+  });
+
+  it("the new owner cannot re-settle already-settled generation (NoUnsettledBalance)", async () => {
+    const blob = sendExpectFail([await program.methods.settleMeterBalance(new BN(0))
+      .accounts({ meterAccount: meterPda, meterOwner: buyer.publicKey } as any).instruction()], [buyer]);
+    expect(blob, blob).to.match(/NoUnsettledBalance/);
+  });
+});
@@ -0,0 +1,165 @@
+// Litesvm coverage for the registry's two-step oracle rotation: set_oracle_authority only
+// configures the first oracle (OracleAlreadySet afterwards), propose_oracle_authority parks
+// the new key in the oracle_proposal PDA, and only that key can accept it. Also covers
+// cancel_oracle_authority_proposal, a duplicate proposal and the authority checks.
+
+import { LiteSVM, Clock, FailedTransactionMetadata } from "litesvm";
+import { Program } from "@anchor-lang/core";
+import { Registry } from "../target/types/registry";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  Transaction,
+  SystemProgram,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import BN from "bn.js";
+import { createRequire } from "module";
+
+const require = createRequire(import.meta.url);
+const idl = require("../target/idl/registry.json");
+
+const NOW = 10_000;
+const METER = "ROT-1";
+
+describe("registry oracle rotation (litesvm)", () => {
+  let svm: LiteSVM;
+  let program: Program<Registry>;
+  let programId: PublicKey;
+
+  const payer = Keypair.generate();      // registry authority + funder
+  const user = Keypair.generate();       // meter owner
+  const oldOracle = Keypair.generate();
+  const newOracle = Keypair.generate();
+  const outsider = Keypair.generate();
+
+  let registryPda: PublicKey;
+  let proposalPda: PublicKey;
+  let meterPda: PublicKey;
+  let readingTs = 5_000;
+
+  function trySend(ixs: TransactionInstruction[], signers: Keypair[]): FailedTransactionMetadata | null {
+    const tx = new Transaction();
+    tx.recentBlockhash = svm.latestBlockhash();
+    tx.feePayer = payer.publicKey;
+    ixs.forEach((ix) => tx.add(ix));
+    tx.sign(payer, ...signers);
+    const res = svm.sendTransaction(tx);
+    svm.expireBlockhash();
+    return res instanceof FailedTransactionMetadata ? res : null;
+  }
+  function send(ixs: TransactionInstruction[], signers: Keypair[] = []) {
+    const f = trySend(ixs, signers);
+    if (f) throw new Error("tx failed: " + f.err().toString() + "\n" + f.meta().logs().join("\n"));
+  }
+  function sendExpectFail(ixs: TransactionInstruction[], signers: Keypair[] = []): string {
+    const f = trySend(ixs, signers);
+    if (!f) throw new Error("expected tx to fail but it succeeded");
+    return f.err().toString() + "\n" + f.meta().logs().join("\n");
+  }
+
+  const registry = (): any =>
+    program.coder.accounts.decode("registry", Buffer.from(svm.getAccount(registryPda)!.data));
+
+  const setIx = (oracle: PublicKey) =>
+    program.methods.setOracleAuthority(oracle).accounts({ registry: registryPda, authority: payer.publicKey }).instruction();
+  const proposeIx = (oracle: PublicKey, authority: PublicKey = payer.publicKey) =>
+    program.methods.proposeOracleAuthority(oracle).accounts({
+      registry: registryPda, oracleProposal: proposalPda, authority, systemProgram: SystemProgram.programId,
+    } as any).instruction();
+  const acceptIx = (signer: PublicKey) =>
+    program.methods.acceptOracleAuthority().accounts({
+      registry: registryPda, oracleProposal: proposalPda, proposer: payer.publicKey, newOracle: signer,
+    } as any).instruction();
+  const cancelIx = (authority: PublicKey = payer.publicKey) =>
+    program.methods.cancelOracleAuthorityProposal().accounts({
+      registry: registryPda, oracleProposal: proposalPda, proposer: payer.publicKey, authority,
+    } as any).instruction();
+  const readingIx = (oracle: PublicKey) => {
+    readingTs += 100;
+    return program.methods.updateMeterReading(new BN(100), new BN(0), new BN(readingTs)).accounts({
+      registry: registryPda, meterAccount: meterPda, oracleAuthority: oracle,
+    } as any).instruction();
+  };
+
+  before(async () => {
+    svm = new LiteSVM().withDefaultPrograms();
+    program = new Program(idl, { connection: {}, publicKey: PublicKey.default } as any);
+    programId = program.programId;
+    svm.addProgramFromFile(programId, "target/deploy/registry.so");
+    for (const kp of [payer, outsider]) svm.airdrop(kp.publicKey, BigInt(1_000_000_000_000));
+    const c = svm.getClock();
+    svm.setClock(new Clock(c.slot, 0n, 0n, 0n, BigInt(NOW)));
+
+    [registryPda] = PublicKey.findProgramAddressSync([Buffer.from("registry")], programId);
+    [proposalPda] = PublicKey.findProgramAddressSync([Buffer.from("oracle_proposal")], programId);
+    const [userPda] = PublicKey.findProgramAddressSync([Buffer.from("user"), user.publicKey.toBuffer()], programId);
+    const shardId = user.publicKey.toBytes()[0] % 16;
+    const [shardPda] = PublicKey.findProgramAddressSync([Buffer.from("registry_shard"), Buffer.from([shardId])], programId);
+    [meterPda] = PublicKey.findProgramAddressSync([Buffer.from("meter"), user.publicKey.toBuffer(), Buffer.from(METER)], programId);
+
+    send([
+      await program.methods.initialize().accounts({ registry: registryPda, authority: payer.publicKey, systemProgram: SystemProgram.programId }).instruction(),
+      await program.methods.initializeShard(shardId).accounts({ shard: shardPda, authority: payer.publicKey, systemProgram: SystemProgram.programId }).instruction(),
+    ]);
+    send([await program.methods.registerUser({ prosumer: {} }, 0, 0, new BN(0), shardId).accounts({
+      userAccount: userPda, registryShard: shardPda, registry: registryPda, authority: user.publicKey, payer: payer.publicKey, systemProgram: SystemProgram.programId,
+    }).instruction()]);
+    send([await program.methods.registerMeter(METER, { solar: {} }, shardId, 0).accounts({
+      meterAccount: meterPda, userAccount: userPda, registryShard: shardPda, registry: registryPda,
+      owner: user.publicKey, payer: payer.publicKey, systemProgram: SystemProgram.programId,
+    }).instruction()]);
+  });
+
+  it("set_oracle_authority only configures the first oracle", async () => {
+    send([await setIx(oldOracle.publicKey)]);
+    expect(registry().oracleAuthority.toBase58()).to.equal(oldOracle.publicKey.toBase58());
+    const blob = sendExpectFail([await setIx(newOracle.publicKey)]);
+    expect(blob, blob).to.match(/OracleAlreadySet/);
+  });
+
+  it("only the authority proposes, and not the current oracle", async () => {
+    let blob = sendExpectFail([await proposeIx(newOracle.publicKey, outsider.publicKey)], [outsider]);
+    expect(blob, blob).to.match(/UnauthorizedAuthority/);
+    blob = sendExpectFail([await proposeIx(oldOracle.publicKey)]);
+    expect(blob, blob).to.match(/OracleUnchanged/);
+
+    send([await proposeIx(newOracle.publicKey)]);
+    const proposal: any = program.coder.accounts.decode("oracleAuthorityProposal", Buffer.from(svm.getAccount(proposalPda)!.data));
+    expect(proposal.proposedOracle.toBase58()).to.equal(newOracle.publicKey.toBase58());
+    expect(proposal.proposer.toBase58()).to.equal(payer.publicKey.toBase58());
+    expect(proposal.proposedAt.toNumber()).to.equal(NOW);
+
+    // A second proposal cannot overwrite the pending one
+    expect(trySend([await proposeIx(outsider.publicKey)])).to.not.be.null;
+  });
+
+  it("the old oracle keeps working until the proposed key accepts", async () => {
+    send([await readingIx(oldOracle.publicKey)], [oldOracle]);
+    const blob = sendExpectFail([await acceptIx(outsider.publicKey)], [outsider]);
+    expect(blob, blob).to.match(/InvalidPendingOracle/);
+    expect(registry().oracleAuthority.toBase58()).to.equal(oldOracle.publicKey.toBase58());
+  });
+
+  it("accept rotates the oracle and closes the proposal", async () => {
+    send([await acceptIx(newOracle.publicKey)], [newOracle]);
+    expect(registry().oracleAuthority.toBase58()).to.equal(newOracle.publicKey.toBase58());
+    expect(svm.getAccount(proposalPda)).to.be.null;
+
+    const blob = sendExpectFail([await readingIx(oldOracle.publicKey)], [oldOracle]);
+    expect(blob, blob).to.match(/UnauthorizedOracle/);
+    send([await readingIx(newOracle.publicKey)], [newOracle]);
+  });
+
+  it("cancel withdraws a proposal (authority only)", async () => {
+    send([await proposeIx(outsider.publicKey)]);
+    const blob = sendExpectFail([await cancelIx(outsider.publicKey)], [outsider]);
+    expect(blob, blob).to.match(/UnauthorizedAuthority/);
+
+    send([await cancelIx()]);
+    expect(svm.getAccount(proposalPda)).to.be.null;
+    expect(trySend([await acceptIx(outsider.publicKey)], [outsider])).to.not.be.null;
+    expect(registry().oracleAuthority.toBase58()).to.equal(newOracle.publicKey.toBase58());
+  });
+});
@@ -0,0 +1,169 @@
+// Litesvm coverage for registry settle_meters_batch: one call settles every meter passed as
+// remaining_accounts, skips meters with nothing to settle, returns the total and emits one
+// BatchSettled next to the per-meter MeterBalanceSettled events.
+// Guards: InvalidMeterBatch (empty batch, read-only meter) and UnauthorizedUser (a meter of
+// another owner).
+
+import { LiteSVM, Clock, FailedTransactionMetadata } from "litesvm";
+import { Program, EventParser } from "@anchor-lang/core";
+import { Registry } from "../target/types/registry";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  Transaction,
+  SystemProgram,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import BN from "bn.js";
+import { createRequire } from "module";
+
+const require = createRequire(import.meta.url);
+const idl = require("../target/idl/registry.json");
+
+const NOW = 10_000;
+const READ_AT = 9_000;
+const METERS = ["SET-A", "SET-B", "SET-C"];
+
+describe("registry settle_meters_batch (litesvm)", () => {
+  let svm: LiteSVM;
+  let program: Program<Registry>;
+  let programId: PublicKey;
+
+  const payer = Keypair.generate();   // registry authority + funder
+  const user = Keypair.generate();    // meter owner
+  const other = Keypair.generate();   // owner of an unrelated meter
+  const oracle = Keypair.generate();  // configured oracle authority
+
+  let registryPda: PublicKey;
+
+  function trySend(ixs: TransactionInstruction[], signers: Keypair[]): FailedTransactionMetadata | null {
+    const tx = new Transaction();
+    tx.recentBlockhash = svm.latestBlockhash();
+    tx.feePayer = payer.publicKey;
+    ixs.forEach((ix) => tx.add(ix));
+    tx.sign(payer, ...signers);
+    const res = svm.sendTransaction(tx);
+    svm.expireBlockhash();
+    return res instanceof FailedTransactionMetadata ? res : null;
+  }
+  function send(ixs: TransactionInstruction[], signers: Keypair[] = []) {
+    const f = trySend(ixs, signers);
+    if (f) throw new Error("tx failed: " + f.err().toString() + "\n" + f.meta().logs().join("\n"));
+  }
+  function sendExpectFail(ixs: TransactionInstruction[], signers: Keypair[] = []): string {
+    const f = trySend(ixs, signers);
+    if (!f) throw new Error("expected tx to fail but it succeeded");
+    return f.err().toString() + "\n" + f.meta().logs().join("\n");
+  }
+  function sendRaw(ixs: TransactionInstruction[], signers: Keypair[] = []) {
+    const tx = new Transaction();
+    tx.recentBlockhash = svm.latestBlockhash();
+    tx.feePayer = payer.publicKey;
+    ixs.forEach((ix) => tx.add(ix));
+    tx.sign(payer, ...signers);
+    const res = svm.sendTransaction(tx);
+    svm.expireBlockhash();
+    if (res instanceof FailedTransactionMetadata) throw new Error("tx failed: " + res.err().toString());
+    return res;
+  }
+
+  const events = (logs: string[], name: string) => {
+    const parser = new EventParser(programId, (program as any).coder);
+    const camel = name[0].toLowerCase() + name.slice(1);
+    return [...parser.parseLogs(logs)].filter((e) => e.name === name || e.name === camel);
+  };
+  const meterPda = (owner: PublicKey, id: string) =>
+    PublicKey.findProgramAddressSync([Buffer.from("meter"), owner.toBuffer(), Buffer.from(id)], programId)[0];
+  const meter = (owner: PublicKey, id: string): any =>
+    program.coder.accounts.decode("meterAccount", Buffer.from(svm.getAccount(meterPda(owner, id))!.data));
+
+  const batchIx = (accounts: PublicKey[], signer: PublicKey = user.publicKey, isWritable = true) =>
+    program.methods.settleMetersBatch().accounts({ meterOwner: signer } as any)
+      .remainingAccounts(accounts.map((pubkey) => ({ pubkey, isWritable, isSigner: false })))
+      .instruction();
+
+  async function registerWithMeters(owner: Keypair, ids: string[]) {
+    const [userPda] = PublicKey.findProgramAddressSync([Buffer.from("user"), owner.publicKey.toBuffer()], programId);
+    const shardId = owner.publicKey.toBytes()[0] % 16;
+    const [shardPda] = PublicKey.findProgramAddressSync([Buffer.from("registry_shard"), Buffer.from([shardId])], programId);
+    if (!svm.getAccount(shardPda)) {
+      send([await program.methods.initializeShard(shardId).accounts({ shard: shardPda, authority: payer.publicKey, systemProgram: SystemProgram.programId }).instruction()]);
+    }
+    send([await program.methods.registerUser({ prosumer: {} }, 0, 0, new BN(0), shardId).accounts({
+      userAccount: userPda, registryShard: shardPda, registry: registryPda, authority: owner.publicKey, payer: payer.publicKey, systemProgram: SystemProgram.programId,
+    }).instruction()]);
+    for (const id of ids) {
+      send([await program.methods.registerMeter(id, { solar: {} }, shardId, 0).accounts({
+        meterAccount: meterPda(owner.publicKey, id), userAccount: userPda, registryShard: shardPda, registry: registryPda,
+        owner: owner.publicKey, payer: payer.publicKey, systemProgram: SystemProgram.programId,
+      }).instruction()]);
+    }
+  }
+  const readingIx = (owner: PublicKey, id: string, gen: number, cons: number) =>
+    program.methods.updateMeterReading(new BN(gen), new BN(cons), new BN(READ_AT)).accounts({
+      registry: registryPda, meterAccount: meterPda(owner, id), oracleAuthority: oracle.publicKey,
+    } as any).instruction();
+
+  before(async () => {
+    svm = new LiteSVM().withDefaultPrograms();
+    program = new Program(idl, { connection: {}, publicKey: PublicKey.default } as any);
+    programId = program.programId;
+    svm.addProgramFromFile(programId, "target/deploy/registry.so");
+    svm.airdrop(payer.publicKey, BigInt(1_000_000_000_000));
+    const c = svm.getClock();
+    svm.setClock(new Clock(c.slot, 0n, 0n, 0n, BigInt(NOW)));
+
+    [registryPda] = PublicKey.findProgramAddressSync([Buffer.from("registry")], programId);
+    send([await program.methods.initialize().accounts({ registry: registryPda, authority: payer.publicKey, systemProgram: SystemProgram.programId }).instruction()]);
+    await registerWithMeters(user, METERS);
+    await registerWithMeters(other, ["SET-X"]);
+    send([await program.methods.setOracleAuthority(oracle.publicKey).accounts({ registry: registryPda, authority: payer.publicKey }).instruction()]);
+
+    // A: 800 net, B: 400 net, C: consumption above generation -> nothing to settle. X: 50 net.
+    const readings: [PublicKey, string, number, number][] = [
+      [user.publicKey, "SET-A", 1000, 200], [user.publicKey, "SET-B", 500, 100],
+      [user.publicKey, "SET-C", 100, 300], [other.publicKey, "SET-X", 50, 0],
+    ];
+    for (const [owner, id, gen, cons] of readings) {
+      send([await readingIx(owner, id, gen, cons)], [oracle]);
+    }
+  });
+
+  it("rejects an empty batch and read-only meters (InvalidMeterBatch)", async () => {
+    expect(sendExpectFail([await batchIx([])], [user])).to.match(/InvalidMeterBatch/);
+    const blob = sendExpectFail([await batchIx([meterPda(user.publicKey, "SET-A")], user.publicKey, false)], [user]);
+    expect(blob, blob).to.match(/InvalidMeterBatch/);
+  });
+
+  it("rejects a meter owned by someone else, leaving the batch unsettled", async () => {
+    const blob = sendExpectFail([await batchIx([meterPda(user.publicKey, "SET-A"), meterPda(other.publicKey, "SET-X")])], [user]);
+    expect(blob, blob).to.match(/UnauthorizedUser/);
+    expect(meter(user.publicKey, "SET-A").settledNetGeneration.toNumber()).to.equal(0);
+  });
+
+  it("settles every meter, skips the empty one and emits one summary", async () => {
+    const res = sendRaw([await batchIx(METERS.map((id) => meterPda(user.publicKey, id)))], [user]);
+    expect(Number(Buffer.from(res.returnData().data()).readBigUInt64LE(0))).to.equal(1_200);
+
+    const logs = res.logs();
+    expect(events(logs, "MeterBalanceSettled")).to.have.length(2);
+    const summary = events(logs, "BatchSettled");
+    expect(summary).to.have.length(1);
+    expect(summary[0].data.owner.toBase58()).to.equal(user.publicKey.toBase58());
+    expect(summary[0].data.meterCount).to.equal(2);
+    expect(summary[0].data.totalTokens.toNumber()).to.equal(1_200);
+    expect(summary[0].data.timestamp.toNumber()).to.equal(NOW);
+
+    expect(meter(user.publicKey, "SET-A").settledNetGeneration.toNumber()).to.equal(800);
+    expect(meter(user.publicKey, "SET-B").settledNetGeneration.toNumber()).to.equal(400);
+    expect(meter(user.publicKey, "SET-C").settledNetGeneration.toNumber()).to.equal(0);
+  });
+
+  it("a repeat batch settles nothing and reports zero", async () => {
+    const res = sendRaw([await batchIx(METERS.map((id) => meterPda(user.publicKey, id)))], [user]);
+    const [summary] = events(res.logs(), "BatchSettled");
+    expect(summary.data.meterCount).to.equal(0);
+    expect(summary.data.totalTokens.toNumber()).to.equal(0);
+  });
+});
@@ -0,0 +1,175 @@
+// Litesvm coverage for registry get_unsettled_balances: one read-only call returns the
+// saturating unsettled balance of every MeterAccount passed as remaining_accounts, in order.
+// Guards: InvalidBalanceBatch (empty batch) and Anchor's discriminator check (a registry
+// account that is not a MeterAccount). Also covers the single-meter get_meter_summary view.
+
+import { LiteSVM, Clock, FailedTransactionMetadata } from "litesvm";
+import { Program } from "@anchor-lang/core";
+import { Registry } from "../target/types/registry";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  Transaction,
+  SystemProgram,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import BN from "bn.js";
+import { createRequire } from "module";
+
+const require = createRequire(import.meta.url);
+const idl = require("../target/idl/registry.json");
+
+const NOW = 10_000;
+const READ_AT = 9_000;
+const METERS = ["BAL-A", "BAL-B", "BAL-C"];
+
+describe("registry get_unsettled_balances (litesvm)", () => {
+  let svm: LiteSVM;
+  let program: Program<Registry>;
+  let programId: PublicKey;
+
+  const payer = Keypair.generate();   // registry authority + funder
+  const user = Keypair.generate();    // meter owner
+  const oracle = Keypair.generate();  // configured oracle authority
+
+  let registryPda: PublicKey;
+  let userPda: PublicKey;
+  let shardPda: PublicKey;
+  let shardId: number;
+
+  function trySend(ixs: TransactionInstruction[], signers: Keypair[]): FailedTransactionMetadata | null {
+    const tx = new Transaction();
+    tx.recentBlockhash = svm.latestBlockhash();
+    tx.feePayer = payer.publicKey;
+    ixs.forEach((ix) => tx.add(ix));
+    tx.sign(payer, ...signers);
+    const res = svm.sendTransaction(tx);
+    svm.expireBlockhash();
+    return res instanceof FailedTransactionMetadata ? res : null;
+  }
+  function send(ixs: TransactionInstruction[], signers: Keypair[] = []) {
+    const f = trySend(ixs, signers);
+    if (f) throw new Error("tx failed: " + f.err().toString() + "\n" + f.meta().logs().join("\n"));
+  }
+  function sendExpectFail(ixs: TransactionInstruction[], signers: Keypair[] = []): string {
+    const f = trySend(ixs, signers);
+    if (!f) throw new Error("expected tx to fail but it succeeded");
+    return f.err().toString() + "\n" + f.meta().logs().join("\n");
+  }
+
+  // Capture an instruction's Anchor return value (read-only getters).
+  function sendRaw(ixs: TransactionInstruction[], signers: Keypair[] = []) {
+    const tx = new Transaction();
+    tx.recentBlockhash = svm.latestBlockhash();
+    tx.feePayer = payer.publicKey;
+    ixs.forEach((ix) => tx.add(ix));
+    tx.sign(payer, ...signers);
+    const res = svm.sendTransaction(tx);
+    svm.expireBlockhash();
+    if (res instanceof FailedTransactionMetadata) throw new Error("tx failed: " + res.err().toString());
+    return res;
+  }
+  // Borsh Vec<u64>: u32 length prefix, then little-endian u64s.
+  const retU64Vec = (res: any): number[] => {
+    const d = Buffer.from(res.returnData().data());
+    const n = d.readUInt32LE(0);
+    return Array.from({ length: n }, (_, i) => Number(d.readBigUInt64LE(4 + 8 * i)));
+  };
+
+  // Borsh MeterSummary: four u64s, the MeterStatus tag (u8), then last_reading_at (i64).
+  const retSummary = (res: any) => {
+    const d = Buffer.from(res.returnData().data());
+    return {
+      netGeneration: Number(d.readBigUInt64LE(0)),
+      unsettled: Number(d.readBigUInt64LE(8)),
+      claimedErc: Number(d.readBigUInt64LE(16)),
+      unclaimedErc: Number(d.readBigUInt64LE(24)),
+      status: d.readUInt8(32),
+      lastReadingAt: Number(d.readBigInt64LE(33)),
+    };
+  };
+
+  const meterPda = (id: string) =>
+    PublicKey.findProgramAddressSync([Buffer.from("meter"), user.publicKey.toBuffer(), Buffer.from(id)], programId)[0];
+
+  const balancesIx = (accounts: PublicKey[]) =>
+    program.methods.getUnsettledBalances().accounts({ registry: registryPda } as any)
+      .remainingAccounts(accounts.map((pubkey) => ({ pubkey, isWritable: false, isSigner: false })))
+      .instruction();
+
+  before(async () => {
+    svm = new LiteSVM().withDefaultPrograms();
+    program = new Program(idl, { connection: {}, publicKey: PublicKey.default } as any);
+    programId = program.programId;
+    svm.addProgramFromFile(programId, "target/deploy/registry.so");
+    svm.airdrop(payer.publicKey, BigInt(1_000_000_000_000));
+    const c = svm.getClock();
+    svm.setClock(new Clock(c.slot, 0n, 0n, 0n, BigInt(NOW)));
+
+    [registryPda] = PublicKey.findProgramAddressSync([Buffer.from("registry")], programId);
+    [userPda] = PublicKey.findProgramAddressSync([Buffer.from("user"), user.publicKey.toBuffer()], programId);
+    shardId = user.publicKey.toBytes()[0] % 16;
+    [shardPda] = PublicKey.findProgramAddressSync([Buffer.from("registry_shard"), Buffer.from([shardId])], programId);
+
+    send([
+      await program.methods.initialize().accounts({ registry: registryPda, authority: payer.publicKey, systemProgram: SystemProgram.programId }).instruction(),
+      await program.methods.initializeShard(shardId).accounts({ shard: shardPda, authority: payer.publicKey, systemProgram: SystemProgram.programId }).instruction(),
+    ]);
+    send([await program.methods.registerUser({ prosumer: {} }, 0, 0, new BN(0), shardId).accounts({
+      userAccount: userPda, registryShard: shardPda, registry: registryPda, authority: user.publicKey, payer: payer.publicKey, systemProgram: SystemProgram.programId,
+    }).instruction()]);
+    for (const id of METERS) {
+      send([await program.methods.registerMeter(id, { solar: {} }, shardId, 0).accounts({
+        meterAccount: meterPda(id), userAccount: userPda, registryShard: shardPda, registry: registryPda,
+        owner: user.publicKey, payer: payer.publicKey, systemProgram: SystemProgram.programId,
+      }).instruction()]);
+    }
+    send([await program.methods.setOracleAuthority(oracle.publicKey).accounts({ registry: registryPda, authority: payer.publicKey }).instruction()]);
+
+    // A: 1000 - 200 = 800, B: 500 - 100 = 400, C: consumption above generation -> 0.
+    const readings: [string, number, number][] = [["BAL-A", 1000, 200], ["BAL-B", 500, 100], ["BAL-C", 100, 300]];
+    for (const [id, gen, cons] of readings) {
+      send([await program.methods.updateMeterReading(new BN(gen), new BN(cons), new BN(READ_AT)).accounts({
+        registry: registryPda, meterAccount: meterPda(id), oracleAuthority: oracle.publicKey,
+      } as any).instruction()], [oracle]);
+    }
+  });
+
+  it("returns one saturating balance per meter, in the order passed", async () => {
+    const res = sendRaw([await balancesIx(METERS.map(meterPda))]);
+    expect(retU64Vec(res)).to.deep.equal([800, 400, 0]);
+  });
+
+  it("reflects a settlement and follows the caller's ordering", async () => {
+    send([await program.methods.settleMeterBalance(new BN(0)).accounts({ meterAccount: meterPda("BAL-A"), meterOwner: user.publicKey } as any).instruction()], [user]);
+    const res = sendRaw([await balancesIx([meterPda("BAL-B"), meterPda("BAL-A")])]);
+    expect(retU64Vec(res)).to.deep.equal([400, 0]);
+  });
+
+  it("get_meter_summary returns the derived balances of one meter", async () => {
+    send([await program.methods.markErcClaimed(new BN(100)).accounts({
+      meterAccount: meterPda("BAL-B"), registry: registryPda, authority: oracle.publicKey,
+    } as any).instruction()], [oracle]);
+    const summaryIx = (id: string) =>
+      program.methods.getMeterSummary().accounts({ meterAccount: meterPda(id) } as any).instruction();
+
+    // B: net 400, nothing settled, 100 claimed for ERC. Status 0 = Active.
+    expect(retSummary(sendRaw([await summaryIx("BAL-B")]))).to.deep.equal({
+      netGeneration: 400, unsettled: 400, claimedErc: 100, unclaimedErc: 300, status: 0, lastReadingAt: READ_AT,
+    });
+    // A was fully settled above; C's consumption exceeds its generation.
+    expect(retSummary(sendRaw([await summaryIx("BAL-A")]))).to.include({ netGeneration: 800, unsettled: 0, unclaimedErc: 800 });
+    expect(retSummary(sendRaw([await summaryIx("BAL-C")]))).to.include({ netGeneration: 0, unsettled: 0, unclaimedErc: 0 });
+  });
+
+  it("rejects an empty batch (InvalidBalanceBatch)", async () => {
+    const blob = sendExpectFail([await balancesIx([])]);
+    expect(blob, blob).to.match(/InvalidBalanceBatch/);
+  });
+
+  it("rejects a registry account that is not a MeterAccount", async () => {
+    const blob = sendExpectFail([await balancesIx([meterPda("BAL-A"), userPda])]);
+    expect(blob, blob).to.match(/AccountDiscriminatorMismatch|3002/);
+  });
+});
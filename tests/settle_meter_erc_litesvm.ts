@@ -8,8 +8,10 @@
 // This test arms one meter (net = 800) and walks the shared budget across BOTH
 // instructions to prove the cross-claim cap holds:
 //   mark_erc_claimed: UnauthorizedAuthority, control (claim 300), NoUnsettledBalance (over-claim)
-//   settle_meter_balance: UnauthorizedUser, control (settles the remaining 500),
-//                         NoUnsettledBalance (budget exhausted), InvalidMeterStatus (deactivated)
+//   settle_meter_balance: UnauthorizedUser, BelowMinimumSettlement (500 < 501 leaves the
+//                         balance untouched), control (settles the remaining 500 with a
+//                         500 minimum), NoUnsettledBalance (budget exhausted),
+//                         InvalidMeterStatus (deactivated)
 //
 // settle_and_mint_tokens (the CPI variant) is out of scope here — it needs the
 // energy-token program + Token-2022 mint; this isolates the pure registry accounting.
@@ -94,8 +96,8 @@ describe("registry settle_meter_balance + mark_erc_claimed (litesvm)", () => {
   const isValidMeterIx = () => program.methods.isValidMeter().accounts({ meterAccount: meterPda } as any).instruction();
   const getUnsettledIx = () => program.methods.getUnsettledBalance().accounts({ meterAccount: meterPda } as any).instruction();
 
-  const settleIx = (owner: PublicKey) =>
-    program.methods.settleMeterBalance().accounts({ meterAccount: meterPda, meterOwner: owner } as any).instruction();
+  const settleIx = (owner: PublicKey, minAmount = 0) =>
+    program.methods.settleMeterBalance(new BN(minAmount)).accounts({ meterAccount: meterPda, meterOwner: owner } as any).instruction();
   const ercIx = (authority: PublicKey, amount: number) =>
     program.methods.markErcClaimed(new BN(amount)).accounts({ meterAccount: meterPda, registry: registryPda, authority } as any).instruction();
 
@@ -178,9 +180,16 @@ describe("registry settle_meter_balance + mark_erc_claimed (litesvm)", () => {
     expect(blob, blob).to.match(/UnauthorizedUser/);
   });
 
+  it("rejects a balance below the minimum settlement amount (BelowMinimumSettlement)", async () => {
+    // remaining = net 800 - settled 0 - claimed 300 = 500 < 501.
+    const blob = sendExpectFail([await settleIx(user.publicKey, 501)], [user]);
+    expect(blob, blob).to.match(/BelowMinimumSettlement/);
+    expect(meter().settledNetGeneration.toNumber()).to.equal(0);
+  });
+
   it("settles the GRID remainder left after the ERC claim (control)", async () => {
-    // remaining = net 800 - settled 0 - claimed 300 = 500.
-    send([await settleIx(user.publicKey)], [user]);
+    // remaining = 500, exactly the minimum.
+    send([await settleIx(user.publicKey, 500)], [user]);
     expect(meter().settledNetGeneration.toNumber()).to.equal(500);
   });
 
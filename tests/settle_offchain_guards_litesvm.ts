@@ -227,7 +227,7 @@ describe("trading settle_offchain_match — validation guards (litesvm)", () =>
       ercValidityPeriod: new BN(0), requireOracleValidation: false, oracleAuthority: PublicKey.default, minOracleConfidence: 0,
       allowCertificateTransfers: true, minQuorumVotes: new BN(0), totalErcsIssued: new BN(0), totalErcsValidated: new BN(0),
       totalErcsRevoked: new BN(0), totalEnergyCertified: new BN(0), createdAt: new BN(0), lastUpdated: new BN(0), lastErcIssuedAt: new BN(0),
-      pendingAuthority: PublicKey.default, pendingAuthorityProposedAt: new BN(0), pendingAuthorityExpiresAt: new BN(0), reserved: Array(5).fill(0),
+      pendingAuthority: PublicKey.default, pendingAuthorityProposedAt: new BN(0), pendingAuthorityExpiresAt: new BN(0), minFeeBps: 0, maxFeeBps: 0, reserved: Array(1).fill(0),
     };
     const data = await governance.coder.accounts.encode("governanceConfig", c as any);
     svm.setAccount(governanceConfigPda, { lamports: Number(svm.minimumBalanceForRentExemption(BigInt(data.length))), data, owner: GOVERNANCE_PROGRAM_ID, executable: false, rentEpoch: 0 } as any);
@@ -394,7 +394,7 @@ describe("trading settle_offchain_match — validation guards (litesvm)", () =>
 
     // --- energy Token-2022 mint + token_info (registry_authority = payer so mint_to_wallet authorizes) ---
     const initEnergy = await energy.methods
-      .initializeToken(PublicKey.default, payer.publicKey)
+      .initializeToken(PublicKey.default, payer.publicKey, new BN(0))
       .accounts({
         tokenInfo: energyInfoPda,
         mint: energyMintPda,
@@ -426,11 +426,11 @@ describe("trading settle_offchain_match — validation guards (litesvm)", () =>
 
     // --- trading market + zone + shards + collectors ---
     send([await trading.methods.initializeMarket(16).accounts({ market: marketPda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()], []);
-    send([await trading.methods.initializeZoneMarket(ZONE, 16, new BN(1_000_000)).accounts({ market: marketPda, zoneMarket: zoneMarketPda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()], []);
+    send([await trading.methods.initializeZoneMarket(ZONE, 16, new BN(1_000_000), 10).accounts({ market: marketPda, zoneMarket: zoneMarketPda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()], []);
     // Low-cap zone for the CapacityExceeded case.
     [lowZonePda] = PublicKey.findProgramAddressSync([Buffer.from("zone_market"), marketPda.toBuffer(), new BN(LOW_ZONE).toArrayLike(Buffer, "le", 4)], tradingId);
     [lowZoneShardPda] = PublicKey.findProgramAddressSync([Buffer.from("zone_shard"), lowZonePda.toBuffer(), Buffer.from([shardByte])], tradingId);
-    send([await trading.methods.initializeZoneMarket(LOW_ZONE, 16, new BN(LOW_CAP)).accounts({ market: marketPda, zoneMarket: lowZonePda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()], []);
+    send([await trading.methods.initializeZoneMarket(LOW_ZONE, 16, new BN(LOW_CAP), 10).accounts({ market: marketPda, zoneMarket: lowZonePda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()], []);
     send([await trading.methods.initializeZoneMarketShard(shardByte).accounts({ zoneMarket: lowZonePda, zoneShard: lowZoneShardPda, payer: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()], []);
     // Tier-A: cross-zone capacity counter for the low-cap zone.
     [lowZoneCapacityPda] = PublicKey.findProgramAddressSync([Buffer.from("zone_capacity"), lowZonePda.toBuffer()], tradingId);
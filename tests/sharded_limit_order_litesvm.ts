@@ -1,8 +1,9 @@
 // Litesvm coverage for submit_limit_order_sharded (instructions/submit_sharded_limit_order.rs),
 // previously untested. Unlike submit_limit_order this path has NO maintenance / amount / price /
-// bounds guards and does NOT read governance_config — it only inits the Order PDA and stamps
-// zone_shard.last_update (per-shard write to avoid contention on the global market). So coverage
-// here is the happy-path order initialization for both sides + the missing-shard account guard.
+// bounds guards and does NOT read governance_config — it only checks the global market's freeze
+// flag (read-only), inits the Order PDA and stamps zone_shard.last_update (per-shard write to avoid
+// contention on the global market). So coverage here is the happy-path order initialization for
+// both sides, the missing-shard account guard and the MarketFrozen guard.
 
 import { LiteSVM, Clock, FailedTransactionMetadata } from "litesvm";
 import { Program } from "@anchor-lang/core";
@@ -66,7 +67,7 @@ describe("trading submit_limit_order_sharded (litesvm)", () => {
 
   const shardedIx = (orderId: number, side: number, amount: number, price: number, shard: number) =>
     trading.methods.submitLimitOrderSharded(new BN(orderId), side, new BN(amount), new BN(price), shard).accounts({
-      order: orderPda(orderId), zoneMarket: zoneMarketPda, zoneShard: shardPdaFor(shard),
+      market: marketPda, order: orderPda(orderId), zoneMarket: zoneMarketPda, zoneShard: shardPdaFor(shard),
       authority: payer.publicKey, systemProgram: SystemProgram.programId, governanceConfig: payer.publicKey,
     } as any).instruction();
 
@@ -82,7 +83,7 @@ describe("trading submit_limit_order_sharded (litesvm)", () => {
     zoneShardPda = shardPdaFor(SHARD);
 
     send([await trading.methods.initializeMarket(16).accounts({ market: marketPda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()]);
-    send([await trading.methods.initializeZoneMarket(ZONE, 16, new BN(1_000_000)).accounts({ market: marketPda, zoneMarket: zoneMarketPda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()]);
+    send([await trading.methods.initializeZoneMarket(ZONE, 16, new BN(1_000_000), 10).accounts({ market: marketPda, zoneMarket: zoneMarketPda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()]);
     send([await trading.methods.initializeZoneMarketShard(SHARD).accounts({ zoneMarket: zoneMarketPda, zoneShard: zoneShardPda, payer: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()]);
 
     svm.setClock(new Clock(svm.getClock().slot, 0n, 0n, 0n, BigInt(NOW)));
@@ -111,4 +112,17 @@ describe("trading submit_limit_order_sharded (litesvm)", () => {
     const blob = sendExpectFail([await shardedIx(3, BUY, 100, 50, 7)]);
     expect(blob, blob).to.match(/AccountOwnedByWrongProgram|3007|AccountNotInitialized|3012/i);
   });
+
+  it("rejects a sharded submit while the market is frozen (MarketFrozen), then accepts after unfreeze", async () => {
+    const frozenIx = (frozen: boolean) =>
+      trading.methods.setMarketFrozen(frozen).accounts({ market: marketPda, authority: payer.publicKey } as any).instruction();
+
+    send([await frozenIx(true)]);
+    const blob = sendExpectFail([await shardedIx(4, BUY, 100, 50, SHARD)]);
+    expect(blob, blob).to.match(/MarketFrozen/);
+
+    send([await frozenIx(false)]);
+    send([await shardedIx(4, BUY, 100, 50, SHARD)]);
+    expect(order(4).amount.toNumber()).to.equal(100);
+  });
 });
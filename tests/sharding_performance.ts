@@ -195,29 +195,35 @@ describe("sharding-performance", () => {
 
     console.log(`   Executing ${TRADE_COUNT} sharded matches...`);
     const startTime = Date.now();
-    const matchPromises = [];
+    // Trade records are seeded by the market's trade_seq, so each match must land before
+    // the next one's PDA can be derived; matches are submitted one after another.
+    let seq = (await tradingProgram.account.market.fetch(marketPda)).tradeSeq;
+    let success = 0;
     for (let i = 0; i < TRADE_COUNT; i++) {
         const shardId = i % SHARD_COUNT;
         const [zoneShardPda] = PublicKey.findProgramAddressSync([Buffer.from("zone_shard"), zoneMarketPda.toBuffer(), Buffer.from([shardId])], tradingProgram.programId);
-        const [tradePda] = PublicKey.findProgramAddressSync([Buffer.from("trade"), buyOrders[i].toBuffer(), sellOrders[i].toBuffer()], tradingProgram.programId);
-
-        const promise = tradingProgram.methods.shardedMatchOrders(new BN(100), shardId).accounts({
-            market: marketPda,
-            zoneMarket: zoneMarketPda,
-            zoneShard: zoneShardPda,
-            buyOrder: buyOrders[i],
-            sellOrder: sellOrders[i],
-            tradeRecord: tradePda,
-            authority: authority,
-            systemProgram: SystemProgram.programId,
-            governanceConfig: governanceConfigPda,
-        } as any).rpc();
-        matchPromises.push(promise);
+        const [tradePda] = PublicKey.findProgramAddressSync([Buffer.from("trade"), marketPda.toBuffer(), seq.toArrayLike(Buffer, "le", 8)], tradingProgram.programId);
+
+        try {
+            await tradingProgram.methods.shardedMatchOrders(new BN(100), shardId).accounts({
+                market: marketPda,
+                zoneMarket: zoneMarketPda,
+                zoneShard: zoneShardPda,
+                buyOrder: buyOrders[i],
+                sellOrder: sellOrders[i],
+                tradeRecord: tradePda,
+                authority: authority,
+                systemProgram: SystemProgram.programId,
+                governanceConfig: governanceConfigPda,
+            } as any).rpc();
+            success++;
+            seq = seq.addn(1);
+        } catch {
+            // counted as a failed match below
+        }
     }
 
-    const results = await Promise.allSettled(matchPromises);
     const endTime = Date.now();
-    const success = results.filter(r => r.status === 'fulfilled').length;
     const duration = (endTime - startTime) / 1000;
 
     console.log(`   Success: ${success}/${TRADE_COUNT}`);
@@ -0,0 +1,117 @@
+// Litesvm coverage for tpc-benchmark initialize_customer maintaining the last-name index:
+// the first customer with a given C_LAST creates the ["idx_c_last", w_id, d_id, sha256(c_last)]
+// PDA, later ones are inserted in sorted c_id order.
+//
+// Guards: InvalidLastNameHash (hash does not match `last`) and CustomerIndexFull once
+// MAX_CUSTOMERS_PER_NAME (20) ids share a last name. DuplicateCustomerInIndex cannot be reached
+// here because the customer PDA `init` fails first; it is covered by the state.rs unit tests.
+
+import { LiteSVM, FailedTransactionMetadata } from "litesvm";
+import { Program } from "@anchor-lang/core";
+import { TpcBenchmark } from "../target/types/tpc_benchmark";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  Transaction,
+  SystemProgram,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import BN from "bn.js";
+import { createHash } from "crypto";
+import { createRequire } from "module";
+
+const require = createRequire(import.meta.url);
+const idl = require("../target/idl/tpc_benchmark.json");
+
+const W_ID = new BN(1);
+const D_ID = new BN(1);
+const LAST = "BARBARBAR";
+const MAX_CUSTOMERS_PER_NAME = 20;
+
+describe("tpc-benchmark customer last-name index (litesvm)", () => {
+  let svm: LiteSVM;
+  let program: Program<TpcBenchmark>;
+  let programId: PublicKey;
+
+  const payer = Keypair.generate();
+
+  function trySend(ixs: TransactionInstruction[]): FailedTransactionMetadata | null {
+    const tx = new Transaction();
+    tx.recentBlockhash = svm.latestBlockhash();
+    tx.feePayer = payer.publicKey;
+    ixs.forEach((ix) => tx.add(ix));
+    tx.sign(payer);
+    const res = svm.sendTransaction(tx);
+    svm.expireBlockhash();
+    return res instanceof FailedTransactionMetadata ? res : null;
+  }
+  function send(ixs: TransactionInstruction[]) {
+    const f = trySend(ixs);
+    if (f) throw new Error("tx failed: " + f.err().toString() + "\n" + f.meta().logs().join("\n"));
+  }
+  function sendExpectFail(ixs: TransactionInstruction[]): string {
+    const f = trySend(ixs);
+    if (!f) throw new Error("expected tx to fail but it succeeded");
+    return f.err().toString() + "\n" + f.meta().logs().join("\n");
+  }
+
+  const le = (n: BN) => n.toArrayLike(Buffer, "le", 8);
+  const pda = (seeds: Buffer[]) => PublicKey.findProgramAddressSync(seeds, programId)[0];
+  const nameHash = (last: string) => createHash("sha256").update(last).digest();
+  const warehousePda = () => pda([Buffer.from("warehouse"), le(W_ID)]);
+  const districtPda = () => pda([Buffer.from("district"), le(W_ID), le(D_ID)]);
+  const customerPda = (cId: BN) => pda([Buffer.from("customer"), le(W_ID), le(D_ID), le(cId)]);
+  const indexPda = (hash: Buffer) => pda([Buffer.from("idx_c_last"), le(W_ID), le(D_ID), hash]);
+
+  const customerIx = (cId: number, last: string, hash: Buffer = nameHash(last)) =>
+    program.methods.initializeCustomer(
+      W_ID, D_ID, new BN(cId), Array.from(hash), "First", "OE", last,
+      "Street", "Street", "City", "ST", "12345", "555-1234", { goodCredit: {} }, new BN(5000), new BN(10),
+    ).accounts({
+      customer: customerPda(new BN(cId)),
+      district: districtPda(),
+      customerIndex: indexPda(hash),
+      authority: payer.publicKey,
+      systemProgram: SystemProgram.programId,
+    } as any).instruction();
+
+  const indexIds = (): number[] =>
+    (program.coder.accounts.decode("customerLastNameIndex", Buffer.from(svm.getAccount(indexPda(nameHash(LAST)))!.data)) as any)
+      .customerIds.map((id: BN) => id.toNumber());
+
+  before(async () => {
+    svm = new LiteSVM().withDefaultPrograms();
+    program = new Program(idl, { connection: {}, publicKey: PublicKey.default } as any);
+    programId = program.programId;
+    svm.addProgramFromFile(programId, "target/deploy/tpc_benchmark.so");
+    svm.airdrop(payer.publicKey, BigInt(1_000_000_000_000));
+
+    send([await program.methods.initializeWarehouse(W_ID, "Whse 1", "S1", "S2", "City", "ST", "12345", new BN(10))
+      .accounts({ warehouse: warehousePda(), authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()]);
+    send([await program.methods.initializeDistrict(W_ID, D_ID, "Dist 1", "S1", "S2", "City", "ST", "12345", new BN(5))
+      .accounts({ district: districtPda(), warehouse: warehousePda(), authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()]);
+  });
+
+  it("rejects a hash that does not match the last name (InvalidLastNameHash)", async () => {
+    const blob = sendExpectFail([await customerIx(1, LAST, nameHash("OUGHTPRES"))]);
+    expect(blob, blob).to.match(/InvalidLastNameHash/);
+  });
+
+  it("creates the index on first use and keeps ids sorted", async () => {
+    for (const cId of [42, 7, 19]) send([await customerIx(cId, LAST)]);
+    expect(indexIds()).to.deep.equal([7, 19, 42]);
+
+    const index: any = program.coder.accounts.decode(
+      "customerLastNameIndex", Buffer.from(svm.getAccount(indexPda(nameHash(LAST)))!.data));
+    expect(index.wId.toNumber()).to.equal(1);
+    expect(index.dId.toNumber()).to.equal(1);
+    expect(Buffer.from(index.lastNameHash).equals(nameHash(LAST))).to.equal(true);
+  });
+
+  it("rejects inserts beyond MAX_CUSTOMERS_PER_NAME (CustomerIndexFull)", async () => {
+    for (let cId = 100; indexIds().length < MAX_CUSTOMERS_PER_NAME; cId++) send([await customerIx(cId, LAST)]);
+    const blob = sendExpectFail([await customerIx(500, LAST)]);
+    expect(blob, blob).to.match(/CustomerIndexFull/);
+  });
+});
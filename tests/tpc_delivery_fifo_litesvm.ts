@@ -0,0 +1,164 @@
+// Litesvm coverage for tpc-benchmark delivery_district queue ordering. Client-provided o_ids
+// must increase per district (OrderIdNotIncreasing) and each NewOrderEntry links the order
+// queued before it, so delivery_district only accepts the oldest undelivered order
+// (NotOldestOrder) even when the ids are sparse. Delivering closes the NewOrderEntry, stamps
+// carrier_id / delivery_d, credits the customer and advances District.min_undelivered_o_id.
+
+import { LiteSVM, FailedTransactionMetadata } from "litesvm";
+import { Program } from "@anchor-lang/core";
+import { TpcBenchmark } from "../target/types/tpc_benchmark";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  Transaction,
+  SystemProgram,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import BN from "bn.js";
+import { createHash } from "crypto";
+import { createRequire } from "module";
+
+const require = createRequire(import.meta.url);
+const idl = require("../target/idl/tpc_benchmark.json");
+
+const W_ID = new BN(1);
+const D_ID = new BN(1);
+const C_ID = new BN(1);
+const LAST = "BARBARBAR";
+const ITEMS = [1, 2, 3, 4, 5];
+const CARRIER = 3;
+// Sparse, increasing ids, as a load generator deriving them from a clock would produce.
+const O1 = 5_000, O2 = 5_003, O3 = 5_010;
+
+describe("tpc-benchmark delivery_district FIFO (litesvm)", () => {
+  let svm: LiteSVM;
+  let program: Program<TpcBenchmark>;
+  let programId: PublicKey;
+
+  const payer = Keypair.generate();
+
+  function trySend(ixs: TransactionInstruction[]): FailedTransactionMetadata | null {
+    const tx = new Transaction();
+    tx.recentBlockhash = svm.latestBlockhash();
+    tx.feePayer = payer.publicKey;
+    ixs.forEach((ix) => tx.add(ix));
+    tx.sign(payer);
+    const res = svm.sendTransaction(tx);
+    svm.expireBlockhash();
+    return res instanceof FailedTransactionMetadata ? res : null;
+  }
+  function send(ixs: TransactionInstruction[]) {
+    const f = trySend(ixs);
+    if (f) throw new Error("tx failed: " + f.err().toString() + "\n" + f.meta().logs().join("\n"));
+  }
+  function sendExpectFail(ixs: TransactionInstruction[]): string {
+    const f = trySend(ixs);
+    if (!f) throw new Error("expected tx to fail but it succeeded");
+    return f.err().toString() + "\n" + f.meta().logs().join("\n");
+  }
+
+  const le = (n: BN) => n.toArrayLike(Buffer, "le", 8);
+  const pda = (seeds: Buffer[]) => PublicKey.findProgramAddressSync(seeds, programId)[0];
+  const nameHash = (last: string) => createHash("sha256").update(last).digest();
+  const warehousePda = () => pda([Buffer.from("warehouse"), le(W_ID)]);
+  const districtPda = () => pda([Buffer.from("district"), le(W_ID), le(D_ID)]);
+  const customerPda = () => pda([Buffer.from("customer"), le(W_ID), le(D_ID), le(C_ID)]);
+  const itemPda = (i: number) => pda([Buffer.from("item"), le(new BN(i))]);
+  const stockPda = (i: number) => pda([Buffer.from("stock"), le(W_ID), le(new BN(i))]);
+  const orderPda = (o: number) => pda([Buffer.from("order"), le(W_ID), le(D_ID), le(new BN(o))]);
+  const newOrderPda = (o: number) => pda([Buffer.from("new_order"), le(W_ID), le(D_ID), le(new BN(o))]);
+  const meta = (pubkey: PublicKey, isWritable = false) => ({ pubkey, isWritable, isSigner: false });
+
+  const decode = (name: string, key: PublicKey): any =>
+    program.coder.accounts.decode(name, Buffer.from(svm.getAccount(key)!.data));
+
+  const newOrderIx = (oId: number) =>
+    program.methods.newOrder(W_ID, D_ID, C_ID, new BN(oId), ITEMS.map((i) => ({ iId: new BN(i), supplyWId: W_ID, quantity: 1 })))
+      .accounts({
+        warehouse: warehousePda(), district: districtPda(), customer: customerPda(),
+        order: orderPda(oId), newOrder: newOrderPda(oId), payer: payer.publicKey, systemProgram: SystemProgram.programId,
+      } as any)
+      .remainingAccounts(ITEMS.flatMap((i) => [meta(itemPda(i)), meta(stockPda(i), true)]))
+      .instruction();
+
+  const deliverIx = (oId: number) =>
+    program.methods.deliveryDistrict(W_ID, D_ID, new BN(CARRIER)).accounts({
+      district: districtPda(), newOrder: newOrderPda(oId), order: orderPda(oId), customer: customerPda(),
+      payer: payer.publicKey, systemProgram: SystemProgram.programId,
+    } as any).instruction();
+
+  before(async () => {
+    svm = new LiteSVM().withDefaultPrograms();
+    program = new Program(idl, { connection: {}, publicKey: PublicKey.default } as any);
+    programId = program.programId;
+    svm.addProgramFromFile(programId, "target/deploy/tpc_benchmark.so");
+    svm.airdrop(payer.publicKey, BigInt(1_000_000_000_000));
+
+    send([await program.methods.initializeWarehouse(W_ID, "Whse 1", "S1", "S2", "City", "ST", "12345", new BN(10))
+      .accounts({ warehouse: warehousePda(), authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()]);
+    send([await program.methods.initializeDistrict(W_ID, D_ID, "Dist 1", "S1", "S2", "City", "ST", "12345", new BN(5))
+      .accounts({ district: districtPda(), warehouse: warehousePda(), authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()]);
+    const hash = nameHash(LAST);
+    send([await program.methods.initializeCustomer(
+      W_ID, D_ID, C_ID, Array.from(hash), "First", "OE", LAST,
+      "Street", "Street", "City", "ST", "12345", "555-1234", { goodCredit: {} }, new BN(5000), new BN(10),
+    ).accounts({
+      customer: customerPda(), district: districtPda(),
+      customerIndex: pda([Buffer.from("idx_c_last"), le(W_ID), le(D_ID), hash]),
+      authority: payer.publicKey, systemProgram: SystemProgram.programId,
+    } as any).instruction()]);
+    for (const id of ITEMS) {
+      send([await program.methods.initializeItem(new BN(id), new BN(id), `Item ${id}`, new BN(100), "data")
+        .accounts({ item: itemPda(id), authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()]);
+      send([await (program.methods.initializeStock as any)(W_ID, new BN(id), new BN(100), ...Array(10).fill("dist-info"), "data")
+        .accounts({ stock: stockPda(id), warehouse: warehousePda(), item: itemPda(id), authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()]);
+    }
+
+    for (const oId of [O1, O2, O3]) send([await newOrderIx(oId)]);
+  });
+
+  it("links each queued order to the one before it", () => {
+    expect(decode("newOrderEntry", newOrderPda(O2)).prevOId.toNumber()).to.equal(O1);
+    expect(decode("newOrderEntry", newOrderPda(O3)).prevOId.toNumber()).to.equal(O2);
+    const district = decode("district", districtPda());
+    expect(district.nextOId.toNumber()).to.equal(O3 + 1);
+    expect(district.minUndeliveredOId.toNumber()).to.equal(3001);
+  });
+
+  it("rejects an order id below the district's next id (OrderIdNotIncreasing)", async () => {
+    const blob = sendExpectFail([await newOrderIx(O3 - 1)]);
+    expect(blob, blob).to.match(/OrderIdNotIncreasing/);
+  });
+
+  it("rejects delivering anything but the oldest order (NotOldestOrder)", async () => {
+    for (const oId of [O2, O3]) {
+      const blob = sendExpectFail([await deliverIx(oId)]);
+      expect(blob, blob).to.match(/NotOldestOrder/);
+    }
+  });
+
+  it("delivers the oldest order and advances the queue head", async () => {
+    const before = decode("customer", customerPda());
+    send([await deliverIx(O1)]);
+
+    expect(svm.getAccount(newOrderPda(O1))?.lamports ?? 0).to.equal(0);
+    const order = decode("order", orderPda(O1));
+    expect(order.carrierId.toNumber()).to.equal(CARRIER);
+    expect(order.lines.slice(0, ITEMS.length).every((l: any) => l.deliveryD.toNumber() > 0)).to.equal(true);
+    const total = order.lines.slice(0, ITEMS.length).reduce((s: number, l: any) => s + l.amount.toNumber(), 0);
+    const after = decode("customer", customerPda());
+    expect(after.deliveryCnt).to.equal(before.deliveryCnt + 1);
+    expect(after.balance.toNumber()).to.equal(before.balance.toNumber() + total);
+    expect(decode("district", districtPda()).minUndeliveredOId.toNumber()).to.equal(O1 + 1);
+
+    const blob = sendExpectFail([await deliverIx(O3)]);
+    expect(blob, blob).to.match(/NotOldestOrder/);
+  });
+
+  it("then delivers the rest in order", async () => {
+    send([await deliverIx(O2)]);
+    send([await deliverIx(O3)]);
+    expect(decode("district", districtPda()).minUndeliveredOId.toNumber()).to.equal(O3 + 1);
+  });
+});
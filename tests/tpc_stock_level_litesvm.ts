@@ -0,0 +1,158 @@
+// Litesvm coverage for tpc-benchmark stock_level: the caller passes the district's recent
+// Order accounts followed by Stock accounts; the instruction returns (and emits in
+// StockLevelComputed) the number of distinct items on those orders' lines whose stock is
+// below the threshold. Stock for items outside the orders is ignored and a repeated Stock
+// account counts once.
+//
+// Guards: InvalidStockThreshold (threshold 0) and InvalidStockLevelAccounts (no Order
+// accounts ahead of the Stock list).
+
+import { LiteSVM, FailedTransactionMetadata, TransactionMetadata } from "litesvm";
+import { Program, EventParser } from "@anchor-lang/core";
+import { TpcBenchmark } from "../target/types/tpc_benchmark";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  Transaction,
+  SystemProgram,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import BN from "bn.js";
+import { createHash } from "crypto";
+import { createRequire } from "module";
+
+const require = createRequire(import.meta.url);
+const idl = require("../target/idl/tpc_benchmark.json");
+
+const W_ID = new BN(1);
+const D_ID = new BN(1);
+const C_ID = new BN(1);
+const LAST = "BARBARBAR";
+// Starting S_QUANTITY per item; item 7 is never ordered.
+const QUANTITY: Record<number, number> = { 1: 15, 2: 50, 3: 12, 4: 100, 5: 30, 6: 11, 7: 1 };
+
+describe("tpc-benchmark stock_level (litesvm)", () => {
+  let svm: LiteSVM;
+  let program: Program<TpcBenchmark>;
+  let programId: PublicKey;
+
+  const payer = Keypair.generate();
+
+  function trySend(ixs: TransactionInstruction[]): FailedTransactionMetadata | TransactionMetadata {
+    const tx = new Transaction();
+    tx.recentBlockhash = svm.latestBlockhash();
+    tx.feePayer = payer.publicKey;
+    ixs.forEach((ix) => tx.add(ix));
+    tx.sign(payer);
+    const res = svm.sendTransaction(tx);
+    svm.expireBlockhash();
+    return res;
+  }
+  function send(ixs: TransactionInstruction[]): TransactionMetadata {
+    const res = trySend(ixs);
+    if (res instanceof FailedTransactionMetadata) {
+      throw new Error("tx failed: " + res.err().toString() + "\n" + res.meta().logs().join("\n"));
+    }
+    return res;
+  }
+  function sendExpectFail(ixs: TransactionInstruction[]): string {
+    const res = trySend(ixs);
+    if (!(res instanceof FailedTransactionMetadata)) throw new Error("expected tx to fail but it succeeded");
+    return res.err().toString() + "\n" + res.meta().logs().join("\n");
+  }
+
+  const le = (n: BN) => n.toArrayLike(Buffer, "le", 8);
+  const pda = (seeds: Buffer[]) => PublicKey.findProgramAddressSync(seeds, programId)[0];
+  const nameHash = (last: string) => createHash("sha256").update(last).digest();
+  const warehousePda = () => pda([Buffer.from("warehouse"), le(W_ID)]);
+  const districtPda = () => pda([Buffer.from("district"), le(W_ID), le(D_ID)]);
+  const customerPda = () => pda([Buffer.from("customer"), le(W_ID), le(D_ID), le(C_ID)]);
+  const itemPda = (i: number) => pda([Buffer.from("item"), le(new BN(i))]);
+  const stockPda = (i: number) => pda([Buffer.from("stock"), le(W_ID), le(new BN(i))]);
+  const orderPda = (o: number) => pda([Buffer.from("order"), le(W_ID), le(D_ID), le(new BN(o))]);
+  const newOrderPda = (o: number) => pda([Buffer.from("new_order"), le(W_ID), le(D_ID), le(new BN(o))]);
+  const meta = (pubkey: PublicKey, isWritable = false) => ({ pubkey, isWritable, isSigner: false });
+
+  const newOrderIx = (oId: number, items: number[]) =>
+    program.methods.newOrder(W_ID, D_ID, C_ID, new BN(oId), items.map((i) => ({ iId: new BN(i), supplyWId: W_ID, quantity: 1 })))
+      .accounts({
+        warehouse: warehousePda(), district: districtPda(), customer: customerPda(),
+        order: orderPda(oId), newOrder: newOrderPda(oId), payer: payer.publicKey, systemProgram: SystemProgram.programId,
+      } as any)
+      .remainingAccounts(items.flatMap((i) => [meta(itemPda(i)), meta(stockPda(i), true)]))
+      .instruction();
+
+  const stockLevelIx = (threshold: number, orders: number[], stocks: number[]) =>
+    program.methods.stockLevel(W_ID, D_ID, new BN(threshold))
+      .accounts({ district: districtPda() } as any)
+      .remainingAccounts([...orders.map(orderPda), ...stocks.map(stockPda)].map((k) => meta(k)))
+      .instruction();
+
+  const retU64 = (res: TransactionMetadata) => Number(Buffer.from(res.returnData().data()).readBigUInt64LE(0));
+  const event = (res: TransactionMetadata): any => {
+    const parser = new EventParser(programId, (program as any).coder);
+    return [...parser.parseLogs(res.logs())].find((e) => e.name === "StockLevelComputed" || e.name === "stockLevelComputed")!.data;
+  };
+
+  before(async () => {
+    svm = new LiteSVM().withDefaultPrograms();
+    program = new Program(idl, { connection: {}, publicKey: PublicKey.default } as any);
+    programId = program.programId;
+    svm.addProgramFromFile(programId, "target/deploy/tpc_benchmark.so");
+    svm.airdrop(payer.publicKey, BigInt(1_000_000_000_000));
+
+    send([await program.methods.initializeWarehouse(W_ID, "Whse 1", "S1", "S2", "City", "ST", "12345", new BN(10))
+      .accounts({ warehouse: warehousePda(), authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()]);
+    send([await program.methods.initializeDistrict(W_ID, D_ID, "Dist 1", "S1", "S2", "City", "ST", "12345", new BN(5))
+      .accounts({ district: districtPda(), warehouse: warehousePda(), authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()]);
+    const hash = nameHash(LAST);
+    send([await program.methods.initializeCustomer(
+      W_ID, D_ID, C_ID, Array.from(hash), "First", "OE", LAST,
+      "Street", "Street", "City", "ST", "12345", "555-1234", { goodCredit: {} }, new BN(5000), new BN(10),
+    ).accounts({
+      customer: customerPda(), district: districtPda(),
+      customerIndex: pda([Buffer.from("idx_c_last"), le(W_ID), le(D_ID), hash]),
+      authority: payer.publicKey, systemProgram: SystemProgram.programId,
+    } as any).instruction()]);
+
+    for (const [id, quantity] of Object.entries(QUANTITY).map(([k, v]) => [Number(k), v])) {
+      send([await program.methods.initializeItem(new BN(id), new BN(id), `Item ${id}`, new BN(100), "data")
+        .accounts({ item: itemPda(id), authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()]);
+      const dist = Array(10).fill("dist-info");
+      send([await (program.methods.initializeStock as any)(W_ID, new BN(id), new BN(quantity), ...dist, "data")
+        .accounts({ stock: stockPda(id), warehouse: warehousePda(), item: itemPda(id), authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()]);
+    }
+
+    // One unit of each line: 1 -> 13, 2 -> 48, 3 -> 10, 4 -> 98, 5 -> 29, 6 -> 10.
+    send([await newOrderIx(4001, [1, 2, 3, 4, 5])]);
+    send([await newOrderIx(4002, [1, 3, 6, 2, 4])]);
+  });
+
+  it("returns the distinct items below the threshold and emits StockLevelComputed", async () => {
+    const res = send([await stockLevelIx(20, [4001, 4002], [1, 2, 3, 4, 5, 6, 3])]);
+    expect(retU64(res)).to.equal(3); // items 1, 3, 6
+    const ev = event(res);
+    expect(ev.lowStockCount.toNumber()).to.equal(3);
+    expect(ev.ordersExamined.toNumber()).to.equal(2);
+    expect(ev.distinctItems.toNumber()).to.equal(6);
+    expect(ev.itemsChecked.toNumber()).to.equal(6);
+    expect(ev.threshold.toNumber()).to.equal(20);
+  });
+
+  it("ignores stock for items not on the examined orders", async () => {
+    const res = send([await stockLevelIx(20, [4001], [1, 2, 3, 6, 7])]);
+    expect(retU64(res)).to.equal(2); // items 1, 3; item 6 is only on order 4002, item 7 on none
+    expect(event(res).itemsChecked.toNumber()).to.equal(3);
+  });
+
+  it("rejects a zero threshold (InvalidStockThreshold)", async () => {
+    const blob = sendExpectFail([await stockLevelIx(0, [4001], [1])]);
+    expect(blob, blob).to.match(/InvalidStockThreshold/);
+  });
+
+  it("rejects Stock accounts without any Order ahead of them (InvalidStockLevelAccounts)", async () => {
+    const blob = sendExpectFail([await stockLevelIx(20, [], [1, 2])]);
+    expect(blob, blob).to.match(/InvalidStockLevelAccounts/);
+  });
+});
@@ -1,6 +1,7 @@
 import * as anchor from "@anchor-lang/core";
 import { Program } from "@anchor-lang/core";
 import BN from "bn.js";
+import { createHash } from "crypto";
 import * as fs from 'fs';
 import * as path from 'path';
 import {
@@ -109,13 +110,20 @@ describe("TPC-C Performance Stress Test", () => {
                 program.programId
             );
             customerAccounts.push(custPda);
+            const last = `Last${i}`;
+            const lastHash = createHash("sha256").update(last).digest();
+            const [indexPda] = PublicKey.findProgramAddressSync(
+                [Buffer.from("idx_c_last"), W_ID.toArrayLike(Buffer, "le", 8), D_ID.toArrayLike(Buffer, "le", 8), lastHash],
+                program.programId
+            );
 
             try {
                 await program.methods.initializeCustomer(
-                    W_ID, D_ID, cId, "First", "MD", `Last${i}`, "Street", "Street", "City", "ST", "12345", "555-1234", { goodCredit: {} }, new BN(5000), new BN(10)
+                    W_ID, D_ID, cId, Array.from(lastHash), "First", "MD", last, "Street", "Street", "City", "ST", "12345", "555-1234", { goodCredit: {} }, new BN(5000), new BN(10)
                 ).accounts({
                     customer: custPda,
                     district: districtAccount,
+                    customerIndex: indexPda,
                     authority: authority.publicKey,
                     systemProgram: SystemProgram.programId
                 }).rpc();
@@ -0,0 +1,291 @@
+// Litesvm coverage for on-chain ZoneMarket depth maintenance on the legacy order path:
+// create_sell_order / create_buy_order rest their unfilled amount at their price,
+// match_orders takes the exact fill off each order's level, and cancel_order removes the
+// residual; get_market_stats reports the per-side sums and get_order_book both sides. A fresh zone market keeps the depth
+// arrays isolated from other suites. A second zone covers the per-zone max_depth_levels.
+//
+// GovernanceConfig is fabricated via svm.setAccount, same trick as order_guards_litesvm.ts.
+
+import { LiteSVM, FailedTransactionMetadata } from "litesvm";
+import { Program, EventParser } from "@anchor-lang/core";
+import { Trading } from "../target/types/trading";
+import { Governance } from "../target/types/governance";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  Transaction,
+  SystemProgram,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import BN from "bn.js";
+import { createRequire } from "module";
+
+const require = createRequire(import.meta.url);
+const tradingIdl = require("../target/idl/trading.json");
+const governanceIdl = require("../target/idl/governance.json");
+
+const ZONE = 0;
+
+describe("trading order-book depth maintenance (litesvm)", () => {
+  let svm: LiteSVM;
+  let trading: Program<Trading>;
+  let governance: Program<Governance>;
+  let tradingId: PublicKey;
+  let governanceId: PublicKey;
+
+  const payer = Keypair.generate(); // market authority + trader + fee payer
+
+  let marketPda: PublicKey;
+  let zoneMarketPda: PublicKey;
+  let cfgKey: PublicKey;
+
+  function trySend(ixs: TransactionInstruction[], signers: Keypair[] = []): FailedTransactionMetadata | null {
+    const tx = new Transaction();
+    tx.recentBlockhash = svm.latestBlockhash();
+    tx.feePayer = payer.publicKey;
+    ixs.forEach((ix) => tx.add(ix));
+    tx.sign(payer, ...signers);
+    const res = svm.sendTransaction(tx);
+    svm.expireBlockhash();
+    return res instanceof FailedTransactionMetadata ? res : null;
+  }
+  function send(ixs: TransactionInstruction[], signers: Keypair[] = []) {
+    const f = trySend(ixs, signers);
+    if (f) throw new Error("tx failed: " + f.err().toString() + "\n" + f.meta().logs().join("\n"));
+  }
+  function sendRaw(ixs: TransactionInstruction[]) {
+    const tx = new Transaction();
+    tx.recentBlockhash = svm.latestBlockhash();
+    tx.feePayer = payer.publicKey;
+    ixs.forEach((ix) => tx.add(ix));
+    tx.sign(payer);
+    const res = svm.sendTransaction(tx);
+    svm.expireBlockhash();
+    if (res instanceof FailedTransactionMetadata) throw new Error("tx failed: " + res.err().toString());
+    return res;
+  }
+
+  const orderPda = (auth: PublicKey, orderId: number) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("order"), auth.toBuffer(), new BN(orderId).toArrayLike(Buffer, "le", 8)], tradingId)[0];
+
+  async function installConfig(): Promise<PublicKey> {
+    const key = Keypair.generate().publicKey;
+    const cfg = {
+      authority: PublicKey.default, authorityName: Array(64).fill(0), nameLen: 0,
+      contactInfo: Array(128).fill(0), contactLen: 0, version: 1, maintenanceMode: false,
+      ercValidationEnabled: true, minEnergyAmount: new BN(0), maxErcAmount: new BN(0),
+      ercValidityPeriod: new BN(0), requireOracleValidation: false, oracleAuthority: PublicKey.default,
+      minOracleConfidence: 0, allowCertificateTransfers: true, minQuorumVotes: new BN(0),
+      totalErcsIssued: new BN(0), totalErcsValidated: new BN(0), totalErcsRevoked: new BN(0),
+      totalEnergyCertified: new BN(0), createdAt: new BN(0), lastUpdated: new BN(0), lastErcIssuedAt: new BN(0),
+      pendingAuthority: PublicKey.default, pendingAuthorityProposedAt: new BN(0), pendingAuthorityExpiresAt: new BN(0),
+      minFeeBps: 0, maxFeeBps: 0, reserved: Array(1).fill(0),
+    };
+    const data = await governance.coder.accounts.encode("governanceConfig", cfg as any);
+    svm.setAccount(key, {
+      lamports: Number(svm.minimumBalanceForRentExemption(BigInt(data.length))),
+      data, owner: governanceId, executable: false, rentEpoch: 0,
+    } as any);
+    return key;
+  }
+
+  const order = (orderId: number) => orderPda(payer.publicKey, orderId);
+  const levels = (side: "buy" | "sell") => {
+    const zm: any = trading.coder.accounts.decode("zoneMarket", Buffer.from(svm.getAccount(zoneMarketPda)!.data));
+    const count = side === "buy" ? zm.buySideDepthCount : zm.sellSideDepthCount;
+    const depth = side === "buy" ? zm.buySideDepth : zm.sellSideDepth;
+    return depth.slice(0, count).map((l: any) => [l.price.toNumber(), l.totalAmount.toNumber(), l.orderCount]);
+  };
+
+  const sellIx = (orderId: number, amount: number, price: number) =>
+    trading.methods.createSellOrder(new BN(orderId), new BN(amount), new BN(price)).accounts({
+      market: marketPda, zoneMarket: zoneMarketPda, order: order(orderId), ercCertificate: null,
+      authority: payer.publicKey, systemProgram: SystemProgram.programId, governanceConfig: cfgKey,
+    } as any).instruction();
+  const buyIx = (orderId: number, amount: number, maxPrice: number) =>
+    trading.methods.createBuyOrder(new BN(orderId), new BN(amount), new BN(maxPrice)).accounts({
+      market: marketPda, zoneMarket: zoneMarketPda, order: order(orderId),
+      authority: payer.publicKey, systemProgram: SystemProgram.programId, governanceConfig: cfgKey,
+    } as any).instruction();
+  const matchIx = (buyId: number, sellId: number, amount: number) => {
+    const buy = order(buyId), sell = order(sellId);
+    const tradeRecord = tradePda();
+    return trading.methods.matchOrders(new BN(amount)).accounts({
+      market: marketPda, zoneMarket: zoneMarketPda, buyOrder: buy, sellOrder: sell,
+      tradeRecord, authority: payer.publicKey, governanceConfig: cfgKey, systemProgram: SystemProgram.programId,
+      buyerStats: statsPda(payer.publicKey), // self-trade: seller_stats omitted
+    } as any).instruction();
+  };
+  const statsIx = () =>
+    trading.methods.getMarketStats().accounts({ market: marketPda, zoneMarket: zoneMarketPda } as any).instruction();
+  const bookIx = () =>
+    trading.methods.getOrderBook().accounts({ market: marketPda, zoneMarket: zoneMarketPda } as any).instruction();
+  const cancelIx = (orderId: number) =>
+    trading.methods.cancelOrder().accounts({
+      market: marketPda, zoneMarket: zoneMarketPda, order: order(orderId),
+      authority: payer.publicKey, governanceConfig: cfgKey, traderStats: statsPda(payer.publicKey),
+    } as any).instruction();
+
+  const statsPda = (trader: PublicKey) =>
+    PublicKey.findProgramAddressSync([Buffer.from("trader"), trader.toBuffer()], tradingId)[0];
+
+  const tradePda = () => {
+    const m: any = trading.coder.accounts.decode("market", Buffer.from(svm.getAccount(marketPda)!.data));
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("trade"), marketPda.toBuffer(), m.tradeSeq.toArrayLike(Buffer, "le", 8)], tradingId)[0];
+  };
+
+  before(async () => {
+    svm = new LiteSVM().withDefaultPrograms();
+    trading = new Program(tradingIdl, { connection: {}, publicKey: PublicKey.default } as any);
+    governance = new Program(governanceIdl, { connection: {}, publicKey: PublicKey.default } as any);
+    tradingId = trading.programId; governanceId = governance.programId;
+    svm.addProgramFromFile(tradingId, "target/deploy/trading.so");
+    svm.addProgramFromFile(governanceId, "target/deploy/governance.so");
+    svm.airdrop(payer.publicKey, BigInt(1_000_000_000_000));
+
+    [marketPda] = PublicKey.findProgramAddressSync([Buffer.from("market")], tradingId);
+    [zoneMarketPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("zone_market"), marketPda.toBuffer(), new BN(ZONE).toArrayLike(Buffer, "le", 4)], tradingId);
+
+    send([await trading.methods.initializeMarket(16).accounts({ market: marketPda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()]);
+    send([await trading.methods.initializeZoneMarket(ZONE, 16, new BN(1_000_000), 10).accounts({ market: marketPda, zoneMarket: zoneMarketPda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()]);
+    cfgKey = await installConfig();
+  });
+
+  it("rests new orders at their unfilled amount", async () => {
+    send([await sellIx(1, 100, 50)]);
+    send([await buyIx(2, 30, 60)]);
+    expect(levels("sell")).to.deep.equal([[50, 100, 1]]);
+    expect(levels("buy")).to.deep.equal([[60, 30, 1]]);
+  });
+
+  it("leaves the residual of a partially filled sell order in sell_side_depth", async () => {
+    send([await matchIx(2, 1, 30)]);
+    expect(levels("sell")).to.deep.equal([[50, 70, 1]]); // 100 - 30 still resting
+    expect(levels("buy")).to.deep.equal([]);              // buy fully filled, level dropped
+  });
+
+  it("reports each order's remaining amount in OrderMatched", async () => {
+    send([await sellIx(6, 20, 50), await buyIx(7, 50, 60)]);
+    const logs = sendRaw([await matchIx(7, 6, 20)]).logs();
+    const parser = new EventParser(tradingId, (trading as any).coder);
+    const ev = [...parser.parseLogs(logs)].find((e) => e.name === "OrderMatched" || e.name === "orderMatched");
+    expect(ev, "OrderMatched emitted").to.not.be.undefined;
+    expect(ev!.data.amount.toNumber()).to.equal(20);
+    expect(ev!.data.buyRemaining.toNumber()).to.equal(30);
+    expect(ev!.data.sellRemaining.toNumber()).to.equal(0);
+    expect(levels("buy")).to.deep.equal([[60, 30, 1]]);
+    send([await cancelIx(7)]);
+  });
+
+  it("aggregates orders at the same price and removes a cancelled residual", async () => {
+    send([await sellIx(3, 40, 50)]);
+    expect(levels("sell")).to.deep.equal([[50, 110, 2]]);
+    send([await cancelIx(1)]);
+    expect(levels("sell")).to.deep.equal([[50, 40, 1]]);
+  });
+
+  it("get_market_stats sums each side of the depth snapshot", async () => {
+    send([await buyIx(4, 15, 45), await buyIx(5, 10, 44)]);
+    const data = Buffer.from(sendRaw([await statsIx()]).returnData().data());
+    const stats: any = trading.coder.types.decode("marketStats", data);
+    expect(stats.zoneId).to.equal(ZONE);
+    expect(stats.zoneActiveOrders).to.equal(3); // sell 3, buys 4 and 5
+    expect(stats.sellSideDepth.toNumber()).to.equal(40);
+    expect(stats.buySideDepth.toNumber()).to.equal(25);
+  });
+
+  it("get_order_book returns both sides of the depth snapshot", async () => {
+    const data = Buffer.from(sendRaw([await bookIx()]).returnData().data());
+    const book: any = trading.coder.types.decode("orderBookSnapshot", data);
+    const rows = (side: any[]) => side.map((l: any) => [l.price.toNumber(), l.totalAmount.toNumber(), l.orderCount]);
+    expect(rows(book.bids)).to.deep.equal(levels("buy")).and.to.deep.equal([[45, 15, 1], [44, 10, 1]]);
+    expect(rows(book.asks)).to.deep.equal(levels("sell")).and.to.deep.equal([[50, 40, 1]]);
+    expect(book.lastClearingPrice.toNumber()).to.be.greaterThan(0); // set by the match above
+    expect(book.capturedAt.toNumber()).to.equal(Number(svm.getClock().unixTimestamp));
+  });
+
+  it("match_orders enforces the market's min_price_improvement_bps", async () => {
+    const paramsIx = (minImprovementBps: number) =>
+      trading.methods.updateMarketParams(0, true, new BN(0), new BN(0), 0, new BN(0), minImprovementBps).accounts({
+        market: marketPda, authority: payer.publicKey, governanceConfig: cfgKey,
+      } as any).instruction();
+    send([await paramsIx(1_000), await sellIx(8, 10, 100), await buyIx(9, 10, 105)]);
+    const m: any = trading.coder.accounts.decode("market", Buffer.from(svm.getAccount(marketPda)!.data));
+    expect(m.minPriceImprovementBps).to.equal(1_000);
+
+    // 5-point spread on a 100 ask is 500 bps, short of the 1_000 bps requirement
+    const fail = trySend([await matchIx(9, 8, 10)]);
+    expect(fail, "match should be rejected").to.not.be.null;
+    expect(fail!.meta().logs().join("\n")).to.match(/InsufficientPriceImprovement/);
+
+    send([await paramsIx(0)]);
+    send([await matchIx(9, 8, 10)]);
+  });
+
+  it("pages trade records by the market's trade_seq", async () => {
+    const m: any = trading.coder.accounts.decode("market", Buffer.from(svm.getAccount(marketPda)!.data));
+    expect(m.tradeSeq.toNumber()).to.equal(3); // three successful matches above
+    const seqPda = (seq: number) => PublicKey.findProgramAddressSync(
+      [Buffer.from("trade"), marketPda.toBuffer(), new BN(seq).toArrayLike(Buffer, "le", 8)], tradingId)[0];
+    const records = [0, 1, 2].map((seq) =>
+      trading.coder.accounts.decode("tradeRecord", Buffer.from(svm.getAccount(seqPda(seq))!.data)) as any);
+    expect(records.map((r) => r.tradeSeq.toNumber())).to.deep.equal([0, 1, 2]);
+    expect(records.map((r) => r.buyOrder.toBase58())).to.deep.equal([order(2), order(7), order(9)].map((k) => k.toBase58()));
+    expect(svm.getAccount(seqPda(3))).to.be.null;
+  });
+
+  it("match_orders trips the circuit breaker against the previous match price", async () => {
+    const market = () => trading.coder.accounts.decode("market", Buffer.from(svm.getAccount(marketPda)!.data)) as any;
+    expect(market().lastClearingPrice.toNumber()).to.equal(100); // last match above cleared at 100
+
+    const paramsIx = (maxDevBps: number) =>
+      trading.methods.updateMarketParams(0, true, new BN(0), new BN(0), maxDevBps, new BN(0), 0).accounts({
+        market: marketPda, authority: payer.publicKey, governanceConfig: cfgKey,
+      } as any).instruction();
+    send([await paramsIx(1_000), await sellIx(10, 10, 200), await buyIx(11, 10, 210)]);
+
+    // 200 is 100% away from 100, far outside the 10% band
+    const fail = trySend([await matchIx(11, 10, 10)]);
+    expect(fail, "match should be rejected").to.not.be.null;
+    expect(fail!.meta().logs().join("\n")).to.match(/PriceDeviationExceeded/);
+
+    // 105 is within 10%, and then becomes the reference for the next fill
+    send([await sellIx(12, 10, 105), await buyIx(13, 10, 105)]);
+    send([await matchIx(13, 12, 10)]);
+    expect(market().lastClearingPrice.toNumber()).to.equal(105);
+    send([await paramsIx(0), await cancelIx(10), await cancelIx(11)]);
+  });
+
+  it("keeps the depth size chosen at zone init (InvalidDepthLevels outside 1..=10)", async () => {
+    const SMALL_ZONE = 1;
+    const [smallZonePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("zone_market"), marketPda.toBuffer(), new BN(SMALL_ZONE).toArrayLike(Buffer, "le", 4)], tradingId);
+    const initIx = (levels: number) =>
+      trading.methods.initializeZoneMarket(SMALL_ZONE, 16, new BN(1_000_000), levels).accounts({
+        market: marketPda, zoneMarket: smallZonePda, authority: payer.publicKey, systemProgram: SystemProgram.programId,
+      } as any).instruction();
+    for (const levels of [0, 11]) {
+      const fail = trySend([await initIx(levels)]);
+      expect(fail, `depth ${levels} should be rejected`).to.not.be.null;
+      expect(fail!.meta().logs().join("\n")).to.match(/InvalidDepthLevels/);
+    }
+
+    send([await initIx(2)]);
+    const zm: any = trading.coder.accounts.decode("zoneMarket", Buffer.from(svm.getAccount(smallZonePda)!.data));
+    expect(zm.maxDepthLevels).to.equal(2);
+
+    const depthIx = (asks: number[]) =>
+      trading.methods.updateDepth([], [], asks.map((p) => new BN(p)), asks.map(() => new BN(10))).accounts({
+        market: marketPda, zoneMarket: smallZonePda, authority: payer.publicKey, governanceConfig: cfgKey,
+      } as any).instruction();
+    const fail = trySend([await depthIx([50, 51, 52])]);
+    expect(fail, "three levels exceed the zone's depth").to.not.be.null;
+    expect(fail!.meta().logs().join("\n")).to.match(/BatchTooLarge/);
+    send([await depthIx([50, 51])]);
+  });
+});
+
@@ -0,0 +1,269 @@
+// Litesvm coverage for escrowed sell orders: create_sell_order_escrowed locks the seller's
+// energy in the per-order ["order_escrow", order] token account, match_orders releases the
+// filled portion to the buyer (and refuses to fill without the escrow accounts), and once
+// the order is cancelled refund_order_escrow returns the remainder and closes the escrow.
+//
+// GovernanceConfig is fabricated directly (svm.setAccount + governance coder), same trick as
+// order_guards_litesvm.ts. The energy mint is a plain SPL mint; the token interface accepts it.
+
+import { LiteSVM, FailedTransactionMetadata } from "litesvm";
+import { Program } from "@anchor-lang/core";
+import { Trading } from "../target/types/trading";
+import { Governance } from "../target/types/governance";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  Transaction,
+  SystemProgram,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import {
+  TOKEN_PROGRAM_ID,
+  MINT_SIZE,
+  createInitializeMint2Instruction,
+  createAssociatedTokenAccountInstruction,
+  createMintToInstruction,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+import BN from "bn.js";
+import { createRequire } from "module";
+
+const require = createRequire(import.meta.url);
+const tradingIdl = require("../target/idl/trading.json");
+const governanceIdl = require("../target/idl/governance.json");
+
+const ZONE = 0;
+const SELL_ID = 1;
+const BUY_ID = 2;
+
+describe("trading escrowed sell orders (litesvm)", () => {
+  let svm: LiteSVM;
+  let trading: Program<Trading>;
+  let governance: Program<Governance>;
+  let tradingId: PublicKey;
+  let governanceId: PublicKey;
+
+  const payer = Keypair.generate();   // market authority + fee payer + mint authority
+  const seller = Keypair.generate();
+  const buyer = Keypair.generate();
+  const mintKp = Keypair.generate();
+
+  let marketPda: PublicKey;
+  let zoneMarketPda: PublicKey;
+  let marketAuthorityPda: PublicKey;
+  let sellerAta: PublicKey;
+  let buyerAta: PublicKey;
+  let cfg: PublicKey;
+
+  function trySend(ixs: TransactionInstruction[], signers: Keypair[] = []): FailedTransactionMetadata | null {
+    const tx = new Transaction();
+    tx.recentBlockhash = svm.latestBlockhash();
+    tx.feePayer = payer.publicKey;
+    ixs.forEach((ix) => tx.add(ix));
+    tx.sign(payer, ...signers);
+    const res = svm.sendTransaction(tx);
+    svm.expireBlockhash();
+    return res instanceof FailedTransactionMetadata ? res : null;
+  }
+  function send(ixs: TransactionInstruction[], signers: Keypair[] = []) {
+    const f = trySend(ixs, signers);
+    if (f) throw new Error("tx failed: " + f.err().toString() + "\n" + f.meta().logs().join("\n"));
+  }
+  function sendExpectFail(ixs: TransactionInstruction[], signers: Keypair[] = []): string {
+    const f = trySend(ixs, signers);
+    if (!f) throw new Error("expected tx to fail but it succeeded");
+    return f.err().toString() + "\n" + f.meta().logs().join("\n");
+  }
+
+  const orderPda = (auth: PublicKey, orderId: number) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("order"), auth.toBuffer(), new BN(orderId).toArrayLike(Buffer, "le", 8)],
+      tradingId
+    )[0];
+  const escrowPda = (order: PublicKey) =>
+    PublicKey.findProgramAddressSync([Buffer.from("order_escrow"), order.toBuffer()], tradingId)[0];
+  const statsPda = (trader: PublicKey) =>
+    PublicKey.findProgramAddressSync([Buffer.from("trader"), trader.toBuffer()], tradingId)[0];
+  const balance = (ata: PublicKey): number => Number(Buffer.from(svm.getAccount(ata)!.data).readBigUInt64LE(64));
+  const order = (key: PublicKey): any =>
+    trading.coder.accounts.decode("order", Buffer.from(svm.getAccount(key)!.data));
+
+  async function installConfig(): Promise<PublicKey> {
+    const key = Keypair.generate().publicKey;
+    const config = {
+      authority: PublicKey.default,
+      authorityName: Array(64).fill(0),
+      nameLen: 0,
+      contactInfo: Array(128).fill(0),
+      contactLen: 0,
+      version: 1,
+      maintenanceMode: false,
+      ercValidationEnabled: true,
+      minEnergyAmount: new BN(0),
+      maxErcAmount: new BN(0),
+      ercValidityPeriod: new BN(0),
+      requireOracleValidation: false,
+      oracleAuthority: PublicKey.default,
+      minOracleConfidence: 0,
+      allowCertificateTransfers: true,
+      minQuorumVotes: new BN(0),
+      totalErcsIssued: new BN(0),
+      totalErcsValidated: new BN(0),
+      totalErcsRevoked: new BN(0),
+      totalEnergyCertified: new BN(0),
+      createdAt: new BN(0),
+      lastUpdated: new BN(0),
+      lastErcIssuedAt: new BN(0),
+      pendingAuthority: PublicKey.default,
+      pendingAuthorityProposedAt: new BN(0),
+      pendingAuthorityExpiresAt: new BN(0),
+      minFeeBps: 0, maxFeeBps: 0, reserved: Array(1).fill(0),
+    };
+    const data = await governance.coder.accounts.encode("governanceConfig", config as any);
+    svm.setAccount(key, {
+      lamports: Number(svm.minimumBalanceForRentExemption(BigInt(data.length))),
+      data,
+      owner: governanceId,
+      executable: false,
+      rentEpoch: 0,
+    } as any);
+    return key;
+  }
+
+  const escrowedSellIx = (orderId: number, amount: number, price: number) =>
+    trading.methods.createSellOrderEscrowed(new BN(orderId), new BN(amount), new BN(price)).accounts({
+      market: marketPda, zoneMarket: zoneMarketPda, order: orderPda(seller.publicKey, orderId),
+      energyMint: mintKp.publicKey, sellerEnergyAccount: sellerAta, orderEscrow: escrowPda(orderPda(seller.publicKey, orderId)),
+      marketAuthority: marketAuthorityPda, authority: seller.publicKey,
+      tokenProgram: TOKEN_PROGRAM_ID, systemProgram: SystemProgram.programId, governanceConfig: cfg,
+    } as any).instruction();
+
+  const matchIx = (withEscrow: boolean, buyerAccount: PublicKey = buyerAta) => {
+    const buy = orderPda(buyer.publicKey, BUY_ID), sell = orderPda(seller.publicKey, SELL_ID);
+    const tradeRecord = tradePda();
+    return trading.methods.matchOrders(new BN(300)).accounts({
+      market: marketPda, zoneMarket: zoneMarketPda, buyOrder: buy, sellOrder: sell,
+      tradeRecord, authority: payer.publicKey, governanceConfig: cfg, systemProgram: SystemProgram.programId,
+      energyMint: withEscrow ? mintKp.publicKey : null,
+      sellOrderEscrow: withEscrow ? escrowPda(sell) : null,
+      buyerEnergyAccount: withEscrow ? buyerAccount : null,
+      marketAuthority: withEscrow ? marketAuthorityPda : null,
+      tokenProgram: withEscrow ? TOKEN_PROGRAM_ID : null,
+      buyerStats: statsPda(buyer.publicKey), sellerStats: statsPda(seller.publicKey),
+    } as any).instruction();
+  };
+
+  const refundIx = (orderId: number) => {
+    const o = orderPda(seller.publicKey, orderId);
+    return trading.methods.refundOrderEscrow().accounts({
+      order: o, energyMint: mintKp.publicKey, orderEscrow: escrowPda(o), sellerEnergyAccount: sellerAta,
+      seller: seller.publicKey, marketAuthority: marketAuthorityPda, tokenProgram: TOKEN_PROGRAM_ID,
+    } as any).instruction();
+  };
+
+  const tradePda = () => {
+    const m: any = trading.coder.accounts.decode("market", Buffer.from(svm.getAccount(marketPda)!.data));
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("trade"), marketPda.toBuffer(), m.tradeSeq.toArrayLike(Buffer, "le", 8)], tradingId)[0];
+  };
+
+  before(async () => {
+    svm = new LiteSVM().withDefaultPrograms();
+    trading = new Program(tradingIdl, { connection: {}, publicKey: PublicKey.default } as any);
+    governance = new Program(governanceIdl, { connection: {}, publicKey: PublicKey.default } as any);
+    tradingId = trading.programId;
+    governanceId = governance.programId;
+    svm.addProgramFromFile(tradingId, "target/deploy/trading.so");
+    svm.addProgramFromFile(governanceId, "target/deploy/governance.so");
+    svm.airdrop(payer.publicKey, BigInt(1_000_000_000_000));
+    svm.airdrop(seller.publicKey, BigInt(10_000_000_000));
+    svm.airdrop(buyer.publicKey, BigInt(10_000_000_000));
+
+    [marketPda] = PublicKey.findProgramAddressSync([Buffer.from("market")], tradingId);
+    [zoneMarketPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("zone_market"), marketPda.toBuffer(), new BN(ZONE).toArrayLike(Buffer, "le", 4)], tradingId);
+    [marketAuthorityPda] = PublicKey.findProgramAddressSync([Buffer.from("market_authority")], tradingId);
+
+    send([await trading.methods.initializeMarket(16).accounts({ market: marketPda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()]);
+    send([await trading.methods.initializeZoneMarket(ZONE, 16, new BN(1_000_000), 10).accounts({ market: marketPda, zoneMarket: zoneMarketPda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()]);
+    cfg = await installConfig();
+
+    const mintRent = Number(svm.minimumBalanceForRentExemption(BigInt(MINT_SIZE)));
+    send([
+      SystemProgram.createAccount({ fromPubkey: payer.publicKey, newAccountPubkey: mintKp.publicKey, lamports: mintRent, space: MINT_SIZE, programId: TOKEN_PROGRAM_ID }),
+      createInitializeMint2Instruction(mintKp.publicKey, 9, payer.publicKey, null, TOKEN_PROGRAM_ID),
+    ], [mintKp]);
+    sellerAta = getAssociatedTokenAddressSync(mintKp.publicKey, seller.publicKey, false, TOKEN_PROGRAM_ID);
+    buyerAta = getAssociatedTokenAddressSync(mintKp.publicKey, buyer.publicKey, false, TOKEN_PROGRAM_ID);
+    send([
+      createAssociatedTokenAccountInstruction(payer.publicKey, sellerAta, seller.publicKey, mintKp.publicKey, TOKEN_PROGRAM_ID),
+      createAssociatedTokenAccountInstruction(payer.publicKey, buyerAta, buyer.publicKey, mintKp.publicKey, TOKEN_PROGRAM_ID),
+      createMintToInstruction(mintKp.publicKey, sellerAta, payer.publicKey, 1_000, [], TOKEN_PROGRAM_ID),
+    ]);
+  });
+
+  it("rejects an escrowed sell larger than the seller's balance", async () => {
+    sendExpectFail([await escrowedSellIx(9, 5_000, 50)], [seller]);
+    expect(svm.getAccount(orderPda(seller.publicKey, 9))).to.be.null;
+  });
+
+  it("locks the sell amount in the order escrow", async () => {
+    send([await escrowedSellIx(SELL_ID, 400, 50)], [seller]);
+    const sell = orderPda(seller.publicKey, SELL_ID);
+    expect(balance(sellerAta)).to.equal(600);
+    expect(balance(escrowPda(sell))).to.equal(400);
+    expect(order(sell).escrowed).to.equal(1);
+
+    send([await trading.methods.createBuyOrder(new BN(BUY_ID), new BN(300), new BN(60)).accounts({
+      market: marketPda, zoneMarket: zoneMarketPda, order: orderPda(buyer.publicKey, BUY_ID),
+      authority: buyer.publicKey, systemProgram: SystemProgram.programId, governanceConfig: cfg,
+    } as any).instruction()], [buyer]);
+  });
+
+  it("refuses to match an escrowed sell without the escrow accounts (EscrowAccountsRequired)", async () => {
+    const blob = sendExpectFail([await matchIx(false)]);
+    expect(blob, blob).to.match(/EscrowAccountsRequired/);
+  });
+
+  it("refuses to release into an account the buyer does not own (UnauthorizedAuthority)", async () => {
+    const blob = sendExpectFail([await matchIx(true, sellerAta)]);
+    expect(blob, blob).to.match(/UnauthorizedAuthority/);
+  });
+
+  it("releases the filled amount to the buyer on match", async () => {
+    send([await matchIx(true)]);
+    const sell = orderPda(seller.publicKey, SELL_ID);
+    expect(balance(buyerAta)).to.equal(300);
+    expect(balance(escrowPda(sell))).to.equal(100);
+    expect(order(sell).filledAmount.toNumber()).to.equal(300);
+  });
+
+  it("records the match in both traders' stats", async () => {
+    const now = Number(svm.getClock().unixTimestamp);
+    for (const [trader, key] of [[buyer, statsPda(buyer.publicKey)], [seller, statsPda(seller.publicKey)]] as const) {
+      const s: any = trading.coder.accounts.decode("traderStats", Buffer.from(svm.getAccount(key)!.data));
+      expect(s.authority.toBase58()).to.equal(trader.publicKey.toBase58());
+      expect(s.totalVolume.toNumber()).to.equal(300);
+      expect(s.tradeCount.toNumber()).to.equal(1);
+      expect(s.lastTradeAt.toNumber()).to.equal(now);
+    }
+  });
+
+  it("refuses a refund while the order is still open (OrderNotRefundable)", async () => {
+    const blob = sendExpectFail([await refundIx(SELL_ID)]);
+    expect(blob, blob).to.match(/OrderNotRefundable/);
+  });
+
+  it("refunds the unfilled remainder after cancel and closes the escrow", async () => {
+    const sell = orderPda(seller.publicKey, SELL_ID);
+    send([await trading.methods.cancelOrder().accounts({
+      market: marketPda, zoneMarket: zoneMarketPda, order: sell, authority: seller.publicKey, governanceConfig: cfg,
+      traderStats: statsPda(seller.publicKey),
+    } as any).instruction()], [seller]);
+    send([await refundIx(SELL_ID)]); // permissionless: only the fee payer signs
+    expect(balance(sellerAta)).to.equal(700);
+    expect(svm.getAccount(escrowPda(sell))).to.be.null;
+    expect(order(sell).escrowed).to.equal(0);
+  });
+});
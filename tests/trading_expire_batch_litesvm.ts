@@ -0,0 +1,180 @@
+// Litesvm coverage for trading expire_orders_batch: a keeper sweep that flips stale
+// Active/PartiallyFilled orders to Expired, decrements the zone's active_orders and pulls the
+// unfilled residual out of the depth snapshot. Orders that are not yet expired or already
+// closed are skipped rather than failing the batch.
+//
+// Guards: UnauthorizedAuthority (non-market-authority signer) and InvalidExpireBatch (empty
+// batch). GovernanceConfig is fabricated via svm.setAccount, same trick as order_guards_litesvm.ts.
+
+import { LiteSVM, Clock, FailedTransactionMetadata } from "litesvm";
+import { Program } from "@anchor-lang/core";
+import { Trading } from "../target/types/trading";
+import { Governance } from "../target/types/governance";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  Transaction,
+  SystemProgram,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import BN from "bn.js";
+import { createRequire } from "module";
+
+const require = createRequire(import.meta.url);
+const tradingIdl = require("../target/idl/trading.json");
+const governanceIdl = require("../target/idl/governance.json");
+
+const ZONE = 0;
+const NOW = 1_700_000_000;
+const ORDER_TTL = 86_400; // create_*_order sets expires_at = now + 1 day
+
+describe("trading expire_orders_batch (litesvm)", () => {
+  let svm: LiteSVM;
+  let trading: Program<Trading>;
+  let governance: Program<Governance>;
+  let tradingId: PublicKey;
+  let governanceId: PublicKey;
+
+  const payer = Keypair.generate(); // market authority + trader + fee payer
+  const outsider = Keypair.generate();
+
+  let marketPda: PublicKey;
+  let zoneMarketPda: PublicKey;
+  let cfgKey: PublicKey;
+
+  function trySend(ixs: TransactionInstruction[], signers: Keypair[] = []): FailedTransactionMetadata | null {
+    const tx = new Transaction();
+    tx.recentBlockhash = svm.latestBlockhash();
+    tx.feePayer = payer.publicKey;
+    ixs.forEach((ix) => tx.add(ix));
+    tx.sign(payer, ...signers);
+    const res = svm.sendTransaction(tx);
+    svm.expireBlockhash();
+    return res instanceof FailedTransactionMetadata ? res : null;
+  }
+  function send(ixs: TransactionInstruction[], signers: Keypair[] = []) {
+    const f = trySend(ixs, signers);
+    if (f) throw new Error("tx failed: " + f.err().toString() + "\n" + f.meta().logs().join("\n"));
+  }
+  function sendExpectFail(ixs: TransactionInstruction[], signers: Keypair[] = []): string {
+    const f = trySend(ixs, signers);
+    if (!f) throw new Error("expected tx to fail but it succeeded");
+    return f.err().toString() + "\n" + f.meta().logs().join("\n");
+  }
+  function sendRaw(ixs: TransactionInstruction[], signers: Keypair[] = []) {
+    const tx = new Transaction();
+    tx.recentBlockhash = svm.latestBlockhash();
+    tx.feePayer = payer.publicKey;
+    ixs.forEach((ix) => tx.add(ix));
+    tx.sign(payer, ...signers);
+    const res = svm.sendTransaction(tx);
+    svm.expireBlockhash();
+    if (res instanceof FailedTransactionMetadata) throw new Error("tx failed: " + res.err().toString() + "\n" + res.meta().logs().join("\n"));
+    return res;
+  }
+  const retU32 = (res: any) => Buffer.from(res.returnData().data()).readUInt32LE(0);
+
+  function warpClock(unixTs: number) {
+    const c = svm.getClock();
+    svm.setClock(new Clock(c.slot, 0n, 0n, 0n, BigInt(unixTs)));
+  }
+
+  const order = (orderId: number) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("order"), payer.publicKey.toBuffer(), new BN(orderId).toArrayLike(Buffer, "le", 8)], tradingId)[0];
+
+  async function installConfig(): Promise<PublicKey> {
+    const key = Keypair.generate().publicKey;
+    const cfg = {
+      authority: PublicKey.default, authorityName: Array(64).fill(0), nameLen: 0,
+      contactInfo: Array(128).fill(0), contactLen: 0, version: 1, maintenanceMode: false,
+      ercValidationEnabled: true, minEnergyAmount: new BN(0), maxErcAmount: new BN(0),
+      ercValidityPeriod: new BN(0), requireOracleValidation: false, oracleAuthority: PublicKey.default,
+      minOracleConfidence: 0, allowCertificateTransfers: true, minQuorumVotes: new BN(0),
+      totalErcsIssued: new BN(0), totalErcsValidated: new BN(0), totalErcsRevoked: new BN(0),
+      totalEnergyCertified: new BN(0), createdAt: new BN(0), lastUpdated: new BN(0), lastErcIssuedAt: new BN(0),
+      pendingAuthority: PublicKey.default, pendingAuthorityProposedAt: new BN(0), pendingAuthorityExpiresAt: new BN(0),
+      minFeeBps: 0, maxFeeBps: 0, reserved: Array(1).fill(0),
+    };
+    const data = await governance.coder.accounts.encode("governanceConfig", cfg as any);
+    svm.setAccount(key, {
+      lamports: Number(svm.minimumBalanceForRentExemption(BigInt(data.length))),
+      data, owner: governanceId, executable: false, rentEpoch: 0,
+    } as any);
+    return key;
+  }
+
+  const zoneMarket = (): any =>
+    trading.coder.accounts.decode("zoneMarket", Buffer.from(svm.getAccount(zoneMarketPda)!.data));
+  const status = (orderId: number): any =>
+    (trading.coder.accounts.decode("order", Buffer.from(svm.getAccount(order(orderId))!.data)) as any).status;
+  const sellLevels = () => {
+    const zm = zoneMarket();
+    return zm.sellSideDepth.slice(0, zm.sellSideDepthCount).map((l: any) => [l.price.toNumber(), l.totalAmount.toNumber(), l.orderCount]);
+  };
+
+  const sellIx = (orderId: number, amount: number, price: number) =>
+    trading.methods.createSellOrder(new BN(orderId), new BN(amount), new BN(price)).accounts({
+      market: marketPda, zoneMarket: zoneMarketPda, order: order(orderId), ercCertificate: null,
+      authority: payer.publicKey, systemProgram: SystemProgram.programId, governanceConfig: cfgKey,
+    } as any).instruction();
+  const expireIx = (orderIds: number[], authority: PublicKey = payer.publicKey) =>
+    trading.methods.expireOrdersBatch().accounts({
+      market: marketPda, zoneMarket: zoneMarketPda, authority,
+    } as any)
+      .remainingAccounts(orderIds.map((id) => ({ pubkey: order(id), isWritable: true, isSigner: false })))
+      .instruction();
+
+  before(async () => {
+    svm = new LiteSVM().withDefaultPrograms();
+    trading = new Program(tradingIdl, { connection: {}, publicKey: PublicKey.default } as any);
+    governance = new Program(governanceIdl, { connection: {}, publicKey: PublicKey.default } as any);
+    tradingId = trading.programId; governanceId = governance.programId;
+    svm.addProgramFromFile(tradingId, "target/deploy/trading.so");
+    svm.addProgramFromFile(governanceId, "target/deploy/governance.so");
+    svm.airdrop(payer.publicKey, BigInt(1_000_000_000_000));
+    svm.airdrop(outsider.publicKey, BigInt(1_000_000_000));
+
+    [marketPda] = PublicKey.findProgramAddressSync([Buffer.from("market")], tradingId);
+    [zoneMarketPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("zone_market"), marketPda.toBuffer(), new BN(ZONE).toArrayLike(Buffer, "le", 4)], tradingId);
+
+    send([await trading.methods.initializeMarket(16).accounts({ market: marketPda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()]);
+    send([await trading.methods.initializeZoneMarket(ZONE, 16, new BN(1_000_000), 10).accounts({ market: marketPda, zoneMarket: zoneMarketPda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()]);
+    cfgKey = await installConfig();
+
+    warpClock(NOW);
+    send([await sellIx(1, 100, 50)]);
+    send([await sellIx(2, 40, 50)]);
+    warpClock(NOW + ORDER_TTL);
+    send([await sellIx(3, 70, 60)]); // expires a day after orders 1 and 2
+    expect(zoneMarket().activeOrders).to.equal(3);
+  });
+
+  it("rejects a signer other than the market authority (UnauthorizedAuthority)", async () => {
+    const blob = sendExpectFail([await expireIx([1], outsider.publicKey)], [outsider]);
+    expect(blob, blob).to.match(/UnauthorizedAuthority/);
+  });
+
+  it("rejects an empty batch (InvalidExpireBatch)", async () => {
+    const blob = sendExpectFail([await expireIx([])]);
+    expect(blob, blob).to.match(/InvalidExpireBatch/);
+  });
+
+  it("expires the stale orders and skips the one still live", async () => {
+    const res = sendRaw([await expireIx([1, 3, 2])]);
+    expect(retU32(res)).to.equal(2);
+
+    expect(status(1)).to.equal(4); // OrderStatus::Expired
+    expect(status(2)).to.equal(4);
+    expect(status(3)).to.equal(0); // still Active
+    expect(zoneMarket().activeOrders).to.equal(1);
+    expect(sellLevels()).to.deep.equal([[60, 70, 1]]);
+  });
+
+  it("skips already-expired orders on a second sweep", async () => {
+    expect(retU32(sendRaw([await expireIx([1, 2])]))).to.equal(0);
+    expect(zoneMarket().activeOrders).to.equal(1);
+  });
+});
@@ -0,0 +1,213 @@
+// Litesvm coverage for the volume-based fee schedule and per-trader stats: set_fee_tiers
+// validation, match_orders creating and updating both traders' TraderStats, and the
+// taker's fee tier following its recorded volume (flat market_fee_bps below the first tier).
+//
+// GovernanceConfig is fabricated via svm.setAccount, same trick as trading_depth_litesvm.ts.
+
+import { LiteSVM, FailedTransactionMetadata } from "litesvm";
+import { Program, EventParser } from "@anchor-lang/core";
+import { Trading } from "../target/types/trading";
+import { Governance } from "../target/types/governance";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  Transaction,
+  SystemProgram,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import BN from "bn.js";
+import { createRequire } from "module";
+
+const require = createRequire(import.meta.url);
+const tradingIdl = require("../target/idl/trading.json");
+const governanceIdl = require("../target/idl/governance.json");
+
+const ZONE = 0;
+const BASE_FEE_BPS = 25; // initialize_market default
+
+describe("trading volume fee tiers (litesvm)", () => {
+  let svm: LiteSVM;
+  let trading: Program<Trading>;
+  let governance: Program<Governance>;
+  let tradingId: PublicKey;
+  let governanceId: PublicKey;
+
+  const payer = Keypair.generate(); // market authority + seller + fee payer
+  const taker = Keypair.generate(); // buyer
+
+  let marketPda: PublicKey;
+  let zoneMarketPda: PublicKey;
+  let cfgKey: PublicKey;
+
+  function trySend(ixs: TransactionInstruction[], signers: Keypair[] = []): FailedTransactionMetadata | null {
+    const tx = new Transaction();
+    tx.recentBlockhash = svm.latestBlockhash();
+    tx.feePayer = payer.publicKey;
+    ixs.forEach((ix) => tx.add(ix));
+    tx.sign(payer, ...signers);
+    const res = svm.sendTransaction(tx);
+    svm.expireBlockhash();
+    return res instanceof FailedTransactionMetadata ? res : null;
+  }
+  function send(ixs: TransactionInstruction[], signers: Keypair[] = []) {
+    const f = trySend(ixs, signers);
+    if (f) throw new Error("tx failed: " + f.err().toString() + "\n" + f.meta().logs().join("\n"));
+  }
+  function sendExpectFail(ixs: TransactionInstruction[], pattern: RegExp, signers: Keypair[] = []) {
+    const f = trySend(ixs, signers);
+    expect(f, "transaction should fail").to.not.be.null;
+    expect(f!.meta().logs().join("\n")).to.match(pattern);
+  }
+  function sendRaw(ixs: TransactionInstruction[], signers: Keypair[] = []) {
+    const tx = new Transaction();
+    tx.recentBlockhash = svm.latestBlockhash();
+    tx.feePayer = payer.publicKey;
+    ixs.forEach((ix) => tx.add(ix));
+    tx.sign(payer, ...signers);
+    const res = svm.sendTransaction(tx);
+    svm.expireBlockhash();
+    if (res instanceof FailedTransactionMetadata) throw new Error("tx failed: " + res.err().toString());
+    return res;
+  }
+
+  const orderPda = (auth: PublicKey, orderId: number) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("order"), auth.toBuffer(), new BN(orderId).toArrayLike(Buffer, "le", 8)], tradingId)[0];
+  const statsPda = (trader: PublicKey) =>
+    PublicKey.findProgramAddressSync([Buffer.from("trader"), trader.toBuffer()], tradingId)[0];
+  const tradePda = () => {
+    const m: any = trading.coder.accounts.decode("market", Buffer.from(svm.getAccount(marketPda)!.data));
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("trade"), marketPda.toBuffer(), m.tradeSeq.toArrayLike(Buffer, "le", 8)], tradingId)[0];
+  };
+  const stats = (trader: PublicKey): any =>
+    trading.coder.accounts.decode("traderStats", Buffer.from(svm.getAccount(statsPda(trader))!.data));
+
+  async function installConfig(): Promise<PublicKey> {
+    const key = Keypair.generate().publicKey;
+    const cfg = {
+      authority: PublicKey.default, authorityName: Array(64).fill(0), nameLen: 0,
+      contactInfo: Array(128).fill(0), contactLen: 0, version: 1, maintenanceMode: false,
+      ercValidationEnabled: true, minEnergyAmount: new BN(0), maxErcAmount: new BN(0),
+      ercValidityPeriod: new BN(0), requireOracleValidation: false, oracleAuthority: PublicKey.default,
+      minOracleConfidence: 0, allowCertificateTransfers: true, minQuorumVotes: new BN(0),
+      totalErcsIssued: new BN(0), totalErcsValidated: new BN(0), totalErcsRevoked: new BN(0),
+      totalEnergyCertified: new BN(0), createdAt: new BN(0), lastUpdated: new BN(0), lastErcIssuedAt: new BN(0),
+      pendingAuthority: PublicKey.default, pendingAuthorityProposedAt: new BN(0), pendingAuthorityExpiresAt: new BN(0),
+      minFeeBps: 0, maxFeeBps: 0, reserved: Array(1).fill(0),
+    };
+    const data = await governance.coder.accounts.encode("governanceConfig", cfg as any);
+    svm.setAccount(key, {
+      lamports: Number(svm.minimumBalanceForRentExemption(BigInt(data.length))),
+      data, owner: governanceId, executable: false, rentEpoch: 0,
+    } as any);
+    return key;
+  }
+
+  const tier = (volumeThreshold: number, feeBps: number) =>
+    ({ volumeThreshold: new BN(volumeThreshold), feeBps, padding: Array(6).fill(0) });
+  const NO_TIER = tier(0, 0);
+
+  const setTiersIx = (tiers: any[], authority: PublicKey = payer.publicKey) =>
+    trading.methods.setFeeTiers(tiers as any).accounts({
+      market: marketPda, authority, governanceConfig: cfgKey,
+    } as any).instruction();
+  const sellIx = (orderId: number, amount: number, price: number) =>
+    trading.methods.createSellOrder(new BN(orderId), new BN(amount), new BN(price)).accounts({
+      market: marketPda, zoneMarket: zoneMarketPda, order: orderPda(payer.publicKey, orderId), ercCertificate: null,
+      authority: payer.publicKey, systemProgram: SystemProgram.programId, governanceConfig: cfgKey,
+    } as any).instruction();
+  const buyIx = (orderId: number, amount: number, maxPrice: number) =>
+    trading.methods.createBuyOrder(new BN(orderId), new BN(amount), new BN(maxPrice)).accounts({
+      market: marketPda, zoneMarket: zoneMarketPda, order: orderPda(taker.publicKey, orderId),
+      authority: taker.publicKey, systemProgram: SystemProgram.programId, governanceConfig: cfgKey,
+    } as any).instruction();
+  const matchIx = (orderId: number, amount: number, withSellerStats = true) =>
+    trading.methods.matchOrders(new BN(amount)).accounts({
+      market: marketPda, zoneMarket: zoneMarketPda,
+      buyOrder: orderPda(taker.publicKey, orderId), sellOrder: orderPda(payer.publicKey, orderId),
+      tradeRecord: tradePda(), authority: payer.publicKey, governanceConfig: cfgKey,
+      systemProgram: SystemProgram.programId,
+      buyerStats: statsPda(taker.publicKey),
+      sellerStats: withSellerStats ? statsPda(payer.publicKey) : null,
+    } as any).instruction();
+
+  // Place a crossing pair under `orderId` (same slot, so the buyer is the taker) and match
+  // it; returns the OrderMatched event.
+  async function matchPair(orderId: number, amount: number) {
+    send([await sellIx(orderId, amount, 50)]);
+    send([await buyIx(orderId, amount, 60)], [taker]);
+    const logs = sendRaw([await matchIx(orderId, amount)]).logs();
+    const parser = new EventParser(tradingId, (trading as any).coder);
+    const ev = [...parser.parseLogs(logs)].find((e) => e.name === "OrderMatched" || e.name === "orderMatched");
+    expect(ev, "OrderMatched emitted").to.not.be.undefined;
+    return ev!.data;
+  }
+
+  before(async () => {
+    svm = new LiteSVM().withDefaultPrograms();
+    trading = new Program(tradingIdl, { connection: {}, publicKey: PublicKey.default } as any);
+    governance = new Program(governanceIdl, { connection: {}, publicKey: PublicKey.default } as any);
+    tradingId = trading.programId; governanceId = governance.programId;
+    svm.addProgramFromFile(tradingId, "target/deploy/trading.so");
+    svm.addProgramFromFile(governanceId, "target/deploy/governance.so");
+    svm.airdrop(payer.publicKey, BigInt(1_000_000_000_000));
+    svm.airdrop(taker.publicKey, BigInt(1_000_000_000_000));
+
+    [marketPda] = PublicKey.findProgramAddressSync([Buffer.from("market")], tradingId);
+    [zoneMarketPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("zone_market"), marketPda.toBuffer(), new BN(ZONE).toArrayLike(Buffer, "le", 4)], tradingId);
+
+    send([await trading.methods.initializeMarket(16).accounts({ market: marketPda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()]);
+    send([await trading.methods.initializeZoneMarket(ZONE, 16, new BN(1_000_000), 10).accounts({ market: marketPda, zoneMarket: zoneMarketPda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()]);
+    cfgKey = await installConfig();
+  });
+
+  it("rejects malformed schedules and non-authority callers", async () => {
+    // Thresholds must strictly increase
+    sendExpectFail([await setTiersIx([tier(5_000, 20), tier(1_000, 10), NO_TIER])], /InvalidFeeTiers/);
+    // A configured tier may not follow an unused one
+    sendExpectFail([await setTiersIx([tier(1_000, 20), NO_TIER, tier(5_000, 10)])], /InvalidFeeTiers/);
+    const outsider = Keypair.generate();
+    svm.airdrop(outsider.publicKey, BigInt(1_000_000_000));
+    expect(trySend([await setTiersIx([tier(1_000, 20), NO_TIER, NO_TIER], outsider.publicKey)], [outsider])).to.not.be.null;
+
+    send([await setTiersIx([tier(1_000, 20), tier(5_000, 10), NO_TIER])]);
+    const m: any = trading.coder.accounts.decode("market", Buffer.from(svm.getAccount(marketPda)!.data));
+    expect(m.feeTiers.map((t: any) => [t.volumeThreshold.toNumber(), t.feeBps]))
+      .to.deep.equal([[1_000, 20], [5_000, 10], [0, 0]]);
+  });
+
+  it("charges the base fee below the first tier and creates both traders' stats", async () => {
+    expect(svm.getAccount(statsPda(taker.publicKey))).to.be.null;
+    const ev = await matchPair(1, 1_000);
+    expect(ev.feeBps).to.equal(BASE_FEE_BPS);
+    expect(ev.feeAmount.toNumber()).to.equal(Math.floor(ev.totalValue.toNumber() * BASE_FEE_BPS / 10_000));
+    for (const trader of [taker.publicKey, payer.publicKey]) {
+      const s = stats(trader);
+      expect(s.authority.toBase58()).to.equal(trader.toBase58());
+      expect(s.totalVolume.toNumber()).to.equal(1_000);
+      expect(s.tradeCount.toNumber()).to.equal(1);
+      expect(s.lastTradeAt.toNumber()).to.equal(Number(svm.getClock().unixTimestamp));
+    }
+  });
+
+  it("moves the taker into the tier its volume has reached", async () => {
+    const ev = await matchPair(2, 4_000);
+    expect(ev.feeBps).to.equal(20);
+    expect(ev.feeAmount.toNumber()).to.equal(Math.floor(ev.totalValue.toNumber() * 20 / 10_000));
+    expect(stats(taker.publicKey).totalVolume.toNumber()).to.equal(5_000);
+
+    const top = await matchPair(3, 100);
+    expect(top.feeBps).to.equal(10);
+    expect(stats(payer.publicKey).tradeCount.toNumber()).to.equal(3);
+  });
+
+  it("requires seller_stats unless the match is a self-trade", async () => {
+    send([await sellIx(4, 100, 50)]);
+    send([await buyIx(4, 100, 60)], [taker]);
+    sendExpectFail([await matchIx(4, 100, false)], /InvalidSellerStats/);
+    send([await matchIx(4, 100)]);
+  });
+});
@@ -0,0 +1,141 @@
+// Litesvm coverage for governance-bounded trading fees. Governance set_fee_bounds stores
+// [min_fee_bps, max_fee_bps] on the poa_config PDA; trading's set_market_fee_via_governance
+// lets that governance authority (not the market authority) set market_fee_bps inside the
+// bounds, and update_market_params holds the market authority to the same range.
+// max_fee_bps == 0 lifts the bounds.
+
+import { LiteSVM, FailedTransactionMetadata } from "litesvm";
+import { Program } from "@anchor-lang/core";
+import { Trading } from "../target/types/trading";
+import { Governance } from "../target/types/governance";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  Transaction,
+  SystemProgram,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import BN from "bn.js";
+import { createRequire } from "module";
+
+const require = createRequire(import.meta.url);
+const tradingIdl = require("../target/idl/trading.json");
+const governanceIdl = require("../target/idl/governance.json");
+
+describe("trading governance fee bounds (litesvm)", () => {
+  let svm: LiteSVM;
+  let trading: Program<Trading>;
+  let governance: Program<Governance>;
+  let tradingId: PublicKey;
+  let governanceId: PublicKey;
+
+  const payer = Keypair.generate();     // market authority + funder
+  const regulator = Keypair.generate(); // governance authority
+
+  let marketPda: PublicKey;
+  let configPda: PublicKey;
+
+  function trySend(ixs: TransactionInstruction[], signers: Keypair[]): FailedTransactionMetadata | null {
+    const tx = new Transaction();
+    tx.recentBlockhash = svm.latestBlockhash();
+    tx.feePayer = payer.publicKey;
+    ixs.forEach((ix) => tx.add(ix));
+    tx.sign(payer, ...signers);
+    const res = svm.sendTransaction(tx);
+    svm.expireBlockhash();
+    return res instanceof FailedTransactionMetadata ? res : null;
+  }
+  function send(ixs: TransactionInstruction[], signers: Keypair[] = []) {
+    const f = trySend(ixs, signers);
+    if (f) throw new Error("tx failed: " + f.err().toString() + "\n" + f.meta().logs().join("\n"));
+  }
+  function sendExpectFail(ixs: TransactionInstruction[], signers: Keypair[] = []): string {
+    const f = trySend(ixs, signers);
+    if (!f) throw new Error("expected tx to fail but it succeeded");
+    return f.err().toString() + "\n" + f.meta().logs().join("\n");
+  }
+
+  const market = (): any =>
+    trading.coder.accounts.decode("market", Buffer.from(svm.getAccount(marketPda)!.data));
+  const config = (): any =>
+    governance.coder.accounts.decode("governanceConfig", Buffer.from(svm.getAccount(configPda)!.data));
+
+  const boundsIx = (auth: PublicKey, min: number, max: number) =>
+    governance.methods.setFeeBounds(min, max).accounts({ governanceConfig: configPda, authority: auth } as any).instruction();
+  const govFeeIx = (auth: PublicKey, fee: number) =>
+    trading.methods.setMarketFeeViaGovernance(fee).accounts({
+      market: marketPda, governanceConfig: configPda, authority: auth,
+    } as any).instruction();
+  const paramsIx = (fee: number) =>
+    trading.methods.updateMarketParams(fee, true, new BN(0), new BN(0), 0, new BN(0), 0).accounts({
+      market: marketPda, authority: payer.publicKey, governanceConfig: configPda,
+    } as any).instruction();
+
+  before(async () => {
+    svm = new LiteSVM().withDefaultPrograms();
+    trading = new Program(tradingIdl, { connection: {}, publicKey: PublicKey.default } as any);
+    governance = new Program(governanceIdl, { connection: {}, publicKey: PublicKey.default } as any);
+    tradingId = trading.programId; governanceId = governance.programId;
+    svm.addProgramFromFile(tradingId, "target/deploy/trading.so");
+    svm.addProgramFromFile(governanceId, "target/deploy/governance.so");
+    svm.airdrop(payer.publicKey, BigInt(1_000_000_000_000));
+    svm.airdrop(regulator.publicKey, BigInt(1_000_000_000));
+
+    [marketPda] = PublicKey.findProgramAddressSync([Buffer.from("market")], tradingId);
+    [configPda] = PublicKey.findProgramAddressSync([Buffer.from("poa_config")], governanceId);
+
+    send([await governance.methods.initializeGovernance().accounts({
+      governanceConfig: configPda, authority: regulator.publicKey, systemProgram: SystemProgram.programId,
+    } as any).instruction()], [regulator]);
+    send([await trading.methods.initializeMarket(16).accounts({ market: marketPda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()]);
+  });
+
+  it("starts with no bounds, so the market authority may set any fee", async () => {
+    expect(config().maxFeeBps).to.equal(0);
+    send([await paramsIx(500)]);
+    expect(market().marketFeeBps).to.equal(500);
+  });
+
+  it("rejects fee bounds from a non-governance signer (UnauthorizedAuthority)", async () => {
+    const blob = sendExpectFail([await boundsIx(payer.publicKey, 10, 50)]);
+    expect(blob, blob).to.match(/UnauthorizedAuthority/);
+  });
+
+  it("rejects inverted fee bounds (InvalidFeeBounds)", async () => {
+    const blob = sendExpectFail([await boundsIx(regulator.publicKey, 60, 50)], [regulator]);
+    expect(blob, blob).to.match(/InvalidFeeBounds/);
+  });
+
+  it("lets the governance authority set the fee inside the bounds", async () => {
+    send([await boundsIx(regulator.publicKey, 10, 50)], [regulator]);
+    expect(config().minFeeBps).to.equal(10);
+    expect(config().maxFeeBps).to.equal(50);
+    send([await govFeeIx(regulator.publicKey, 40)], [regulator]);
+    expect(market().marketFeeBps).to.equal(40);
+  });
+
+  it("rejects the market authority on the governance path (UnauthorizedAuthority)", async () => {
+    const blob = sendExpectFail([await govFeeIx(payer.publicKey, 30)]);
+    expect(blob, blob).to.match(/UnauthorizedAuthority/);
+  });
+
+  it("rejects a governance fee outside the bounds (FeeOutOfGovernanceBounds)", async () => {
+    const blob = sendExpectFail([await govFeeIx(regulator.publicKey, 60)], [regulator]);
+    expect(blob, blob).to.match(/FeeOutOfGovernanceBounds/);
+    expect(market().marketFeeBps).to.equal(40);
+  });
+
+  it("holds update_market_params to the bounds", async () => {
+    const blob = sendExpectFail([await paramsIx(500)]);
+    expect(blob, blob).to.match(/FeeOutOfGovernanceBounds/);
+    send([await paramsIx(25)]);
+    expect(market().marketFeeBps).to.equal(25);
+  });
+
+  it("lifts the bounds again with max_fee_bps = 0", async () => {
+    send([await boundsIx(regulator.publicKey, 0, 0)], [regulator]);
+    send([await paramsIx(500)]);
+    expect(market().marketFeeBps).to.equal(500);
+  });
+});
@@ -0,0 +1,126 @@
+// Litesvm coverage for the market's two-step authority transfer: propose_market_authority
+// parks the new key in Market.pending_authority, only that key can accept, and the old
+// authority loses its admin rights on acceptance. Also covers cancel, a duplicate proposal,
+// proposing the current key and the authority checks.
+
+import { LiteSVM, FailedTransactionMetadata } from "litesvm";
+import { Program, EventParser } from "@anchor-lang/core";
+import { Trading } from "../target/types/trading";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  Transaction,
+  SystemProgram,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import { createRequire } from "module";
+
+const require = createRequire(import.meta.url);
+const tradingIdl = require("../target/idl/trading.json");
+
+describe("trading market authority transfer (litesvm)", () => {
+  let svm: LiteSVM;
+  let trading: Program<Trading>;
+  let tradingId: PublicKey;
+
+  const payer = Keypair.generate();      // initial market authority + fee payer
+  const successor = Keypair.generate();
+  const outsider = Keypair.generate();
+
+  let marketPda: PublicKey;
+
+  function trySend(ixs: TransactionInstruction[], signers: Keypair[] = []) {
+    const tx = new Transaction();
+    tx.recentBlockhash = svm.latestBlockhash();
+    tx.feePayer = payer.publicKey;
+    ixs.forEach((ix) => tx.add(ix));
+    tx.sign(payer, ...signers);
+    const res = svm.sendTransaction(tx);
+    svm.expireBlockhash();
+    return res;
+  }
+  function send(ixs: TransactionInstruction[], signers: Keypair[] = []) {
+    const res = trySend(ixs, signers);
+    if (res instanceof FailedTransactionMetadata) {
+      throw new Error("tx failed: " + res.err().toString() + "\n" + res.meta().logs().join("\n"));
+    }
+    return res.logs();
+  }
+  function sendExpectFail(ixs: TransactionInstruction[], pattern: RegExp, signers: Keypair[] = []) {
+    const res = trySend(ixs, signers);
+    expect(res instanceof FailedTransactionMetadata, "transaction should fail").to.be.true;
+    expect((res as FailedTransactionMetadata).meta().logs().join("\n")).to.match(pattern);
+  }
+
+  const market = (): any =>
+    trading.coder.accounts.decode("market", Buffer.from(svm.getAccount(marketPda)!.data));
+  const event = (logs: string[], name: string) => {
+    const parser = new EventParser(tradingId, (trading as any).coder);
+    const camel = name[0].toLowerCase() + name.slice(1);
+    return [...parser.parseLogs(logs)].find((e) => e.name === name || e.name === camel);
+  };
+
+  const proposeIx = (newAuthority: PublicKey, authority: PublicKey = payer.publicKey) =>
+    trading.methods.proposeMarketAuthority(newAuthority).accounts({ market: marketPda, authority } as any).instruction();
+  const acceptIx = (signer: PublicKey) =>
+    trading.methods.acceptMarketAuthority().accounts({ market: marketPda, newAuthority: signer } as any).instruction();
+  const cancelIx = (authority: PublicKey = payer.publicKey) =>
+    trading.methods.cancelMarketAuthorityProposal().accounts({ market: marketPda, authority } as any).instruction();
+  const freezeIx = (frozen: boolean, authority: PublicKey) =>
+    trading.methods.setMarketFrozen(frozen).accounts({ market: marketPda, authority } as any).instruction();
+
+  before(async () => {
+    svm = new LiteSVM().withDefaultPrograms();
+    trading = new Program(tradingIdl, { connection: {}, publicKey: PublicKey.default } as any);
+    tradingId = trading.programId;
+    svm.addProgramFromFile(tradingId, "target/deploy/trading.so");
+    for (const kp of [payer, successor, outsider]) svm.airdrop(kp.publicKey, BigInt(1_000_000_000_000));
+
+    [marketPda] = PublicKey.findProgramAddressSync([Buffer.from("market")], tradingId);
+    send([await trading.methods.initializeMarket(16).accounts({ market: marketPda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()]);
+  });
+
+  it("only the authority proposes, and not itself", async () => {
+    sendExpectFail([await proposeIx(successor.publicKey, outsider.publicKey)], /ConstraintHasOne|has[_ ]one/i, [outsider]);
+    sendExpectFail([await proposeIx(payer.publicKey)], /InvalidMarketAuthority/);
+    sendExpectFail([await proposeIx(PublicKey.default)], /InvalidMarketAuthority/);
+
+    const logs = send([await proposeIx(successor.publicKey)]);
+    expect(market().pendingAuthority.toBase58()).to.equal(successor.publicKey.toBase58());
+    expect(event(logs, "MarketAuthorityProposed")!.data.pendingAuthority.toBase58()).to.equal(successor.publicKey.toBase58());
+    sendExpectFail([await proposeIx(outsider.publicKey)], /MarketAuthorityChangePending/);
+  });
+
+  it("nothing changes until the pending key accepts", async () => {
+    sendExpectFail([await acceptIx(outsider.publicKey)], /InvalidPendingAuthority/, [outsider]);
+    expect(market().authority.toBase58()).to.equal(payer.publicKey.toBase58());
+    send([await freezeIx(false, payer.publicKey)]); // the old authority is still in charge
+  });
+
+  it("accept hands the market over", async () => {
+    const logs = send([await acceptIx(successor.publicKey)], [successor]);
+    const m = market();
+    expect(m.authority.toBase58()).to.equal(successor.publicKey.toBase58());
+    expect(m.pendingAuthority.toBase58()).to.equal(PublicKey.default.toBase58());
+
+    const ev = event(logs, "MarketAuthorityTransferred")!;
+    expect(ev.data.oldAuthority.toBase58()).to.equal(payer.publicKey.toBase58());
+    expect(ev.data.newAuthority.toBase58()).to.equal(successor.publicKey.toBase58());
+
+    expect(trySend([await freezeIx(true, payer.publicKey)]) instanceof FailedTransactionMetadata).to.be.true;
+    send([await freezeIx(false, successor.publicKey)], [successor]);
+    sendExpectFail([await acceptIx(successor.publicKey)], /NoMarketAuthorityChangePending/, [successor]);
+  });
+
+  it("cancel withdraws a proposal (authority only)", async () => {
+    sendExpectFail([await cancelIx(successor.publicKey)], /NoMarketAuthorityChangePending/, [successor]);
+    send([await proposeIx(outsider.publicKey, successor.publicKey)], [successor]);
+    sendExpectFail([await cancelIx(outsider.publicKey)], /ConstraintHasOne|has[_ ]one/i, [outsider]);
+
+    const logs = send([await cancelIx(successor.publicKey)], [successor]);
+    expect(event(logs, "MarketAuthorityProposalCancelled")!.data.cancelledAuthority.toBase58()).to.equal(outsider.publicKey.toBase58());
+    expect(market().pendingAuthority.toBase58()).to.equal(PublicKey.default.toBase58());
+    sendExpectFail([await acceptIx(outsider.publicKey)], /NoMarketAuthorityChangePending/, [outsider]);
+  });
+});
@@ -0,0 +1,209 @@
+// Litesvm coverage for the per-trader open-order cap: set_max_open_orders_per_user, order
+// creation rejected once a trader's TraderStats holds the cap, and slots handed back by
+// cancel_order, a filling match_orders, and release_open_order after an expiry sweep.
+//
+// GovernanceConfig is fabricated via svm.setAccount, same trick as trading_depth_litesvm.ts.
+
+import { LiteSVM, Clock, FailedTransactionMetadata } from "litesvm";
+import { Program } from "@anchor-lang/core";
+import { Trading } from "../target/types/trading";
+import { Governance } from "../target/types/governance";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  Transaction,
+  SystemProgram,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import BN from "bn.js";
+import { createRequire } from "module";
+
+const require = createRequire(import.meta.url);
+const tradingIdl = require("../target/idl/trading.json");
+const governanceIdl = require("../target/idl/governance.json");
+
+const ZONE = 0;
+const CAP = 2;
+const DEFAULT_ORDER_TTL_SECONDS = 86_400;
+
+describe("trading open-order cap (litesvm)", () => {
+  let svm: LiteSVM;
+  let trading: Program<Trading>;
+  let governance: Program<Governance>;
+  let tradingId: PublicKey;
+  let governanceId: PublicKey;
+
+  const payer = Keypair.generate(); // market authority + seller + fee payer
+  const taker = Keypair.generate(); // buyer
+
+  let marketPda: PublicKey;
+  let zoneMarketPda: PublicKey;
+  let cfgKey: PublicKey;
+
+  function trySend(ixs: TransactionInstruction[], signers: Keypair[] = []): FailedTransactionMetadata | null {
+    const tx = new Transaction();
+    tx.recentBlockhash = svm.latestBlockhash();
+    tx.feePayer = payer.publicKey;
+    ixs.forEach((ix) => tx.add(ix));
+    tx.sign(payer, ...signers);
+    const res = svm.sendTransaction(tx);
+    svm.expireBlockhash();
+    return res instanceof FailedTransactionMetadata ? res : null;
+  }
+  function send(ixs: TransactionInstruction[], signers: Keypair[] = []) {
+    const f = trySend(ixs, signers);
+    if (f) throw new Error("tx failed: " + f.err().toString() + "\n" + f.meta().logs().join("\n"));
+  }
+  function sendExpectFail(ixs: TransactionInstruction[], pattern: RegExp, signers: Keypair[] = []) {
+    const f = trySend(ixs, signers);
+    expect(f, "transaction should fail").to.not.be.null;
+    expect(f!.meta().logs().join("\n")).to.match(pattern);
+  }
+
+  const orderPda = (auth: PublicKey, orderId: number) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("order"), auth.toBuffer(), new BN(orderId).toArrayLike(Buffer, "le", 8)], tradingId)[0];
+  const statsPda = (trader: PublicKey) =>
+    PublicKey.findProgramAddressSync([Buffer.from("trader"), trader.toBuffer()], tradingId)[0];
+  const tradePda = () => {
+    const m: any = trading.coder.accounts.decode("market", Buffer.from(svm.getAccount(marketPda)!.data));
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("trade"), marketPda.toBuffer(), m.tradeSeq.toArrayLike(Buffer, "le", 8)], tradingId)[0];
+  };
+  const openOrders = (trader: PublicKey): number =>
+    (trading.coder.accounts.decode("traderStats", Buffer.from(svm.getAccount(statsPda(trader))!.data)) as any).openOrders;
+  const order = (key: PublicKey): any =>
+    trading.coder.accounts.decode("order", Buffer.from(svm.getAccount(key)!.data));
+
+  async function installConfig(): Promise<PublicKey> {
+    const key = Keypair.generate().publicKey;
+    const cfg = {
+      authority: PublicKey.default, authorityName: Array(64).fill(0), nameLen: 0,
+      contactInfo: Array(128).fill(0), contactLen: 0, version: 1, maintenanceMode: false,
+      ercValidationEnabled: true, minEnergyAmount: new BN(0), maxErcAmount: new BN(0),
+      ercValidityPeriod: new BN(0), requireOracleValidation: false, oracleAuthority: PublicKey.default,
+      minOracleConfidence: 0, allowCertificateTransfers: true, minQuorumVotes: new BN(0),
+      totalErcsIssued: new BN(0), totalErcsValidated: new BN(0), totalErcsRevoked: new BN(0),
+      totalEnergyCertified: new BN(0), createdAt: new BN(0), lastUpdated: new BN(0), lastErcIssuedAt: new BN(0),
+      pendingAuthority: PublicKey.default, pendingAuthorityProposedAt: new BN(0), pendingAuthorityExpiresAt: new BN(0),
+      minFeeBps: 0, maxFeeBps: 0, reserved: Array(1).fill(0),
+    };
+    const data = await governance.coder.accounts.encode("governanceConfig", cfg as any);
+    svm.setAccount(key, {
+      lamports: Number(svm.minimumBalanceForRentExemption(BigInt(data.length))),
+      data, owner: governanceId, executable: false, rentEpoch: 0,
+    } as any);
+    return key;
+  }
+
+  const capIx = (max: number, authority: PublicKey = payer.publicKey) =>
+    trading.methods.setMaxOpenOrdersPerUser(max).accounts({ market: marketPda, authority } as any).instruction();
+  const sellIx = (orderId: number, amount = 100, price = 50) =>
+    trading.methods.createSellOrder(new BN(orderId), new BN(amount), new BN(price)).accounts({
+      market: marketPda, zoneMarket: zoneMarketPda, order: orderPda(payer.publicKey, orderId), ercCertificate: null,
+      authority: payer.publicKey, systemProgram: SystemProgram.programId, governanceConfig: cfgKey,
+      traderStats: statsPda(payer.publicKey),
+    } as any).instruction();
+  const buyIx = (orderId: number, amount = 100, maxPrice = 60) =>
+    trading.methods.createBuyOrder(new BN(orderId), new BN(amount), new BN(maxPrice)).accounts({
+      market: marketPda, zoneMarket: zoneMarketPda, order: orderPda(taker.publicKey, orderId),
+      authority: taker.publicKey, systemProgram: SystemProgram.programId, governanceConfig: cfgKey,
+      traderStats: statsPda(taker.publicKey),
+    } as any).instruction();
+  const cancelIx = (orderId: number, withStats = true) =>
+    trading.methods.cancelOrder().accounts({
+      market: marketPda, zoneMarket: zoneMarketPda, order: orderPda(payer.publicKey, orderId),
+      authority: payer.publicKey, governanceConfig: cfgKey,
+      traderStats: withStats ? statsPda(payer.publicKey) : null,
+    } as any).instruction();
+  const matchIx = (buyId: number, sellId: number, amount: number) =>
+    trading.methods.matchOrders(new BN(amount)).accounts({
+      market: marketPda, zoneMarket: zoneMarketPda,
+      buyOrder: orderPda(taker.publicKey, buyId), sellOrder: orderPda(payer.publicKey, sellId),
+      tradeRecord: tradePda(), authority: payer.publicKey, governanceConfig: cfgKey,
+      systemProgram: SystemProgram.programId,
+      buyerStats: statsPda(taker.publicKey), sellerStats: statsPda(payer.publicKey),
+    } as any).instruction();
+  const releaseIx = (key: PublicKey, owner: PublicKey) =>
+    trading.methods.releaseOpenOrder().accounts({ order: key, traderStats: statsPda(owner) } as any).instruction();
+
+  before(async () => {
+    svm = new LiteSVM().withDefaultPrograms();
+    trading = new Program(tradingIdl, { connection: {}, publicKey: PublicKey.default } as any);
+    governance = new Program(governanceIdl, { connection: {}, publicKey: PublicKey.default } as any);
+    tradingId = trading.programId; governanceId = governance.programId;
+    svm.addProgramFromFile(tradingId, "target/deploy/trading.so");
+    svm.addProgramFromFile(governanceId, "target/deploy/governance.so");
+    svm.airdrop(payer.publicKey, BigInt(1_000_000_000_000));
+    svm.airdrop(taker.publicKey, BigInt(1_000_000_000_000));
+
+    [marketPda] = PublicKey.findProgramAddressSync([Buffer.from("market")], tradingId);
+    [zoneMarketPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("zone_market"), marketPda.toBuffer(), new BN(ZONE).toArrayLike(Buffer, "le", 4)], tradingId);
+
+    send([await trading.methods.initializeMarket(16).accounts({ market: marketPda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()]);
+    send([await trading.methods.initializeZoneMarket(ZONE, 16, new BN(1_000_000), 10).accounts({ market: marketPda, zoneMarket: zoneMarketPda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()]);
+    cfgKey = await installConfig();
+  });
+
+  it("only the market authority sets the cap", async () => {
+    const outsider = Keypair.generate();
+    svm.airdrop(outsider.publicKey, BigInt(1_000_000_000));
+    expect(trySend([await capIx(CAP, outsider.publicKey)], [outsider])).to.not.be.null;
+
+    send([await capIx(CAP)]);
+    const m: any = trading.coder.accounts.decode("market", Buffer.from(svm.getAccount(marketPda)!.data));
+    expect(m.maxOpenOrdersPerUser).to.equal(CAP);
+  });
+
+  it("rejects an order past the cap", async () => {
+    send([await sellIx(1)]);
+    send([await sellIx(2)]);
+    expect(openOrders(payer.publicKey)).to.equal(CAP);
+    expect(order(orderPda(payer.publicKey, 1)).openCounted).to.equal(1);
+    sendExpectFail([await sellIx(3)], /TooManyOpenOrders/);
+  });
+
+  it("cancel_order frees a slot, and needs the stats to do it", async () => {
+    sendExpectFail([await cancelIx(2, false)], /MissingTraderStats/);
+    send([await cancelIx(2)]);
+    expect(openOrders(payer.publicKey)).to.equal(1);
+    expect(order(orderPda(payer.publicKey, 2)).openCounted).to.equal(0);
+    send([await sellIx(3)]);
+    expect(openOrders(payer.publicKey)).to.equal(CAP);
+  });
+
+  it("a full fill releases both sides, a partial fill does not", async () => {
+    send([await buyIx(1, 40)], [taker]);
+    send([await matchIx(1, 1, 40)]);
+    expect(openOrders(taker.publicKey)).to.equal(0);
+    expect(openOrders(payer.publicKey)).to.equal(CAP); // sell order 1 still has 60 resting
+
+    send([await buyIx(2, 60)], [taker]);
+    send([await matchIx(2, 1, 60)]);
+    expect(openOrders(payer.publicKey)).to.equal(1);
+    expect(order(orderPda(payer.publicKey, 1)).openCounted).to.equal(0);
+  });
+
+  it("release_open_order returns the slot of an expired order", async () => {
+    const key = orderPda(payer.publicKey, 3);
+    sendExpectFail([await releaseIx(key, payer.publicKey)], /OrderStillOpen/);
+
+    const c = svm.getClock();
+    svm.setClock(new Clock(c.slot, 0n, 0n, 0n, c.unixTimestamp + BigInt(DEFAULT_ORDER_TTL_SECONDS + 1)));
+    const expireIx = await trading.methods.expireOrdersBatch().accounts({
+      market: marketPda, zoneMarket: zoneMarketPda, authority: payer.publicKey,
+    } as any).remainingAccounts([{ pubkey: key, isSigner: false, isWritable: true }]).instruction();
+    send([expireIx]);
+    expect(openOrders(payer.publicKey)).to.equal(1); // expiry does not touch TraderStats
+
+    send([await releaseIx(key, payer.publicKey)]); // permissionless: no owner signature
+    expect(openOrders(payer.publicKey)).to.equal(0);
+    expect(order(key).openCounted).to.equal(0);
+
+    // A second release is a no-op rather than a double decrement
+    send([await releaseIx(key, payer.publicKey)]);
+    expect(openOrders(payer.publicKey)).to.equal(0);
+  });
+});
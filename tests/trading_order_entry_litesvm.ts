@@ -91,7 +91,7 @@ describe("trading CDA order-entry + market-admin (litesvm)", () => {
       totalErcsIssued: new BN(0), totalErcsValidated: new BN(0), totalErcsRevoked: new BN(0),
       totalEnergyCertified: new BN(0), createdAt: new BN(0), lastUpdated: new BN(0), lastErcIssuedAt: new BN(0),
       pendingAuthority: PublicKey.default, pendingAuthorityProposedAt: new BN(0), pendingAuthorityExpiresAt: new BN(0),
-      reserved: Array(5).fill(0),
+      minFeeBps: 0, maxFeeBps: 0, reserved: Array(1).fill(0),
     };
     const data = await governance.coder.accounts.encode("governanceConfig", cfg as any);
     svm.setAccount(key, {
@@ -117,8 +117,8 @@ describe("trading CDA order-entry + market-admin (litesvm)", () => {
       market: marketPda, zoneMarket: zoneMarketPda, authority: payer.publicKey, governanceConfig: cfg,
     } as any).instruction();
 
-  const paramsIx = (auth: PublicKey, fee: number, clearing: boolean, minP: number, maxP: number, cfg: PublicKey) =>
-    trading.methods.updateMarketParams(fee, clearing, new BN(minP), new BN(maxP)).accounts({
+  const paramsIx = (auth: PublicKey, fee: number, clearing: boolean, minP: number, maxP: number, cfg: PublicKey, maxDevBps = 0, ttl = 0, minImprovementBps = 0) =>
+    trading.methods.updateMarketParams(fee, clearing, new BN(minP), new BN(maxP), maxDevBps, new BN(ttl), minImprovementBps).accounts({
       market: marketPda, authority: auth, governanceConfig: cfg,
     } as any).instruction();
 
@@ -130,6 +130,9 @@ describe("trading CDA order-entry + market-admin (litesvm)", () => {
       market: marketPda, authority: auth, governanceConfig: cfg,
     } as any).instruction();
 
+  const vwapWindowIx = (auth: PublicKey, window: number) =>
+    trading.methods.setVwapWindow(window).accounts({ market: marketPda, authority: auth } as any).instruction();
+
   before(async () => {
     svm = new LiteSVM().withDefaultPrograms();
     trading = new Program(tradingIdl, { connection: {}, publicKey: PublicKey.default } as any);
@@ -145,7 +148,7 @@ describe("trading CDA order-entry + market-admin (litesvm)", () => {
       [Buffer.from("zone_market"), marketPda.toBuffer(), new BN(ZONE).toArrayLike(Buffer, "le", 4)], tradingId);
 
     send([await trading.methods.initializeMarket(16).accounts({ market: marketPda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()]);
-    send([await trading.methods.initializeZoneMarket(ZONE, 16, new BN(1_000_000)).accounts({ market: marketPda, zoneMarket: zoneMarketPda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()]);
+    send([await trading.methods.initializeZoneMarket(ZONE, 16, new BN(1_000_000), 10).accounts({ market: marketPda, zoneMarket: zoneMarketPda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()]);
   });
 
   // --- submit_limit_order ---
@@ -225,9 +228,10 @@ describe("trading CDA order-entry + market-admin (litesvm)", () => {
 
   it("updates fee + min/max price bounds (control)", async () => {
     const cfg = await installConfig(false);
-    send([await paramsIx(payer.publicKey, 50, true, 10, 100, cfg)]);
+    send([await paramsIx(payer.publicKey, 50, true, 10, 100, cfg, 500)]);
     const m = market();
     expect(m.marketFeeBps).to.equal(50);
+    expect(m.maxPriceDeviationBps).to.equal(500);
     expect(m.minPricePerKwh.toNumber()).to.equal(10);
     expect(m.maxPricePerKwh.toNumber()).to.equal(100);
   });
@@ -244,6 +248,27 @@ describe("trading CDA order-entry + market-admin (litesvm)", () => {
     expect(blob, blob).to.match(/PriceAboveMaximum/);
   });
 
+  it("rejects an order TTL outside the 60s..7d bounds (InvalidOrderTtl)", async () => {
+    const cfg = await installConfig(false);
+    let blob = sendExpectFail([await paramsIx(payer.publicKey, 50, true, 10, 100, cfg, 500, 30)]);
+    expect(blob, blob).to.match(/InvalidOrderTtl/);
+    blob = sendExpectFail([await paramsIx(payer.publicKey, 50, true, 10, 100, cfg, 500, 8 * 86_400)]);
+    expect(blob, blob).to.match(/InvalidOrderTtl/);
+  });
+
+  it("applies a custom order TTL to new limit orders (control)", async () => {
+    const cfg = await installConfig(false);
+    expect(market().orderTtlSeconds.toNumber()).to.equal(86_400);
+    send([await paramsIx(payer.publicKey, 50, true, 10, 100, cfg, 500, 3_600)]);
+    expect(market().orderTtlSeconds.toNumber()).to.equal(3_600);
+    send([await limitIx(payer.publicKey, 7, BUY, 100, 50, cfg)]);
+    const order: any = trading.coder.accounts.decode("order", Buffer.from(svm.getAccount(orderPda(payer.publicKey, 7))!.data));
+    expect(order.expiresAt.toNumber() - order.createdAt.toNumber()).to.equal(3_600);
+    // ttl = 0 leaves the configured value untouched
+    send([await paramsIx(payer.publicKey, 50, true, 10, 100, cfg, 500)]);
+    expect(market().orderTtlSeconds.toNumber()).to.equal(3_600);
+  });
+
   it("rejects update_market_params in maintenance mode (MaintenanceMode)", async () => {
     const cfg = await installConfig(true);
     const blob = sendExpectFail([await paramsIx(payer.publicKey, 50, true, 10, 100, cfg)]);
@@ -292,4 +317,46 @@ describe("trading CDA order-entry + market-admin (litesvm)", () => {
     expect(m.lastClearingPrice.toNumber()).to.equal(70);
     expect(m.volumeWeightedPrice.toNumber()).to.equal(60);
   });
+
+  it("get_price_history returns a clamped window, oldest first", async () => {
+    async function history(start: number, count: number): Promise<number[]> {
+      const tx = new Transaction();
+      tx.recentBlockhash = svm.latestBlockhash();
+      tx.feePayer = payer.publicKey;
+      tx.add(await trading.methods.getPriceHistory(start, count).accounts({ market: marketPda } as any).instruction());
+      tx.sign(payer);
+      const res = svm.sendTransaction(tx);
+      svm.expireBlockhash();
+      if (res instanceof FailedTransactionMetadata) throw new Error("tx failed: " + res.meta().logs().join("\n"));
+      // Vec<PricePoint>: u32 length, then (price u64, volume u64, timestamp i64) per point
+      const data = Buffer.from(res.returnData().data());
+      const len = data.length ? data.readUInt32LE(0) : 0;
+      return Array.from({ length: len }, (_, i) => Number(data.readBigUInt64LE(4 + i * 24)));
+    }
+
+    // history so far: 50, 70
+    expect(await history(0, 100)).to.deep.equal([50, 70]);
+    expect(await history(1, 5)).to.deep.equal([70]);
+    expect(await history(0, 1)).to.deep.equal([50]);
+    expect(await history(2, 1)).to.deep.equal([]);
+  });
+
+  it("computes VWAP over the newest vwap_window points only", async () => {
+    const cfg = await installConfig(false);
+    let blob = sendExpectFail([await vwapWindowIx(attacker.publicKey, 1)], [attacker]);
+    expect(blob, blob).to.match(/has_one|2001|UnauthorizedAuthority|ConstraintHasOne/);
+    blob = sendExpectFail([await vwapWindowIx(payer.publicKey, 25)]);
+    expect(blob, blob).to.match(/InvalidVwapWindow/);
+
+    send([await vwapWindowIx(payer.publicKey, 2)]);
+    expect(market().vwapWindow).to.equal(2);
+    // history is 50, 70, 90 (100 each); the window drops the 50: (70 + 90) / 2 = 80
+    send([await priceHistIx(payer.publicKey, 90, 100, cfg)]);
+    expect(market().volumeWeightedPrice.toNumber()).to.equal(80);
+
+    // 0 restores the full history: (50 + 70 + 90 + 110) / 4 = 80
+    send([await vwapWindowIx(payer.publicKey, 0)]);
+    send([await priceHistIx(payer.publicKey, 110, 100, cfg)]);
+    expect(market().volumeWeightedPrice.toNumber()).to.equal(80);
+  });
 });
@@ -0,0 +1,175 @@
+// Litesvm coverage for the create_sell_order registry standing gate: when the seller passes
+// their registry UserAccount, it must belong to the signing authority and be Active.
+// Omitting the account keeps the legacy path (no registry check).
+//
+// Real registry flow (initialize -> shard -> register_user -> update_user_status) so the
+// UserAccount is a genuine registry-owned zero-copy PDA. GovernanceConfig is fabricated via
+// svm.setAccount, same trick as order_guards_litesvm.ts.
+
+import { LiteSVM, FailedTransactionMetadata } from "litesvm";
+import { Program } from "@anchor-lang/core";
+import { Trading } from "../target/types/trading";
+import { Governance } from "../target/types/governance";
+import { Registry } from "../target/types/registry";
+import { expect } from "chai";
+import {
+  PublicKey,
+  Keypair,
+  Transaction,
+  SystemProgram,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import BN from "bn.js";
+import { createRequire } from "module";
+
+const require = createRequire(import.meta.url);
+const tradingIdl = require("../target/idl/trading.json");
+const governanceIdl = require("../target/idl/governance.json");
+const registryIdl = require("../target/idl/registry.json");
+
+const ZONE = 0;
+
+describe("trading create_sell_order seller status (litesvm)", () => {
+  let svm: LiteSVM;
+  let trading: Program<Trading>;
+  let governance: Program<Governance>;
+  let reg: Program<Registry>;
+  let tradingId: PublicKey;
+  let governanceId: PublicKey;
+  let regId: PublicKey;
+
+  const payer = Keypair.generate(); // registry + market authority + fee payer
+  const seller = Keypair.generate();
+  const other = Keypair.generate(); // second registered user
+
+  let marketPda: PublicKey;
+  let zoneMarketPda: PublicKey;
+  let registryPda: PublicKey;
+  let cfgKey: PublicKey;
+
+  function trySend(ixs: TransactionInstruction[], signers: Keypair[] = []): FailedTransactionMetadata | null {
+    const tx = new Transaction();
+    tx.recentBlockhash = svm.latestBlockhash();
+    tx.feePayer = payer.publicKey;
+    ixs.forEach((ix) => tx.add(ix));
+    tx.sign(payer, ...signers);
+    const res = svm.sendTransaction(tx);
+    svm.expireBlockhash();
+    return res instanceof FailedTransactionMetadata ? res : null;
+  }
+  function send(ixs: TransactionInstruction[], signers: Keypair[] = []) {
+    const f = trySend(ixs, signers);
+    if (f) throw new Error("tx failed: " + f.err().toString() + "\n" + f.meta().logs().join("\n"));
+  }
+  function sendExpectFail(ixs: TransactionInstruction[], signers: Keypair[] = []): string {
+    const f = trySend(ixs, signers);
+    if (!f) throw new Error("expected tx to fail but it succeeded");
+    return f.err().toString() + "\n" + f.meta().logs().join("\n");
+  }
+
+  const userPda = (auth: PublicKey) =>
+    PublicKey.findProgramAddressSync([Buffer.from("user"), auth.toBuffer()], regId)[0];
+  const orderPda = (auth: PublicKey, orderId: number) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("order"), auth.toBuffer(), new BN(orderId).toArrayLike(Buffer, "le", 8)], tradingId)[0];
+
+  async function installConfig(): Promise<PublicKey> {
+    const key = Keypair.generate().publicKey;
+    const cfg = {
+      authority: PublicKey.default, authorityName: Array(64).fill(0), nameLen: 0,
+      contactInfo: Array(128).fill(0), contactLen: 0, version: 1, maintenanceMode: false,
+      ercValidationEnabled: true, minEnergyAmount: new BN(0), maxErcAmount: new BN(0),
+      ercValidityPeriod: new BN(0), requireOracleValidation: false, oracleAuthority: PublicKey.default,
+      minOracleConfidence: 0, allowCertificateTransfers: true, minQuorumVotes: new BN(0),
+      totalErcsIssued: new BN(0), totalErcsValidated: new BN(0), totalErcsRevoked: new BN(0),
+      totalEnergyCertified: new BN(0), createdAt: new BN(0), lastUpdated: new BN(0), lastErcIssuedAt: new BN(0),
+      pendingAuthority: PublicKey.default, pendingAuthorityProposedAt: new BN(0), pendingAuthorityExpiresAt: new BN(0),
+      minFeeBps: 0, maxFeeBps: 0, reserved: Array(1).fill(0),
+    };
+    const data = await governance.coder.accounts.encode("governanceConfig", cfg as any);
+    svm.setAccount(key, {
+      lamports: Number(svm.minimumBalanceForRentExemption(BigInt(data.length))),
+      data, owner: governanceId, executable: false, rentEpoch: 0,
+    } as any);
+    return key;
+  }
+
+  const sellIx = (orderId: number, userAccount: PublicKey | null) =>
+    trading.methods.createSellOrder(new BN(orderId), new BN(100), new BN(50)).accounts({
+      market: marketPda,
+      zoneMarket: zoneMarketPda,
+      order: orderPda(seller.publicKey, orderId),
+      ercCertificate: null,
+      authority: seller.publicKey,
+      systemProgram: SystemProgram.programId,
+      governanceConfig: cfgKey,
+      userAccount,
+    } as any).instruction();
+
+  const statusIx = (status: any) =>
+    reg.methods.updateUserStatus(status).accounts({
+      registry: registryPda, userAccount: userPda(seller.publicKey), authority: payer.publicKey,
+    } as any).instruction();
+
+  before(async () => {
+    svm = new LiteSVM().withDefaultPrograms();
+    trading = new Program(tradingIdl, { connection: {}, publicKey: PublicKey.default } as any);
+    governance = new Program(governanceIdl, { connection: {}, publicKey: PublicKey.default } as any);
+    reg = new Program(registryIdl, { connection: {}, publicKey: PublicKey.default } as any);
+    tradingId = trading.programId; governanceId = governance.programId; regId = reg.programId;
+    svm.addProgramFromFile(tradingId, "target/deploy/trading.so");
+    svm.addProgramFromFile(governanceId, "target/deploy/governance.so");
+    svm.addProgramFromFile(regId, "target/deploy/registry.so");
+    svm.airdrop(payer.publicKey, BigInt(1_000_000_000_000));
+    svm.airdrop(seller.publicKey, BigInt(1_000_000_000));
+
+    [marketPda] = PublicKey.findProgramAddressSync([Buffer.from("market")], tradingId);
+    [zoneMarketPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("zone_market"), marketPda.toBuffer(), new BN(ZONE).toArrayLike(Buffer, "le", 4)], tradingId);
+    [registryPda] = PublicKey.findProgramAddressSync([Buffer.from("registry")], regId);
+
+    send([await trading.methods.initializeMarket(16).accounts({ market: marketPda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()]);
+    send([await trading.methods.initializeZoneMarket(ZONE, 16, new BN(1_000_000), 10).accounts({ market: marketPda, zoneMarket: zoneMarketPda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()]);
+    cfgKey = await installConfig();
+
+    send([await reg.methods.initialize().accounts({ registry: registryPda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()]);
+    for (const kp of [seller, other]) {
+      const shardId = kp.publicKey.toBytes()[0] % 16;
+      const [shardPda] = PublicKey.findProgramAddressSync([Buffer.from("registry_shard"), Buffer.from([shardId])], regId);
+      if (!svm.getAccount(shardPda)) {
+        send([await reg.methods.initializeShard(shardId).accounts({ shard: shardPda, authority: payer.publicKey, systemProgram: SystemProgram.programId } as any).instruction()]);
+      }
+      send([await reg.methods.registerUser({ prosumer: {} }, 0, 0, new BN(0), shardId).accounts({
+        userAccount: userPda(kp.publicKey), registryShard: shardPda, registry: registryPda,
+        authority: kp.publicKey, payer: payer.publicKey, systemProgram: SystemProgram.programId,
+      } as any).instruction()]);
+    }
+  });
+
+  it("accepts a sell order without a user account (legacy path)", async () => {
+    send([await sellIx(1, null)], [seller]);
+    expect(svm.getAccount(orderPda(seller.publicKey, 1))).to.not.be.null;
+  });
+
+  it("accepts a sell order from an Active registered seller", async () => {
+    send([await sellIx(2, userPda(seller.publicKey))], [seller]);
+    expect(svm.getAccount(orderPda(seller.publicKey, 2))).to.not.be.null;
+  });
+
+  it("rejects another user's account (UnauthorizedAuthority)", async () => {
+    const blob = sendExpectFail([await sellIx(3, userPda(other.publicKey))], [seller]);
+    expect(blob, blob).to.match(/UnauthorizedAuthority/);
+  });
+
+  it("rejects a suspended seller (SellerNotActive)", async () => {
+    send([await statusIx({ suspended: {} })]);
+    const blob = sendExpectFail([await sellIx(4, userPda(seller.publicKey))], [seller]);
+    expect(blob, blob).to.match(/SellerNotActive/);
+  });
+
+  it("accepts the seller again once reactivated", async () => {
+    send([await statusIx({ active: {} })]);
+    send([await sellIx(5, userPda(seller.publicKey))], [seller]);
+    expect(svm.getAccount(orderPda(seller.publicKey, 5))).to.not.be.null;
+  });
+});